@@ -0,0 +1,52 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use glam::DAffine2;
+use std::ops::Mul;
+use support::*;
+
+pub fn random_srt_daffine2(rng: &mut PCG32) -> DAffine2 {
+    DAffine2::from_scale_angle_translation(
+        random_nonzero_vec2(rng).as_dvec2(),
+        random_f64(rng),
+        random_dvec2(rng),
+    )
+}
+
+bench_unop!(daffine2_inverse, "daffine2 inverse", op => inverse, from => random_srt_daffine2);
+
+bench_binop!(
+    daffine2_transform_point2,
+    "daffine2 transform point2",
+    op => transform_point2,
+    from1 => random_srt_daffine2,
+    from2 => random_dvec2
+);
+
+bench_binop!(
+    daffine2_transform_vector2,
+    "daffine2 transform vector2",
+    op => transform_vector2,
+    from1 => random_srt_daffine2,
+    from2 => random_dvec2
+);
+
+bench_binop!(
+    daffine2_mul_daffine2,
+    "daffine2 mul daffine2",
+    op => mul,
+    from => random_srt_daffine2
+);
+
+criterion_group!(
+    benches,
+    daffine2_inverse,
+    daffine2_transform_point2,
+    daffine2_transform_vector2,
+    daffine2_mul_daffine2,
+);
+
+criterion_main!(benches);
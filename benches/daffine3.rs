@@ -0,0 +1,52 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use glam::DAffine3;
+use std::ops::Mul;
+use support::*;
+
+pub fn random_srt_daffine3(rng: &mut PCG32) -> DAffine3 {
+    DAffine3::from_scale_rotation_translation(
+        random_nonzero_vec3(rng).as_dvec3(),
+        random_dquat(rng),
+        random_dvec3(rng),
+    )
+}
+
+bench_unop!(daffine3_inverse, "daffine3 inverse", op => inverse, from => random_srt_daffine3);
+
+bench_binop!(
+    daffine3_transform_point3,
+    "daffine3 transform point3",
+    op => transform_point3,
+    from1 => random_srt_daffine3,
+    from2 => random_dvec3
+);
+
+bench_binop!(
+    daffine3_transform_vector3,
+    "daffine3 transform vector3",
+    op => transform_vector3,
+    from1 => random_srt_daffine3,
+    from2 => random_dvec3
+);
+
+bench_binop!(
+    daffine3_mul_daffine3,
+    "daffine3 mul daffine3",
+    op => mul,
+    from => random_srt_daffine3
+);
+
+criterion_group!(
+    benches,
+    daffine3_inverse,
+    daffine3_transform_point3,
+    daffine3_transform_vector3,
+    daffine3_mul_daffine3,
+);
+
+criterion_main!(benches);
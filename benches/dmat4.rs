@@ -0,0 +1,55 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::ops::Mul;
+use support::*;
+
+bench_unop!(
+    dmat4_transpose,
+    "dmat4 transpose",
+    op => transpose,
+    from => random_srt_dmat4
+);
+
+bench_unop!(
+    dmat4_determinant,
+    "dmat4 determinant",
+    op => determinant,
+    from => random_srt_dmat4
+);
+
+bench_unop!(
+    dmat4_inverse,
+    "dmat4 inverse",
+    op => inverse,
+    from => random_srt_dmat4
+);
+
+bench_binop!(
+    dmat4_mul_dvec4,
+    "dmat4 mul dvec4",
+    op => mul,
+    from1 => random_srt_dmat4,
+    from2 => random_dvec4
+);
+
+bench_binop!(
+    dmat4_mul_dmat4,
+    "dmat4 mul dmat4",
+    op => mul,
+    from => random_srt_dmat4
+);
+
+criterion_group!(
+    benches,
+    dmat4_determinant,
+    dmat4_inverse,
+    dmat4_mul_dmat4,
+    dmat4_mul_dvec4,
+    dmat4_transpose,
+);
+
+criterion_main!(benches);
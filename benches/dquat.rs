@@ -0,0 +1,59 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::ops::Mul;
+use support::*;
+
+bench_binop!(
+    dquat_mul_dvec3,
+    "dquat mul dvec3",
+    op => mul,
+    from1 => random_dquat,
+    from2 => random_dvec3
+);
+
+bench_binop!(
+    dquat_mul_dquat,
+    "dquat mul dquat",
+    op => mul,
+    from => random_dquat
+);
+
+bench_binop!(
+    dquat_dot,
+    "dquat dot",
+    op => dot,
+    from => random_dquat
+);
+
+bench_trinop!(
+    dquat_lerp,
+    "dquat lerp",
+    op => lerp,
+    from1 => random_dquat,
+    from2 => random_dquat,
+    from3 => random_f64
+);
+
+bench_trinop!(
+    dquat_slerp,
+    "dquat slerp",
+    op => slerp,
+    from1 => random_dquat,
+    from2 => random_dquat,
+    from3 => random_f64
+);
+
+criterion_group!(
+    benches,
+    dquat_dot,
+    dquat_lerp,
+    dquat_slerp,
+    dquat_mul_dquat,
+    dquat_mul_dvec3
+);
+
+criterion_main!(benches);
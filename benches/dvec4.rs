@@ -0,0 +1,50 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use glam::DVec4;
+use std::ops::Mul;
+use support::random_dvec4;
+
+bench_binop!(
+    dvec4_mul_dvec4,
+    "dvec4 mul dvec4",
+    op => mul,
+    from1 => random_dvec4,
+    from2 => random_dvec4
+);
+
+bench_binop!(
+    dvec4_dot,
+    "dvec4 dot",
+    op => dot,
+    from1 => random_dvec4,
+    from2 => random_dvec4
+);
+
+bench_unop!(
+    dvec4_normalize,
+    "dvec4 normalize",
+    op => normalize,
+    from => random_dvec4
+);
+
+bench_select!(
+    dvec4_select,
+    "dvec4 select",
+    ty => DVec4,
+    op => cmple,
+    from => random_dvec4
+);
+
+criterion_group!(
+    benches,
+    dvec4_dot,
+    dvec4_mul_dvec4,
+    dvec4_normalize,
+    dvec4_select
+);
+
+criterion_main!(benches);
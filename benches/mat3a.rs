@@ -56,6 +56,18 @@ bench_binop!(
     from2 => random_vec2
 );
 
+#[inline]
+fn mat3a_from_quat_op(q: glam::Quat) -> Mat3A {
+    Mat3A::from_quat(q)
+}
+
+bench_func!(
+    mat3a_from_quat,
+    "mat3a from quat",
+    op => mat3a_from_quat_op,
+    from => random_quat
+);
+
 criterion_group!(
     benches,
     mat3a_transpose,
@@ -65,6 +77,7 @@ criterion_group!(
     mat3a_mul_vec3a,
     mat3a_mul_mat3a,
     mat3a_from_ypr,
+    mat3a_from_quat,
     mat3a_transform_vector2,
     mat3a_transform_point2,
 );
@@ -76,6 +76,34 @@ bench_binop!(
     from => random_srt_mat4
 );
 
+pub fn mat4_mul_mat4_affine(c: &mut Criterion) {
+    const SIZE: usize = 1 << 13;
+    let mut rng = support::PCG32::default();
+    let inputs1 = criterion::black_box(
+        (0..SIZE)
+            .map(|_| random_srt_mat4(&mut rng))
+            .collect::<Vec<_>>(),
+    );
+    let inputs2 = criterion::black_box(
+        (0..SIZE)
+            .map(|_| random_srt_mat4(&mut rng))
+            .collect::<Vec<_>>(),
+    );
+    let mut outputs = vec![Mat4::default(); SIZE];
+    let mut i = 0;
+    c.bench_function("mat4 mul mat4 affine", |b| {
+        b.iter(|| {
+            i = (i + 1) & (SIZE - 1);
+            unsafe {
+                *outputs.get_unchecked_mut(i) = inputs1
+                    .get_unchecked(i)
+                    .mul_mat4_affine(inputs2.get_unchecked(i));
+            }
+        })
+    });
+    criterion::black_box(outputs);
+}
+
 bench_from_ypr!(
     mat4_from_ypr,
     "mat4 from ypr",
@@ -111,13 +139,27 @@ pub fn mat4_from_srt(c: &mut Criterion) {
     });
 }
 
+#[inline]
+fn mat4_from_quat_op(q: glam::Quat) -> Mat4 {
+    Mat4::from_quat(q)
+}
+
+bench_func!(
+    mat4_from_quat,
+    "mat4 from quat",
+    op => mat4_from_quat_op,
+    from => random_quat
+);
+
 criterion_group!(
     benches,
     mat4_determinant,
     mat4_from_srt,
+    mat4_from_quat,
     mat4_from_ypr,
     mat4_inverse,
     mat4_mul_mat4,
+    mat4_mul_mat4_affine,
     mat4_mul_vec4,
     mat4_transform_point3,
     mat4_transform_point3a,
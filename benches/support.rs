@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 use core::f32;
-use glam::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use glam::{
+    DMat4, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4,
+};
 
 pub struct PCG32 {
     state: u64,
@@ -129,3 +131,27 @@ pub fn random_srt_mat4(rng: &mut PCG32) -> Mat4 {
         random_vec3(rng),
     )
 }
+
+pub fn random_dvec4(rng: &mut PCG32) -> DVec4 {
+    random_vec4(rng).as_dvec4()
+}
+
+pub fn random_srt_dmat4(rng: &mut PCG32) -> DMat4 {
+    random_srt_mat4(rng).as_dmat4()
+}
+
+pub fn random_f64(rng: &mut PCG32) -> f64 {
+    rng.next_f32() as f64
+}
+
+pub fn random_dvec2(rng: &mut PCG32) -> DVec2 {
+    random_vec2(rng).as_dvec2()
+}
+
+pub fn random_dvec3(rng: &mut PCG32) -> DVec3 {
+    random_vec3(rng).as_dvec3()
+}
+
+pub fn random_dquat(rng: &mut PCG32) -> DQuat {
+    random_quat(rng).as_dquat()
+}
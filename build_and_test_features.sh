@@ -6,15 +6,17 @@ set -ex
 FEATURE_SETS=(
   # std
   "std"
-  "std approx bytemuck mint rand serde debug-glam-assert"
-  "std scalar-math approx bytemuck mint rand serde debug-glam-assert"
+  "std approx borsh bytemuck fixed mint num-traits quickcheck rand serde simba speedy debug-glam-assert"
+  "std scalar-math approx borsh bytemuck fixed mint num-traits quickcheck rand serde simba speedy debug-glam-assert"
   "std cuda"
   "std scalar-math cuda"
   "std libm"
   "std scalar-math libm"
+  "std deterministic"
   # no_std
   "libm"
-  "libm scalar-math approx bytemuck mint rand serde debug-glam-assert"
+  "deterministic"
+  "libm scalar-math approx bytemuck fixed mint num-traits rand serde debug-glam-assert"
 )
 
 rustc --version
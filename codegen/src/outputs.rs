@@ -154,6 +154,66 @@ impl ContextBuilder {
         Self::new_taffinen(3, "f64")
     }
 
+    fn new_tray3(scalar_t: &str) -> Self {
+        ContextBuilder::new()
+            .with_template("ray.rs.tera")
+            .target_scalar()
+            .with_scalar_t(scalar_t)
+    }
+
+    pub fn new_ray3() -> Self {
+        Self::new_tray3("f32")
+    }
+
+    pub fn new_dray3() -> Self {
+        Self::new_tray3("f64")
+    }
+
+    fn new_tplane(scalar_t: &str) -> Self {
+        ContextBuilder::new()
+            .with_template("plane.rs.tera")
+            .target_scalar()
+            .with_scalar_t(scalar_t)
+    }
+
+    pub fn new_plane() -> Self {
+        Self::new_tplane("f32")
+    }
+
+    pub fn new_dplane() -> Self {
+        Self::new_tplane("f64")
+    }
+
+    fn new_tgeometry(scalar_t: &str) -> Self {
+        ContextBuilder::new()
+            .with_template("geometry.rs.tera")
+            .target_scalar()
+            .with_scalar_t(scalar_t)
+    }
+
+    pub fn new_geometry() -> Self {
+        Self::new_tgeometry("f32")
+    }
+
+    pub fn new_dgeometry() -> Self {
+        Self::new_tgeometry("f64")
+    }
+
+    fn new_taabb3(scalar_t: &str) -> Self {
+        ContextBuilder::new()
+            .with_template("aabb.rs.tera")
+            .target_scalar()
+            .with_scalar_t(scalar_t)
+    }
+
+    pub fn new_aabb3() -> Self {
+        Self::new_taabb3("f32")
+    }
+
+    pub fn new_daabb3() -> Self {
+        Self::new_taabb3("f64")
+    }
+
     pub fn new_bvecn(dim: u32, scalar_t: &str) -> Self {
         ContextBuilder::new()
             .with_template("vec_mask.rs.tera")
@@ -377,6 +437,11 @@ impl ContextBuilder {
         self.with_target(Target::CoreSimd)
     }
 
+    /// Scalar storage with `f64x2` SSE2 intrinsics used for the hot paths.
+    pub fn target_sse2_f64x2(self) -> Self {
+        self.target_scalar().with_key_val("is_f64x2", &true)
+    }
+
     fn with_self_t(mut self, self_t: &str) -> Self {
         self.0.insert("self_t", self_t);
         self
@@ -552,13 +617,35 @@ pub fn build_output_pairs() -> HashMap<&'static str, tera::Context> {
             ContextBuilder::new_affine3a().build(),
         ),
         (
-            "src/f64/daffine2.rs",
-            ContextBuilder::new_daffine2().build(),
+            "src/f64/scalar/daffine2.rs",
+            ContextBuilder::new_daffine2().target_scalar().build(),
+        ),
+        (
+            "src/f64/sse2/daffine2.rs",
+            ContextBuilder::new_daffine2().target_sse2_f64x2().build(),
+        ),
+        (
+            "src/f64/scalar/daffine3.rs",
+            ContextBuilder::new_daffine3().target_scalar().build(),
+        ),
+        (
+            "src/f64/sse2/daffine3.rs",
+            ContextBuilder::new_daffine3().target_sse2_f64x2().build(),
         ),
         (
-            "src/f64/daffine3.rs",
-            ContextBuilder::new_daffine3().build(),
+            "src/f32/geometry.rs",
+            ContextBuilder::new_geometry().build(),
         ),
+        (
+            "src/f64/geometry.rs",
+            ContextBuilder::new_dgeometry().build(),
+        ),
+        ("src/f32/aabb3.rs", ContextBuilder::new_aabb3().build()),
+        ("src/f64/daabb3.rs", ContextBuilder::new_daabb3().build()),
+        ("src/f32/plane.rs", ContextBuilder::new_plane().build()),
+        ("src/f64/dplane.rs", ContextBuilder::new_dplane().build()),
+        ("src/f32/ray3.rs", ContextBuilder::new_ray3().build()),
+        ("src/f64/dray3.rs", ContextBuilder::new_dray3().build()),
         ("src/bool/bvec2.rs", ContextBuilder::new_bvec2().build()),
         ("src/bool/bvec3.rs", ContextBuilder::new_bvec3().build()),
         ("src/bool/bvec4.rs", ContextBuilder::new_bvec4().build()),
@@ -627,7 +714,14 @@ pub fn build_output_pairs() -> HashMap<&'static str, tera::Context> {
         ),
         ("src/f64/dvec2.rs", ContextBuilder::new_dvec2().build()),
         ("src/f64/dvec3.rs", ContextBuilder::new_dvec3().build()),
-        ("src/f64/dvec4.rs", ContextBuilder::new_dvec4().build()),
+        (
+            "src/f64/scalar/dvec4.rs",
+            ContextBuilder::new_dvec4().target_scalar().build(),
+        ),
+        (
+            "src/f64/sse2/dvec4.rs",
+            ContextBuilder::new_dvec4().target_sse2_f64x2().build(),
+        ),
         ("src/i16/i16vec2.rs", ContextBuilder::new_i16vec2().build()),
         ("src/i16/i16vec3.rs", ContextBuilder::new_i16vec3().build()),
         ("src/i16/i16vec4.rs", ContextBuilder::new_i16vec4().build()),
@@ -659,7 +753,14 @@ pub fn build_output_pairs() -> HashMap<&'static str, tera::Context> {
             "src/f32/coresimd/quat.rs",
             ContextBuilder::new_quat().target_coresimd().build(),
         ),
-        ("src/f64/dquat.rs", ContextBuilder::new_dquat().build()),
+        (
+            "src/f64/scalar/dquat.rs",
+            ContextBuilder::new_dquat().target_scalar().build(),
+        ),
+        (
+            "src/f64/sse2/dquat.rs",
+            ContextBuilder::new_dquat().target_sse2_f64x2().build(),
+        ),
         ("src/f32/scalar/mat2.rs", ContextBuilder::new_mat2().build()),
         (
             "src/f32/sse2/mat2.rs",
@@ -705,7 +806,14 @@ pub fn build_output_pairs() -> HashMap<&'static str, tera::Context> {
             ContextBuilder::new_mat4().target_coresimd().build(),
         ),
         ("src/f64/dmat3.rs", ContextBuilder::new_dmat3().build()),
-        ("src/f64/dmat4.rs", ContextBuilder::new_dmat4().build()),
+        (
+            "src/f64/scalar/dmat4.rs",
+            ContextBuilder::new_dmat4().target_scalar().build(),
+        ),
+        (
+            "src/f64/sse2/dmat4.rs",
+            ContextBuilder::new_dmat4().target_sse2_f64x2().build(),
+        ),
         ("src/f32/float.rs", ContextBuilder::new_float("f32").build()),
         ("src/f64/float.rs", ContextBuilder::new_float("f64").build()),
     ])
@@ -0,0 +1,122 @@
+//! Control over how failed `glam` assertions are handled.
+//!
+//! When the `glam-assert` or `debug-glam-assert` features are enabled `glam` checks that the
+//! inputs to many methods are valid, for example that a vector is normalized or that a matrix is
+//! invertible. By default a failed check panics, [`set_handler`] can be used to install a
+//! different [`AssertHandler`], for example one which logs the failure and continues so that
+//! invariant violations can be recorded in shipping builds without crashing.
+//!
+//! If the handler returns the method which failed the check continues with the invalid input
+//! and its result is unspecified, as it would be without the assertion enabled.
+//!
+//! ```
+//! fn log_and_continue(info: &glam::assert::AssertInfo<'_>) {
+//!     eprintln!("glam assertion failed at {}: {}", info.location(), info);
+//! }
+//!
+//! glam::assert::set_handler(log_and_continue);
+//! ```
+
+use core::fmt;
+use core::panic::Location;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// A function which is called when a `glam` assertion fails.
+pub type AssertHandler = fn(&AssertInfo<'_>);
+
+/// Information about a failed `glam` assertion which is passed to the [`AssertHandler`].
+#[derive(Clone, Copy, Debug)]
+pub struct AssertInfo<'a> {
+    condition: &'static str,
+    message: Option<fmt::Arguments<'a>>,
+    location: &'static Location<'static>,
+}
+
+impl<'a> AssertInfo<'a> {
+    /// Returns the source text of the condition which failed.
+    #[inline]
+    #[must_use]
+    pub fn condition(&self) -> &'static str {
+        self.condition
+    }
+
+    /// Returns the message of the assertion, if it has one.
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> Option<fmt::Arguments<'a>> {
+        self.message
+    }
+
+    /// Returns the location of the assertion in the `glam` source.
+    #[inline]
+    #[must_use]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+/// Writes the message of the assertion, or the failed condition if it has no message.
+impl fmt::Display for AssertInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(message) = self.message {
+            fmt::Display::fmt(&message, f)
+        } else {
+            write!(f, "assertion failed: {}", self.condition)
+        }
+    }
+}
+
+/// The installed handler, null if the default handler is used.
+static HANDLER: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Installs `handler` to be called when a `glam` assertion fails, replacing the previous handler.
+///
+/// The handler is global and shared by all threads.
+pub fn set_handler(handler: AssertHandler) {
+    HANDLER.store(handler as *mut (), Ordering::Release);
+}
+
+/// Restores the default handler, [`panic_handler`].
+pub fn reset_handler() {
+    HANDLER.store(core::ptr::null_mut(), Ordering::Release);
+}
+
+/// Returns the currently installed handler.
+#[must_use]
+pub fn handler() -> AssertHandler {
+    let ptr = HANDLER.load(Ordering::Acquire);
+    if ptr.is_null() {
+        panic_handler
+    } else {
+        // SAFETY: non-null values are only ever stored by `set_handler` from an `AssertHandler`
+        unsafe { core::mem::transmute::<*mut (), AssertHandler>(ptr) }
+    }
+}
+
+/// The default handler, panics with the assertion message and location.
+pub fn panic_handler(info: &AssertInfo<'_>) {
+    panic!("{} at {}", info, info.location());
+}
+
+/// A handler which ignores failed assertions.
+pub fn ignore_handler(_info: &AssertInfo<'_>) {}
+
+/// A handler which prints the assertion message and location to stderr and aborts the process.
+#[cfg(feature = "std")]
+pub fn abort_handler(info: &AssertInfo<'_>) {
+    std::eprintln!("glam assertion failed: {} at {}", info, info.location());
+    std::process::abort();
+}
+
+/// Called by `glam_assert!` when an assertion fails.
+#[cold]
+#[inline(never)]
+#[track_caller]
+#[allow(dead_code)]
+pub(crate) fn fail(condition: &'static str, message: Option<fmt::Arguments<'_>>) {
+    handler()(&AssertInfo {
+        condition,
+        message,
+        location: Location::caller(),
+    });
+}
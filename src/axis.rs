@@ -0,0 +1,50 @@
+/// A coordinate axis, used to access vector elements and matrix columns by name rather than by
+/// a raw `usize` index.
+///
+/// Using an axis which a type does not have, such as [`Axis::Z`] with a 2D vector, panics the
+/// same as an out of bounds `usize` index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Axis {
+    /// The x axis, index 0.
+    X,
+    /// The y axis, index 1.
+    Y,
+    /// The z axis, index 2.
+    Z,
+    /// The w axis, index 3.
+    W,
+}
+
+impl Axis {
+    /// All axes in index order.
+    ///
+    /// Use a sub-slice such as `&Axis::ALL[..3]` to loop over the axes of a 3D type.
+    pub const ALL: [Self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
+
+    /// Returns the element index of this axis.
+    #[inline]
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the axis with the given element `index`, or `None` if `index` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub const fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Self::X),
+            1 => Some(Self::Y),
+            2 => Some(Self::Z),
+            3 => Some(Self::W),
+            _ => None,
+        }
+    }
+}
+
+impl From<Axis> for usize {
+    #[inline]
+    fn from(axis: Axis) -> Self {
+        axis.index()
+    }
+}
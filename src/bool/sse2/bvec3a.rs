@@ -57,6 +57,16 @@ impl BVec3A {
         Self::new(a[0], a[1], a[2])
     }
 
+    /// Creates a new vector mask from the lowest 3 bits of `bitmask`.
+    ///
+    /// A `1` bit results in a true element and a `0` bit in a false element. This is the
+    /// inverse of [`Self::bitmask()`], any higher bits are ignored.
+    #[inline]
+    #[must_use]
+    pub const fn from_bitmask(bitmask: u32) -> Self {
+        Self::new((bitmask & 1) != 0, (bitmask & 2) != 0, (bitmask & 4) != 0)
+    }
+
     /// Returns a bitmask with the lowest 3 bits set from the elements of `self`.
     ///
     /// A true element results in a `1` bit and a false element in a `0` bit.  Element `x` goes
@@ -81,6 +91,13 @@ impl BVec3A {
         self.bitmask() == 0x7
     }
 
+    /// Returns the number of elements that are true.
+    #[inline]
+    #[must_use]
+    pub fn count_true(self) -> u32 {
+        self.bitmask().count_ones()
+    }
+
     /// Tests the value at `index`.
     ///
     /// Panics if `index` is greater than 2.
@@ -231,6 +248,17 @@ impl fmt::Display for BVec3A {
     }
 }
 
+impl IntoIterator for BVec3A {
+    type Item = bool;
+    type IntoIter = core::array::IntoIter<bool, 3>;
+
+    /// Returns an iterator over the elements of `self` as `bool` values.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_bool_array().into_iter()
+    }
+}
+
 impl From<[bool; 3]> for BVec3A {
     #[inline]
     fn from(a: [bool; 3]) -> Self {
@@ -0,0 +1,78 @@
+//! Reinterpreting slices of vectors and matrices as slices of their scalar elements and back.
+//!
+//! The public API is the `as_flat_slice` and `from_flat_slice` family of associated functions on
+//! each type, which are only generated for types without padding.
+
+use crate::error::Error;
+use core::mem::{align_of, size_of};
+use core::slice;
+
+/// Returns `items` as a slice of their `S` elements.
+///
+/// # Safety
+///
+/// `T` must consist of exactly `size_of::<T>() / size_of::<S>()` consecutive `S` values with no
+/// padding.
+#[inline(always)]
+pub(crate) unsafe fn flatten<T, S>(items: &[T]) -> &[S] {
+    let len = items.len() * (size_of::<T>() / size_of::<S>());
+    slice::from_raw_parts(items.as_ptr().cast::<S>(), len)
+}
+
+/// See [`flatten`].
+#[inline(always)]
+pub(crate) unsafe fn flatten_mut<T, S>(items: &mut [T]) -> &mut [S] {
+    let len = items.len() * (size_of::<T>() / size_of::<S>());
+    slice::from_raw_parts_mut(items.as_mut_ptr().cast::<S>(), len)
+}
+
+/// Checks that `flat` can be reinterpreted as a slice of `T`, returning the number of items.
+///
+/// The alignment of an empty `flat` is not checked, as its dangling pointer is only aligned for
+/// `S`. Callers must return an empty slice instead of casting the pointer in that case.
+#[inline(always)]
+fn check_unflatten<T, S>(flat: &[S]) -> Result<usize, Error> {
+    let multiple = size_of::<T>() / size_of::<S>();
+    let remainder = flat.len() % multiple;
+    if remainder != 0 {
+        Err(Error::SliceRemainder {
+            multiple,
+            remainder,
+        })
+    } else if !flat.is_empty() && flat.as_ptr() as usize % align_of::<T>() != 0 {
+        Err(Error::SliceMisaligned {
+            align: align_of::<T>(),
+        })
+    } else {
+        Ok(flat.len() / multiple)
+    }
+}
+
+/// Returns `flat` as a slice of `T`, or an error if its length is not a multiple of the number
+/// of `S` elements in `T` or it is not aligned for `T`.
+///
+/// # Safety
+///
+/// `T` must consist of exactly `size_of::<T>() / size_of::<S>()` consecutive `S` values with no
+/// padding, and every bit pattern of those values must be valid for `T`.
+#[inline(always)]
+pub(crate) unsafe fn unflatten<S, T>(flat: &[S]) -> Result<&[T], Error> {
+    let len = check_unflatten::<T, S>(flat)?;
+    if len == 0 {
+        return Ok(&[]);
+    }
+    Ok(slice::from_raw_parts(flat.as_ptr().cast::<T>(), len))
+}
+
+/// See [`unflatten`].
+#[inline(always)]
+pub(crate) unsafe fn unflatten_mut<S, T>(flat: &mut [S]) -> Result<&mut [T], Error> {
+    let len = check_unflatten::<T, S>(flat)?;
+    if len == 0 {
+        return Ok(&mut []);
+    }
+    Ok(slice::from_raw_parts_mut(
+        flat.as_mut_ptr().cast::<T>(),
+        len,
+    ))
+}
@@ -0,0 +1,202 @@
+//! Support code for the `vec2!`, `vec3!`, `vec4!` and `mat4!` construction macros.
+//!
+//! The macros wrap each argument in an [`Arg`] which has an inherent `const fn` for every
+//! supported argument type, allowing scalars and vectors to be mixed in `const` contexts where
+//! trait methods can't be called.
+
+use crate::{Vec2, Vec3, Vec3A, Vec4};
+
+/// Up to four components of a macro argument.
+#[derive(Clone, Copy)]
+pub struct Part {
+    data: [f32; 4],
+    len: usize,
+}
+
+/// A macro argument, see the [module level documentation](self).
+pub struct Arg<T>(pub T);
+
+impl Arg<f32> {
+    #[inline]
+    pub const fn part(self) -> Part {
+        Part {
+            data: [self.0, 0.0, 0.0, 0.0],
+            len: 1,
+        }
+    }
+}
+
+impl Arg<Vec2> {
+    #[inline]
+    pub const fn part(self) -> Part {
+        let [x, y] = self.0.to_array();
+        Part {
+            data: [x, y, 0.0, 0.0],
+            len: 2,
+        }
+    }
+}
+
+impl Arg<Vec3> {
+    #[inline]
+    pub const fn part(self) -> Part {
+        let [x, y, z] = self.0.to_array();
+        Part {
+            data: [x, y, z, 0.0],
+            len: 3,
+        }
+    }
+}
+
+impl Arg<Vec3A> {
+    #[inline]
+    pub const fn part(self) -> Part {
+        let [x, y, z] = self.0.to_array();
+        Part {
+            data: [x, y, z, 0.0],
+            len: 3,
+        }
+    }
+}
+
+impl Arg<Vec4> {
+    #[inline]
+    pub const fn part(self) -> Part {
+        Part {
+            data: self.0.to_array(),
+            len: 4,
+        }
+    }
+}
+
+/// Concatenates the components of `parts`, which must total exactly `N`.
+const fn concat<const N: usize>(parts: &[Part]) -> [f32; N] {
+    let mut out = [0.0; N];
+    let mut n = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let mut j = 0;
+        while j < parts[i].len {
+            assert!(n < N, "too many components for the constructed type");
+            out[n] = parts[i].data[j];
+            n += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(n == N, "too few components for the constructed type");
+    out
+}
+
+/// Splats a single scalar part to all `N` components, otherwise concatenates `parts`.
+#[inline]
+pub const fn vector<const N: usize>(parts: &[Part]) -> [f32; N] {
+    if parts.len() == 1 && parts[0].len == 1 {
+        [parts[0].data[0]; N]
+    } else {
+        concat(parts)
+    }
+}
+
+/// Places a single scalar part on the diagonal of a `DIM` x `DIM` matrix, otherwise concatenates
+/// `parts` in column-major order.
+#[inline]
+pub const fn matrix<const DIM: usize, const N: usize>(parts: &[Part]) -> [f32; N] {
+    if parts.len() == 1 && parts[0].len == 1 {
+        let mut out = [0.0; N];
+        let mut i = 0;
+        while i < DIM {
+            out[i * DIM + i] = parts[0].data[0];
+            i += 1;
+        }
+        out
+    } else {
+        concat(parts)
+    }
+}
+
+/// Creates a [`Vec2`](crate::Vec2) from a mix of scalars and vectors.
+///
+/// A single scalar is splatted to all components, otherwise the components of the arguments are
+/// concatenated and must total exactly two. The macro can be used in `const` contexts.
+///
+/// ```
+/// use glam::{vec2, Vec2};
+/// const HALF: Vec2 = vec2!(0.5);
+/// assert_eq!(HALF, Vec2::new(0.5, 0.5));
+/// assert_eq!(vec2!(1.0, 2.0), Vec2::new(1.0, 2.0));
+/// ```
+#[macro_export]
+macro_rules! vec2 {
+    ($($arg:expr),+ $(,)?) => {
+        $crate::Vec2::from_array($crate::construct::vector::<2>(&[
+            $($crate::construct::Arg($arg).part()),+
+        ]))
+    };
+}
+
+/// Creates a [`Vec3`](crate::Vec3) from a mix of scalars and vectors.
+///
+/// A single scalar is splatted to all components, otherwise the components of the arguments are
+/// concatenated and must total exactly three. The macro can be used in `const` contexts.
+///
+/// ```
+/// use glam::{vec2, vec3, Vec2, Vec3};
+/// const HALF: Vec3 = vec3!(0.5);
+/// assert_eq!(HALF, Vec3::new(0.5, 0.5, 0.5));
+/// assert_eq!(vec3!(Vec2::new(1.0, 2.0), 3.0), Vec3::new(1.0, 2.0, 3.0));
+/// assert_eq!(vec3!(1.0, vec2!(2.0, 3.0)), Vec3::new(1.0, 2.0, 3.0));
+/// ```
+#[macro_export]
+macro_rules! vec3 {
+    ($($arg:expr),+ $(,)?) => {
+        $crate::Vec3::from_array($crate::construct::vector::<3>(&[
+            $($crate::construct::Arg($arg).part()),+
+        ]))
+    };
+}
+
+/// Creates a [`Vec4`](crate::Vec4) from a mix of scalars and vectors.
+///
+/// A single scalar is splatted to all components, otherwise the components of the arguments are
+/// concatenated and must total exactly four. The macro can be used in `const` contexts.
+///
+/// ```
+/// use glam::{vec4, Vec2, Vec3, Vec4};
+/// const POINT: Vec4 = vec4!(Vec3::new(1.0, 2.0, 3.0), 1.0);
+/// assert_eq!(POINT, Vec4::new(1.0, 2.0, 3.0, 1.0));
+/// assert_eq!(vec4!(Vec2::X, Vec2::Y), Vec4::new(1.0, 0.0, 0.0, 1.0));
+/// ```
+#[macro_export]
+macro_rules! vec4 {
+    ($($arg:expr),+ $(,)?) => {
+        $crate::Vec4::from_array($crate::construct::vector::<4>(&[
+            $($crate::construct::Arg($arg).part()),+
+        ]))
+    };
+}
+
+/// Creates a [`Mat4`](crate::Mat4) from a mix of scalars and vectors.
+///
+/// A single scalar is placed on the diagonal with all other elements zero, otherwise the
+/// components of the arguments are concatenated in column-major order and must total exactly
+/// sixteen. The macro can be used in `const` contexts.
+///
+/// ```
+/// use glam::{mat4, vec4, Mat4, Vec3, Vec4};
+/// const IDENTITY: Mat4 = mat4!(1.0);
+/// assert_eq!(IDENTITY, Mat4::IDENTITY);
+/// let t = Vec3::new(1.0, 2.0, 3.0);
+/// assert_eq!(
+///     mat4!(Vec4::X, Vec4::Y, Vec4::Z, vec4!(t, 1.0)),
+///     Mat4::from_translation(t)
+/// );
+/// ```
+#[macro_export]
+macro_rules! mat4 {
+    ($($arg:expr),+ $(,)?) => {
+        $crate::Mat4::from_cols_array(&$crate::construct::matrix::<4, 16>(&[
+            $($crate::construct::Arg($arg).part()),+
+        ]))
+    };
+}
@@ -42,6 +42,32 @@ pub(crate) fn dot4_into_f32x4(lhs: f32x4, rhs: f32x4) -> f32x4 {
     simd_swizzle!(dot_in_x, [0, 0, 0, 0])
 }
 
+/// Returns the x, y and z axes of the rotation matrix of the normalized quaternion `q`, with the w
+/// lane of each axis set to zero.
+///
+/// The products and sums are computed in the same order as the scalar implementation, so the
+/// result is identical to it.
+#[inline]
+pub(crate) fn f32x4_quat_to_axes(q: f32x4) -> [f32x4; 3] {
+    // 2x 2y 2z 0
+    let q2 = q * f32x4::from_array([2.0, 2.0, 2.0, 0.0]);
+    // 2xx 2yy 2zz 0
+    let sq = q * q2;
+    let diag = f32x4::from_array([1.0, 1.0, 1.0, 0.0])
+        - (simd_swizzle!(sq, [1, 0, 0, 3]) + simd_swizzle!(sq, [2, 2, 1, 3]));
+    // 2xy 2xz 2yz 0
+    let v0 = simd_swizzle!(q, [0, 0, 1, 3]) * simd_swizzle!(q2, [1, 2, 2, 3]);
+    // 2wz 2wy 2wx 0
+    let v1 = simd_swizzle!(q, [3, 3, 3, 3]) * simd_swizzle!(q2, [2, 1, 0, 3]);
+    let r1 = v0 + v1;
+    let r2 = v0 - v1;
+    [
+        simd_swizzle!(simd_swizzle!(diag, r1, [0, 4, 1, 5]), r2, [0, 1, 5, 7]),
+        simd_swizzle!(simd_swizzle!(r2, diag, [0, 0, 5, 5]), r1, [0, 2, 6, 7]),
+        simd_swizzle!(simd_swizzle!(r1, r2, [1, 1, 6, 6]), diag, [0, 2, 6, 7]),
+    ]
+}
+
 #[inline(always)]
 pub(crate) fn f32x4_bitand(a: f32x4, b: f32x4) -> f32x4 {
     let a = a.to_bits();
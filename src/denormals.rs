@@ -0,0 +1,195 @@
+//! Control over the handling of denormal floating point numbers.
+//!
+//! Denormal (or subnormal) numbers are floating point values which are smaller in magnitude than
+//! [`f32::MIN_POSITIVE`]. On many CPUs arithmetic which consumes or produces denormals is
+//! dramatically slower than arithmetic on normal values, which can cause large slowdowns in hot
+//! loops such as audio processing or physics simulation where values decay towards zero.
+//!
+//! [`FlushDenormalsGuard`] enables the CPU flush-to-zero (FTZ) and denormals-are-zero (DAZ) modes
+//! for the current thread while it is alive, treating denormal inputs as zero and replacing
+//! denormal results with zero. It is supported on `x86` with SSE, `x86_64` and `aarch64`, on other
+//! targets it does nothing.
+//!
+//! # Operations which can produce denormals
+//!
+//! In general any operation on values with a very small magnitude can produce denormals, in
+//! `glam` these are commonly:
+//!
+//! * multiplying vectors, matrices or quaternions by small scalars or by each other, for example
+//!   repeatedly scaling by a damping factor or computing the square of a small length with
+//!   `length_squared` or `dot`
+//! * `lerp`, `slerp` and `move_towards` when the difference between the inputs is very small
+//! * `normalize`, `length_recip` and `project_onto` of vectors with a very small length, which
+//!   may also produce infinite or `NaN` results
+//! * matrix `inverse` and `determinant` of nearly singular matrices
+//! * trigonometric functions of angles close to zero, for example `from_rotation_x` or
+//!   `from_axis_angle` with a tiny angle
+
+/// A guard which enables flush-to-zero and denormals-are-zero modes for the current thread until
+/// it is dropped, restoring the previous mode.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Debug)]
+#[must_use = "denormals are only flushed until the guard is dropped"]
+pub struct FlushDenormalsGuard {
+    previous: u64,
+    // the floating point control register is per thread
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+impl FlushDenormalsGuard {
+    /// Returns true if flushing denormals is supported on the current target.
+    #[inline]
+    #[must_use]
+    pub const fn is_supported() -> bool {
+        cfg!(any(
+            target_arch = "x86_64",
+            all(target_arch = "x86", target_feature = "sse"),
+            target_arch = "aarch64"
+        ))
+    }
+
+    /// Enables flush-to-zero and denormals-are-zero modes for the current thread.
+    ///
+    /// # Safety
+    ///
+    /// The Rust compiler assumes that the default floating point environment is always in use and
+    /// may evaluate floating point expressions at compile time using default rounding and
+    /// denormal handling. The caller must ensure that code executed while the guard is alive does
+    /// not rely on denormal values being preserved, and that the guard is dropped in the same
+    /// scope it was created in.
+    #[inline]
+    pub unsafe fn new() -> Self {
+        let previous = read_control();
+        write_control(previous | FLUSH_DENORMALS);
+        Self {
+            previous,
+            _not_send: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Drop for FlushDenormalsGuard {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: restores the mode that was active when the guard was created
+        unsafe { write_control(self.previous) }
+    }
+}
+
+// MXCSR bit 15 is flush-to-zero, bit 6 is denormals-are-zero
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse")
+))]
+const FLUSH_DENORMALS: u64 = 0x8040;
+
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse")
+))]
+#[inline]
+fn read_control() -> u64 {
+    let mut mxcsr = 0_u32;
+    // SAFETY: `stmxcsr` only stores the MXCSR register to memory
+    unsafe {
+        core::arch::asm!(
+            "stmxcsr [{}]",
+            in(reg) &mut mxcsr,
+            options(nostack, preserves_flags)
+        );
+    }
+    u64::from(mxcsr)
+}
+
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse")
+))]
+#[inline]
+unsafe fn write_control(value: u64) {
+    let mxcsr = value as u32;
+    core::arch::asm!(
+        "ldmxcsr [{}]",
+        in(reg) &mxcsr,
+        options(nostack, preserves_flags, readonly)
+    );
+}
+
+// FPCR bit 24 is flush-to-zero, which also flushes denormal inputs
+#[cfg(target_arch = "aarch64")]
+const FLUSH_DENORMALS: u64 = 1 << 24;
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+fn read_control() -> u64 {
+    let fpcr: u64;
+    // SAFETY: reading FPCR has no side effects
+    unsafe {
+        core::arch::asm!(
+            "mrs {}, fpcr",
+            out(reg) fpcr,
+            options(nomem, nostack, preserves_flags)
+        );
+    }
+    fpcr
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+unsafe fn write_control(value: u64) {
+    core::arch::asm!(
+        "msr fpcr, {}",
+        in(reg) value,
+        options(nomem, nostack, preserves_flags)
+    );
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse"),
+    target_arch = "aarch64"
+)))]
+const FLUSH_DENORMALS: u64 = 0;
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse"),
+    target_arch = "aarch64"
+)))]
+#[inline]
+fn read_control() -> u64 {
+    0
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse"),
+    target_arch = "aarch64"
+)))]
+#[inline]
+unsafe fn write_control(_value: u64) {}
+
+#[cfg(test)]
+mod test {
+    use super::FlushDenormalsGuard;
+    use core::hint::black_box;
+
+    #[test]
+    fn test_flush_denormals_guard() {
+        let min = black_box(f32::MIN_POSITIVE);
+        let half = black_box(0.5_f32);
+        assert_ne!(min * half, 0.0);
+        {
+            let _guard = unsafe { FlushDenormalsGuard::new() };
+            let result = black_box(black_box(min) * black_box(half));
+            if FlushDenormalsGuard::is_supported() {
+                assert_eq!(result, 0.0);
+                // denormal inputs are treated as zero
+                let denormal = black_box(f32::from_bits(1));
+                assert_eq!(black_box(denormal * black_box(2.0_f32)), 0.0);
+            }
+        }
+        assert_ne!(black_box(black_box(min) * black_box(half)), 0.0);
+    }
+}
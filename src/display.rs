@@ -0,0 +1,100 @@
+use core::fmt;
+
+/// Counts the number of characters written to it, used for measuring column widths.
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes `value` with the given precision and sign flag, zero padded to `zero_pad_width`.
+fn write_value<T: fmt::Display>(
+    w: &mut impl fmt::Write,
+    value: &T,
+    precision: Option<usize>,
+    sign_plus: bool,
+    zero_pad_width: usize,
+) -> fmt::Result {
+    match (precision, sign_plus) {
+        (Some(p), true) => write!(w, "{:+0w$.p$}", value, w = zero_pad_width, p = p),
+        (Some(p), false) => write!(w, "{:0w$.p$}", value, w = zero_pad_width, p = p),
+        (None, true) => write!(w, "{:+0w$}", value, w = zero_pad_width),
+        (None, false) => write!(w, "{:0w$}", value, w = zero_pad_width),
+    }
+}
+
+fn write_fill(f: &mut fmt::Formatter<'_>, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        fmt::Write::write_char(f, f.fill())?;
+    }
+    Ok(())
+}
+
+/// Writes `values` as a comma separated list surrounded by `[` and `]`.
+///
+/// Each value is formatted with the flags of `f`, so width, fill, alignment, sign and precision
+/// apply to every element.
+pub(crate) fn fmt_list<T: fmt::Display>(f: &mut fmt::Formatter<'_>, values: &[T]) -> fmt::Result {
+    f.write_str("[")?;
+    for (i, value) in values.iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        fmt::Display::fmt(value, f)?;
+    }
+    f.write_str("]")
+}
+
+/// Writes the column major `cols` as a multi-line grid with one row of the matrix per line.
+///
+/// Values are right aligned so that each column is as wide as its widest value, or the width of
+/// `f` if that is larger. Like [`fmt_list`] the fill, alignment, sign, zero padding and precision
+/// of `f` apply to every value.
+pub(crate) fn fmt_grid<T: fmt::Display, const R: usize, const C: usize>(
+    f: &mut fmt::Formatter<'_>,
+    cols: &[[T; R]; C],
+) -> fmt::Result {
+    let precision = f.precision();
+    let sign_plus = f.sign_plus();
+    let mut widths = [f.width().unwrap_or(0); C];
+    for (width, col) in widths.iter_mut().zip(cols) {
+        for value in col {
+            let mut count = CharCount(0);
+            write_value(&mut count, value, precision, sign_plus, 0)?;
+            *width = (*width).max(count.0);
+        }
+    }
+
+    for row in 0..R {
+        if row != 0 {
+            f.write_str("\n")?;
+        }
+        f.write_str("[")?;
+        for (i, (&width, col)) in widths.iter().zip(cols).enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            if f.sign_aware_zero_pad() {
+                // zero padding ignores fill and alignment, as it does for a single value
+                write_value(f, &col[row], precision, sign_plus, width)?;
+                continue;
+            }
+            let mut count = CharCount(0);
+            write_value(&mut count, &col[row], precision, sign_plus, 0)?;
+            let padding = width.saturating_sub(count.0);
+            let (before, after) = match f.align() {
+                Some(fmt::Alignment::Left) => (0, padding),
+                Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+                Some(fmt::Alignment::Right) | None => (padding, 0),
+            };
+            write_fill(f, before)?;
+            write_value(f, &col[row], precision, sign_plus, 0)?;
+            write_fill(f, after)?;
+        }
+        f.write_str("]")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,131 @@
+//! Error types returned by fallible `glam` operations.
+//!
+//! Many `glam` functions assume that their inputs are valid and will panic or return
+//! meaningless results when they are not, checking some of these preconditions with
+//! `glam_assert!` when the `glam-assert` or `debug-glam-assert` features are enabled. Functions
+//! with a `_checked` suffix validate their input instead and return an [`Error`] so that it can be
+//! propagated to the caller.
+
+use core::fmt;
+
+pub use crate::parse::ParseError;
+
+/// An error returned by the `_checked` variants of `glam` functions.
+///
+/// More variants may be added as new checked functions are introduced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A slice contained fewer elements than required.
+    SliceTooShort {
+        /// The number of elements required.
+        required: usize,
+        /// The number of elements in the slice.
+        found: usize,
+    },
+    /// A slice could not be reinterpreted because its length is not a multiple of the number of
+    /// elements in the target type.
+    SliceRemainder {
+        /// The number of elements in the target type.
+        multiple: usize,
+        /// The number of elements left over.
+        remainder: usize,
+    },
+    /// A slice could not be reinterpreted because it is not aligned for the target type.
+    SliceMisaligned {
+        /// The required alignment in bytes.
+        align: usize,
+    },
+    /// An iterator yielded fewer elements than required.
+    IterTooShort {
+        /// The number of elements required.
+        required: usize,
+        /// The number of elements the iterator yielded.
+        found: usize,
+    },
+    /// A vector could not be normalized because its length is zero, infinite or `NaN`.
+    NonNormalizable,
+    /// A matrix could not be inverted because it is singular or close to singular, or its inverse
+    /// would overflow.
+    Singular,
+    /// A matrix is not a pure rotation, either its axes are not orthonormal or it contains a
+    /// reflection.
+    NotOrthonormal,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::SliceTooShort { required, found } => {
+                write!(
+                    f,
+                    "expected at least {} elements, found {}",
+                    required, found
+                )
+            }
+            Self::SliceRemainder {
+                multiple,
+                remainder,
+            } => {
+                write!(
+                    f,
+                    "slice length is not a multiple of {}, {} elements left over",
+                    multiple, remainder
+                )
+            }
+            Self::SliceMisaligned { align } => {
+                write!(f, "slice is not aligned to {} bytes", align)
+            }
+            Self::IterTooShort { required, found } => {
+                write!(
+                    f,
+                    "expected at least {} elements from iterator, found {}",
+                    required, found
+                )
+            }
+            Self::NonNormalizable => f.write_str("vector length is zero or not finite"),
+            Self::Singular => f.write_str("matrix is not invertible"),
+            Self::NotOrthonormal => f.write_str("matrix is not a pure rotation"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// An error returned by the `TryFrom` conversions between vector types when a component cannot
+/// be represented exactly by the target scalar type.
+///
+/// Integer components fail when they are out of range of the target type. Float components
+/// also fail when they are `NaN`, infinite or have a fractional part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromVecError {
+    /// The index of the first component which could not be converted.
+    pub index: usize,
+}
+
+impl fmt::Display for TryFromVecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "vector component {} cannot be represented by the target type",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromVecError {}
+
+/// Returns an error if `slice` is shorter than `required`.
+#[inline]
+pub(crate) fn check_slice_len<T>(slice: &[T], required: usize) -> Result<(), Error> {
+    if slice.len() < required {
+        Err(Error::SliceTooShort {
+            required,
+            found: slice.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
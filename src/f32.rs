@@ -1,8 +1,14 @@
+mod aabb3;
 mod affine2;
 mod affine3a;
+mod double;
 mod float;
+mod frustum;
+pub mod geometry;
 mod mat3;
 pub(crate) mod math;
+mod plane;
+mod ray3;
 mod vec2;
 mod vec3;
 
@@ -56,13 +62,18 @@ use wasm32::*;
 #[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
 use coresimd::*;
 
+pub use aabb3::Aabb3;
 pub use affine2::Affine2;
 pub use affine3a::Affine3A;
+pub use double::{DoubleF32, Vec3X};
+pub use frustum::Frustum;
 pub use mat2::{mat2, Mat2};
 pub use mat3::{mat3, Mat3};
 pub use mat3a::{mat3a, Mat3A};
 pub use mat4::{mat4, Mat4};
+pub use plane::Plane;
 pub use quat::{quat, Quat};
+pub use ray3::{Ray3, RayHit};
 pub use vec2::{vec2, Vec2};
 pub use vec3::{vec3, Vec3};
 pub use vec3a::{vec3a, Vec3A};
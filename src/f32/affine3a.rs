@@ -68,6 +68,62 @@ impl Affine3A {
         [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z, w.x, w.y, w.z]
     }
 
+    /// Creates an affine transform from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 48]) -> Self {
+        let mut a = [f32::default(); 12];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 48] {
+        let mut bytes = [0; 48];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates an affine transform from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 48]) -> Self {
+        let mut a = [f32::default(); 12];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 48] {
+        let mut bytes = [0; 48];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates an affine transform from a `[[f32; 3]; 4]`
     /// 3D array stored in column major order.
     /// If your data is in row major order you will need to `transpose` the returned
@@ -109,6 +165,14 @@ impl Affine3A {
         }
     }
 
+    /// Creates an affine transform from the first 12 values in `slice`, returning an
+    /// error if `slice` is less than 12 elements long.
+    #[inline]
+    pub fn from_cols_slice_checked(slice: &[f32]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 12)?;
+        Ok(Self::from_cols_slice(slice))
+    }
+
     /// Writes the columns of `self` to the first 12 elements in `slice`.
     ///
     /// # Panics
@@ -440,6 +504,30 @@ impl Affine3A {
             && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(&self, rhs: Self, max_ulps: u32) -> bool {
+        self.matrix3.ulps_eq(rhs.matrix3, max_ulps)
+            && self.translation.ulps_eq(rhs.translation, max_ulps)
+    }
+
+    /// Returns `true` if this transform mirrors space, reversing the winding order of
+    /// transformed triangles.
+    ///
+    /// See [`Mat3A::is_flipping()`].
+    #[inline]
+    #[must_use]
+    pub fn is_flipping(&self) -> bool {
+        self.matrix3.is_flipping()
+    }
+
     /// Return the inverse of this transform.
     ///
     /// Note that if the transform is not invertible the result will be invalid.
@@ -455,6 +543,38 @@ impl Affine3A {
             translation,
         }
     }
+
+    /// Returns `true` if this transform can be inverted.
+    ///
+    /// See [`Mat3A::is_invertible()`].
+    #[inline]
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        self.matrix3.is_invertible()
+    }
+
+    /// Returns the inverse of this transform, or `None` if the transform is not invertible.
+    ///
+    /// See [`Mat3A::try_inverse()`].
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let matrix3 = self.matrix3.try_inverse()?;
+        // transform negative translation by the matrix inverse:
+        let translation = -(matrix3 * self.translation);
+        Some(Self {
+            matrix3,
+            translation,
+        })
+    }
+
+    /// Returns the inverse of this transform, or an error if the transform is not invertible.
+    ///
+    /// See [`Mat3A::try_inverse()`].
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, crate::error::Error> {
+        self.try_inverse().ok_or(crate::error::Error::Singular)
+    }
 }
 
 impl Default for Affine3A {
@@ -499,29 +619,31 @@ impl core::fmt::Debug for Affine3A {
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for Affine3A {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(
-                f,
-                "[{:.*}, {:.*}, {:.*}, {:.*}]",
-                p,
-                self.matrix3.x_axis,
-                p,
-                self.matrix3.y_axis,
-                p,
-                self.matrix3.z_axis,
-                p,
-                self.translation
-            )
+        if f.alternate() {
+            crate::display::fmt_grid(f, &self.to_cols_array_2d())
         } else {
-            write!(
+            crate::display::fmt_list(
                 f,
-                "[{}, {}, {}, {}]",
-                self.matrix3.x_axis, self.matrix3.y_axis, self.matrix3.z_axis, self.translation
+                &[
+                    self.matrix3.x_axis,
+                    self.matrix3.y_axis,
+                    self.matrix3.z_axis,
+                    self.translation,
+                ],
             )
         }
     }
 }
 
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Affine3A {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|a| Self::from_cols_array(&a))
+    }
+}
+
 impl<'a> core::iter::Product<&'a Self> for Affine3A {
     fn product<I>(iter: I) -> Self
     where
@@ -576,6 +698,13 @@ impl Mul<Affine3A> for Mat4 {
 
     #[inline]
     fn mul(self, rhs: Affine3A) -> Self::Output {
-        self * Mat4::from(rhs)
+        self.mul_mat4_affine(&Mat4::from(rhs))
+    }
+}
+
+impl MulAssign<Affine3A> for Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Affine3A) {
+        *self = self.mul(rhs);
     }
 }
@@ -1,6 +1,8 @@
 // Generated from mat.rs.tera template. Edit the template, not the generated file.
 
-use crate::{f32::math, swizzles::*, DMat3, EulerRot, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A};
+use crate::{
+    coresimd::*, f32::math, swizzles::*, DMat3, EulerRot, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A,
+};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -89,6 +91,29 @@ impl Mat3A {
         }
     }
 
+    /// Creates a 3x3 matrix where each element is the result of calling `f` with its column and
+    /// row index.
+    ///
+    /// `f` is called for each element in column major order.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> f32,
+    {
+        Self::new(
+            f(0, 0),
+            f(0, 1),
+            f(0, 2),
+            f(1, 0),
+            f(1, 1),
+            f(1, 2),
+            f(2, 0),
+            f(2, 1),
+            f(2, 2),
+        )
+    }
+
     /// Creates a 3x3 matrix from a `[f32; 9]` array stored in column major order.
     /// If your data is stored in row major you will need to `transpose` the returned
     /// matrix.
@@ -138,6 +163,83 @@ impl Mat3A {
         ]
     }
 
+    /// Creates a 3x3 matrix from a `[[f32; 3]; 3]` 3D array stored in row major order.
+    #[inline]
+    #[must_use]
+    pub const fn from_rows_array_2d(m: &[[f32; 3]; 3]) -> Self {
+        Self::new(
+            m[0][0], m[1][0], m[2][0], m[0][1], m[1][1], m[2][1], m[0][2], m[1][2], m[2][2],
+        )
+    }
+
+    /// Creates a `[[f32; 3]; 3]` 3D array storing data in row major order.
+    #[inline]
+    #[must_use]
+    pub const fn to_rows_array_2d(&self) -> [[f32; 3]; 3] {
+        let m = self.to_cols_array_2d();
+        [
+            [m[0][0], m[1][0], m[2][0]],
+            [m[0][1], m[1][1], m[2][1]],
+            [m[0][2], m[1][2], m[2][2]],
+        ]
+    }
+
+    /// Creates a matrix from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 36]) -> Self {
+        let mut a = [f32::default(); 9];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 36] {
+        let mut bytes = [0; 36];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a matrix from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 36]) -> Self {
+        let mut a = [f32::default(); 9];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 36] {
+        let mut bytes = [0; 36];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates a 3x3 matrix with its diagonal set to `diagonal` and all other entries set to 0.
     #[doc(alias = "scale")]
     #[inline]
@@ -148,6 +250,17 @@ impl Mat3A {
         )
     }
 
+    /// Creates a 3x3 matrix from the outer product of `a` and `b`, this is `a * bᵀ`.
+    ///
+    /// Each column `j` of the result is `a * b[j]`. For example the projection onto the plane
+    /// with the unit normal `n` is `IDENTITY - from_outer_product(n, n)`.
+    #[inline]
+    #[must_use]
+    pub fn from_outer_product(a: Vec3, b: Vec3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self::from_cols((a * b.x).into(), (a * b.y).into(), (a * b.z).into())
+    }
+
     /// Creates a 3x3 matrix from a 4x4 matrix, discarding the 4th row and column.
     #[inline]
     #[must_use]
@@ -165,24 +278,8 @@ impl Mat3A {
     pub fn from_quat(rotation: Quat) -> Self {
         glam_assert!(rotation.is_normalized());
 
-        let x2 = rotation.x + rotation.x;
-        let y2 = rotation.y + rotation.y;
-        let z2 = rotation.z + rotation.z;
-        let xx = rotation.x * x2;
-        let xy = rotation.x * y2;
-        let xz = rotation.x * z2;
-        let yy = rotation.y * y2;
-        let yz = rotation.y * z2;
-        let zz = rotation.z * z2;
-        let wx = rotation.w * x2;
-        let wy = rotation.w * y2;
-        let wz = rotation.w * z2;
-
-        Self::from_cols(
-            Vec3A::new(1.0 - (yy + zz), xy + wz, xz - wy),
-            Vec3A::new(xy - wz, 1.0 - (xx + zz), yz + wx),
-            Vec3A::new(xz + wy, yz - wx, 1.0 - (xx + yy)),
-        )
+        let [x_axis, y_axis, z_axis] = f32x4_quat_to_axes(rotation.0);
+        Self::from_cols(Vec3A(x_axis), Vec3A(y_axis), Vec3A(z_axis))
     }
 
     /// Creates a 3D rotation matrix from a normalized rotation `axis` and `angle` (in
@@ -256,13 +353,91 @@ impl Mat3A {
         )
     }
 
+    /// Creates a 3D rotation matrix whose columns form a right-handed orthonormal basis with the
+    /// given `normal` as the z axis.
+    ///
+    /// The x and y axes are computed with the branchless method from [Building an Orthonormal
+    /// Basis, Revisited](https://graphics.pixar.com/library/OrthonormalB/paper.pdf) by Duff et
+    /// al., which has no singularities and is the same basis returned by
+    /// [`Vec3::any_orthonormal_pair()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn orthonormal_basis_from_normal(normal: Vec3) -> Self {
+        glam_assert!(normal.is_normalized());
+        let (x_axis, y_axis) = normal.any_orthonormal_pair();
+        #[allow(clippy::useless_conversion)]
+        Self::from_cols(x_axis.into(), y_axis.into(), normal.into())
+    }
+
+    /// Creates a tangent space (TBN) matrix from a `normal`, a `tangent` and the `handedness` of
+    /// the bitangent.
+    ///
+    /// The tangent is re-orthogonalized against the normal using Gram-Schmidt and normalized,
+    /// and the bitangent is computed as `normal.cross(tangent) * handedness`. Only the sign of
+    /// `handedness` is used, this matches the `w` component of glTF and MikkTSpace tangents. If
+    /// `tangent` is zero or nearly parallel to `normal` an arbitrary orthogonal tangent is used
+    /// instead.
+    ///
+    /// The columns of the result are the tangent, bitangent and normal in that order, so it
+    /// transforms vectors from tangent space to the space of `normal`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_normal_tangent(normal: Vec3, tangent: Vec3, handedness: f32) -> Self {
+        glam_assert!(normal.is_normalized());
+        let orthogonal = tangent - normal * normal.dot(tangent);
+        let tangent = if orthogonal.length_squared() > tangent.length_squared() * f32::EPSILON {
+            orthogonal.normalize()
+        } else {
+            normal.any_orthonormal_vector()
+        };
+        let bitangent = normal.cross(tangent) * math::copysign(1.0, handedness);
+        #[allow(clippy::useless_conversion)]
+        Self::from_cols(tangent.into(), bitangent.into(), normal.into())
+    }
+
+    /// Creates the skew-symmetric cross product matrix of `v`.
+    ///
+    /// The result `K` satisfies `K * x == v.cross(x)` for all `x`.
+    #[doc(alias = "skew")]
+    #[inline]
+    #[must_use]
+    pub fn from_cross_product(v: Vec3) -> Self {
+        Self::from_cols(
+            Vec3A::new(0.0, v.z, -v.y),
+            Vec3A::new(-v.z, 0.0, v.x),
+            Vec3A::new(v.y, -v.x, 0.0),
+        )
+    }
+
+    /// Returns the axial vector of the skew-symmetric part of `self`.
+    ///
+    /// This is the inverse of [`Self::from_cross_product()`] for skew-symmetric matrices. For
+    /// other matrices the symmetric part `(self + self.transpose()) / 2` is ignored.
+    #[inline]
+    #[must_use]
+    pub fn to_axial_vector(&self) -> Vec3 {
+        Vec3::new(
+            self.y_axis.z - self.z_axis.y,
+            self.z_axis.x - self.x_axis.z,
+            self.x_axis.y - self.y_axis.x,
+        ) * 0.5
+    }
+
     /// Creates an affine transformation matrix from the given 2D `translation`.
     ///
     /// The resulting matrix can be used to transform 2D points and vectors. See
     /// [`Self::transform_point2()`] and [`Self::transform_vector2()`].
     #[inline]
     #[must_use]
-    pub fn from_translation(translation: Vec2) -> Self {
+    pub const fn from_translation(translation: Vec2) -> Self {
         Self::from_cols(
             Vec3A::X,
             Vec3A::Y,
@@ -346,6 +521,14 @@ impl Mat3A {
         )
     }
 
+    /// Creates a 3x3 matrix from the first 9 values in `slice`, returning an
+    /// error if `slice` is less than 9 elements long.
+    #[inline]
+    pub fn from_cols_slice_checked(slice: &[f32]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 9)?;
+        Ok(Self::from_cols_slice(slice))
+    }
+
     /// Writes the columns of `self` to the first 9 elements in `slice`.
     ///
     /// # Panics
@@ -395,6 +578,27 @@ impl Mat3A {
         }
     }
 
+    /// Returns the matrix column for the given `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 3 axes of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn col_axis(&self, axis: crate::Axis) -> Vec3A {
+        self.col(axis.index())
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 3 axes of this matrix.
+    #[inline]
+    pub fn col_axis_mut(&mut self, axis: crate::Axis) -> &mut Vec3A {
+        self.col_mut(axis.index())
+    }
+
     /// Returns the matrix row for the given `index`.
     ///
     /// # Panics
@@ -411,6 +615,79 @@ impl Mat3A {
         }
     }
 
+    /// Returns an iterator over the columns of `self`.
+    #[inline]
+    #[must_use]
+    pub fn cols(&self) -> core::array::IntoIter<Vec3A, 3> {
+        [self.x_axis, self.y_axis, self.z_axis].into_iter()
+    }
+
+    /// Returns an iterator over mutable references to the columns of `self`.
+    #[inline]
+    pub fn cols_mut(&mut self) -> core::array::IntoIter<&mut Vec3A, 3> {
+        [&mut self.x_axis, &mut self.y_axis, &mut self.z_axis].into_iter()
+    }
+
+    /// Returns an iterator over the rows of `self`.
+    #[inline]
+    #[must_use]
+    pub fn rows(&self) -> core::array::IntoIter<Vec3A, 3> {
+        [self.row(0), self.row(1), self.row(2)].into_iter()
+    }
+
+    /// Sets the matrix row for the given `index` to `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn set_row(&mut self, index: usize, row: Vec3A) {
+        // check the index first so that a panic leaves `self` unmodified
+        assert!(index < 3, "index out of bounds");
+        self.x_axis[index] = row.x;
+        self.y_axis[index] = row.y;
+        self.z_axis[index] = row.z;
+    }
+
+    /// Calls `f` with a mutable copy of the matrix row for the given `index` and writes the
+    /// result back to `self`.
+    ///
+    /// Matrices are stored in column major order so rows can not be borrowed directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn row_mut<F>(&mut self, index: usize, f: F)
+    where
+        F: FnOnce(&mut Vec3A),
+    {
+        let mut row = self.row(index);
+        f(&mut row);
+        self.set_row(index, row);
+    }
+
+    /// Returns the matrix element in the given `col` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn element(&self, col: usize, row: usize) -> f32 {
+        self.col(col)[row]
+    }
+
+    /// Returns a mutable reference to the matrix element in the given `col` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is greater than 2.
+    #[inline]
+    pub fn element_mut(&mut self, col: usize, row: usize) -> &mut f32 {
+        &mut self.col_mut(col)[row]
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
     #[inline]
@@ -427,6 +704,9 @@ impl Mat3A {
     }
 
     /// Returns the transpose of `self`.
+    ///
+    /// This is only a `const fn` when `Mat3A` is not using SIMD storage, for example when
+    /// the `scalar-math` feature is enabled.
     #[inline]
     #[must_use]
     pub fn transpose(&self) -> Self {
@@ -447,6 +727,54 @@ impl Mat3A {
         self.z_axis.dot(self.x_axis.cross(self.y_axis))
     }
 
+    /// Returns `true` if the columns of `self` form a right-handed basis, which is the case when
+    /// the determinant is positive.
+    ///
+    /// Returns `false` if `self` is close to singular, that is if the determinant is tiny
+    /// relative to the product of the column lengths.
+    #[inline]
+    #[must_use]
+    pub fn is_right_handed(&self) -> bool {
+        self.relative_determinant(self.determinant()) > f32::EPSILON
+    }
+
+    /// Returns `true` if `self` mirrors space, which is the case when the determinant is negative.
+    ///
+    /// Transforming by a flipping matrix reverses the winding order of triangles. Returns `false`
+    /// if `self` is close to singular, that is if the determinant is tiny relative to the product
+    /// of the column lengths.
+    #[inline]
+    #[must_use]
+    pub fn is_flipping(&self) -> bool {
+        self.relative_determinant(self.determinant()) < -f32::EPSILON
+    }
+
+    /// Returns `det` divided by the product of the column lengths.
+    ///
+    /// Dividing avoids the underflow of multiplying a tolerance by tiny column lengths.
+    #[inline]
+    fn relative_determinant(&self, det: f32) -> f32 {
+        det / self.x_axis.length() / self.y_axis.length() / self.z_axis.length()
+    }
+
+    #[inline]
+    fn is_invertible_determinant(&self, det: f32) -> bool {
+        det.is_finite()
+            && det.recip().is_finite()
+            && math::abs(self.relative_determinant(det)) > f32::EPSILON
+    }
+
+    /// Returns `true` if `self` can be inverted.
+    ///
+    /// A matrix is considered invertible if its determinant and the reciprocal of its determinant
+    /// are finite, and the determinant is not tiny relative to the product of the column lengths.
+    /// This is the same check as [`Self::try_inverse()`].
+    #[inline]
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        self.is_invertible_determinant(self.determinant())
+    }
+
     /// Returns the inverse of `self`.
     ///
     /// If the matrix is not invertible the returned matrix will be invalid.
@@ -466,6 +794,32 @@ impl Mat3A {
         Self::from_cols(tmp0.mul(inv_det), tmp1.mul(inv_det), tmp2.mul(inv_det)).transpose()
     }
 
+    /// Returns the inverse of `self`, or `None` if `self` is not invertible.
+    ///
+    /// This rejects matrices that are close to singular, see [`Self::is_invertible()`]. The
+    /// determinant is only computed once.
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let tmp0 = self.y_axis.cross(self.z_axis);
+        let tmp1 = self.z_axis.cross(self.x_axis);
+        let tmp2 = self.x_axis.cross(self.y_axis);
+        let det = self.z_axis.dot(tmp2);
+        if !self.is_invertible_determinant(det) {
+            return None;
+        }
+        let inv_det = Vec3A::splat(det.recip());
+        Some(Self::from_cols(tmp0.mul(inv_det), tmp1.mul(inv_det), tmp2.mul(inv_det)).transpose())
+    }
+
+    /// Returns the inverse of `self`, or an error if `self` is not invertible.
+    ///
+    /// This is the same check as [`Self::try_inverse()`], see [`Self::is_invertible()`].
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, crate::error::Error> {
+        self.try_inverse().ok_or(crate::error::Error::Singular)
+    }
+
     /// Transforms the given 2D vector as a point.
     ///
     /// This is the equivalent of multiplying `rhs` as a 3D vector where `z` is `1`.
@@ -588,6 +942,50 @@ impl Mat3A {
             && self.z_axis.abs_diff_eq(rhs.z_axis, max_abs_diff)
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(&self, rhs: Self, max_ulps: u32) -> bool {
+        self.x_axis.ulps_eq(rhs.x_axis, max_ulps)
+            && self.y_axis.ulps_eq(rhs.y_axis, max_ulps)
+            && self.z_axis.ulps_eq(rhs.z_axis, max_ulps)
+    }
+
+    /// Returns a matrix with elements of `self` that are not finite replaced by the
+    /// corresponding element of `fallback`.
+    ///
+    /// Elements that are `NaN`, positive or negative infinity are replaced. This can be used to
+    /// sanitize untrusted input.
+    #[inline]
+    #[must_use]
+    pub fn finite_or(&self, fallback: Self) -> Self {
+        Self::from_cols(
+            self.x_axis.finite_or(fallback.x_axis),
+            self.y_axis.finite_or(fallback.y_axis),
+            self.z_axis.finite_or(fallback.z_axis),
+        )
+    }
+
+    /// Returns a matrix containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::from_cols(
+            self.x_axis.map(&f),
+            self.y_axis.map(&f),
+            self.z_axis.map(&f),
+        )
+    }
+
     /// Takes the absolute value of each element in `self`
     #[inline]
     #[must_use]
@@ -738,6 +1136,26 @@ impl From<Mat3> for Mat3A {
     }
 }
 
+impl From<((f32, f32, f32), (f32, f32, f32), (f32, f32, f32))> for Mat3A {
+    /// Creates a matrix from a tuple of column tuples.
+    #[inline]
+    fn from((x_axis, y_axis, z_axis): ((f32, f32, f32), (f32, f32, f32), (f32, f32, f32))) -> Self {
+        Self::from_cols(
+            Vec3A::from(x_axis),
+            Vec3A::from(y_axis),
+            Vec3A::from(z_axis),
+        )
+    }
+}
+
+impl From<Mat3A> for ((f32, f32, f32), (f32, f32, f32), (f32, f32, f32)) {
+    /// Converts a matrix into a tuple of column tuples.
+    #[inline]
+    fn from(m: Mat3A) -> Self {
+        (m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+    }
+}
+
 impl Sum<Self> for Mat3A {
     fn sum<I>(iter: I) -> Self
     where
@@ -795,14 +1213,19 @@ impl fmt::Debug for Mat3A {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Mat3A {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(
-                f,
-                "[{:.*}, {:.*}, {:.*}]",
-                p, self.x_axis, p, self.y_axis, p, self.z_axis
-            )
+        if f.alternate() {
+            crate::display::fmt_grid(f, &self.to_cols_array_2d())
         } else {
-            write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+            crate::display::fmt_list(f, &[self.x_axis, self.y_axis, self.z_axis])
         }
     }
 }
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Mat3A {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|a| Self::from_cols_array(&a))
+    }
+}
@@ -106,6 +106,36 @@ impl Mat4 {
         }
     }
 
+    /// Creates a 4x4 matrix where each element is the result of calling `f` with its column and
+    /// row index.
+    ///
+    /// `f` is called for each element in column major order.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> f32,
+    {
+        Self::new(
+            f(0, 0),
+            f(0, 1),
+            f(0, 2),
+            f(0, 3),
+            f(1, 0),
+            f(1, 1),
+            f(1, 2),
+            f(1, 3),
+            f(2, 0),
+            f(2, 1),
+            f(2, 2),
+            f(2, 3),
+            f(3, 0),
+            f(3, 1),
+            f(3, 2),
+            f(3, 3),
+        )
+    }
+
     /// Creates a 4x4 matrix from a `[f32; 16]` array stored in column major order.
     /// If your data is stored in row major you will need to `transpose` the returned
     /// matrix.
@@ -161,6 +191,85 @@ impl Mat4 {
         ]
     }
 
+    /// Creates a 4x4 matrix from a `[[f32; 4]; 4]` 4D array stored in row major order.
+    #[inline]
+    #[must_use]
+    pub const fn from_rows_array_2d(m: &[[f32; 4]; 4]) -> Self {
+        Self::new(
+            m[0][0], m[1][0], m[2][0], m[3][0], m[0][1], m[1][1], m[2][1], m[3][1], m[0][2],
+            m[1][2], m[2][2], m[3][2], m[0][3], m[1][3], m[2][3], m[3][3],
+        )
+    }
+
+    /// Creates a `[[f32; 4]; 4]` 4D array storing data in row major order.
+    #[inline]
+    #[must_use]
+    pub const fn to_rows_array_2d(&self) -> [[f32; 4]; 4] {
+        let m = self.to_cols_array_2d();
+        [
+            [m[0][0], m[1][0], m[2][0], m[3][0]],
+            [m[0][1], m[1][1], m[2][1], m[3][1]],
+            [m[0][2], m[1][2], m[2][2], m[3][2]],
+            [m[0][3], m[1][3], m[2][3], m[3][3]],
+        ]
+    }
+
+    /// Creates a matrix from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 64]) -> Self {
+        let mut a = [f32::default(); 16];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a matrix from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 64]) -> Self {
+        let mut a = [f32::default(); 16];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates a 4x4 matrix with its diagonal set to `diagonal` and all other entries set to 0.
     #[doc(alias = "scale")]
     #[inline]
@@ -173,29 +282,29 @@ impl Mat4 {
         )
     }
 
+    /// Creates a 4x4 matrix from the outer product of `a` and `b`, this is `a * bᵀ`.
+    ///
+    /// Each column `j` of the result is `a * b[j]`. For example the projection onto the plane
+    /// with the unit normal `n` is `IDENTITY - from_outer_product(n, n)`.
+    #[inline]
+    #[must_use]
+    pub fn from_outer_product(a: Vec4, b: Vec4) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self::from_cols(
+            (a * b.x).into(),
+            (a * b.y).into(),
+            (a * b.z).into(),
+            (a * b.w).into(),
+        )
+    }
+
     #[inline]
     #[must_use]
     fn quat_to_axes(rotation: Quat) -> (Vec4, Vec4, Vec4) {
         glam_assert!(rotation.is_normalized());
 
-        let (x, y, z, w) = rotation.into();
-        let x2 = x + x;
-        let y2 = y + y;
-        let z2 = z + z;
-        let xx = x * x2;
-        let xy = x * y2;
-        let xz = x * z2;
-        let yy = y * y2;
-        let yz = y * z2;
-        let zz = z * z2;
-        let wx = w * x2;
-        let wy = w * y2;
-        let wz = w * z2;
-
-        let x_axis = Vec4::new(1.0 - (yy + zz), xy + wz, xz - wy, 0.0);
-        let y_axis = Vec4::new(xy - wz, 1.0 - (xx + zz), yz + wx, 0.0);
-        let z_axis = Vec4::new(xz + wy, yz - wx, 1.0 - (xx + yy), 0.0);
-        (x_axis, y_axis, z_axis)
+        let [x_axis, y_axis, z_axis] = f32x4_quat_to_axes(rotation.0);
+        (Vec4(x_axis), Vec4(y_axis), Vec4(z_axis))
     }
 
     /// Creates an affine transformation matrix from the given 3D `scale`, `rotation` and
@@ -321,7 +430,7 @@ impl Mat4 {
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
     #[inline]
     #[must_use]
-    pub fn from_translation(translation: Vec3) -> Self {
+    pub const fn from_translation(translation: Vec3) -> Self {
         Self::from_cols(
             Vec4::X,
             Vec4::Y,
@@ -473,6 +582,14 @@ impl Mat4 {
         )
     }
 
+    /// Creates a 4x4 matrix from the first 16 values in `slice`, returning an
+    /// error if `slice` is less than 16 elements long.
+    #[inline]
+    pub fn from_cols_slice_checked(slice: &[f32]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 16)?;
+        Ok(Self::from_cols_slice(slice))
+    }
+
     /// Writes the columns of `self` to the first 16 elements in `slice`.
     ///
     /// # Panics
@@ -498,6 +615,76 @@ impl Mat4 {
         slice[15] = self.w_axis.w;
     }
 
+    /// Reinterprets a slice of matrices as a slice of their elements without copying.
+    ///
+    /// This is useful for passing transform data to APIs expecting a flat slice of
+    /// `f32`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice(matrices: &[Self]) -> &[f32] {
+        // SAFETY: `Self` consists of 16 `f32` elements with no padding
+        unsafe { crate::cast::flatten(matrices) }
+    }
+
+    /// Reinterprets a mutable slice of matrices as a mutable slice of their elements without
+    /// copying.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice_mut(matrices: &mut [Self]) -> &mut [f32] {
+        // SAFETY: see `as_flat_slice`
+        unsafe { crate::cast::flatten_mut(matrices) }
+    }
+
+    /// Reinterprets a slice of elements as a slice of matrices without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 16 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice(flat: &[f32]) -> &[Self] {
+        Self::from_flat_slice_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of matrices without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 16 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice_mut(flat: &mut [f32]) -> &mut [Self] {
+        Self::from_flat_slice_mut_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a slice of elements as a slice of matrices without copying, returning an
+    /// error if the length of `flat` is not a multiple of 16 or if `flat` is not aligned to
+    /// `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_checked(flat: &[f32]) -> Result<&[Self], crate::error::Error> {
+        // SAFETY: see `as_flat_slice`, any bit pattern is a valid `f32`
+        unsafe { crate::cast::unflatten(flat) }
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of matrices without copying,
+    /// returning an error if the length of `flat` is not a multiple of 16 or if `flat` is
+    /// not aligned to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_mut_checked(
+        flat: &mut [f32],
+    ) -> Result<&mut [Self], crate::error::Error> {
+        // SAFETY: see `from_flat_slice_checked`
+        unsafe { crate::cast::unflatten_mut(flat) }
+    }
+
     /// Returns the matrix column for the given `index`.
     ///
     /// # Panics
@@ -531,6 +718,27 @@ impl Mat4 {
         }
     }
 
+    /// Returns the matrix column for the given `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 4 axes of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn col_axis(&self, axis: crate::Axis) -> Vec4 {
+        self.col(axis.index())
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 4 axes of this matrix.
+    #[inline]
+    pub fn col_axis_mut(&mut self, axis: crate::Axis) -> &mut Vec4 {
+        self.col_mut(axis.index())
+    }
+
     /// Returns the matrix row for the given `index`.
     ///
     /// # Panics
@@ -548,6 +756,86 @@ impl Mat4 {
         }
     }
 
+    /// Returns an iterator over the columns of `self`.
+    #[inline]
+    #[must_use]
+    pub fn cols(&self) -> core::array::IntoIter<Vec4, 4> {
+        [self.x_axis, self.y_axis, self.z_axis, self.w_axis].into_iter()
+    }
+
+    /// Returns an iterator over mutable references to the columns of `self`.
+    #[inline]
+    pub fn cols_mut(&mut self) -> core::array::IntoIter<&mut Vec4, 4> {
+        [
+            &mut self.x_axis,
+            &mut self.y_axis,
+            &mut self.z_axis,
+            &mut self.w_axis,
+        ]
+        .into_iter()
+    }
+
+    /// Returns an iterator over the rows of `self`.
+    #[inline]
+    #[must_use]
+    pub fn rows(&self) -> core::array::IntoIter<Vec4, 4> {
+        [self.row(0), self.row(1), self.row(2), self.row(3)].into_iter()
+    }
+
+    /// Sets the matrix row for the given `index` to `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    pub fn set_row(&mut self, index: usize, row: Vec4) {
+        // check the index first so that a panic leaves `self` unmodified
+        assert!(index < 4, "index out of bounds");
+        self.x_axis[index] = row.x;
+        self.y_axis[index] = row.y;
+        self.z_axis[index] = row.z;
+        self.w_axis[index] = row.w;
+    }
+
+    /// Calls `f` with a mutable copy of the matrix row for the given `index` and writes the
+    /// result back to `self`.
+    ///
+    /// Matrices are stored in column major order so rows can not be borrowed directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    pub fn row_mut<F>(&mut self, index: usize, f: F)
+    where
+        F: FnOnce(&mut Vec4),
+    {
+        let mut row = self.row(index);
+        f(&mut row);
+        self.set_row(index, row);
+    }
+
+    /// Returns the matrix element in the given `col` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn element(&self, col: usize, row: usize) -> f32 {
+        self.col(col)[row]
+    }
+
+    /// Returns a mutable reference to the matrix element in the given `col` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is greater than 3.
+    #[inline]
+    pub fn element_mut(&mut self, col: usize, row: usize) -> &mut f32 {
+        &mut self.col_mut(col)[row]
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
     #[inline]
@@ -567,20 +855,27 @@ impl Mat4 {
     }
 
     /// Returns the transpose of `self`.
+    ///
+    /// This is only a `const fn` when `Mat4` is not using SIMD storage, for example when
+    /// the `scalar-math` feature is enabled.
     #[inline]
     #[must_use]
     pub fn transpose(&self) -> Self {
-        // Based on https://github.com/microsoft/DirectXMath `XMMatrixTranspose`
-        let tmp0 = simd_swizzle!(self.x_axis.0, self.y_axis.0, [0, 1, 4, 5]);
-        let tmp1 = simd_swizzle!(self.x_axis.0, self.y_axis.0, [2, 3, 6, 7]);
-        let tmp2 = simd_swizzle!(self.z_axis.0, self.w_axis.0, [0, 1, 4, 5]);
-        let tmp3 = simd_swizzle!(self.z_axis.0, self.w_axis.0, [2, 3, 6, 7]);
-
+        let [x_axis, y_axis, z_axis, w_axis] =
+            Vec4::transpose4(self.x_axis, self.y_axis, self.z_axis, self.w_axis);
         Self {
-            x_axis: Vec4(simd_swizzle!(tmp0, tmp2, [0, 2, 4, 6])),
-            y_axis: Vec4(simd_swizzle!(tmp0, tmp2, [1, 3, 5, 7])),
-            z_axis: Vec4(simd_swizzle!(tmp1, tmp3, [0, 2, 4, 6])),
-            w_axis: Vec4(simd_swizzle!(tmp1, tmp3, [1, 3, 5, 7])),
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Transposes each of `matrices` in place.
+    #[inline]
+    pub fn transpose_slice(matrices: &mut [Self]) {
+        for m in matrices {
+            *m = m.transpose();
         }
     }
 
@@ -622,6 +917,71 @@ impl Mat4 {
         dot4(self.x_axis.0, detcof)
     }
 
+    /// Returns `true` if the columns of `self` form a right-handed basis, which is the case when
+    /// the determinant is positive.
+    ///
+    /// Returns `false` if `self` is close to singular, that is if the determinant is tiny
+    /// relative to the product of the column lengths.
+    ///
+    /// If the last row is `[0, 0, 0, 1]` the translation in `w_axis` is left out of this
+    /// product, as it doesn't change the determinant of an affine transform.
+    #[inline]
+    #[must_use]
+    pub fn is_right_handed(&self) -> bool {
+        self.relative_determinant(self.determinant()) > f32::EPSILON
+    }
+
+    /// Returns `true` if `self` mirrors space, which is the case when the determinant is negative.
+    ///
+    /// Transforming by a flipping matrix reverses the winding order of triangles. Returns `false`
+    /// if `self` is close to singular, that is if the determinant is tiny relative to the product
+    /// of the column lengths.
+    ///
+    /// If the last row is `[0, 0, 0, 1]` the translation in `w_axis` is left out of this
+    /// product, as it doesn't change the determinant of an affine transform.
+    #[inline]
+    #[must_use]
+    pub fn is_flipping(&self) -> bool {
+        self.relative_determinant(self.determinant()) < -f32::EPSILON
+    }
+
+    /// Returns `det` divided by the product of the column lengths.
+    ///
+    /// Dividing avoids the underflow of multiplying a tolerance by tiny column lengths.
+    #[inline]
+    fn relative_determinant(&self, det: f32) -> f32 {
+        // The translation of an affine transform doesn't change the determinant and is
+        // excluded, otherwise transforms far from the origin would be considered close to
+        // singular.
+        let w_length = if self.row(3) == Vec4::W {
+            1.0
+        } else {
+            self.w_axis.length()
+        };
+        det / self.x_axis.length() / self.y_axis.length() / self.z_axis.length() / w_length
+    }
+
+    #[inline]
+    fn is_invertible_determinant(&self, det: f32) -> bool {
+        det.is_finite()
+            && det.recip().is_finite()
+            && math::abs(self.relative_determinant(det)) > f32::EPSILON
+    }
+
+    /// Returns `true` if `self` can be inverted.
+    ///
+    /// A matrix is considered invertible if its determinant and the reciprocal of its determinant
+    /// are finite, and the determinant is not tiny relative to the product of the column lengths.
+    /// This is the same check as [`Self::try_inverse()`].
+    ///
+    /// If the last row is `[0, 0, 0, 1]` the translation in `w_axis` is left out of this
+    /// product, as it doesn't change the determinant of an affine transform.
+    #[inline]
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        self.is_invertible_determinant(self.determinant())
+    }
+
     /// Returns the inverse of `self`.
     ///
     /// If the matrix is not invertible the returned matrix will be invalid.
@@ -770,6 +1130,161 @@ impl Mat4 {
         }
     }
 
+    /// Returns the inverse of `self`, or `None` if `self` is not invertible.
+    ///
+    /// This rejects matrices that are close to singular, see [`Self::is_invertible()`]. The
+    /// determinant is only computed once.
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        // Based on https://github.com/g-truc/glm `glm_mat4_inverse`
+        let fac0 = {
+            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
+            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
+
+            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
+            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
+
+            let mul00 = swp00 * swp01;
+            let mul01 = swp02 * swp03;
+            mul00 - mul01
+        };
+        let fac1 = {
+            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
+            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
+
+            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
+            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
+
+            let mul00 = swp00 * swp01;
+            let mul01 = swp02 * swp03;
+            mul00 - mul01
+        };
+        let fac2 = {
+            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
+            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
+
+            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
+            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
+
+            let mul00 = swp00 * swp01;
+            let mul01 = swp02 * swp03;
+            mul00 - mul01
+        };
+        let fac3 = {
+            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
+            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
+
+            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
+            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
+
+            let mul00 = swp00 * swp01;
+            let mul01 = swp02 * swp03;
+            mul00 - mul01
+        };
+        let fac4 = {
+            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
+            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
+
+            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
+            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
+
+            let mul00 = swp00 * swp01;
+            let mul01 = swp02 * swp03;
+            mul00 - mul01
+        };
+        let fac5 = {
+            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
+            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
+
+            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
+            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
+
+            let mul00 = swp00 * swp01;
+            let mul01 = swp02 * swp03;
+            mul00 - mul01
+        };
+        let sign_a = f32x4::from_array([-1.0, 1.0, -1.0, 1.0]);
+        let sign_b = f32x4::from_array([1.0, -1.0, 1.0, -1.0]);
+
+        let temp0 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [0, 0, 4, 4]);
+        let vec0 = simd_swizzle!(temp0, [0, 2, 2, 2]);
+
+        let temp1 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [1, 1, 5, 5]);
+        let vec1 = simd_swizzle!(temp1, [0, 2, 2, 2]);
+
+        let temp2 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [2, 2, 6, 6]);
+        let vec2 = simd_swizzle!(temp2, [0, 2, 2, 2]);
+
+        let temp3 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [3, 3, 7, 7]);
+        let vec3 = simd_swizzle!(temp3, [0, 2, 2, 2]);
+
+        let mul00 = vec1 * fac0;
+        let mul01 = vec2 * fac1;
+        let mul02 = vec3 * fac2;
+        let sub00 = mul00 - mul01;
+        let add00 = sub00 + mul02;
+        let inv0 = sign_b * add00;
+
+        let mul03 = vec0 * fac0;
+        let mul04 = vec2 * fac3;
+        let mul05 = vec3 * fac4;
+        let sub01 = mul03 - mul04;
+        let add01 = sub01 + mul05;
+        let inv1 = sign_a * add01;
+
+        let mul06 = vec0 * fac1;
+        let mul07 = vec1 * fac3;
+        let mul08 = vec3 * fac5;
+        let sub02 = mul06 - mul07;
+        let add02 = sub02 + mul08;
+        let inv2 = sign_b * add02;
+
+        let mul09 = vec0 * fac2;
+        let mul10 = vec1 * fac4;
+        let mul11 = vec2 * fac5;
+        let sub03 = mul09 - mul10;
+        let add03 = sub03 + mul11;
+        let inv3 = sign_a * add03;
+
+        let row0 = simd_swizzle!(inv0, inv1, [0, 0, 4, 4]);
+        let row1 = simd_swizzle!(inv2, inv3, [0, 0, 4, 4]);
+        let row2 = simd_swizzle!(row0, row1, [0, 2, 4, 6]);
+
+        let dot0 = dot4(self.x_axis.0, row2);
+        if !self.is_invertible_determinant(dot0) {
+            return None;
+        }
+
+        let rcp0 = f32x4::splat(dot0.recip());
+
+        Some(Self {
+            x_axis: Vec4(inv0 * rcp0),
+            y_axis: Vec4(inv1 * rcp0),
+            z_axis: Vec4(inv2 * rcp0),
+            w_axis: Vec4(inv3 * rcp0),
+        })
+    }
+
+    /// Returns the inverse of `self`, or an error if `self` is not invertible.
+    ///
+    /// This is the same check as [`Self::try_inverse()`], see [`Self::is_invertible()`].
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, crate::error::Error> {
+        self.try_inverse().ok_or(crate::error::Error::Singular)
+    }
+
     /// Creates a left-handed view matrix using a camera position, an up direction, and a facing
     /// direction.
     ///
@@ -1165,6 +1680,30 @@ impl Mat4 {
         )
     }
 
+    /// Multiplies two 4x4 matrices where `rhs` contains a valid affine transform.
+    ///
+    /// This skips the arithmetic involving the last row of `rhs`, which is assumed to be
+    /// `(0, 0, 0, 1)`, making it faster than [`Self::mul_mat4()`]. `self` may be any matrix,
+    /// such as a projection, and the result is an affine transform if `self` is one.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `rhs` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn mul_mat4_affine(&self, rhs: &Self) -> Self {
+        glam_assert!(rhs.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        Self::from_cols(
+            self.x_axis * rhs.x_axis.x + self.y_axis * rhs.x_axis.y + self.z_axis * rhs.x_axis.z,
+            self.x_axis * rhs.y_axis.x + self.y_axis * rhs.y_axis.y + self.z_axis * rhs.y_axis.z,
+            self.x_axis * rhs.z_axis.x + self.y_axis * rhs.z_axis.y + self.z_axis * rhs.z_axis.z,
+            self.x_axis * rhs.w_axis.x
+                + self.y_axis * rhs.w_axis.y
+                + self.z_axis * rhs.w_axis.z
+                + self.w_axis,
+        )
+    }
+
     /// Adds two 4x4 matrices.
     #[inline]
     #[must_use]
@@ -1232,6 +1771,53 @@ impl Mat4 {
             && self.w_axis.abs_diff_eq(rhs.w_axis, max_abs_diff)
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(&self, rhs: Self, max_ulps: u32) -> bool {
+        self.x_axis.ulps_eq(rhs.x_axis, max_ulps)
+            && self.y_axis.ulps_eq(rhs.y_axis, max_ulps)
+            && self.z_axis.ulps_eq(rhs.z_axis, max_ulps)
+            && self.w_axis.ulps_eq(rhs.w_axis, max_ulps)
+    }
+
+    /// Returns a matrix with elements of `self` that are not finite replaced by the
+    /// corresponding element of `fallback`.
+    ///
+    /// Elements that are `NaN`, positive or negative infinity are replaced. This can be used to
+    /// sanitize untrusted input.
+    #[inline]
+    #[must_use]
+    pub fn finite_or(&self, fallback: Self) -> Self {
+        Self::from_cols(
+            self.x_axis.finite_or(fallback.x_axis),
+            self.y_axis.finite_or(fallback.y_axis),
+            self.z_axis.finite_or(fallback.z_axis),
+            self.w_axis.finite_or(fallback.w_axis),
+        )
+    }
+
+    /// Returns a matrix containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::from_cols(
+            self.x_axis.map(&f),
+            self.y_axis.map(&f),
+            self.z_axis.map(&f),
+            self.w_axis.map(&f),
+        )
+    }
+
     /// Takes the absolute value of each element in `self`
     #[inline]
     #[must_use]
@@ -1374,6 +1960,53 @@ impl DivAssign<f32> for Mat4 {
     }
 }
 
+impl
+    From<(
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+    )> for Mat4
+{
+    /// Creates a matrix from a tuple of column tuples.
+    #[inline]
+    fn from(
+        (x_axis, y_axis, z_axis, w_axis): (
+            (f32, f32, f32, f32),
+            (f32, f32, f32, f32),
+            (f32, f32, f32, f32),
+            (f32, f32, f32, f32),
+        ),
+    ) -> Self {
+        Self::from_cols(
+            Vec4::from(x_axis),
+            Vec4::from(y_axis),
+            Vec4::from(z_axis),
+            Vec4::from(w_axis),
+        )
+    }
+}
+
+impl From<Mat4>
+    for (
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+    )
+{
+    /// Converts a matrix into a tuple of column tuples.
+    #[inline]
+    fn from(m: Mat4) -> Self {
+        (
+            m.x_axis.into(),
+            m.y_axis.into(),
+            m.z_axis.into(),
+            m.w_axis.into(),
+        )
+    }
+}
+
 impl Sum<Self> for Mat4 {
     fn sum<I>(iter: I) -> Self
     where
@@ -1451,18 +2084,19 @@ impl fmt::Debug for Mat4 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Mat4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(
-                f,
-                "[{:.*}, {:.*}, {:.*}, {:.*}]",
-                p, self.x_axis, p, self.y_axis, p, self.z_axis, p, self.w_axis
-            )
+        if f.alternate() {
+            crate::display::fmt_grid(f, &self.to_cols_array_2d())
         } else {
-            write!(
-                f,
-                "[{}, {}, {}, {}]",
-                self.x_axis, self.y_axis, self.z_axis, self.w_axis
-            )
+            crate::display::fmt_list(f, &[self.x_axis, self.y_axis, self.z_axis, self.w_axis])
         }
     }
 }
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Mat4 {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|a| Self::from_cols_array(&a))
+    }
+}
@@ -78,6 +78,17 @@ impl Vec4 {
     /// The unit axes.
     pub const AXES: [Self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
 
+    /// Returns the unit vector pointing along `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 4 axes of this vector.
+    #[inline]
+    #[must_use]
+    pub const fn axis(axis: crate::Axis) -> Self {
+        Self::AXES[axis.index()]
+    }
+
     /// Creates a new vector.
     #[inline(always)]
     #[must_use]
@@ -92,6 +103,44 @@ impl Vec4 {
         Self(Simd::from_array([v; 4]))
     }
 
+    /// Creates a vector where each element is the result of calling `f` with its index.
+    ///
+    /// `f` is called for each element in order, starting from index 0.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> f32,
+    {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z), f(self.w))
+    }
+
+    /// Returns a vector containing the result of calling `f` with each element of `self` and the
+    /// corresponding element of `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn zip_with<F>(self, rhs: Self, f: F) -> Self
+    where
+        F: Fn(f32, f32) -> f32,
+    {
+        Self::new(
+            f(self.x, rhs.x),
+            f(self.y, rhs.y),
+            f(self.z, rhs.z),
+            f(self.w, rhs.w),
+        )
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -128,6 +177,34 @@ impl Vec4 {
         Self::new(slice[0], slice[1], slice[2], slice[3])
     }
 
+    /// Creates a vector from the first 4 values in `slice`, returning an error if
+    /// `slice` is less than 4 elements long.
+    #[inline]
+    pub fn from_slice_checked(slice: &[f32]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 4)?;
+        Ok(Self::from_slice(slice))
+    }
+
+    /// Creates a vector from the first 4 values yielded by `iter`, returning an error if
+    /// `iter` yields fewer than 4 values.
+    ///
+    /// Any remaining values are not consumed. The [`FromIterator`] implementation behaves the
+    /// same but panics instead of returning an error.
+    #[inline]
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, crate::error::Error>
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        let mut iter = iter.into_iter();
+        let mut out = Self::ZERO.to_array();
+        for (found, e) in out.iter_mut().enumerate() {
+            *e = iter
+                .next()
+                .ok_or(crate::error::Error::IterTooShort { required: 4, found })?;
+        }
+        Ok(Self::from_array(out))
+    }
+
     /// Writes the elements of `self` to the first 4 elements in `slice`.
     ///
     /// # Panics
@@ -141,6 +218,132 @@ impl Vec4 {
         slice[3] = self.w;
     }
 
+    /// Reinterprets a slice of vectors as a slice of their elements without copying.
+    ///
+    /// This is useful for passing vertex data to APIs expecting a flat slice of
+    /// `f32`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice(vectors: &[Self]) -> &[f32] {
+        // SAFETY: `Self` consists of 4 `f32` elements with no padding
+        unsafe { crate::cast::flatten(vectors) }
+    }
+
+    /// Reinterprets a mutable slice of vectors as a mutable slice of their elements without
+    /// copying.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice_mut(vectors: &mut [Self]) -> &mut [f32] {
+        // SAFETY: see `as_flat_slice`
+        unsafe { crate::cast::flatten_mut(vectors) }
+    }
+
+    /// Reinterprets a slice of elements as a slice of vectors without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 4 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice(flat: &[f32]) -> &[Self] {
+        Self::from_flat_slice_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of vectors without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 4 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice_mut(flat: &mut [f32]) -> &mut [Self] {
+        Self::from_flat_slice_mut_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a slice of elements as a slice of vectors without copying, returning an
+    /// error if the length of `flat` is not a multiple of 4 or if `flat` is not aligned to
+    /// `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_checked(flat: &[f32]) -> Result<&[Self], crate::error::Error> {
+        // SAFETY: see `as_flat_slice`, any bit pattern is a valid `f32`
+        unsafe { crate::cast::unflatten(flat) }
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of vectors without copying,
+    /// returning an error if the length of `flat` is not a multiple of 4 or if `flat` is
+    /// not aligned to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_mut_checked(
+        flat: &mut [f32],
+    ) -> Result<&mut [Self], crate::error::Error> {
+        // SAFETY: see `from_flat_slice_checked`
+        unsafe { crate::cast::unflatten_mut(flat) }
+    }
+
+    /// Creates a vector from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let mut a = [f32::default(); 4];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_array(a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a vector from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let mut a = [f32::default(); 4];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_array(a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates a 3D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
     ///
     /// Truncation to [`Vec3`] may also be performed by using [`self.xyz()`][crate::swizzles::Vec4Swizzles::xyz()].
@@ -199,6 +402,104 @@ impl Vec4 {
         Self(dot4_into_f32x4(self.0, rhs.0))
     }
 
+    /// Transposes the 4x4 matrix with rows `a`, `b`, `c` and `d`, returning the rows of the
+    /// result. In other words element `j` of the `i`th result is element `i` of the `j`th
+    /// argument.
+    ///
+    /// This converts four vectors between an array of structures layout, where each vector
+    /// holds one item, and a structure of arrays layout, where each vector holds one component
+    /// of four items, so that all four items can be processed at once. It is the equivalent of
+    /// the `_MM_TRANSPOSE4_PS` macro.
+    #[inline]
+    #[must_use]
+    pub fn transpose4(a: Self, b: Self, c: Self, d: Self) -> [Self; 4] {
+        // Based on https://github.com/microsoft/DirectXMath `XMMatrixTranspose`
+        let tmp0 = simd_swizzle!(a.0, b.0, [0, 1, 4, 5]);
+        let tmp1 = simd_swizzle!(a.0, b.0, [2, 3, 6, 7]);
+        let tmp2 = simd_swizzle!(c.0, d.0, [0, 1, 4, 5]);
+        let tmp3 = simd_swizzle!(c.0, d.0, [2, 3, 6, 7]);
+
+        [
+            Self(simd_swizzle!(tmp0, tmp2, [0, 2, 4, 6])),
+            Self(simd_swizzle!(tmp0, tmp2, [1, 3, 5, 7])),
+            Self(simd_swizzle!(tmp1, tmp3, [0, 2, 4, 6])),
+            Self(simd_swizzle!(tmp1, tmp3, [1, 3, 5, 7])),
+        ]
+    }
+
+    /// Returns the dot products `a0.dot(b0)`, `a1.dot(b1)`, `a2.dot(b2)` and `a3.dot(b3)` as the
+    /// elements of a [`Vec4`].
+    ///
+    /// The element-wise products are transposed so that the four sums are computed together,
+    /// which is faster than four calls to [`Self::dot`] when SIMD is available. The sums are
+    /// added in the same order as [`Self::dot`], so the results are identical to it.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    #[must_use]
+    pub fn dot4(
+        a0: Self,
+        b0: Self,
+        a1: Self,
+        b1: Self,
+        a2: Self,
+        b2: Self,
+        a3: Self,
+        b3: Self,
+    ) -> Vec4 {
+        let [x, y, z, w] = Self::transpose4(a0 * b0, a1 * b1, a2 * b2, a3 * b3);
+
+        // same order as `dot4_in_x`
+        (x + z) + (y + w)
+    }
+
+    /// Computes the dot product of `self` and `rhs` using compensated arithmetic.
+    ///
+    /// The rounding errors of each multiplication and addition are tracked using error-free
+    /// transformations, so the result is as accurate as if it was computed with twice the
+    /// precision and then rounded. This is slower than [`Self::dot`] but avoids catastrophic
+    /// cancellation when the products have very different magnitudes or opposite signs.
+    #[inline]
+    #[must_use]
+    pub fn dot_compensated(self, rhs: Self) -> f32 {
+        let (mut sum, mut err) = math::two_product(self.x, rhs.x);
+
+        let (p, p_err) = math::two_product(self.y, rhs.y);
+        let (s, s_err) = math::two_sum(sum, p);
+        sum = s;
+        err += p_err + s_err;
+
+        let (p, p_err) = math::two_product(self.z, rhs.z);
+        let (s, s_err) = math::two_sum(sum, p);
+        sum = s;
+        err += p_err + s_err;
+
+        let (p, p_err) = math::two_product(self.w, rhs.w);
+        let (s, s_err) = math::two_sum(sum, p);
+        sum = s;
+        err += p_err + s_err;
+
+        sum + err
+    }
+
+    /// Computes the sum of all vectors in `slice` using compensated summation.
+    ///
+    /// The rounding error of each addition is accumulated separately and added to the result,
+    /// which greatly reduces the loss of precision when summing a large number of vectors
+    /// compared to [`Iterator::sum`].
+    #[inline]
+    #[must_use]
+    pub fn sum_compensated(slice: &[Self]) -> Self {
+        let mut sum = Self::ZERO;
+        let mut err = Self::ZERO;
+        for &v in slice {
+            let s = sum + v;
+            let b = s - sum;
+            err += (sum - (s - b)) + (v - b);
+            sum = s;
+        }
+        sum + err
+    }
+
     /// Returns a vector containing the minimum values for each element of `self` and `rhs`.
     ///
     /// In other words this computes `[self.x.min(rhs.x), self.y.min(rhs.y), ..]`.
@@ -217,6 +518,20 @@ impl Vec4 {
         Self(self.0.simd_max(rhs.0))
     }
 
+    /// Returns the element-wise minimum and maximum of `self` and `rhs` as a pair, using a single
+    /// comparison.
+    ///
+    /// This orders the near and far distances of each axis in a slab test.
+    ///
+    /// Unlike [`Self::min`] and [`Self::max`], if either element is `NaN` the pair of elements is
+    /// returned as `(rhs, self)`.
+    #[inline]
+    #[must_use]
+    pub fn min_max(self, rhs: Self) -> (Self, Self) {
+        let mask = self.cmplt(rhs);
+        (Self::select(mask, self, rhs), Self::select(mask, rhs, self))
+    }
+
     /// Component-wise clamping of values, similar to [`f32::clamp`].
     ///
     /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
@@ -231,6 +546,158 @@ impl Vec4 {
         self.max(min).min(max)
     }
 
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`,
+    /// propagating `NaN`.
+    ///
+    /// This follows the IEEE 754-2019 `minimum` operation, if either element is `NaN` the result
+    /// is `NaN` and `-0.0` is considered less than `0.0`. This differs from [`Self::min`] which
+    /// may ignore `NaN` elements.
+    #[inline]
+    #[must_use]
+    pub fn min_nan(self, rhs: Self) -> Self {
+        Self::new(
+            math::minimum(self.x, rhs.x),
+            math::minimum(self.y, rhs.y),
+            math::minimum(self.z, rhs.z),
+            math::minimum(self.w, rhs.w),
+        )
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`,
+    /// propagating `NaN`.
+    ///
+    /// This follows the IEEE 754-2019 `maximum` operation, if either element is `NaN` the result
+    /// is `NaN` and `0.0` is considered greater than `-0.0`. This differs from [`Self::max`]
+    /// which may ignore `NaN` elements.
+    #[inline]
+    #[must_use]
+    pub fn max_nan(self, rhs: Self) -> Self {
+        Self::new(
+            math::maximum(self.x, rhs.x),
+            math::maximum(self.y, rhs.y),
+            math::maximum(self.z, rhs.z),
+            math::maximum(self.w, rhs.w),
+        )
+    }
+
+    /// Component-wise clamping of values, propagating `NaN`.
+    ///
+    /// If an element of `self`, `min` or `max` is `NaN` the corresponding element of the result is
+    /// `NaN`. This differs from [`Self::clamp`] which may ignore `NaN` elements.
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn clamp_nan(self, min: Self, max: Self) -> Self {
+        glam_assert!(!min.cmpgt(max).any(), "clamp_nan: expected min <= max");
+        self.max_nan(min).min_nan(max)
+    }
+
+    /// Compares `self` and `rhs` lexicographically using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// Elements are compared in order using [`f32::total_cmp`], the first element which
+    /// is not equal determines the result. Use with [`slice::sort_by`] to sort points
+    /// deterministically without a wrapper type.
+    #[inline]
+    #[must_use]
+    pub fn cmp_total(self, rhs: Self) -> core::cmp::Ordering {
+        self.x
+            .total_cmp(&rhs.x)
+            .then_with(|| self.y.total_cmp(&rhs.y))
+            .then_with(|| self.z.total_cmp(&rhs.z))
+            .then_with(|| self.w.total_cmp(&rhs.w))
+    }
+
+    /// Compares `self` and `rhs` lexicographically, the same as [`Self::cmp_total`].
+    #[inline]
+    #[must_use]
+    pub fn cmp_lex(self, rhs: Self) -> core::cmp::Ordering {
+        self.cmp_total(rhs)
+    }
+
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`
+    /// using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// Unlike [`Self::min`] the result is well defined for all inputs, `-0.0` is less than `0.0`,
+    /// `NaN` with the sign bit set is less than all other values and `NaN` without the sign bit
+    /// set is greater than all other values.
+    #[inline]
+    #[must_use]
+    pub fn min_total(self, rhs: Self) -> Self {
+        Self::new(
+            core::cmp::min_by(self.x, rhs.x, f32::total_cmp),
+            core::cmp::min_by(self.y, rhs.y, f32::total_cmp),
+            core::cmp::min_by(self.z, rhs.z, f32::total_cmp),
+            core::cmp::min_by(self.w, rhs.w, f32::total_cmp),
+        )
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`
+    /// using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// Unlike [`Self::max`] the result is well defined for all inputs, `0.0` is greater than
+    /// `-0.0`, `NaN` without the sign bit set is greater than all other values and `NaN` with the
+    /// sign bit set is less than all other values.
+    #[inline]
+    #[must_use]
+    pub fn max_total(self, rhs: Self) -> Self {
+        Self::new(
+            core::cmp::max_by(self.x, rhs.x, f32::total_cmp),
+            core::cmp::max_by(self.y, rhs.y, f32::total_cmp),
+            core::cmp::max_by(self.z, rhs.z, f32::total_cmp),
+            core::cmp::max_by(self.w, rhs.w, f32::total_cmp),
+        )
+    }
+
+    /// Wraps `self` in a [`TotalOrd`](crate::TotalOrd) which implements `Eq`, `Ord` and `Hash`
+    /// using [`Self::cmp_total`].
+    #[inline]
+    #[must_use]
+    pub fn total_ord(self) -> crate::TotalOrd<Self> {
+        crate::TotalOrd(self)
+    }
+
+    /// Returns the raw bit patterns of the elements of `self`.
+    ///
+    /// See [`f32::to_bits`] for details.
+    #[inline]
+    #[must_use]
+    pub fn to_bits(self) -> crate::UVec4 {
+        crate::UVec4::new(
+            self.x.to_bits(),
+            self.y.to_bits(),
+            self.z.to_bits(),
+            self.w.to_bits(),
+        )
+    }
+
+    /// Creates a vector from the raw bit patterns of its elements.
+    ///
+    /// See [`f32::from_bits`] for details.
+    #[inline]
+    #[must_use]
+    pub fn from_bits(v: crate::UVec4) -> Self {
+        Self::new(
+            f32::from_bits(v.x),
+            f32::from_bits(v.y),
+            f32::from_bits(v.z),
+            f32::from_bits(v.w),
+        )
+    }
+
+    /// Wraps `self` in a [`Hashable`](crate::Hashable) which implements `Eq` and `Hash` using
+    /// the bit patterns of the elements after mapping `-0.0` to `0.0` and all `NaN` values to a
+    /// single `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn hashable(self) -> crate::Hashable<Self> {
+        crate::Hashable(self)
+    }
+
     /// Returns the horizontal minimum of `self`.
     ///
     /// In other words this computes `min(x, y, ..)`.
@@ -249,6 +716,111 @@ impl Vec4 {
         self.0.reduce_max()
     }
 
+    /// Returns the index of the first minimum element of `self`.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[doc(alias = "argmin")]
+    #[inline]
+    #[must_use]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+
+        if self.y < min {
+            min = self.y;
+            index = 1;
+        }
+
+        if self.z < min {
+            min = self.z;
+            index = 2;
+        }
+
+        if self.w < min {
+            index = 3;
+        }
+
+        index
+    }
+
+    /// Returns the index of the first element of `self` with the smallest absolute value.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn abs_min_position(self) -> usize {
+        self.abs().min_position()
+    }
+
+    /// Returns the index of the first maximum element of `self`.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[doc(alias = "argmax")]
+    #[inline]
+    #[must_use]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+
+        if self.y > max {
+            max = self.y;
+            index = 1;
+        }
+
+        if self.z > max {
+            max = self.z;
+            index = 2;
+        }
+
+        if self.w > max {
+            index = 3;
+        }
+
+        index
+    }
+
+    /// Returns the index of the first element of `self` with the largest absolute value.
+    ///
+    /// This selects the dominant axis of a direction vector.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn abs_max_position(self) -> usize {
+        self.abs().max_position()
+    }
+
+    /// Returns a vector containing the elements of `self` sorted in ascending order.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn sorted(self) -> Self {
+        let mut a = self.to_array();
+
+        if a[1] < a[0] {
+            a.swap(0, 1);
+        }
+
+        if a[3] < a[2] {
+            a.swap(2, 3);
+        }
+
+        if a[2] < a[0] {
+            a.swap(0, 2);
+        }
+
+        if a[3] < a[1] {
+            a.swap(1, 3);
+        }
+
+        if a[2] < a[1] {
+            a.swap(1, 2);
+        }
+
+        Self::from_array(a)
+    }
+
     /// Returns the sum of all elements of `self`.
     ///
     /// In other words, this computes `self.x + self.y + ..`.
@@ -376,6 +948,26 @@ impl Vec4 {
         f32x4::is_finite(self.0).all()
     }
 
+    /// Performs `is_finite` on each element of self, returning a vector mask of the results.
+    ///
+    /// In other words, this computes `[x.is_finite(), y.is_finite(), ...]`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite_mask(self) -> BVec4A {
+        BVec4A(f32x4::is_finite(self.0))
+    }
+
+    /// Returns a vector with elements of `self` that are not finite replaced by the
+    /// corresponding element of `fallback`.
+    ///
+    /// Elements that are `NaN`, positive or negative infinity are replaced. This can be used to
+    /// sanitize untrusted input.
+    #[inline]
+    #[must_use]
+    pub fn finite_or(self, fallback: Self) -> Self {
+        Self::select(self.is_finite_mask(), self, fallback)
+    }
+
     /// Returns `true` if any elements are `NaN`.
     #[inline]
     #[must_use]
@@ -461,6 +1053,40 @@ impl Vec4 {
         )
     }
 
+    /// Returns the element-wise quotient of `self` divided by `rhs`, rounded towards negative
+    /// infinity.
+    #[inline]
+    #[must_use]
+    pub fn div_floor(self, rhs: Self) -> Self {
+        (self / rhs).floor()
+    }
+
+    /// Returns a vector containing each element of `self` rounded to the nearest multiple of the
+    /// corresponding element of `step`.
+    ///
+    /// This snaps `self` to a grid with cells of size `step` and a corner at the origin.
+    /// Half-way cases are rounded up and the sign of `step` is ignored. A zero `step` element results in `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn snap(self, step: Self) -> Self {
+        let step = step.abs();
+        let q = self / step;
+        let down = q.floor();
+        // `q - down` is exact, so half-way cases are always rounded up
+        Self::select((q - down).cmpge(Self::splat(0.5)), down + Self::ONE, down) * step
+    }
+
+    /// Returns a vector containing each element of `self` rounded to the nearest multiple of the
+    /// corresponding element of `step`, offset by `origin`.
+    ///
+    /// This snaps `self` to a grid with cells of size `step` and a corner at `origin`. See
+    /// [`Self::snap()`] for how half-way cases are rounded.
+    #[inline]
+    #[must_use]
+    pub fn snap_to(self, step: Self, origin: Self) -> Self {
+        (self - origin).snap(step) + origin
+    }
+
     /// Returns `self` normalized to length 1.0.
     ///
     /// For valid results, `self` must _not_ be of length zero, nor very close to zero.
@@ -497,6 +1123,16 @@ impl Vec4 {
         }
     }
 
+    /// Returns `self` normalized to length 1.0, or an error if the length of `self` is zero,
+    /// infinite or `NaN`.
+    ///
+    /// See also [`Self::try_normalize()`] and [`Self::normalize_or_zero()`].
+    #[inline]
+    pub fn normalize_checked(self) -> Result<Self, crate::error::Error> {
+        self.try_normalize()
+            .ok_or(crate::error::Error::NonNormalizable)
+    }
+
     /// Returns `self` normalized to length 1.0 if possible, else returns a
     /// fallback value.
     ///
@@ -527,6 +1163,97 @@ impl Vec4 {
         self.normalize_or(Self::ZERO)
     }
 
+    /// Computes the length of `self` using a fast reciprocal square root approximation.
+    ///
+    /// The relative error is less than `1e-6`. If the squared length overflows the result is
+    /// infinite, the same as [`Self::length()`]. On x86 targets with SSE this refines the
+    /// hardware reciprocal square root estimate with one Newton-Raphson step, which is faster
+    /// than [`Self::length()`] but not bit-for-bit identical across CPUs. When the
+    /// `deterministic` feature is enabled, or on other targets, this computes `1.0 / sqrt` of
+    /// the squared length instead.
+    ///
+    /// Unlike the `fast-math` feature this only affects the methods it is called on, so it can
+    /// be used selectively in hot loops.
+    #[inline]
+    #[must_use]
+    pub fn length_fast(self) -> f32 {
+        let length_squared = self.dot(self);
+        if length_squared < f32::MIN_POSITIVE || !length_squared.is_finite() {
+            // the estimate is not accurate for zero or subnormal values, and an overflowed
+            // squared length would give `inf * 0.0`
+            math::sqrt(length_squared)
+        } else {
+            length_squared * math::rsqrt_fast(length_squared)
+        }
+    }
+
+    /// Computes the Euclidean distance between two points in space using a fast reciprocal
+    /// square root approximation.
+    ///
+    /// See [`Self::length_fast()`] for the accuracy of the approximation.
+    #[inline]
+    #[must_use]
+    pub fn distance_fast(self, rhs: Self) -> f32 {
+        (self - rhs).length_fast()
+    }
+
+    /// Returns `self` normalized to length 1.0 using a fast reciprocal square root
+    /// approximation.
+    ///
+    /// The length of the result differs from 1.0 by a relative error of less than `1e-6`, see
+    /// [`Self::length_fast()`] for details of the approximation. For valid results, `self` must
+    /// _not_ be of length zero, nor very close to zero.
+    ///
+    /// Panics
+    ///
+    /// Will panic if the resulting normalized vector is not finite when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn normalize_fast(self) -> Self {
+        #[allow(clippy::let_and_return)]
+        let normalized = self * math::rsqrt_fast(self.dot(self));
+        glam_assert!(normalized.is_finite());
+        normalized
+    }
+
+    /// Returns `self` normalized to length 1.0 and the length of `self`.
+    ///
+    /// This is cheaper than calling [`Self::normalize()`] and [`Self::length()`] separately as
+    /// the square root is only computed once.
+    ///
+    /// See also [`Self::try_normalize_and_length()`].
+    ///
+    /// Panics
+    ///
+    /// Will panic if `self` is zero length when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn normalize_and_length(self) -> (Self, f32) {
+        let length = self.length();
+        let normalized = self * length.recip();
+        glam_assert!(normalized.is_finite());
+        (normalized, length)
+    }
+
+    /// Returns `self` normalized to length 1.0 and the length of `self` if possible, else
+    /// returns `None`.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be `None`.
+    ///
+    /// See also [`Self::normalize_and_length()`].
+    #[inline]
+    #[must_use]
+    pub fn try_normalize_and_length(self) -> Option<(Self, f32)> {
+        let length = self.length();
+        let rcp = length.recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some((self * rcp, length))
+        } else {
+            None
+        }
+    }
+
     /// Returns whether `self` is length `1.0` or not.
     ///
     /// Uses a precision threshold of approximately `1e-4`.
@@ -597,6 +1324,47 @@ impl Vec4 {
         self - self.project_onto_normalized(rhs)
     }
 
+    /// Returns the reflection vector for a given incident vector `self` and surface normal
+    /// `normal`.
+    ///
+    /// `normal` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn reflect(self, normal: Self) -> Self {
+        glam_assert!(normal.is_normalized());
+        self - normal * (2.0 * self.dot(normal))
+    }
+
+    /// Returns the refraction direction for a given incident vector `self`, surface normal
+    /// `normal` and ratio of indices of refraction, `eta`.
+    ///
+    /// `normal` should face against `self`, in other words `self.dot(normal)` should be
+    /// negative. Returns `None` when total internal reflection occurs, in which case
+    /// [`Self::reflect()`] gives the outgoing direction.
+    ///
+    /// `self` and `normal` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `normal` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn refract(self, normal: Self, eta: f32) -> Option<Self> {
+        glam_assert!(self.is_normalized());
+        glam_assert!(normal.is_normalized());
+        let n_dot_i = normal.dot(self);
+        let k = 1.0 - eta * eta * (1.0 - n_dot_i * n_dot_i);
+        if k >= 0.0 {
+            Some(self * eta - normal * (eta * n_dot_i + math::sqrt(k)))
+        } else {
+            None
+        }
+    }
+
     /// Returns a vector containing the nearest integer to a number for each element of `self`.
     /// Round half-way cases away from 0.0.
     #[inline]
@@ -605,6 +1373,21 @@ impl Vec4 {
         Self(self.0.round())
     }
 
+    /// Returns a vector containing the nearest integer to a number for each element of `self`.
+    /// Round half-way cases to the nearest even integer.
+    ///
+    /// This matches the `roundEven` function in shading languages.
+    #[inline]
+    #[must_use]
+    pub fn round_ties_even(self) -> Self {
+        Self::new(
+            math::round_ties_even(self.x),
+            math::round_ties_even(self.y),
+            math::round_ties_even(self.z),
+            math::round_ties_even(self.w),
+        )
+    }
+
     /// Returns a vector containing the largest integer less than or equal to a number for each
     /// element of `self`.
     #[inline]
@@ -617,53 +1400,283 @@ impl Vec4 {
     /// each element of `self`.
     #[inline]
     #[must_use]
-    pub fn ceil(self) -> Self {
-        Self(self.0.ceil())
+    pub fn ceil(self) -> Self {
+        Self(self.0.ceil())
+    }
+
+    /// Returns a vector containing the integer part each element of `self`. This means numbers are
+    /// always truncated towards zero.
+    #[inline]
+    #[must_use]
+    pub fn trunc(self) -> Self {
+        Self(self.0.trunc())
+    }
+
+    /// Returns a vector containing the fractional part of the vector, e.g. `self -
+    /// self.floor()`.
+    ///
+    /// Note that this is fast but not precise for large numbers.
+    #[inline]
+    #[must_use]
+    pub fn fract(self) -> Self {
+        self - self.floor()
+    }
+
+    /// Returns the integer and fractional parts of each element of `self`.
+    ///
+    /// The integer part is truncated towards zero and both parts have the same sign as `self`,
+    /// so `-1.5` is split into `-1.0` and `-0.5`. Infinite elements have a fractional part of
+    /// zero and `NaN` elements return `NaN` for both parts.
+    #[inline]
+    #[must_use]
+    pub fn modf(self) -> (Self, Self) {
+        let trunc = self.trunc();
+        // avoid `inf - inf` for infinite elements
+        let fract = Self::select(
+            self.is_finite_mask() | self.is_nan_mask(),
+            self - trunc,
+            Self::ZERO,
+        );
+        (trunc, fract.copysign(self))
+    }
+
+    /// Returns a vector containing `e^self` (the exponential function) for each element of
+    /// `self`.
+    #[inline]
+    #[must_use]
+    pub fn exp(self) -> Self {
+        Self::new(
+            math::exp(self.x),
+            math::exp(self.y),
+            math::exp(self.z),
+            math::exp(self.w),
+        )
+    }
+
+    /// Returns a vector containing `2^self` for each element of `self`.
+    #[inline]
+    #[must_use]
+    pub fn exp2(self) -> Self {
+        Self::new(
+            math::exp2(self.x),
+            math::exp2(self.y),
+            math::exp2(self.z),
+            math::exp2(self.w),
+        )
+    }
+
+    /// Returns a vector containing the natural logarithm of each element of `self`.
+    ///
+    /// Elements less than zero result in `NaN` and zero results in negative infinity.
+    #[inline]
+    #[must_use]
+    pub fn ln(self) -> Self {
+        Self::new(
+            math::ln(self.x),
+            math::ln(self.y),
+            math::ln(self.z),
+            math::ln(self.w),
+        )
+    }
+
+    /// Returns a vector containing the base 2 logarithm of each element of `self`.
+    ///
+    /// Elements less than zero result in `NaN` and zero results in negative infinity.
+    #[inline]
+    #[must_use]
+    pub fn log2(self) -> Self {
+        Self::new(
+            math::log2(self.x),
+            math::log2(self.y),
+            math::log2(self.z),
+            math::log2(self.w),
+        )
+    }
+
+    /// Returns a vector containing each element of `self` raised to the power of `n`.
+    #[inline]
+    #[must_use]
+    pub fn powf(self, n: f32) -> Self {
+        Self::new(
+            math::powf(self.x, n),
+            math::powf(self.y, n),
+            math::powf(self.z, n),
+            math::powf(self.w, n),
+        )
+    }
+
+    /// Returns a vector containing each element of `self` raised to the power of the
+    /// corresponding element of `rhs`.
+    ///
+    /// In other words this computes `[self.x.powf(rhs.x), self.y.powf(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub fn pow(self, rhs: Self) -> Self {
+        Self::new(
+            math::powf(self.x, rhs.x),
+            math::powf(self.y, rhs.y),
+            math::powf(self.z, rhs.z),
+            math::powf(self.w, rhs.w),
+        )
+    }
+
+    /// Returns a vector containing the sine of each element of `self` in radians.
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Self {
+        Self::new(
+            math::sin(self.x),
+            math::sin(self.y),
+            math::sin(self.z),
+            math::sin(self.w),
+        )
+    }
+
+    /// Returns a vector containing the cosine of each element of `self` in radians.
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Self {
+        Self::new(
+            math::cos(self.x),
+            math::cos(self.y),
+            math::cos(self.z),
+            math::cos(self.w),
+        )
+    }
+
+    /// Returns a vector containing the tangent of each element of `self` in radians.
+    #[inline]
+    #[must_use]
+    pub fn tan(self) -> Self {
+        Self::new(
+            math::tan(self.x),
+            math::tan(self.y),
+            math::tan(self.z),
+            math::tan(self.w),
+        )
+    }
+
+    /// Returns a vector containing the arcsine of each element of `self` in radians.
+    ///
+    /// Elements outside of the range `[-1, 1]` result in `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn asin(self) -> Self {
+        Self::new(
+            math::asin(self.x),
+            math::asin(self.y),
+            math::asin(self.z),
+            math::asin(self.w),
+        )
+    }
+
+    /// Returns a vector containing the arccosine of each element of `self` in radians.
+    ///
+    /// Elements outside of the range `[-1, 1]` result in `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn acos(self) -> Self {
+        Self::new(
+            math::acos(self.x),
+            math::acos(self.y),
+            math::acos(self.z),
+            math::acos(self.w),
+        )
     }
 
-    /// Returns a vector containing the integer part each element of `self`. This means numbers are
-    /// always truncated towards zero.
+    /// Returns a vector containing the arctangent of each element of `self` in radians.
     #[inline]
     #[must_use]
-    pub fn trunc(self) -> Self {
-        Self(self.0.trunc())
+    pub fn atan(self) -> Self {
+        Self::new(
+            math::atan(self.x),
+            math::atan(self.y),
+            math::atan(self.z),
+            math::atan(self.w),
+        )
     }
 
-    /// Returns a vector containing the fractional part of the vector, e.g. `self -
-    /// self.floor()`.
+    /// Returns a vector containing the four quadrant arctangent of each element of `self` and
+    /// the corresponding element of `rhs` in radians.
     ///
-    /// Note that this is fast but not precise for large numbers.
+    /// In other words this computes `[self.x.atan2(rhs.x), self.y.atan2(rhs.y), ..]`, where the
+    /// elements of `self` are the `y` coordinates and the elements of `rhs` the `x` coordinates.
     #[inline]
     #[must_use]
-    pub fn fract(self) -> Self {
-        self - self.floor()
+    pub fn atan2(self, rhs: Self) -> Self {
+        Self::new(
+            math::atan2(self.x, rhs.x),
+            math::atan2(self.y, rhs.y),
+            math::atan2(self.z, rhs.z),
+            math::atan2(self.w, rhs.w),
+        )
     }
 
-    /// Returns a vector containing `e^self` (the exponential function) for each element of
-    /// `self`.
+    /// Converts an sRGB encoded color to linear using the piecewise sRGB transfer function.
+    ///
+    /// The `w` element is treated as alpha and is returned unchanged.
     #[inline]
     #[must_use]
-    pub fn exp(self) -> Self {
+    pub fn to_linear(self) -> Self {
         Self::new(
-            math::exp(self.x),
-            math::exp(self.y),
-            math::exp(self.z),
-            math::exp(self.w),
+            math::srgb_to_linear(self.x),
+            math::srgb_to_linear(self.y),
+            math::srgb_to_linear(self.z),
+            self.w,
         )
     }
 
-    /// Returns a vector containing each element of `self` raised to the power of `n`.
+    /// Converts a linear color to sRGB using the piecewise sRGB transfer function.
+    ///
+    /// The `w` element is treated as alpha and is returned unchanged.
     #[inline]
     #[must_use]
-    pub fn powf(self, n: f32) -> Self {
+    pub fn to_srgb(self) -> Self {
         Self::new(
-            math::powf(self.x, n),
-            math::powf(self.y, n),
-            math::powf(self.z, n),
-            math::powf(self.w, n),
+            math::linear_to_srgb(self.x),
+            math::linear_to_srgb(self.y),
+            math::linear_to_srgb(self.z),
+            self.w,
         )
     }
 
+    /// Converts an sRGB encoded color in the range `[0, 1]` to linear using a fast polynomial
+    /// approximation of the sRGB transfer function.
+    ///
+    /// The absolute error is less than `0.003` which is within one step of an 8-bit color
+    /// channel, prefer [`Self::to_linear()`] when the exact result is required.
+    ///
+    /// The `w` element is treated as alpha and is returned unchanged.
+    #[inline]
+    #[must_use]
+    pub fn to_linear_fast(self) -> Self {
+        let c = self;
+        let l = c * (c * (c * 0.30530602 + 0.6821711) + 0.012522878);
+        Self::new(l.x, l.y, l.z, self.w)
+    }
+
+    /// Converts a linear color in the range `[0, 1]` to sRGB using a fast approximation of the
+    /// sRGB transfer function.
+    ///
+    /// The absolute error is less than `0.003` which is within one step of an 8-bit color
+    /// channel, prefer [`Self::to_srgb()`] when the exact result is required.
+    ///
+    /// The `w` element is treated as alpha and is returned unchanged.
+    #[inline]
+    #[must_use]
+    pub fn to_srgb_fast(self) -> Self {
+        let s1 = Self::new(
+            math::sqrt(self.x),
+            math::sqrt(self.y),
+            math::sqrt(self.z),
+            0.0,
+        );
+        let s2 = Self::new(math::sqrt(s1.x), math::sqrt(s1.y), math::sqrt(s1.z), 0.0);
+        let s3 = Self::new(math::sqrt(s2.x), math::sqrt(s2.y), math::sqrt(s2.z), 0.0);
+        let s = s1 * 0.5851224 + s2 * 0.78314036 - s3 * 0.36826274;
+        Self::new(s.x, s.y, s.z, self.w)
+    }
+
     /// Returns a vector containing the reciprocal `1.0/n` of each element of `self`.
     #[inline]
     #[must_use]
@@ -708,6 +1721,60 @@ impl Vec4 {
         (self + rhs) * 0.5
     }
 
+    /// Returns the centroid, or mean position, of `points`.
+    ///
+    /// The points are summed using Kahan compensated summation so that precision is not lost when
+    /// averaging a large number of points.
+    ///
+    /// Returns a vector of `NaN` if `points` is empty.
+    #[inline]
+    #[must_use]
+    pub fn centroid(points: &[Self]) -> Self {
+        let mut sum = Self::ZERO;
+        let mut c = Self::ZERO;
+        for &p in points {
+            let y = p - c;
+            let t = sum + y;
+            c = (t - sum) - y;
+            sum = t;
+        }
+        sum / points.len() as f32
+    }
+
+    /// Returns the mean of `points` with each point weighted by the matching element of
+    /// `weights`.
+    ///
+    /// The weighted points and the weights are summed using Kahan compensated summation so that
+    /// precision is not lost when averaging a large number of points.
+    ///
+    /// Returns a vector of `NaN` if `points` is empty and a non-finite vector if the weights sum
+    /// to zero.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `weights` have different lengths.
+    #[inline]
+    #[must_use]
+    pub fn weighted_mean(points: &[Self], weights: &[f32]) -> Self {
+        assert_eq!(points.len(), weights.len());
+        let mut sum = Self::ZERO;
+        let mut c = Self::ZERO;
+        let mut weight_sum = 0.0;
+        let mut weight_c = 0.0;
+        for (&p, &w) in points.iter().zip(weights) {
+            let y = p * w - c;
+            let t = sum + y;
+            c = (t - sum) - y;
+            sum = t;
+
+            let y = w - weight_c;
+            let t = weight_sum + y;
+            weight_c = (t - weight_sum) - y;
+            weight_sum = t;
+        }
+        sum / weight_sum
+    }
+
     /// Returns true if the absolute difference of all elements between `self` and `rhs` is
     /// less than or equal to `max_abs_diff`.
     ///
@@ -723,6 +1790,22 @@ impl Vec4 {
         self.sub(rhs).abs().cmple(Self::splat(max_abs_diff)).all()
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_ulps: u32) -> bool {
+        math::ulps_eq(self.x, rhs.x, max_ulps)
+            && math::ulps_eq(self.y, rhs.y, max_ulps)
+            && math::ulps_eq(self.z, rhs.z, max_ulps)
+            && math::ulps_eq(self.w, rhs.w, max_ulps)
+    }
+
     /// Returns a vector with a length no less than `min` and no more than `max`
     ///
     /// # Panics
@@ -779,6 +1862,62 @@ impl Vec4 {
         Self(self.0.mul_add(a.0, b.0))
     }
 
+    /// Converts each element of `self` to a half precision float and packs them into a `u64`
+    /// with `x` in the least significant bits.
+    ///
+    /// Elements are rounded to the nearest half float with halfway cases rounded to even.
+    #[inline]
+    #[must_use]
+    pub fn pack_half_4x16(self) -> u64 {
+        crate::packing::pack_half_4x16(self)
+    }
+
+    /// Unpacks four half precision floats from `p`, the inverse of [`Self::pack_half_4x16()`].
+    #[inline]
+    #[must_use]
+    pub fn unpack_half_4x16(p: u64) -> Self {
+        crate::packing::unpack_half_4x16(p)
+    }
+
+    /// Packs `self` into a `u32` as three 10-bit unsigned normalized integers for `x`, `y` and `z`
+    /// and a 2-bit unsigned normalized integer for `w`.
+    ///
+    /// `x` is stored in the least significant bits, matching the
+    /// `GL_UNSIGNED_INT_2_10_10_10_REV` and `DXGI_FORMAT_R10G10B10A2_UNORM` formats. Elements are
+    /// clamped to the range `[0, 1]`.
+    #[inline]
+    #[must_use]
+    pub fn to_unorm_10_10_10_2(self) -> u32 {
+        crate::packing::pack_unorm_10_10_10_2(self)
+    }
+
+    /// Unpacks a vector from three 10-bit and one 2-bit unsigned normalized integers, the inverse
+    /// of [`Self::to_unorm_10_10_10_2()`].
+    #[inline]
+    #[must_use]
+    pub fn from_unorm_10_10_10_2(p: u32) -> Self {
+        crate::packing::unpack_unorm_10_10_10_2(p)
+    }
+
+    /// Packs `self` into a `u32` as three 10-bit signed normalized integers for `x`, `y` and `z`
+    /// and a 2-bit signed normalized integer for `w`.
+    ///
+    /// `x` is stored in the least significant bits, matching the `GL_INT_2_10_10_10_REV` format.
+    /// Elements are clamped to the range `[-1, 1]`.
+    #[inline]
+    #[must_use]
+    pub fn to_snorm_10_10_10_2(self) -> u32 {
+        crate::packing::pack_snorm_10_10_10_2(self)
+    }
+
+    /// Unpacks a vector from three 10-bit and one 2-bit signed normalized integers, the inverse of
+    /// [`Self::to_snorm_10_10_10_2()`].
+    #[inline]
+    #[must_use]
+    pub fn from_snorm_10_10_10_2(p: u32) -> Self {
+        crate::packing::unpack_snorm_10_10_10_2(p)
+    }
+
     /// Casts all elements of `self` to `f64`.
     #[inline]
     #[must_use]
@@ -827,6 +1966,192 @@ impl Vec4 {
     pub fn as_u64vec4(&self) -> crate::U64Vec4 {
         crate::U64Vec4::new(self.x as u64, self.y as u64, self.z as u64, self.w as u64)
     }
+
+    /// Rounds all elements of `self` using [`Self::round`] and casts them to `i32`.
+    ///
+    /// This is equivalent to `self.round().as_ivec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec4_round(&self) -> crate::IVec4 {
+        self.round().as_ivec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::floor`] and casts them to `i32`.
+    ///
+    /// This is equivalent to `self.floor().as_ivec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec4_floor(&self) -> crate::IVec4 {
+        self.floor().as_ivec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::ceil`] and casts them to `i32`.
+    ///
+    /// This is equivalent to `self.ceil().as_ivec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec4_ceil(&self) -> crate::IVec4 {
+        self.ceil().as_ivec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::round`] and casts them to `u32`.
+    ///
+    /// This is equivalent to `self.round().as_uvec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec4_round(&self) -> crate::UVec4 {
+        self.round().as_uvec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::floor`] and casts them to `u32`.
+    ///
+    /// This is equivalent to `self.floor().as_uvec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec4_floor(&self) -> crate::UVec4 {
+        self.floor().as_uvec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::ceil`] and casts them to `u32`.
+    ///
+    /// This is equivalent to `self.ceil().as_uvec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec4_ceil(&self) -> crate::UVec4 {
+        self.ceil().as_uvec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::round`] and casts them to `i64`.
+    ///
+    /// This is equivalent to `self.round().as_i64vec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec4_round(&self) -> crate::I64Vec4 {
+        self.round().as_i64vec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::floor`] and casts them to `i64`.
+    ///
+    /// This is equivalent to `self.floor().as_i64vec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec4_floor(&self) -> crate::I64Vec4 {
+        self.floor().as_i64vec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::ceil`] and casts them to `i64`.
+    ///
+    /// This is equivalent to `self.ceil().as_i64vec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec4_ceil(&self) -> crate::I64Vec4 {
+        self.ceil().as_i64vec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::round`] and casts them to `u64`.
+    ///
+    /// This is equivalent to `self.round().as_u64vec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec4_round(&self) -> crate::U64Vec4 {
+        self.round().as_u64vec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::floor`] and casts them to `u64`.
+    ///
+    /// This is equivalent to `self.floor().as_u64vec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec4_floor(&self) -> crate::U64Vec4 {
+        self.floor().as_u64vec4()
+    }
+
+    /// Rounds all elements of `self` using [`Self::ceil`] and casts them to `u64`.
+    ///
+    /// This is equivalent to `self.ceil().as_u64vec4()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec4_ceil(&self) -> crate::U64Vec4 {
+        self.ceil().as_u64vec4()
+    }
+
+    /// Casts all elements of `self` to `i16`, clamping values which are out of range to
+    /// `i16::MIN` or `i16::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_i16vec4`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_i16vec4_saturating(&self) -> crate::I16Vec4 {
+        self.as_i16vec4()
+    }
+
+    /// Casts all elements of `self` to `u16`, clamping values which are out of range to
+    /// `u16::MIN` or `u16::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_u16vec4`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_u16vec4_saturating(&self) -> crate::U16Vec4 {
+        self.as_u16vec4()
+    }
+
+    /// Casts all elements of `self` to `i32`, clamping values which are out of range to
+    /// `i32::MIN` or `i32::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_ivec4`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec4_saturating(&self) -> crate::IVec4 {
+        self.as_ivec4()
+    }
+
+    /// Casts all elements of `self` to `u32`, clamping values which are out of range to
+    /// `u32::MIN` or `u32::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_uvec4`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec4_saturating(&self) -> crate::UVec4 {
+        self.as_uvec4()
+    }
+
+    /// Casts all elements of `self` to `i64`, clamping values which are out of range to
+    /// `i64::MIN` or `i64::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_i64vec4`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec4_saturating(&self) -> crate::I64Vec4 {
+        self.as_i64vec4()
+    }
+
+    /// Casts all elements of `self` to `u64`, clamping values which are out of range to
+    /// `u64::MIN` or `u64::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_u64vec4`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec4_saturating(&self) -> crate::U64Vec4 {
+        self.as_u64vec4()
+    }
 }
 
 impl Default for Vec4 {
@@ -1049,6 +2374,57 @@ impl AsMut<[f32; 4]> for Vec4 {
     }
 }
 
+impl IntoIterator for Vec4 {
+    type Item = f32;
+    type IntoIter = core::array::IntoIter<f32, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a> IntoIterator for &'a Vec4 {
+    type Item = &'a f32;
+    type IntoIter = core::slice::Iter<'a, f32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a> IntoIterator for &'a mut Vec4 {
+    type Item = &'a mut f32;
+    type IntoIter = core::slice::IterMut<'a, f32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
+
+impl FromIterator<f32> for Vec4 {
+    /// Creates a vector from the first 4 values yielded by `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` yields fewer than 4 values, see [`Vec4::try_from_iter`]
+    /// for a non-panicking version.
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        match Self::try_from_iter(iter) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
 impl Sum for Vec4 {
     #[inline]
     fn sum<I>(iter: I) -> Self
@@ -1112,18 +2488,95 @@ impl IndexMut<usize> for Vec4 {
     }
 }
 
+impl Index<crate::Axis> for Vec4 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, axis: crate::Axis) -> &Self::Output {
+        &self[axis.index()]
+    }
+}
+
+impl IndexMut<crate::Axis> for Vec4 {
+    #[inline]
+    fn index_mut(&mut self, axis: crate::Axis) -> &mut Self::Output {
+        &mut self[axis.index()]
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Vec4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(
-                f,
-                "[{:.*}, {:.*}, {:.*}, {:.*}]",
-                p, self.x, p, self.y, p, self.z, p, self.w
-            )
-        } else {
-            write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
-        }
+        crate::display::fmt_list(f, &self.to_array())
+    }
+}
+
+impl PartialEq for crate::TotalOrd<Vec4> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.cmp_total(rhs.0).is_eq()
+    }
+}
+
+impl Eq for crate::TotalOrd<Vec4> {}
+
+impl PartialOrd for crate::TotalOrd<Vec4> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for crate::TotalOrd<Vec4> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+        self.0.cmp_total(rhs.0)
+    }
+}
+
+impl core::hash::Hash for crate::TotalOrd<Vec4> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.x.to_bits().hash(state);
+        self.0.y.to_bits().hash(state);
+        self.0.z.to_bits().hash(state);
+        self.0.w.to_bits().hash(state);
+    }
+}
+
+impl crate::Hashable<Vec4> {
+    #[inline]
+    fn canonical_bits(&self) -> [u32; 4] {
+        [
+            crate::hashable::canonical_bits_f32(self.0.x),
+            crate::hashable::canonical_bits_f32(self.0.y),
+            crate::hashable::canonical_bits_f32(self.0.z),
+            crate::hashable::canonical_bits_f32(self.0.w),
+        ]
+    }
+}
+
+impl PartialEq for crate::Hashable<Vec4> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.canonical_bits() == rhs.canonical_bits()
+    }
+}
+
+impl Eq for crate::Hashable<Vec4> {}
+
+impl core::hash::Hash for crate::Hashable<Vec4> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.canonical_bits().hash(state);
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Vec4 {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -0,0 +1,395 @@
+use crate::{f32::math, DVec3, Vec3};
+use core::ops::*;
+
+/// Returns `a + b` and its rounding error, requires `|a| >= |b|`.
+#[inline]
+fn fast_two_sum(a: f32, b: f32) -> (f32, f32) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// A double-single precision floating point number represented as the unevaluated sum of two
+/// `f32` values.
+///
+/// This provides roughly 48 bits of mantissa precision using only `f32` arithmetic, which is
+/// useful on targets or GPUs where `f64` is unavailable or slow. The value is `hi + lo` where `lo`
+/// is at most half an ulp of `hi`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct DoubleF32 {
+    /// The high order part of the value.
+    pub hi: f32,
+    /// The low order part of the value.
+    pub lo: f32,
+}
+
+impl DoubleF32 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    /// All ones.
+    pub const ONE: Self = Self::new(1.0, 0.0);
+
+    /// Creates a new value from its high and low order parts.
+    ///
+    /// The parts are expected to be normalized, `lo` should be no larger than half an ulp of
+    /// `hi`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(hi: f32, lo: f32) -> Self {
+        Self { hi, lo }
+    }
+
+    /// Creates a new value from a `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn from_f32(v: f32) -> Self {
+        Self::new(v, 0.0)
+    }
+
+    /// Creates the closest double-single value to `v`.
+    #[inline]
+    #[must_use]
+    pub fn from_f64(v: f64) -> Self {
+        let hi = v as f32;
+        Self::new(hi, (v - hi as f64) as f32)
+    }
+
+    /// Returns the value as a `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> f64 {
+        self.hi as f64 + self.lo as f64
+    }
+
+    /// Returns the value rounded to a `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_f32(self) -> f32 {
+        self.hi + self.lo
+    }
+}
+
+impl From<f32> for DoubleF32 {
+    #[inline]
+    fn from(v: f32) -> Self {
+        Self::from_f32(v)
+    }
+}
+
+impl From<f64> for DoubleF32 {
+    #[inline]
+    fn from(v: f64) -> Self {
+        Self::from_f64(v)
+    }
+}
+
+impl From<DoubleF32> for f64 {
+    #[inline]
+    fn from(v: DoubleF32) -> Self {
+        v.as_f64()
+    }
+}
+
+impl Add for DoubleF32 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let (s, e) = math::two_sum(self.hi, rhs.hi);
+        let (hi, lo) = fast_two_sum(s, e + self.lo + rhs.lo);
+        Self::new(hi, lo)
+    }
+}
+
+impl AddAssign for DoubleF32 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for DoubleF32 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl SubAssign for DoubleF32 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for DoubleF32 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (p, e) = math::two_product(self.hi, rhs.hi);
+        let (hi, lo) = fast_two_sum(p, e + self.hi * rhs.lo + self.lo * rhs.hi);
+        Self::new(hi, lo)
+    }
+}
+
+impl MulAssign for DoubleF32 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for DoubleF32 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.hi, -self.lo)
+    }
+}
+
+/// A 3-dimensional vector of double-single precision values, see [`DoubleF32`].
+///
+/// The high and low order parts of each element are stored in separate [`Vec3`]s so that
+/// arithmetic is performed on whole vectors.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Vec3X {
+    /// The high order parts of the elements.
+    pub hi: Vec3,
+    /// The low order parts of the elements.
+    pub lo: Vec3,
+}
+
+impl Vec3X {
+    /// All zeroes.
+    pub const ZERO: Self = Self::new(Vec3::ZERO, Vec3::ZERO);
+
+    /// Creates a new vector from its high and low order parts.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(hi: Vec3, lo: Vec3) -> Self {
+        Self { hi, lo }
+    }
+
+    /// Creates a new vector from a `Vec3`.
+    #[inline]
+    #[must_use]
+    pub const fn from_vec3(v: Vec3) -> Self {
+        Self::new(v, Vec3::ZERO)
+    }
+
+    /// Creates the closest double-single vector to `v`.
+    #[inline]
+    #[must_use]
+    pub fn from_dvec3(v: DVec3) -> Self {
+        let hi = v.as_vec3();
+        Self::new(hi, (v - hi.as_dvec3()).as_vec3())
+    }
+
+    /// Returns the vector as a `DVec3`.
+    #[inline]
+    #[must_use]
+    pub fn as_dvec3(self) -> DVec3 {
+        self.hi.as_dvec3() + self.lo.as_dvec3()
+    }
+
+    /// Returns the vector rounded to a `Vec3`.
+    #[inline]
+    #[must_use]
+    pub fn as_vec3(self) -> Vec3 {
+        self.hi + self.lo
+    }
+
+    /// Returns the `x` element.
+    #[inline]
+    #[must_use]
+    pub fn x(self) -> DoubleF32 {
+        DoubleF32::new(self.hi.x, self.lo.x)
+    }
+
+    /// Returns the `y` element.
+    #[inline]
+    #[must_use]
+    pub fn y(self) -> DoubleF32 {
+        DoubleF32::new(self.hi.y, self.lo.y)
+    }
+
+    /// Returns the `z` element.
+    #[inline]
+    #[must_use]
+    pub fn z(self) -> DoubleF32 {
+        DoubleF32::new(self.hi.z, self.lo.z)
+    }
+
+    /// Computes the dot product of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> DoubleF32 {
+        let p = self * rhs;
+        p.x() + p.y() + p.z()
+    }
+
+    /// Returns `a + b` and its rounding error for each element.
+    #[inline]
+    fn two_sum(a: Vec3, b: Vec3) -> (Vec3, Vec3) {
+        let s = a + b;
+        let bb = s - a;
+        (s, (a - (s - bb)) + (b - bb))
+    }
+
+    /// Returns `a + b` and its rounding error for each element, requires `|a| >= |b|`.
+    #[inline]
+    fn fast_two_sum(a: Vec3, b: Vec3) -> Self {
+        let s = a + b;
+        Self::new(s, b - (s - a))
+    }
+}
+
+impl From<Vec3> for Vec3X {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        Self::from_vec3(v)
+    }
+}
+
+impl From<DVec3> for Vec3X {
+    #[inline]
+    fn from(v: DVec3) -> Self {
+        Self::from_dvec3(v)
+    }
+}
+
+impl From<Vec3X> for DVec3 {
+    #[inline]
+    fn from(v: Vec3X) -> Self {
+        v.as_dvec3()
+    }
+}
+
+impl Add for Vec3X {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let (s, e) = Self::two_sum(self.hi, rhs.hi);
+        Self::fast_two_sum(s, e + self.lo + rhs.lo)
+    }
+}
+
+impl AddAssign for Vec3X {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3X {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl SubAssign for Vec3X {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Multiplies each element of `self` by the corresponding element of `rhs`.
+impl Mul for Vec3X {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let p = self.hi * rhs.hi;
+        let e = self.hi.mul_add(rhs.hi, -p);
+        Self::fast_two_sum(p, e + self.hi * rhs.lo + self.lo * rhs.hi)
+    }
+}
+
+impl MulAssign for Vec3X {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<DoubleF32> for Vec3X {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: DoubleF32) -> Self {
+        self * Self::new(Vec3::splat(rhs.hi), Vec3::splat(rhs.lo))
+    }
+}
+
+impl MulAssign<DoubleF32> for Vec3X {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DoubleF32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for Vec3X {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.hi, -self.lo)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DoubleF32, Vec3X};
+    use crate::{DVec3, Vec3};
+
+    #[test]
+    fn test_double_f32() {
+        let a = DoubleF32::from_f64(1.0 + 1e-10);
+        assert_eq!(a.hi, 1.0);
+        assert_ne!(a.lo, 0.0);
+        assert!((a.as_f64() - (1.0 + 1e-10)).abs() < 1e-15);
+
+        let b = DoubleF32::from_f64(1e-10);
+        assert!(((a - b).as_f64() - 1.0).abs() < 1e-15);
+        assert!(((a + b).as_f64() - (1.0 + 2e-10)).abs() < 1e-15);
+
+        let c = DoubleF32::from_f64(1.0 / 3.0);
+        assert!(((c * DoubleF32::from(3.0_f32)).as_f64() - 1.0).abs() < 1e-14);
+        assert_eq!(-DoubleF32::ONE, DoubleF32::from(-1.0_f32));
+
+        // plain f32 loses the small addend entirely
+        let mut sum = DoubleF32::from(1e8_f32);
+        let mut sum_f32 = 1e8_f32;
+        for _ in 0..1000 {
+            sum += DoubleF32::ONE;
+            sum_f32 += 1.0;
+        }
+        assert_eq!(sum.as_f64(), 1e8 + 1000.0);
+        assert_eq!(sum_f32, 1e8);
+    }
+
+    #[test]
+    fn test_vec3x() {
+        let world = DVec3::new(6_378_137.123, -1234.5678, 0.125);
+        let camera = DVec3::new(6_378_136.0, -1234.0, 0.0);
+        let rel = Vec3X::from_dvec3(world) - Vec3X::from(camera);
+        assert!(rel.as_dvec3().abs_diff_eq(world - camera, 1e-7));
+        // a plain f32 subtraction loses most of the precision
+        let rel_f32 = world.as_vec3() - camera.as_vec3();
+        assert!(!rel_f32.abs_diff_eq((world - camera).as_vec3(), 1e-3));
+        assert!(rel.as_vec3().abs_diff_eq((world - camera).as_vec3(), 1e-6));
+
+        let a = Vec3X::from_dvec3(DVec3::new(1.0 / 3.0, 2.0, -1e7 - 0.1));
+        let b = Vec3X::from_vec3(Vec3::new(3.0, 0.5, 1.0));
+        assert!((a * b)
+            .as_dvec3()
+            .abs_diff_eq(DVec3::new(1.0, 1.0, -1e7 - 0.1), 1e-8));
+        assert!((a.dot(b).as_f64() - (2.0 - 1e7 - 0.1)).abs() < 1e-8);
+        assert!((a * DoubleF32::from(2.0_f32))
+            .as_dvec3()
+            .abs_diff_eq(a.as_dvec3() * 2.0, 1e-12));
+        assert_eq!(a - a, Vec3X::ZERO);
+    }
+}
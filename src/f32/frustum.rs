@@ -0,0 +1,121 @@
+use crate::{Mat4, Plane, Vec3, Vec4};
+
+/// A view frustum represented by six planes with normals facing inwards.
+///
+/// The planes are stored in the order left, right, bottom, top, near and far. A point `p` is
+/// inside a plane if `plane.signed_distance(p) >= 0.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Creates a frustum from six planes with normals facing inwards.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_planes(planes: [Plane; 6]) -> Self {
+        Self { planes }
+    }
+
+    /// Extracts the frustum planes from a view projection matrix using the method of Gribb and
+    /// Hartmann.
+    ///
+    /// The matrix is expected to map to a clip space depth range of `[0, 1]`, as used by the
+    /// `Mat4` perspective and orthographic constructors. The planes are normalized unless they
+    /// are degenerate, such as the far plane of an infinite projection.
+    #[must_use]
+    pub fn from_mat4(view_proj: &Mat4) -> Self {
+        let r0 = view_proj.row(0);
+        let r1 = view_proj.row(1);
+        let r2 = view_proj.row(2);
+        let r3 = view_proj.row(3);
+        let plane = |v: Vec4| {
+            let normal = v.truncate();
+            let len = normal.length();
+            if len > 0.0 {
+                Plane::new(normal / len, v.w / len)
+            } else {
+                Plane::new(normal, v.w)
+            }
+        };
+        Self::from_planes([
+            plane(r3 + r0),
+            plane(r3 - r0),
+            plane(r3 + r1),
+            plane(r3 - r1),
+            plane(r2),
+            plane(r3 - r2),
+        ])
+    }
+
+    /// Returns `true` if `point` is inside or on the boundary of the frustum.
+    #[inline]
+    #[must_use]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Returns `true` if the axis aligned bounding box from `min` to `max` is at least partially
+    /// inside the frustum.
+    ///
+    /// This is a conservative test, boxes near the corners of the frustum may be reported as
+    /// intersecting even though they are outside.
+    #[inline]
+    #[must_use]
+    pub fn intersects_aabb(&self, min: Vec3, max: Vec3) -> bool {
+        self.planes.iter().all(|plane| {
+            // the corner furthest along the plane normal
+            let p = Vec3::select(plane.normal.cmpge(Vec3::ZERO), max, min);
+            plane.signed_distance(p) >= 0.0
+        })
+    }
+
+    /// Tests a batch of axis aligned bounding boxes stored as separate arrays of their `min`
+    /// and `max` components against the frustum.
+    ///
+    /// The boxes are tested four at a time using SIMD where available. Box `i` is visible if
+    /// [`Self::intersects_aabb()`] returns `true` for it, in which case bit `i % 32` of
+    /// `visible[i / 32]` is set, otherwise it is cleared. Any remaining bits in the last word
+    /// are cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the component slices are not all the same length or if `visible` has fewer
+    /// than `len.div_ceil(32)` words.
+    pub fn intersects_aabbs_soa(&self, min: [&[f32]; 3], max: [&[f32]; 3], visible: &mut [u32]) {
+        let len = min[0].len();
+        assert!(min.iter().chain(&max).all(|s| s.len() == len));
+        let words = (len + 31) / 32;
+        assert!(visible.len() >= words);
+        visible[..words].fill(0);
+
+        let chunks = len / 4;
+        for chunk in 0..chunks {
+            let i = chunk * 4;
+            let load = |s: &[f32]| Vec4::from_slice(&s[i..i + 4]);
+            let min = [load(min[0]), load(min[1]), load(min[2])];
+            let max = [load(max[0]), load(max[1]), load(max[2])];
+            let mut bits = 0xf;
+            for plane in &self.planes {
+                let n = plane.normal;
+                let px = if n.x >= 0.0 { max[0] } else { min[0] };
+                let py = if n.y >= 0.0 { max[1] } else { min[1] };
+                let pz = if n.z >= 0.0 { max[2] } else { min[2] };
+                let dist = px * n.x + py * n.y + pz * n.z + Vec4::splat(plane.d);
+                bits &= dist.cmpge(Vec4::ZERO).bitmask();
+            }
+            visible[i / 32] |= bits << (i % 32);
+        }
+
+        for i in chunks * 4..len {
+            let min = Vec3::new(min[0][i], min[1][i], min[2][i]);
+            let max = Vec3::new(max[0][i], max[1][i], max[2][i]);
+            if self.intersects_aabb(min, max) {
+                visible[i / 32] |= 1 << (i % 32);
+            }
+        }
+    }
+}
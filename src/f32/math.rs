@@ -32,6 +32,149 @@ fn acos_approx_f32(v: f32) -> f32 {
     }
 }
 
+/// Returns true if `a` and `b` are within `max_ulps` units in the last place of each other.
+///
+/// `NaN` is never equal to anything and values with different signs are only equal if they are
+/// both zero.
+#[inline]
+pub(crate) fn ulps_eq(a: f32, b: f32, max_ulps: u32) -> bool {
+    if a == b {
+        true
+    } else if a.is_nan() || b.is_nan() || a.is_sign_negative() != b.is_sign_negative() {
+        false
+    } else {
+        // the bit patterns of floats with the same sign are ordered by their magnitude
+        a.to_bits().abs_diff(b.to_bits()) <= max_ulps
+    }
+}
+
+/// Returns the minimum of `a` and `b`, propagating `NaN` and treating `-0.0` as less than `0.0`.
+#[inline]
+pub(crate) fn minimum(a: f32, b: f32) -> f32 {
+    if a < b {
+        a
+    } else if b < a {
+        b
+    } else if a == b {
+        if a.is_sign_negative() {
+            a
+        } else {
+            b
+        }
+    } else {
+        // at least one input is NaN
+        a + b
+    }
+}
+
+/// Returns the maximum of `a` and `b`, propagating `NaN` and treating `-0.0` as less than `0.0`.
+#[inline]
+pub(crate) fn maximum(a: f32, b: f32) -> f32 {
+    if a > b {
+        a
+    } else if b > a {
+        b
+    } else if a == b {
+        if a.is_sign_positive() {
+            a
+        } else {
+            b
+        }
+    } else {
+        // at least one input is NaN
+        a + b
+    }
+}
+
+/// Returns `a + b` and the rounding error of the addition, such that the sum of the two results
+/// is exactly `a + b`.
+#[inline]
+pub(crate) fn two_sum(a: f32, b: f32) -> (f32, f32) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Returns `a * b` and the rounding error of the multiplication, such that the sum of the two
+/// results is exactly `a * b`.
+#[inline]
+pub(crate) fn two_product(a: f32, b: f32) -> (f32, f32) {
+    let p = a * b;
+    (p, mul_add(a, b, -p))
+}
+
+/// Returns the hue in the range `[0, 1)` of an RGB color with the given maximum component and
+/// difference between the maximum and minimum components.
+#[inline]
+pub(crate) fn rgb_to_hue(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
+    if delta <= 0.0 {
+        0.0
+    } else if max == r {
+        let h = (g - b) / delta;
+        (if h < 0.0 { h + 6.0 } else { h }) / 6.0
+    } else if max == g {
+        ((b - r) / delta + 2.0) / 6.0
+    } else {
+        ((r - g) / delta + 4.0) / 6.0
+    }
+}
+
+/// Converts an sRGB encoded color component to linear using the piecewise sRGB transfer function.
+#[inline]
+pub(crate) fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c * (1.0 / 12.92)
+    } else {
+        powf((c + 0.055) * (1.0 / 1.055), 2.4)
+    }
+}
+
+/// Converts a linear color component to sRGB using the piecewise sRGB transfer function.
+#[inline]
+pub(crate) fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * powf(c, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// Returns an approximation of `1.0 / sqrt(x)` with a relative error of less than `1e-6` for
+/// positive normal `x`. Other values of `x` return `1.0 / sqrt(x)`.
+///
+/// On x86 targets with SSE this refines the hardware reciprocal square root estimate with one
+/// Newton-Raphson step. The estimate differs between CPUs so it is not used when the
+/// `deterministic` feature is enabled, or on other targets, where `1.0 / sqrt(x)` is returned.
+#[inline]
+pub(crate) fn rsqrt_fast(x: f32) -> f32 {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse",
+        not(feature = "deterministic")
+    ))]
+    {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+        if x < f32::MIN_POSITIVE || !x.is_finite() {
+            // the estimate is not accurate for zero or subnormal values and the refinement
+            // gives `NaN` for infinity
+            return 1.0 / sqrt(x);
+        }
+        let y = unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(x))) };
+        y * (1.5 - 0.5 * x * y * y)
+    }
+    #[cfg(not(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse",
+        not(feature = "deterministic")
+    )))]
+    {
+        1.0 / sqrt(x)
+    }
+}
+
 #[cfg(feature = "libm")]
 mod libm_math {
     #[inline(always)]
@@ -44,16 +187,31 @@ mod libm_math {
         super::acos_approx_f32(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn acos(f: f32) -> f32 {
+        libm::acosf(f)
+    }
+
     #[inline(always)]
     pub(crate) fn asin(f: f32) -> f32 {
         libm::asinf(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn atan(f: f32) -> f32 {
+        libm::atanf(f)
+    }
+
     #[inline(always)]
     pub(crate) fn atan2(f: f32, other: f32) -> f32 {
         libm::atan2f(f, other)
     }
 
+    #[inline(always)]
+    pub(crate) fn cos(f: f32) -> f32 {
+        libm::cosf(f)
+    }
+
     #[allow(unused)]
     #[inline(always)]
     pub(crate) fn sin(f: f32) -> f32 {
@@ -94,6 +252,11 @@ mod libm_math {
         libm::roundf(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn round_ties_even(f: f32) -> f32 {
+        libm::rintf(f)
+    }
+
     #[inline(always)]
     pub(crate) fn trunc(f: f32) -> f32 {
         libm::truncf(f)
@@ -114,6 +277,21 @@ mod libm_math {
         libm::expf(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn exp2(f: f32) -> f32 {
+        libm::exp2f(f)
+    }
+
+    #[inline(always)]
+    pub(crate) fn ln(f: f32) -> f32 {
+        libm::logf(f)
+    }
+
+    #[inline(always)]
+    pub(crate) fn log2(f: f32) -> f32 {
+        libm::log2f(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f32, n: f32) -> f32 {
         libm::powf(f, n)
@@ -157,16 +335,31 @@ mod std_math {
         super::acos_approx_f32(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn acos(f: f32) -> f32 {
+        f32::acos(f)
+    }
+
     #[inline(always)]
     pub(crate) fn asin(f: f32) -> f32 {
         f32::asin(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn atan(f: f32) -> f32 {
+        f32::atan(f)
+    }
+
     #[inline(always)]
     pub(crate) fn atan2(f: f32, other: f32) -> f32 {
         f32::atan2(f, other)
     }
 
+    #[inline(always)]
+    pub(crate) fn cos(f: f32) -> f32 {
+        f32::cos(f)
+    }
+
     #[allow(unused)]
     #[inline(always)]
     pub(crate) fn sin(f: f32) -> f32 {
@@ -203,6 +396,17 @@ mod std_math {
         f32::round(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn round_ties_even(f: f32) -> f32 {
+        // `f32::round_ties_even` requires Rust 1.77
+        let r = f32::round(f);
+        if f32::abs(f - r) == 0.5 {
+            2.0 * f32::round(f * 0.5)
+        } else {
+            r
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn trunc(f: f32) -> f32 {
         f32::trunc(f)
@@ -223,6 +427,21 @@ mod std_math {
         f32::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn exp2(f: f32) -> f32 {
+        f32::exp2(f)
+    }
+
+    #[inline(always)]
+    pub(crate) fn ln(f: f32) -> f32 {
+        f32::ln(f)
+    }
+
+    #[inline(always)]
+    pub(crate) fn log2(f: f32) -> f32 {
+        f32::log2(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f32, n: f32) -> f32 {
         f32::powf(f, n)
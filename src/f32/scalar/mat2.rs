@@ -53,6 +53,19 @@ impl Mat2 {
         Self { x_axis, y_axis }
     }
 
+    /// Creates a 2x2 matrix where each element is the result of calling `f` with its column and
+    /// row index.
+    ///
+    /// `f` is called for each element in column major order.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> f32,
+    {
+        Self::new(f(0, 0), f(0, 1), f(1, 0), f(1, 1))
+    }
+
     /// Creates a 2x2 matrix from a `[f32; 4]` array stored in column major order.
     /// If your data is stored in row major you will need to `transpose` the returned
     /// matrix.
@@ -87,6 +100,77 @@ impl Mat2 {
         [self.x_axis.to_array(), self.y_axis.to_array()]
     }
 
+    /// Creates a 2x2 matrix from a `[[f32; 2]; 2]` 2D array stored in row major order.
+    #[inline]
+    #[must_use]
+    pub const fn from_rows_array_2d(m: &[[f32; 2]; 2]) -> Self {
+        Self::new(m[0][0], m[1][0], m[0][1], m[1][1])
+    }
+
+    /// Creates a `[[f32; 2]; 2]` 2D array storing data in row major order.
+    #[inline]
+    #[must_use]
+    pub const fn to_rows_array_2d(&self) -> [[f32; 2]; 2] {
+        let m = self.to_cols_array_2d();
+        [[m[0][0], m[1][0]], [m[0][1], m[1][1]]]
+    }
+
+    /// Creates a matrix from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let mut a = [f32::default(); 4];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a matrix from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let mut a = [f32::default(); 4];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates a 2x2 matrix with its diagonal set to `diagonal` and all other entries set to 0.
     #[doc(alias = "scale")]
     #[inline]
@@ -95,6 +179,17 @@ impl Mat2 {
         Self::new(diagonal.x, 0.0, 0.0, diagonal.y)
     }
 
+    /// Creates a 2x2 matrix from the outer product of `a` and `b`, this is `a * bᵀ`.
+    ///
+    /// Each column `j` of the result is `a * b[j]`. For example the projection onto the plane
+    /// with the unit normal `n` is `IDENTITY - from_outer_product(n, n)`.
+    #[inline]
+    #[must_use]
+    pub fn from_outer_product(a: Vec2, b: Vec2) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self::from_cols((a * b.x).into(), (a * b.y).into())
+    }
+
     /// Creates a 2x2 matrix containing the combining non-uniform `scale` and rotation of
     /// `angle` (in radians).
     #[inline]
@@ -137,6 +232,14 @@ impl Mat2 {
         Self::new(slice[0], slice[1], slice[2], slice[3])
     }
 
+    /// Creates a 2x2 matrix from the first 4 values in `slice`, returning an
+    /// error if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn from_cols_slice_checked(slice: &[f32]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 4)?;
+        Ok(Self::from_cols_slice(slice))
+    }
+
     /// Writes the columns of `self` to the first 4 elements in `slice`.
     ///
     /// # Panics
@@ -150,6 +253,76 @@ impl Mat2 {
         slice[3] = self.y_axis.y;
     }
 
+    /// Reinterprets a slice of matrices as a slice of their elements without copying.
+    ///
+    /// This is useful for passing transform data to APIs expecting a flat slice of
+    /// `f32`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice(matrices: &[Self]) -> &[f32] {
+        // SAFETY: `Self` consists of 4 `f32` elements with no padding
+        unsafe { crate::cast::flatten(matrices) }
+    }
+
+    /// Reinterprets a mutable slice of matrices as a mutable slice of their elements without
+    /// copying.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice_mut(matrices: &mut [Self]) -> &mut [f32] {
+        // SAFETY: see `as_flat_slice`
+        unsafe { crate::cast::flatten_mut(matrices) }
+    }
+
+    /// Reinterprets a slice of elements as a slice of matrices without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 4 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice(flat: &[f32]) -> &[Self] {
+        Self::from_flat_slice_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of matrices without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 4 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice_mut(flat: &mut [f32]) -> &mut [Self] {
+        Self::from_flat_slice_mut_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a slice of elements as a slice of matrices without copying, returning an
+    /// error if the length of `flat` is not a multiple of 4 or if `flat` is not aligned to
+    /// `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_checked(flat: &[f32]) -> Result<&[Self], crate::error::Error> {
+        // SAFETY: see `as_flat_slice`, any bit pattern is a valid `f32`
+        unsafe { crate::cast::unflatten(flat) }
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of matrices without copying,
+    /// returning an error if the length of `flat` is not a multiple of 4 or if `flat` is
+    /// not aligned to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_mut_checked(
+        flat: &mut [f32],
+    ) -> Result<&mut [Self], crate::error::Error> {
+        // SAFETY: see `from_flat_slice_checked`
+        unsafe { crate::cast::unflatten_mut(flat) }
+    }
+
     /// Returns the matrix column for the given `index`.
     ///
     /// # Panics
@@ -179,6 +352,27 @@ impl Mat2 {
         }
     }
 
+    /// Returns the matrix column for the given `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 2 axes of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn col_axis(&self, axis: crate::Axis) -> Vec2 {
+        self.col(axis.index())
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 2 axes of this matrix.
+    #[inline]
+    pub fn col_axis_mut(&mut self, axis: crate::Axis) -> &mut Vec2 {
+        self.col_mut(axis.index())
+    }
+
     /// Returns the matrix row for the given `index`.
     ///
     /// # Panics
@@ -194,6 +388,78 @@ impl Mat2 {
         }
     }
 
+    /// Returns an iterator over the columns of `self`.
+    #[inline]
+    #[must_use]
+    pub fn cols(&self) -> core::array::IntoIter<Vec2, 2> {
+        [self.x_axis, self.y_axis].into_iter()
+    }
+
+    /// Returns an iterator over mutable references to the columns of `self`.
+    #[inline]
+    pub fn cols_mut(&mut self) -> core::array::IntoIter<&mut Vec2, 2> {
+        [&mut self.x_axis, &mut self.y_axis].into_iter()
+    }
+
+    /// Returns an iterator over the rows of `self`.
+    #[inline]
+    #[must_use]
+    pub fn rows(&self) -> core::array::IntoIter<Vec2, 2> {
+        [self.row(0), self.row(1)].into_iter()
+    }
+
+    /// Sets the matrix row for the given `index` to `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    pub fn set_row(&mut self, index: usize, row: Vec2) {
+        // check the index first so that a panic leaves `self` unmodified
+        assert!(index < 2, "index out of bounds");
+        self.x_axis[index] = row.x;
+        self.y_axis[index] = row.y;
+    }
+
+    /// Calls `f` with a mutable copy of the matrix row for the given `index` and writes the
+    /// result back to `self`.
+    ///
+    /// Matrices are stored in column major order so rows can not be borrowed directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    pub fn row_mut<F>(&mut self, index: usize, f: F)
+    where
+        F: FnOnce(&mut Vec2),
+    {
+        let mut row = self.row(index);
+        f(&mut row);
+        self.set_row(index, row);
+    }
+
+    /// Returns the matrix element in the given `col` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is greater than 1.
+    #[inline]
+    #[must_use]
+    pub fn element(&self, col: usize, row: usize) -> f32 {
+        self.col(col)[row]
+    }
+
+    /// Returns a mutable reference to the matrix element in the given `col` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is greater than 1.
+    #[inline]
+    pub fn element_mut(&mut self, col: usize, row: usize) -> &mut f32 {
+        &mut self.col_mut(col)[row]
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
     #[inline]
@@ -210,9 +476,12 @@ impl Mat2 {
     }
 
     /// Returns the transpose of `self`.
+    ///
+    /// This is only a `const fn` when `Mat2` is not using SIMD storage, for example when
+    /// the `scalar-math` feature is enabled.
     #[inline]
     #[must_use]
-    pub fn transpose(&self) -> Self {
+    pub const fn transpose(&self) -> Self {
         Self {
             x_axis: Vec2::new(self.x_axis.x, self.y_axis.x),
             y_axis: Vec2::new(self.x_axis.y, self.y_axis.y),
@@ -226,6 +495,43 @@ impl Mat2 {
         self.x_axis.x * self.y_axis.y - self.x_axis.y * self.y_axis.x
     }
 
+    /// Returns `true` if `self` mirrors space, which is the case when the determinant is negative.
+    ///
+    /// Transforming by a flipping matrix reverses the winding order of triangles. Returns `false`
+    /// if `self` is close to singular, that is if the determinant is tiny relative to the product
+    /// of the column lengths.
+    #[inline]
+    #[must_use]
+    pub fn is_flipping(&self) -> bool {
+        self.relative_determinant(self.determinant()) < -f32::EPSILON
+    }
+
+    /// Returns `det` divided by the product of the column lengths.
+    ///
+    /// Dividing avoids the underflow of multiplying a tolerance by tiny column lengths.
+    #[inline]
+    fn relative_determinant(&self, det: f32) -> f32 {
+        det / self.x_axis.length() / self.y_axis.length()
+    }
+
+    #[inline]
+    fn is_invertible_determinant(&self, det: f32) -> bool {
+        det.is_finite()
+            && det.recip().is_finite()
+            && math::abs(self.relative_determinant(det)) > f32::EPSILON
+    }
+
+    /// Returns `true` if `self` can be inverted.
+    ///
+    /// A matrix is considered invertible if its determinant and the reciprocal of its determinant
+    /// are finite, and the determinant is not tiny relative to the product of the column lengths.
+    /// This is the same check as [`Self::try_inverse()`].
+    #[inline]
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        self.is_invertible_determinant(self.determinant())
+    }
+
     /// Returns the inverse of `self`.
     ///
     /// If the matrix is not invertible the returned matrix will be invalid.
@@ -249,6 +555,36 @@ impl Mat2 {
         )
     }
 
+    /// Returns the inverse of `self`, or `None` if `self` is not invertible.
+    ///
+    /// This rejects matrices that are close to singular, see [`Self::is_invertible()`]. The
+    /// determinant is only computed once.
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let inv_det = {
+            let det = self.determinant();
+            if !self.is_invertible_determinant(det) {
+                return None;
+            }
+            det.recip()
+        };
+        Some(Self::new(
+            self.y_axis.y * inv_det,
+            self.x_axis.y * -inv_det,
+            self.y_axis.x * -inv_det,
+            self.x_axis.x * inv_det,
+        ))
+    }
+
+    /// Returns the inverse of `self`, or an error if `self` is not invertible.
+    ///
+    /// This is the same check as [`Self::try_inverse()`], see [`Self::is_invertible()`].
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, crate::error::Error> {
+        self.try_inverse().ok_or(crate::error::Error::Singular)
+    }
+
     /// Transforms a 2D vector.
     #[inline]
     #[must_use]
@@ -312,6 +648,43 @@ impl Mat2 {
             && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(&self, rhs: Self, max_ulps: u32) -> bool {
+        self.x_axis.ulps_eq(rhs.x_axis, max_ulps) && self.y_axis.ulps_eq(rhs.y_axis, max_ulps)
+    }
+
+    /// Returns a matrix with elements of `self` that are not finite replaced by the
+    /// corresponding element of `fallback`.
+    ///
+    /// Elements that are `NaN`, positive or negative infinity are replaced. This can be used to
+    /// sanitize untrusted input.
+    #[inline]
+    #[must_use]
+    pub fn finite_or(&self, fallback: Self) -> Self {
+        Self::from_cols(
+            self.x_axis.finite_or(fallback.x_axis),
+            self.y_axis.finite_or(fallback.y_axis),
+        )
+    }
+
+    /// Returns a matrix containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::from_cols(self.x_axis.map(&f), self.y_axis.map(&f))
+    }
+
     /// Takes the absolute value of each element in `self`
     #[inline]
     #[must_use]
@@ -439,6 +812,22 @@ impl DivAssign<f32> for Mat2 {
     }
 }
 
+impl From<((f32, f32), (f32, f32))> for Mat2 {
+    /// Creates a matrix from a tuple of column tuples.
+    #[inline]
+    fn from((x_axis, y_axis): ((f32, f32), (f32, f32))) -> Self {
+        Self::from_cols(Vec2::from(x_axis), Vec2::from(y_axis))
+    }
+}
+
+impl From<Mat2> for ((f32, f32), (f32, f32)) {
+    /// Converts a matrix into a tuple of column tuples.
+    #[inline]
+    fn from(m: Mat2) -> Self {
+        (m.x_axis.into(), m.y_axis.into())
+    }
+}
+
 impl Sum<Self> for Mat2 {
     fn sum<I>(iter: I) -> Self
     where
@@ -511,10 +900,19 @@ impl fmt::Debug for Mat2 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Mat2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(f, "[{:.*}, {:.*}]", p, self.x_axis, p, self.y_axis)
+        if f.alternate() {
+            crate::display::fmt_grid(f, &self.to_cols_array_2d())
         } else {
-            write!(f, "[{}, {}]", self.x_axis, self.y_axis)
+            crate::display::fmt_list(f, &[self.x_axis, self.y_axis])
         }
     }
 }
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Mat2 {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|a| Self::from_cols_array(&a))
+    }
+}
@@ -109,6 +109,36 @@ impl Mat4 {
         }
     }
 
+    /// Creates a 4x4 matrix where each element is the result of calling `f` with its column and
+    /// row index.
+    ///
+    /// `f` is called for each element in column major order.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> f32,
+    {
+        Self::new(
+            f(0, 0),
+            f(0, 1),
+            f(0, 2),
+            f(0, 3),
+            f(1, 0),
+            f(1, 1),
+            f(1, 2),
+            f(1, 3),
+            f(2, 0),
+            f(2, 1),
+            f(2, 2),
+            f(2, 3),
+            f(3, 0),
+            f(3, 1),
+            f(3, 2),
+            f(3, 3),
+        )
+    }
+
     /// Creates a 4x4 matrix from a `[f32; 16]` array stored in column major order.
     /// If your data is stored in row major you will need to `transpose` the returned
     /// matrix.
@@ -173,6 +203,85 @@ impl Mat4 {
         ]
     }
 
+    /// Creates a 4x4 matrix from a `[[f32; 4]; 4]` 4D array stored in row major order.
+    #[inline]
+    #[must_use]
+    pub const fn from_rows_array_2d(m: &[[f32; 4]; 4]) -> Self {
+        Self::new(
+            m[0][0], m[1][0], m[2][0], m[3][0], m[0][1], m[1][1], m[2][1], m[3][1], m[0][2],
+            m[1][2], m[2][2], m[3][2], m[0][3], m[1][3], m[2][3], m[3][3],
+        )
+    }
+
+    /// Creates a `[[f32; 4]; 4]` 4D array storing data in row major order.
+    #[inline]
+    #[must_use]
+    pub const fn to_rows_array_2d(&self) -> [[f32; 4]; 4] {
+        let m = self.to_cols_array_2d();
+        [
+            [m[0][0], m[1][0], m[2][0], m[3][0]],
+            [m[0][1], m[1][1], m[2][1], m[3][1]],
+            [m[0][2], m[1][2], m[2][2], m[3][2]],
+            [m[0][3], m[1][3], m[2][3], m[3][3]],
+        ]
+    }
+
+    /// Creates a matrix from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 64]) -> Self {
+        let mut a = [f32::default(); 16];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a matrix from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 64]) -> Self {
+        let mut a = [f32::default(); 16];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates a 4x4 matrix with its diagonal set to `diagonal` and all other entries set to 0.
     #[doc(alias = "scale")]
     #[inline]
@@ -184,6 +293,22 @@ impl Mat4 {
         )
     }
 
+    /// Creates a 4x4 matrix from the outer product of `a` and `b`, this is `a * bᵀ`.
+    ///
+    /// Each column `j` of the result is `a * b[j]`. For example the projection onto the plane
+    /// with the unit normal `n` is `IDENTITY - from_outer_product(n, n)`.
+    #[inline]
+    #[must_use]
+    pub fn from_outer_product(a: Vec4, b: Vec4) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self::from_cols(
+            (a * b.x).into(),
+            (a * b.y).into(),
+            (a * b.z).into(),
+            (a * b.w).into(),
+        )
+    }
+
     #[inline]
     #[must_use]
     fn quat_to_axes(rotation: Quat) -> (Vec4, Vec4, Vec4) {
@@ -332,7 +457,7 @@ impl Mat4 {
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
     #[inline]
     #[must_use]
-    pub fn from_translation(translation: Vec3) -> Self {
+    pub const fn from_translation(translation: Vec3) -> Self {
         Self::from_cols(
             Vec4::X,
             Vec4::Y,
@@ -484,6 +609,14 @@ impl Mat4 {
         )
     }
 
+    /// Creates a 4x4 matrix from the first 16 values in `slice`, returning an
+    /// error if `slice` is less than 16 elements long.
+    #[inline]
+    pub fn from_cols_slice_checked(slice: &[f32]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 16)?;
+        Ok(Self::from_cols_slice(slice))
+    }
+
     /// Writes the columns of `self` to the first 16 elements in `slice`.
     ///
     /// # Panics
@@ -509,6 +642,76 @@ impl Mat4 {
         slice[15] = self.w_axis.w;
     }
 
+    /// Reinterprets a slice of matrices as a slice of their elements without copying.
+    ///
+    /// This is useful for passing transform data to APIs expecting a flat slice of
+    /// `f32`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice(matrices: &[Self]) -> &[f32] {
+        // SAFETY: `Self` consists of 16 `f32` elements with no padding
+        unsafe { crate::cast::flatten(matrices) }
+    }
+
+    /// Reinterprets a mutable slice of matrices as a mutable slice of their elements without
+    /// copying.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice_mut(matrices: &mut [Self]) -> &mut [f32] {
+        // SAFETY: see `as_flat_slice`
+        unsafe { crate::cast::flatten_mut(matrices) }
+    }
+
+    /// Reinterprets a slice of elements as a slice of matrices without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 16 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice(flat: &[f32]) -> &[Self] {
+        Self::from_flat_slice_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of matrices without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 16 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice_mut(flat: &mut [f32]) -> &mut [Self] {
+        Self::from_flat_slice_mut_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a slice of elements as a slice of matrices without copying, returning an
+    /// error if the length of `flat` is not a multiple of 16 or if `flat` is not aligned to
+    /// `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_checked(flat: &[f32]) -> Result<&[Self], crate::error::Error> {
+        // SAFETY: see `as_flat_slice`, any bit pattern is a valid `f32`
+        unsafe { crate::cast::unflatten(flat) }
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of matrices without copying,
+    /// returning an error if the length of `flat` is not a multiple of 16 or if `flat` is
+    /// not aligned to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_mut_checked(
+        flat: &mut [f32],
+    ) -> Result<&mut [Self], crate::error::Error> {
+        // SAFETY: see `from_flat_slice_checked`
+        unsafe { crate::cast::unflatten_mut(flat) }
+    }
+
     /// Returns the matrix column for the given `index`.
     ///
     /// # Panics
@@ -542,6 +745,27 @@ impl Mat4 {
         }
     }
 
+    /// Returns the matrix column for the given `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 4 axes of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn col_axis(&self, axis: crate::Axis) -> Vec4 {
+        self.col(axis.index())
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 4 axes of this matrix.
+    #[inline]
+    pub fn col_axis_mut(&mut self, axis: crate::Axis) -> &mut Vec4 {
+        self.col_mut(axis.index())
+    }
+
     /// Returns the matrix row for the given `index`.
     ///
     /// # Panics
@@ -559,6 +783,86 @@ impl Mat4 {
         }
     }
 
+    /// Returns an iterator over the columns of `self`.
+    #[inline]
+    #[must_use]
+    pub fn cols(&self) -> core::array::IntoIter<Vec4, 4> {
+        [self.x_axis, self.y_axis, self.z_axis, self.w_axis].into_iter()
+    }
+
+    /// Returns an iterator over mutable references to the columns of `self`.
+    #[inline]
+    pub fn cols_mut(&mut self) -> core::array::IntoIter<&mut Vec4, 4> {
+        [
+            &mut self.x_axis,
+            &mut self.y_axis,
+            &mut self.z_axis,
+            &mut self.w_axis,
+        ]
+        .into_iter()
+    }
+
+    /// Returns an iterator over the rows of `self`.
+    #[inline]
+    #[must_use]
+    pub fn rows(&self) -> core::array::IntoIter<Vec4, 4> {
+        [self.row(0), self.row(1), self.row(2), self.row(3)].into_iter()
+    }
+
+    /// Sets the matrix row for the given `index` to `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    pub fn set_row(&mut self, index: usize, row: Vec4) {
+        // check the index first so that a panic leaves `self` unmodified
+        assert!(index < 4, "index out of bounds");
+        self.x_axis[index] = row.x;
+        self.y_axis[index] = row.y;
+        self.z_axis[index] = row.z;
+        self.w_axis[index] = row.w;
+    }
+
+    /// Calls `f` with a mutable copy of the matrix row for the given `index` and writes the
+    /// result back to `self`.
+    ///
+    /// Matrices are stored in column major order so rows can not be borrowed directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    pub fn row_mut<F>(&mut self, index: usize, f: F)
+    where
+        F: FnOnce(&mut Vec4),
+    {
+        let mut row = self.row(index);
+        f(&mut row);
+        self.set_row(index, row);
+    }
+
+    /// Returns the matrix element in the given `col` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn element(&self, col: usize, row: usize) -> f32 {
+        self.col(col)[row]
+    }
+
+    /// Returns a mutable reference to the matrix element in the given `col` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is greater than 3.
+    #[inline]
+    pub fn element_mut(&mut self, col: usize, row: usize) -> &mut f32 {
+        &mut self.col_mut(col)[row]
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
     #[inline]
@@ -578,9 +882,12 @@ impl Mat4 {
     }
 
     /// Returns the transpose of `self`.
+    ///
+    /// This is only a `const fn` when `Mat4` is not using SIMD storage, for example when
+    /// the `scalar-math` feature is enabled.
     #[inline]
     #[must_use]
-    pub fn transpose(&self) -> Self {
+    pub const fn transpose(&self) -> Self {
         Self {
             x_axis: Vec4::new(self.x_axis.x, self.y_axis.x, self.z_axis.x, self.w_axis.x),
             y_axis: Vec4::new(self.x_axis.y, self.y_axis.y, self.z_axis.y, self.w_axis.y),
@@ -589,6 +896,14 @@ impl Mat4 {
         }
     }
 
+    /// Transposes each of `matrices` in place.
+    #[inline]
+    pub fn transpose_slice(matrices: &mut [Self]) {
+        for m in matrices {
+            *m = m.transpose();
+        }
+    }
+
     /// Returns the determinant of `self`.
     #[must_use]
     pub fn determinant(&self) -> f32 {
@@ -610,6 +925,71 @@ impl Mat4 {
             - m03 * (m10 * a1223 - m11 * a0223 + m12 * a0123)
     }
 
+    /// Returns `true` if the columns of `self` form a right-handed basis, which is the case when
+    /// the determinant is positive.
+    ///
+    /// Returns `false` if `self` is close to singular, that is if the determinant is tiny
+    /// relative to the product of the column lengths.
+    ///
+    /// If the last row is `[0, 0, 0, 1]` the translation in `w_axis` is left out of this
+    /// product, as it doesn't change the determinant of an affine transform.
+    #[inline]
+    #[must_use]
+    pub fn is_right_handed(&self) -> bool {
+        self.relative_determinant(self.determinant()) > f32::EPSILON
+    }
+
+    /// Returns `true` if `self` mirrors space, which is the case when the determinant is negative.
+    ///
+    /// Transforming by a flipping matrix reverses the winding order of triangles. Returns `false`
+    /// if `self` is close to singular, that is if the determinant is tiny relative to the product
+    /// of the column lengths.
+    ///
+    /// If the last row is `[0, 0, 0, 1]` the translation in `w_axis` is left out of this
+    /// product, as it doesn't change the determinant of an affine transform.
+    #[inline]
+    #[must_use]
+    pub fn is_flipping(&self) -> bool {
+        self.relative_determinant(self.determinant()) < -f32::EPSILON
+    }
+
+    /// Returns `det` divided by the product of the column lengths.
+    ///
+    /// Dividing avoids the underflow of multiplying a tolerance by tiny column lengths.
+    #[inline]
+    fn relative_determinant(&self, det: f32) -> f32 {
+        // The translation of an affine transform doesn't change the determinant and is
+        // excluded, otherwise transforms far from the origin would be considered close to
+        // singular.
+        let w_length = if self.row(3) == Vec4::W {
+            1.0
+        } else {
+            self.w_axis.length()
+        };
+        det / self.x_axis.length() / self.y_axis.length() / self.z_axis.length() / w_length
+    }
+
+    #[inline]
+    fn is_invertible_determinant(&self, det: f32) -> bool {
+        det.is_finite()
+            && det.recip().is_finite()
+            && math::abs(self.relative_determinant(det)) > f32::EPSILON
+    }
+
+    /// Returns `true` if `self` can be inverted.
+    ///
+    /// A matrix is considered invertible if its determinant and the reciprocal of its determinant
+    /// are finite, and the determinant is not tiny relative to the product of the column lengths.
+    /// This is the same check as [`Self::try_inverse()`].
+    ///
+    /// If the last row is `[0, 0, 0, 1]` the translation in `w_axis` is left out of this
+    /// product, as it doesn't change the determinant of an affine transform.
+    #[inline]
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        self.is_invertible_determinant(self.determinant())
+    }
+
     /// Returns the inverse of `self`.
     ///
     /// If the matrix is not invertible the returned matrix will be invalid.
@@ -684,13 +1064,99 @@ impl Mat4 {
 
         let dot0 = self.x_axis.mul(col0);
         let dot1 = dot0.x + dot0.y + dot0.z + dot0.w;
-
         glam_assert!(dot1 != 0.0);
 
         let rcp_det = dot1.recip();
         inverse.mul(rcp_det)
     }
 
+    /// Returns the inverse of `self`, or `None` if `self` is not invertible.
+    ///
+    /// This rejects matrices that are close to singular, see [`Self::is_invertible()`]. The
+    /// determinant is only computed once.
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        let coef00 = m22 * m33 - m32 * m23;
+        let coef02 = m12 * m33 - m32 * m13;
+        let coef03 = m12 * m23 - m22 * m13;
+
+        let coef04 = m21 * m33 - m31 * m23;
+        let coef06 = m11 * m33 - m31 * m13;
+        let coef07 = m11 * m23 - m21 * m13;
+
+        let coef08 = m21 * m32 - m31 * m22;
+        let coef10 = m11 * m32 - m31 * m12;
+        let coef11 = m11 * m22 - m21 * m12;
+
+        let coef12 = m20 * m33 - m30 * m23;
+        let coef14 = m10 * m33 - m30 * m13;
+        let coef15 = m10 * m23 - m20 * m13;
+
+        let coef16 = m20 * m32 - m30 * m22;
+        let coef18 = m10 * m32 - m30 * m12;
+        let coef19 = m10 * m22 - m20 * m12;
+
+        let coef20 = m20 * m31 - m30 * m21;
+        let coef22 = m10 * m31 - m30 * m11;
+        let coef23 = m10 * m21 - m20 * m11;
+
+        let fac0 = Vec4::new(coef00, coef00, coef02, coef03);
+        let fac1 = Vec4::new(coef04, coef04, coef06, coef07);
+        let fac2 = Vec4::new(coef08, coef08, coef10, coef11);
+        let fac3 = Vec4::new(coef12, coef12, coef14, coef15);
+        let fac4 = Vec4::new(coef16, coef16, coef18, coef19);
+        let fac5 = Vec4::new(coef20, coef20, coef22, coef23);
+
+        let vec0 = Vec4::new(m10, m00, m00, m00);
+        let vec1 = Vec4::new(m11, m01, m01, m01);
+        let vec2 = Vec4::new(m12, m02, m02, m02);
+        let vec3 = Vec4::new(m13, m03, m03, m03);
+
+        let inv0 = vec1.mul(fac0).sub(vec2.mul(fac1)).add(vec3.mul(fac2));
+        let inv1 = vec0.mul(fac0).sub(vec2.mul(fac3)).add(vec3.mul(fac4));
+        let inv2 = vec0.mul(fac1).sub(vec1.mul(fac3)).add(vec3.mul(fac5));
+        let inv3 = vec0.mul(fac2).sub(vec1.mul(fac4)).add(vec2.mul(fac5));
+
+        let sign_a = Vec4::new(1.0, -1.0, 1.0, -1.0);
+        let sign_b = Vec4::new(-1.0, 1.0, -1.0, 1.0);
+
+        let inverse = Self::from_cols(
+            inv0.mul(sign_a),
+            inv1.mul(sign_b),
+            inv2.mul(sign_a),
+            inv3.mul(sign_b),
+        );
+
+        let col0 = Vec4::new(
+            inverse.x_axis.x,
+            inverse.y_axis.x,
+            inverse.z_axis.x,
+            inverse.w_axis.x,
+        );
+
+        let dot0 = self.x_axis.mul(col0);
+        let dot1 = dot0.x + dot0.y + dot0.z + dot0.w;
+        if !self.is_invertible_determinant(dot1) {
+            return None;
+        }
+
+        let rcp_det = dot1.recip();
+        Some(inverse.mul(rcp_det))
+    }
+
+    /// Returns the inverse of `self`, or an error if `self` is not invertible.
+    ///
+    /// This is the same check as [`Self::try_inverse()`], see [`Self::is_invertible()`].
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, crate::error::Error> {
+        self.try_inverse().ok_or(crate::error::Error::Singular)
+    }
+
     /// Creates a left-handed view matrix using a camera position, an up direction, and a facing
     /// direction.
     ///
@@ -1077,6 +1543,30 @@ impl Mat4 {
         )
     }
 
+    /// Multiplies two 4x4 matrices where `rhs` contains a valid affine transform.
+    ///
+    /// This skips the arithmetic involving the last row of `rhs`, which is assumed to be
+    /// `(0, 0, 0, 1)`, making it faster than [`Self::mul_mat4()`]. `self` may be any matrix,
+    /// such as a projection, and the result is an affine transform if `self` is one.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `rhs` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn mul_mat4_affine(&self, rhs: &Self) -> Self {
+        glam_assert!(rhs.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        Self::from_cols(
+            self.x_axis * rhs.x_axis.x + self.y_axis * rhs.x_axis.y + self.z_axis * rhs.x_axis.z,
+            self.x_axis * rhs.y_axis.x + self.y_axis * rhs.y_axis.y + self.z_axis * rhs.y_axis.z,
+            self.x_axis * rhs.z_axis.x + self.y_axis * rhs.z_axis.y + self.z_axis * rhs.z_axis.z,
+            self.x_axis * rhs.w_axis.x
+                + self.y_axis * rhs.w_axis.y
+                + self.z_axis * rhs.w_axis.z
+                + self.w_axis,
+        )
+    }
+
     /// Adds two 4x4 matrices.
     #[inline]
     #[must_use]
@@ -1144,6 +1634,53 @@ impl Mat4 {
             && self.w_axis.abs_diff_eq(rhs.w_axis, max_abs_diff)
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(&self, rhs: Self, max_ulps: u32) -> bool {
+        self.x_axis.ulps_eq(rhs.x_axis, max_ulps)
+            && self.y_axis.ulps_eq(rhs.y_axis, max_ulps)
+            && self.z_axis.ulps_eq(rhs.z_axis, max_ulps)
+            && self.w_axis.ulps_eq(rhs.w_axis, max_ulps)
+    }
+
+    /// Returns a matrix with elements of `self` that are not finite replaced by the
+    /// corresponding element of `fallback`.
+    ///
+    /// Elements that are `NaN`, positive or negative infinity are replaced. This can be used to
+    /// sanitize untrusted input.
+    #[inline]
+    #[must_use]
+    pub fn finite_or(&self, fallback: Self) -> Self {
+        Self::from_cols(
+            self.x_axis.finite_or(fallback.x_axis),
+            self.y_axis.finite_or(fallback.y_axis),
+            self.z_axis.finite_or(fallback.z_axis),
+            self.w_axis.finite_or(fallback.w_axis),
+        )
+    }
+
+    /// Returns a matrix containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::from_cols(
+            self.x_axis.map(&f),
+            self.y_axis.map(&f),
+            self.z_axis.map(&f),
+            self.w_axis.map(&f),
+        )
+    }
+
     /// Takes the absolute value of each element in `self`
     #[inline]
     #[must_use]
@@ -1286,6 +1823,53 @@ impl DivAssign<f32> for Mat4 {
     }
 }
 
+impl
+    From<(
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+    )> for Mat4
+{
+    /// Creates a matrix from a tuple of column tuples.
+    #[inline]
+    fn from(
+        (x_axis, y_axis, z_axis, w_axis): (
+            (f32, f32, f32, f32),
+            (f32, f32, f32, f32),
+            (f32, f32, f32, f32),
+            (f32, f32, f32, f32),
+        ),
+    ) -> Self {
+        Self::from_cols(
+            Vec4::from(x_axis),
+            Vec4::from(y_axis),
+            Vec4::from(z_axis),
+            Vec4::from(w_axis),
+        )
+    }
+}
+
+impl From<Mat4>
+    for (
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+        (f32, f32, f32, f32),
+    )
+{
+    /// Converts a matrix into a tuple of column tuples.
+    #[inline]
+    fn from(m: Mat4) -> Self {
+        (
+            m.x_axis.into(),
+            m.y_axis.into(),
+            m.z_axis.into(),
+            m.w_axis.into(),
+        )
+    }
+}
+
 impl Sum<Self> for Mat4 {
     fn sum<I>(iter: I) -> Self
     where
@@ -1363,18 +1947,19 @@ impl fmt::Debug for Mat4 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Mat4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(
-                f,
-                "[{:.*}, {:.*}, {:.*}, {:.*}]",
-                p, self.x_axis, p, self.y_axis, p, self.z_axis, p, self.w_axis
-            )
+        if f.alternate() {
+            crate::display::fmt_grid(f, &self.to_cols_array_2d())
         } else {
-            write!(
-                f,
-                "[{}, {}, {}, {}]",
-                self.x_axis, self.y_axis, self.z_axis, self.w_axis
-            )
+            crate::display::fmt_list(f, &[self.x_axis, self.y_axis, self.z_axis, self.w_axis])
         }
     }
 }
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Mat4 {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|a| Self::from_cols_array(&a))
+    }
+}
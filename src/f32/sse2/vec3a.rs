@@ -84,6 +84,17 @@ impl Vec3A {
     /// The unit axes.
     pub const AXES: [Self; 3] = [Self::X, Self::Y, Self::Z];
 
+    /// Returns the unit vector pointing along `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 3 axes of this vector.
+    #[inline]
+    #[must_use]
+    pub const fn axis(axis: crate::Axis) -> Self {
+        Self::AXES[axis.index()]
+    }
+
     /// Creates a new vector.
     #[inline(always)]
     #[must_use]
@@ -98,6 +109,39 @@ impl Vec3A {
         unsafe { UnionCast { a: [v; 4] }.v }
     }
 
+    /// Creates a vector where each element is the result of calling `f` with its index.
+    ///
+    /// `f` is called for each element in order, starting from index 0.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> f32,
+    {
+        Self::new(f(0), f(1), f(2))
+    }
+
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z))
+    }
+
+    /// Returns a vector containing the result of calling `f` with each element of `self` and the
+    /// corresponding element of `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn zip_with<F>(self, rhs: Self, f: F) -> Self
+    where
+        F: Fn(f32, f32) -> f32,
+    {
+        Self::new(f(self.x, rhs.x), f(self.y, rhs.y), f(self.z, rhs.z))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -139,6 +183,34 @@ impl Vec3A {
         Self::new(slice[0], slice[1], slice[2])
     }
 
+    /// Creates a vector from the first 3 values in `slice`, returning an error if
+    /// `slice` is less than 3 elements long.
+    #[inline]
+    pub fn from_slice_checked(slice: &[f32]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 3)?;
+        Ok(Self::from_slice(slice))
+    }
+
+    /// Creates a vector from the first 3 values yielded by `iter`, returning an error if
+    /// `iter` yields fewer than 3 values.
+    ///
+    /// Any remaining values are not consumed. The [`FromIterator`] implementation behaves the
+    /// same but panics instead of returning an error.
+    #[inline]
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, crate::error::Error>
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        let mut iter = iter.into_iter();
+        let mut out = Self::ZERO.to_array();
+        for (found, e) in out.iter_mut().enumerate() {
+            *e = iter
+                .next()
+                .ok_or(crate::error::Error::IterTooShort { required: 3, found })?;
+        }
+        Ok(Self::from_array(out))
+    }
+
     /// Writes the elements of `self` to the first 3 elements in `slice`.
     ///
     /// # Panics
@@ -151,6 +223,62 @@ impl Vec3A {
         slice[2] = self.z;
     }
 
+    /// Creates a vector from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z]` order.
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 12]) -> Self {
+        let mut a = [f32::default(); 3];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_array(a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z]` order.
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 12] {
+        let mut bytes = [0; 12];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a vector from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z]` order.
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 12]) -> Self {
+        let mut a = [f32::default(); 3];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_array(a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z]` order.
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 12] {
+        let mut bytes = [0; 12];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Internal method for creating a 3D vector from a 4D vector, discarding `w`.
     #[allow(dead_code)]
     #[inline]
@@ -214,6 +342,85 @@ impl Vec3A {
         Self(unsafe { dot3_into_m128(self.0, rhs.0) })
     }
 
+    /// Returns the dot products `a0.dot(b0)`, `a1.dot(b1)`, `a2.dot(b2)` and `a3.dot(b3)` as the
+    /// elements of a [`Vec4`].
+    ///
+    /// The element-wise products are transposed so that the four sums are computed together,
+    /// which is faster than four calls to [`Self::dot`] when SIMD is available. The sums are
+    /// added in the same order as [`Self::dot`], so the results are identical to it.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    #[must_use]
+    pub fn dot4(
+        a0: Self,
+        b0: Self,
+        a1: Self,
+        b1: Self,
+        a2: Self,
+        b2: Self,
+        a3: Self,
+        b3: Self,
+    ) -> Vec4 {
+        unsafe {
+            let p0 = _mm_mul_ps(a0.0, b0.0);
+            let p1 = _mm_mul_ps(a1.0, b1.0);
+            let p2 = _mm_mul_ps(a2.0, b2.0);
+            let p3 = _mm_mul_ps(a3.0, b3.0);
+            // transpose the products so each lane holds one dot product
+            let t0 = _mm_unpacklo_ps(p0, p1);
+            let t1 = _mm_unpacklo_ps(p2, p3);
+            let t2 = _mm_unpackhi_ps(p0, p1);
+            let t3 = _mm_unpackhi_ps(p2, p3);
+            let x = _mm_movelh_ps(t0, t1);
+            let y = _mm_movehl_ps(t1, t0);
+            let z = _mm_movelh_ps(t2, t3);
+            Vec4(_mm_add_ps(_mm_add_ps(x, y), z))
+        }
+    }
+
+    /// Computes the dot product of `self` and `rhs` using compensated arithmetic.
+    ///
+    /// The rounding errors of each multiplication and addition are tracked using error-free
+    /// transformations, so the result is as accurate as if it was computed with twice the
+    /// precision and then rounded. This is slower than [`Self::dot`] but avoids catastrophic
+    /// cancellation when the products have very different magnitudes or opposite signs.
+    #[inline]
+    #[must_use]
+    pub fn dot_compensated(self, rhs: Self) -> f32 {
+        let (mut sum, mut err) = math::two_product(self.x, rhs.x);
+
+        let (p, p_err) = math::two_product(self.y, rhs.y);
+        let (s, s_err) = math::two_sum(sum, p);
+        sum = s;
+        err += p_err + s_err;
+
+        let (p, p_err) = math::two_product(self.z, rhs.z);
+        let (s, s_err) = math::two_sum(sum, p);
+        sum = s;
+        err += p_err + s_err;
+
+        sum + err
+    }
+
+    /// Computes the sum of all vectors in `slice` using compensated summation.
+    ///
+    /// The rounding error of each addition is accumulated separately and added to the result,
+    /// which greatly reduces the loss of precision when summing a large number of vectors
+    /// compared to [`Iterator::sum`].
+    #[inline]
+    #[must_use]
+    pub fn sum_compensated(slice: &[Self]) -> Self {
+        let mut sum = Self::ZERO;
+        let mut err = Self::ZERO;
+        for &v in slice {
+            let s = sum + v;
+            let b = s - sum;
+            err += (sum - (s - b)) + (v - b);
+            sum = s;
+        }
+        sum + err
+    }
+
     /// Computes the cross product of `self` and `rhs`.
     #[inline]
     #[must_use]
@@ -251,6 +458,20 @@ impl Vec3A {
         Self(unsafe { _mm_max_ps(self.0, rhs.0) })
     }
 
+    /// Returns the element-wise minimum and maximum of `self` and `rhs` as a pair, using a single
+    /// comparison.
+    ///
+    /// This orders the near and far distances of each axis in a slab test.
+    ///
+    /// Unlike [`Self::min`] and [`Self::max`], if either element is `NaN` the pair of elements is
+    /// returned as `(rhs, self)`.
+    #[inline]
+    #[must_use]
+    pub fn min_max(self, rhs: Self) -> (Self, Self) {
+        let mask = self.cmplt(rhs);
+        (Self::select(mask, self, rhs), Self::select(mask, rhs, self))
+    }
+
     /// Component-wise clamping of values, similar to [`f32::clamp`].
     ///
     /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
@@ -265,6 +486,147 @@ impl Vec3A {
         self.max(min).min(max)
     }
 
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`,
+    /// propagating `NaN`.
+    ///
+    /// This follows the IEEE 754-2019 `minimum` operation, if either element is `NaN` the result
+    /// is `NaN` and `-0.0` is considered less than `0.0`. This differs from [`Self::min`] which
+    /// may ignore `NaN` elements.
+    #[inline]
+    #[must_use]
+    pub fn min_nan(self, rhs: Self) -> Self {
+        Self::new(
+            math::minimum(self.x, rhs.x),
+            math::minimum(self.y, rhs.y),
+            math::minimum(self.z, rhs.z),
+        )
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`,
+    /// propagating `NaN`.
+    ///
+    /// This follows the IEEE 754-2019 `maximum` operation, if either element is `NaN` the result
+    /// is `NaN` and `0.0` is considered greater than `-0.0`. This differs from [`Self::max`]
+    /// which may ignore `NaN` elements.
+    #[inline]
+    #[must_use]
+    pub fn max_nan(self, rhs: Self) -> Self {
+        Self::new(
+            math::maximum(self.x, rhs.x),
+            math::maximum(self.y, rhs.y),
+            math::maximum(self.z, rhs.z),
+        )
+    }
+
+    /// Component-wise clamping of values, propagating `NaN`.
+    ///
+    /// If an element of `self`, `min` or `max` is `NaN` the corresponding element of the result is
+    /// `NaN`. This differs from [`Self::clamp`] which may ignore `NaN` elements.
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn clamp_nan(self, min: Self, max: Self) -> Self {
+        glam_assert!(!min.cmpgt(max).any(), "clamp_nan: expected min <= max");
+        self.max_nan(min).min_nan(max)
+    }
+
+    /// Compares `self` and `rhs` lexicographically using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// Elements are compared in order using [`f32::total_cmp`], the first element which
+    /// is not equal determines the result. Use with [`slice::sort_by`] to sort points
+    /// deterministically without a wrapper type.
+    #[inline]
+    #[must_use]
+    pub fn cmp_total(self, rhs: Self) -> core::cmp::Ordering {
+        self.x
+            .total_cmp(&rhs.x)
+            .then_with(|| self.y.total_cmp(&rhs.y))
+            .then_with(|| self.z.total_cmp(&rhs.z))
+    }
+
+    /// Compares `self` and `rhs` lexicographically, the same as [`Self::cmp_total`].
+    #[inline]
+    #[must_use]
+    pub fn cmp_lex(self, rhs: Self) -> core::cmp::Ordering {
+        self.cmp_total(rhs)
+    }
+
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`
+    /// using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// Unlike [`Self::min`] the result is well defined for all inputs, `-0.0` is less than `0.0`,
+    /// `NaN` with the sign bit set is less than all other values and `NaN` without the sign bit
+    /// set is greater than all other values.
+    #[inline]
+    #[must_use]
+    pub fn min_total(self, rhs: Self) -> Self {
+        Self::new(
+            core::cmp::min_by(self.x, rhs.x, f32::total_cmp),
+            core::cmp::min_by(self.y, rhs.y, f32::total_cmp),
+            core::cmp::min_by(self.z, rhs.z, f32::total_cmp),
+        )
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`
+    /// using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// Unlike [`Self::max`] the result is well defined for all inputs, `0.0` is greater than
+    /// `-0.0`, `NaN` without the sign bit set is greater than all other values and `NaN` with the
+    /// sign bit set is less than all other values.
+    #[inline]
+    #[must_use]
+    pub fn max_total(self, rhs: Self) -> Self {
+        Self::new(
+            core::cmp::max_by(self.x, rhs.x, f32::total_cmp),
+            core::cmp::max_by(self.y, rhs.y, f32::total_cmp),
+            core::cmp::max_by(self.z, rhs.z, f32::total_cmp),
+        )
+    }
+
+    /// Wraps `self` in a [`TotalOrd`](crate::TotalOrd) which implements `Eq`, `Ord` and `Hash`
+    /// using [`Self::cmp_total`].
+    #[inline]
+    #[must_use]
+    pub fn total_ord(self) -> crate::TotalOrd<Self> {
+        crate::TotalOrd(self)
+    }
+
+    /// Returns the raw bit patterns of the elements of `self`.
+    ///
+    /// See [`f32::to_bits`] for details.
+    #[inline]
+    #[must_use]
+    pub fn to_bits(self) -> crate::UVec3 {
+        crate::UVec3::new(self.x.to_bits(), self.y.to_bits(), self.z.to_bits())
+    }
+
+    /// Creates a vector from the raw bit patterns of its elements.
+    ///
+    /// See [`f32::from_bits`] for details.
+    #[inline]
+    #[must_use]
+    pub fn from_bits(v: crate::UVec3) -> Self {
+        Self::new(
+            f32::from_bits(v.x),
+            f32::from_bits(v.y),
+            f32::from_bits(v.z),
+        )
+    }
+
+    /// Wraps `self` in a [`Hashable`](crate::Hashable) which implements `Eq` and `Hash` using
+    /// the bit patterns of the elements after mapping `-0.0` to `0.0` and all `NaN` values to a
+    /// single `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn hashable(self) -> crate::Hashable<Self> {
+        crate::Hashable(self)
+    }
+
     /// Returns the horizontal minimum of `self`.
     ///
     /// In other words this computes `min(x, y, ..)`.
@@ -293,6 +655,93 @@ impl Vec3A {
         }
     }
 
+    /// Returns the index of the first minimum element of `self`.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[doc(alias = "argmin")]
+    #[inline]
+    #[must_use]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+
+        if self.y < min {
+            min = self.y;
+            index = 1;
+        }
+
+        if self.z < min {
+            index = 2;
+        }
+
+        index
+    }
+
+    /// Returns the index of the first element of `self` with the smallest absolute value.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn abs_min_position(self) -> usize {
+        self.abs().min_position()
+    }
+
+    /// Returns the index of the first maximum element of `self`.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[doc(alias = "argmax")]
+    #[inline]
+    #[must_use]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+
+        if self.y > max {
+            max = self.y;
+            index = 1;
+        }
+
+        if self.z > max {
+            index = 2;
+        }
+
+        index
+    }
+
+    /// Returns the index of the first element of `self` with the largest absolute value.
+    ///
+    /// This selects the dominant axis of a direction vector.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn abs_max_position(self) -> usize {
+        self.abs().max_position()
+    }
+
+    /// Returns a vector containing the elements of `self` sorted in ascending order.
+    ///
+    /// The result is unspecified if any element is `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn sorted(self) -> Self {
+        let mut a = self.to_array();
+
+        if a[1] < a[0] {
+            a.swap(0, 1);
+        }
+
+        if a[2] < a[1] {
+            a.swap(1, 2);
+        }
+
+        if a[1] < a[0] {
+            a.swap(0, 1);
+        }
+
+        Self::from_array(a)
+    }
+
     /// Returns the sum of all elements of `self`.
     ///
     /// In other words, this computes `self.x + self.y + ..`.
@@ -440,6 +889,27 @@ impl Vec3A {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
     }
 
+    /// Performs `is_finite` on each element of self, returning a vector mask of the results.
+    ///
+    /// In other words, this computes `[x.is_finite(), y.is_finite(), ...]`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite_mask(self) -> BVec3A {
+        // `x - x` is `NaN` for infinite and `NaN` elements and zero otherwise
+        BVec3A(unsafe { _mm_cmpeq_ps(_mm_sub_ps(self.0, self.0), _mm_setzero_ps()) })
+    }
+
+    /// Returns a vector with elements of `self` that are not finite replaced by the
+    /// corresponding element of `fallback`.
+    ///
+    /// Elements that are `NaN`, positive or negative infinity are replaced. This can be used to
+    /// sanitize untrusted input.
+    #[inline]
+    #[must_use]
+    pub fn finite_or(self, fallback: Self) -> Self {
+        Self::select(self.is_finite_mask(), self, fallback)
+    }
+
     /// Returns `true` if any elements are `NaN`.
     #[inline]
     #[must_use]
@@ -503,6 +973,71 @@ impl Vec3A {
         (self - rhs).length_squared()
     }
 
+    /// Computes the squared distance from each of `points` to `point`, writing the results to
+    /// `out`.
+    ///
+    /// Points are processed four at a time with their components in the lanes of a [`Vec4`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `out` have different lengths.
+    #[inline]
+    pub fn distance_squared_batch(points: &[Self], point: Self, out: &mut [f32]) {
+        assert_eq!(points.len(), out.len());
+        let mut chunks = points.chunks_exact(4);
+        let mut out_chunks = out.chunks_exact_mut(4);
+        for (chunk, out) in (&mut chunks).zip(&mut out_chunks) {
+            Self::distance_squared_x4(chunk, point).write_to_slice(out);
+        }
+        for (p, out) in chunks.remainder().iter().zip(out_chunks.into_remainder()) {
+            *out = p.distance_squared(point);
+        }
+    }
+
+    /// Returns the index of the first of `points` nearest to `point`, or `None` if `points` is
+    /// empty.
+    ///
+    /// Points are processed four at a time with their components in the lanes of a [`Vec4`].
+    /// Points at a `NaN` or infinite distance are never selected, so `None` is also returned if
+    /// there are only such points.
+    #[inline]
+    #[must_use]
+    pub fn nearest_point_index(points: &[Self], point: Self) -> Option<usize> {
+        let mut nearest = None;
+        let mut nearest_distance = f32::INFINITY;
+        let chunks = points.chunks_exact(4);
+        let remainder = chunks.remainder();
+        for (i, chunk) in chunks.enumerate() {
+            let d = Self::distance_squared_x4(chunk, point);
+            // `NaN` lanes would be skipped by `min_element` but not by `min_position`
+            let d = Vec4::select(d.cmpeq(d), d, Vec4::INFINITY);
+            let min = d.min_element();
+            if min < nearest_distance {
+                nearest_distance = min;
+                nearest = Some(i * 4 + d.min_position());
+            }
+        }
+        let offset = points.len() - remainder.len();
+        for (i, p) in remainder.iter().enumerate() {
+            let d = p.distance_squared(point);
+            if d < nearest_distance {
+                nearest_distance = d;
+                nearest = Some(offset + i);
+            }
+        }
+        nearest
+    }
+
+    /// Returns the squared distances from the first four of `points` to `point`.
+    #[inline]
+    fn distance_squared_x4(points: &[Self], point: Self) -> Vec4 {
+        let (a, b, c, d) = (points[0], points[1], points[2], points[3]);
+        let dx = Vec4::new(a.x, b.x, c.x, d.x) - point.x;
+        let dy = Vec4::new(a.y, b.y, c.y, d.y) - point.y;
+        let dz = Vec4::new(a.z, b.z, c.z, d.z) - point.z;
+        dx * dx + dy * dy + dz * dz
+    }
+
     /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
     #[inline]
     #[must_use]
@@ -527,6 +1062,40 @@ impl Vec3A {
         )
     }
 
+    /// Returns the element-wise quotient of `self` divided by `rhs`, rounded towards negative
+    /// infinity.
+    #[inline]
+    #[must_use]
+    pub fn div_floor(self, rhs: Self) -> Self {
+        (self / rhs).floor()
+    }
+
+    /// Returns a vector containing each element of `self` rounded to the nearest multiple of the
+    /// corresponding element of `step`.
+    ///
+    /// This snaps `self` to a grid with cells of size `step` and a corner at the origin.
+    /// Half-way cases are rounded up and the sign of `step` is ignored. A zero `step` element results in `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn snap(self, step: Self) -> Self {
+        let step = step.abs();
+        let q = self / step;
+        let down = q.floor();
+        // `q - down` is exact, so half-way cases are always rounded up
+        Self::select((q - down).cmpge(Self::splat(0.5)), down + Self::ONE, down) * step
+    }
+
+    /// Returns a vector containing each element of `self` rounded to the nearest multiple of the
+    /// corresponding element of `step`, offset by `origin`.
+    ///
+    /// This snaps `self` to a grid with cells of size `step` and a corner at `origin`. See
+    /// [`Self::snap()`] for how half-way cases are rounded.
+    #[inline]
+    #[must_use]
+    pub fn snap_to(self, step: Self, origin: Self) -> Self {
+        (self - origin).snap(step) + origin
+    }
+
     /// Returns `self` normalized to length 1.0.
     ///
     /// For valid results, `self` must _not_ be of length zero, nor very close to zero.
@@ -565,6 +1134,16 @@ impl Vec3A {
         }
     }
 
+    /// Returns `self` normalized to length 1.0, or an error if the length of `self` is zero,
+    /// infinite or `NaN`.
+    ///
+    /// See also [`Self::try_normalize()`] and [`Self::normalize_or_zero()`].
+    #[inline]
+    pub fn normalize_checked(self) -> Result<Self, crate::error::Error> {
+        self.try_normalize()
+            .ok_or(crate::error::Error::NonNormalizable)
+    }
+
     /// Returns `self` normalized to length 1.0 if possible, else returns a
     /// fallback value.
     ///
@@ -595,6 +1174,97 @@ impl Vec3A {
         self.normalize_or(Self::ZERO)
     }
 
+    /// Computes the length of `self` using a fast reciprocal square root approximation.
+    ///
+    /// The relative error is less than `1e-6`. If the squared length overflows the result is
+    /// infinite, the same as [`Self::length()`]. On x86 targets with SSE this refines the
+    /// hardware reciprocal square root estimate with one Newton-Raphson step, which is faster
+    /// than [`Self::length()`] but not bit-for-bit identical across CPUs. When the
+    /// `deterministic` feature is enabled, or on other targets, this computes `1.0 / sqrt` of
+    /// the squared length instead.
+    ///
+    /// Unlike the `fast-math` feature this only affects the methods it is called on, so it can
+    /// be used selectively in hot loops.
+    #[inline]
+    #[must_use]
+    pub fn length_fast(self) -> f32 {
+        let length_squared = self.dot(self);
+        if length_squared < f32::MIN_POSITIVE || !length_squared.is_finite() {
+            // the estimate is not accurate for zero or subnormal values, and an overflowed
+            // squared length would give `inf * 0.0`
+            math::sqrt(length_squared)
+        } else {
+            length_squared * math::rsqrt_fast(length_squared)
+        }
+    }
+
+    /// Computes the Euclidean distance between two points in space using a fast reciprocal
+    /// square root approximation.
+    ///
+    /// See [`Self::length_fast()`] for the accuracy of the approximation.
+    #[inline]
+    #[must_use]
+    pub fn distance_fast(self, rhs: Self) -> f32 {
+        (self - rhs).length_fast()
+    }
+
+    /// Returns `self` normalized to length 1.0 using a fast reciprocal square root
+    /// approximation.
+    ///
+    /// The length of the result differs from 1.0 by a relative error of less than `1e-6`, see
+    /// [`Self::length_fast()`] for details of the approximation. For valid results, `self` must
+    /// _not_ be of length zero, nor very close to zero.
+    ///
+    /// Panics
+    ///
+    /// Will panic if the resulting normalized vector is not finite when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn normalize_fast(self) -> Self {
+        #[allow(clippy::let_and_return)]
+        let normalized = self * math::rsqrt_fast(self.dot(self));
+        glam_assert!(normalized.is_finite());
+        normalized
+    }
+
+    /// Returns `self` normalized to length 1.0 and the length of `self`.
+    ///
+    /// This is cheaper than calling [`Self::normalize()`] and [`Self::length()`] separately as
+    /// the square root is only computed once.
+    ///
+    /// See also [`Self::try_normalize_and_length()`].
+    ///
+    /// Panics
+    ///
+    /// Will panic if `self` is zero length when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn normalize_and_length(self) -> (Self, f32) {
+        let length = self.length();
+        let normalized = self * length.recip();
+        glam_assert!(normalized.is_finite());
+        (normalized, length)
+    }
+
+    /// Returns `self` normalized to length 1.0 and the length of `self` if possible, else
+    /// returns `None`.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be `None`.
+    ///
+    /// See also [`Self::normalize_and_length()`].
+    #[inline]
+    #[must_use]
+    pub fn try_normalize_and_length(self) -> Option<(Self, f32)> {
+        let length = self.length();
+        let rcp = length.recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some((self * rcp, length))
+        } else {
+            None
+        }
+    }
+
     /// Returns whether `self` is length `1.0` or not.
     ///
     /// Uses a precision threshold of approximately `1e-4`.
@@ -665,6 +1335,109 @@ impl Vec3A {
         self - self.project_onto_normalized(rhs)
     }
 
+    /// Returns the projection of `self` onto the plane through the origin with the given
+    /// `normal`.
+    ///
+    /// This removes the component of `self` along `normal` and is equivalent to
+    /// `self.reject_from(normal)`.
+    ///
+    /// `normal` must be of non-zero length.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` has a length of zero when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn project_onto_plane(self, normal: Self) -> Self {
+        self.reject_from(normal)
+    }
+
+    /// Returns the rejection of `self` from the plane through the origin with the given
+    /// `normal`.
+    ///
+    /// This is the component of `self` along `normal`, in other words the result of
+    /// `self - self.project_onto_plane(normal)`.
+    ///
+    /// `normal` must be of non-zero length.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` has a length of zero when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn reject_from_plane(self, normal: Self) -> Self {
+        self.project_onto(normal)
+    }
+
+    /// Returns the projection of `self` onto the plane through the origin with the given
+    /// `normal`.
+    ///
+    /// `normal` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn project_onto_plane_normalized(self, normal: Self) -> Self {
+        self.reject_from_normalized(normal)
+    }
+
+    /// Returns the rejection of `self` from the plane through the origin with the given
+    /// `normal`.
+    ///
+    /// `normal` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn reject_from_plane_normalized(self, normal: Self) -> Self {
+        self.project_onto_normalized(normal)
+    }
+
+    /// Returns the reflection vector for a given incident vector `self` and surface normal
+    /// `normal`.
+    ///
+    /// `normal` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn reflect(self, normal: Self) -> Self {
+        glam_assert!(normal.is_normalized());
+        self - normal * (2.0 * self.dot(normal))
+    }
+
+    /// Returns the refraction direction for a given incident vector `self`, surface normal
+    /// `normal` and ratio of indices of refraction, `eta`.
+    ///
+    /// `normal` should face against `self`, in other words `self.dot(normal)` should be
+    /// negative. Returns `None` when total internal reflection occurs, in which case
+    /// [`Self::reflect()`] gives the outgoing direction.
+    ///
+    /// `self` and `normal` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `normal` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn refract(self, normal: Self, eta: f32) -> Option<Self> {
+        glam_assert!(self.is_normalized());
+        glam_assert!(normal.is_normalized());
+        let n_dot_i = normal.dot(self);
+        let k = 1.0 - eta * eta * (1.0 - n_dot_i * n_dot_i);
+        if k >= 0.0 {
+            Some(self * eta - normal * (eta * n_dot_i + math::sqrt(k)))
+        } else {
+            None
+        }
+    }
+
     /// Returns a vector containing the nearest integer to a number for each element of `self`.
     /// Round half-way cases away from 0.0.
     #[inline]
@@ -673,6 +1446,21 @@ impl Vec3A {
         Self(unsafe { m128_round(self.0) })
     }
 
+    /// Returns a vector containing the nearest integer to a number for each element of `self`.
+    /// Round half-way cases to the nearest even integer.
+    ///
+    /// This matches the `roundEven` function in shading languages.
+    #[inline]
+    #[must_use]
+    pub fn round_ties_even(self) -> Self {
+        unsafe {
+            // rounds to nearest even under the default rounding mode, but does not keep the
+            // sign of results that round to zero
+            let sign = _mm_and_ps(self.0, _mm_set1_ps(-0.0));
+            Self(_mm_or_ps(m128_round(self.0), sign))
+        }
+    }
+
     /// Returns a vector containing the largest integer less than or equal to a number for each
     /// element of `self`.
     #[inline]
@@ -707,23 +1495,248 @@ impl Vec3A {
         self - self.floor()
     }
 
+    /// Returns the integer and fractional parts of each element of `self`.
+    ///
+    /// The integer part is truncated towards zero and both parts have the same sign as `self`,
+    /// so `-1.5` is split into `-1.0` and `-0.5`. Infinite elements have a fractional part of
+    /// zero and `NaN` elements return `NaN` for both parts.
+    #[inline]
+    #[must_use]
+    pub fn modf(self) -> (Self, Self) {
+        let trunc = self.trunc();
+        // avoid `inf - inf` for infinite elements
+        let fract = Self::select(
+            self.is_finite_mask() | self.is_nan_mask(),
+            self - trunc,
+            Self::ZERO,
+        );
+        (trunc, fract.copysign(self))
+    }
+
     /// Returns a vector containing `e^self` (the exponential function) for each element of
     /// `self`.
     #[inline]
     #[must_use]
-    pub fn exp(self) -> Self {
-        Self::new(math::exp(self.x), math::exp(self.y), math::exp(self.z))
+    pub fn exp(self) -> Self {
+        Self::new(math::exp(self.x), math::exp(self.y), math::exp(self.z))
+    }
+
+    /// Returns a vector containing `2^self` for each element of `self`.
+    #[inline]
+    #[must_use]
+    pub fn exp2(self) -> Self {
+        Self::new(math::exp2(self.x), math::exp2(self.y), math::exp2(self.z))
+    }
+
+    /// Returns a vector containing the natural logarithm of each element of `self`.
+    ///
+    /// Elements less than zero result in `NaN` and zero results in negative infinity.
+    #[inline]
+    #[must_use]
+    pub fn ln(self) -> Self {
+        Self::new(math::ln(self.x), math::ln(self.y), math::ln(self.z))
+    }
+
+    /// Returns a vector containing the base 2 logarithm of each element of `self`.
+    ///
+    /// Elements less than zero result in `NaN` and zero results in negative infinity.
+    #[inline]
+    #[must_use]
+    pub fn log2(self) -> Self {
+        Self::new(math::log2(self.x), math::log2(self.y), math::log2(self.z))
+    }
+
+    /// Returns a vector containing each element of `self` raised to the power of `n`.
+    #[inline]
+    #[must_use]
+    pub fn powf(self, n: f32) -> Self {
+        Self::new(
+            math::powf(self.x, n),
+            math::powf(self.y, n),
+            math::powf(self.z, n),
+        )
+    }
+
+    /// Returns a vector containing each element of `self` raised to the power of the
+    /// corresponding element of `rhs`.
+    ///
+    /// In other words this computes `[self.x.powf(rhs.x), self.y.powf(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub fn pow(self, rhs: Self) -> Self {
+        Self::new(
+            math::powf(self.x, rhs.x),
+            math::powf(self.y, rhs.y),
+            math::powf(self.z, rhs.z),
+        )
+    }
+
+    /// Returns a vector containing the sine of each element of `self` in radians.
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Self {
+        Self::new(math::sin(self.x), math::sin(self.y), math::sin(self.z))
+    }
+
+    /// Returns a vector containing the cosine of each element of `self` in radians.
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Self {
+        Self::new(math::cos(self.x), math::cos(self.y), math::cos(self.z))
+    }
+
+    /// Returns a vector containing the tangent of each element of `self` in radians.
+    #[inline]
+    #[must_use]
+    pub fn tan(self) -> Self {
+        Self::new(math::tan(self.x), math::tan(self.y), math::tan(self.z))
+    }
+
+    /// Returns a vector containing the arcsine of each element of `self` in radians.
+    ///
+    /// Elements outside of the range `[-1, 1]` result in `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn asin(self) -> Self {
+        Self::new(math::asin(self.x), math::asin(self.y), math::asin(self.z))
+    }
+
+    /// Returns a vector containing the arccosine of each element of `self` in radians.
+    ///
+    /// Elements outside of the range `[-1, 1]` result in `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn acos(self) -> Self {
+        Self::new(math::acos(self.x), math::acos(self.y), math::acos(self.z))
+    }
+
+    /// Returns a vector containing the arctangent of each element of `self` in radians.
+    #[inline]
+    #[must_use]
+    pub fn atan(self) -> Self {
+        Self::new(math::atan(self.x), math::atan(self.y), math::atan(self.z))
+    }
+
+    /// Returns a vector containing the four quadrant arctangent of each element of `self` and
+    /// the corresponding element of `rhs` in radians.
+    ///
+    /// In other words this computes `[self.x.atan2(rhs.x), self.y.atan2(rhs.y), ..]`, where the
+    /// elements of `self` are the `y` coordinates and the elements of `rhs` the `x` coordinates.
+    #[inline]
+    #[must_use]
+    pub fn atan2(self, rhs: Self) -> Self {
+        Self::new(
+            math::atan2(self.x, rhs.x),
+            math::atan2(self.y, rhs.y),
+            math::atan2(self.z, rhs.z),
+        )
+    }
+
+    /// Converts an sRGB encoded color to linear using the piecewise sRGB transfer function.
+    #[inline]
+    #[must_use]
+    pub fn to_linear(self) -> Self {
+        Self::new(
+            math::srgb_to_linear(self.x),
+            math::srgb_to_linear(self.y),
+            math::srgb_to_linear(self.z),
+        )
+    }
+
+    /// Converts a linear color to sRGB using the piecewise sRGB transfer function.
+    #[inline]
+    #[must_use]
+    pub fn to_srgb(self) -> Self {
+        Self::new(
+            math::linear_to_srgb(self.x),
+            math::linear_to_srgb(self.y),
+            math::linear_to_srgb(self.z),
+        )
+    }
+
+    /// Converts an sRGB encoded color in the range `[0, 1]` to linear using a fast polynomial
+    /// approximation of the sRGB transfer function.
+    ///
+    /// The absolute error is less than `0.003` which is within one step of an 8-bit color
+    /// channel, prefer [`Self::to_linear()`] when the exact result is required.
+    #[inline]
+    #[must_use]
+    pub fn to_linear_fast(self) -> Self {
+        let c = self;
+        c * (c * (c * 0.30530602 + 0.6821711) + 0.012522878)
+    }
+
+    /// Converts a linear color in the range `[0, 1]` to sRGB using a fast approximation of the
+    /// sRGB transfer function.
+    ///
+    /// The absolute error is less than `0.003` which is within one step of an 8-bit color
+    /// channel, prefer [`Self::to_srgb()`] when the exact result is required.
+    #[inline]
+    #[must_use]
+    pub fn to_srgb_fast(self) -> Self {
+        let s1 = Self::new(math::sqrt(self.x), math::sqrt(self.y), math::sqrt(self.z));
+        let s2 = Self::new(math::sqrt(s1.x), math::sqrt(s1.y), math::sqrt(s1.z));
+        let s3 = Self::new(math::sqrt(s2.x), math::sqrt(s2.y), math::sqrt(s2.z));
+        s1 * 0.5851224 + s2 * 0.78314036 - s3 * 0.36826274
+    }
+
+    /// Converts an RGB color to HSV (hue, saturation, value).
+    ///
+    /// All elements of the input and output are in the range `[0, 1]`, with hue wrapping around
+    /// at `1.0`. The hue of a gray color is `0.0`.
+    #[inline]
+    #[must_use]
+    pub fn rgb_to_hsv(self) -> Self {
+        let max = self.max_element();
+        let delta = max - self.min_element();
+        let s = if max > 0.0 { delta / max } else { 0.0 };
+        Self::new(math::rgb_to_hue(self.x, self.y, self.z, max, delta), s, max)
+    }
+
+    /// Converts an HSV (hue, saturation, value) color to RGB.
+    ///
+    /// This is the inverse of [`Self::rgb_to_hsv()`]. All elements are expected to be in the
+    /// range `[0, 1]`, hue values outside this range wrap around.
+    #[inline]
+    #[must_use]
+    pub fn hsv_to_rgb(self) -> Self {
+        let (h, s, v) = (self.x, self.y, self.z);
+        let k = (Self::new(5.0, 3.0, 1.0) + h * 6.0).rem_euclid(Self::splat(6.0));
+        let t = k.min(4.0 - k).clamp(Self::ZERO, Self::ONE);
+        v - v * s * t
+    }
+
+    /// Converts an RGB color to HSL (hue, saturation, lightness).
+    ///
+    /// All elements of the input and output are in the range `[0, 1]`, with hue wrapping around
+    /// at `1.0`. The hue and saturation of a gray color are `0.0`.
+    #[inline]
+    #[must_use]
+    pub fn rgb_to_hsl(self) -> Self {
+        let max = self.max_element();
+        let min = self.min_element();
+        let delta = max - min;
+        let l = (max + min) * 0.5;
+        let s = if delta > 0.0 {
+            delta / (1.0 - math::abs(2.0 * l - 1.0))
+        } else {
+            0.0
+        };
+        Self::new(math::rgb_to_hue(self.x, self.y, self.z, max, delta), s, l)
     }
 
-    /// Returns a vector containing each element of `self` raised to the power of `n`.
+    /// Converts an HSL (hue, saturation, lightness) color to RGB.
+    ///
+    /// This is the inverse of [`Self::rgb_to_hsl()`]. All elements are expected to be in the
+    /// range `[0, 1]`, hue values outside this range wrap around.
     #[inline]
     #[must_use]
-    pub fn powf(self, n: f32) -> Self {
-        Self::new(
-            math::powf(self.x, n),
-            math::powf(self.y, n),
-            math::powf(self.z, n),
-        )
+    pub fn hsl_to_rgb(self) -> Self {
+        let (h, s, l) = (self.x, self.y, self.z);
+        let a = s * l.min(1.0 - l);
+        let k = (Self::new(0.0, 8.0, 4.0) + h * 12.0).rem_euclid(Self::splat(12.0));
+        let t = (k - 3.0).min(9.0 - k).clamp(Self::NEG_ONE, Self::ONE);
+        l - a * t
     }
 
     /// Returns a vector containing the reciprocal `1.0/n` of each element of `self`.
@@ -770,6 +1783,60 @@ impl Vec3A {
         (self + rhs) * 0.5
     }
 
+    /// Returns the centroid, or mean position, of `points`.
+    ///
+    /// The points are summed using Kahan compensated summation so that precision is not lost when
+    /// averaging a large number of points.
+    ///
+    /// Returns a vector of `NaN` if `points` is empty.
+    #[inline]
+    #[must_use]
+    pub fn centroid(points: &[Self]) -> Self {
+        let mut sum = Self::ZERO;
+        let mut c = Self::ZERO;
+        for &p in points {
+            let y = p - c;
+            let t = sum + y;
+            c = (t - sum) - y;
+            sum = t;
+        }
+        sum / points.len() as f32
+    }
+
+    /// Returns the mean of `points` with each point weighted by the matching element of
+    /// `weights`.
+    ///
+    /// The weighted points and the weights are summed using Kahan compensated summation so that
+    /// precision is not lost when averaging a large number of points.
+    ///
+    /// Returns a vector of `NaN` if `points` is empty and a non-finite vector if the weights sum
+    /// to zero.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `weights` have different lengths.
+    #[inline]
+    #[must_use]
+    pub fn weighted_mean(points: &[Self], weights: &[f32]) -> Self {
+        assert_eq!(points.len(), weights.len());
+        let mut sum = Self::ZERO;
+        let mut c = Self::ZERO;
+        let mut weight_sum = 0.0;
+        let mut weight_c = 0.0;
+        for (&p, &w) in points.iter().zip(weights) {
+            let y = p * w - c;
+            let t = sum + y;
+            c = (t - sum) - y;
+            sum = t;
+
+            let y = w - weight_c;
+            let t = weight_sum + y;
+            weight_c = (t - weight_sum) - y;
+            weight_sum = t;
+        }
+        sum / weight_sum
+    }
+
     /// Returns true if the absolute difference of all elements between `self` and `rhs` is
     /// less than or equal to `max_abs_diff`.
     ///
@@ -785,6 +1852,21 @@ impl Vec3A {
         self.sub(rhs).abs().cmple(Self::splat(max_abs_diff)).all()
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_ulps: u32) -> bool {
+        math::ulps_eq(self.x, rhs.x, max_ulps)
+            && math::ulps_eq(self.y, rhs.y, max_ulps)
+            && math::ulps_eq(self.z, rhs.z, max_ulps)
+    }
+
     /// Returns a vector with a length no less than `min` and no more than `max`
     ///
     /// # Panics
@@ -862,6 +1944,52 @@ impl Vec3A {
         )
     }
 
+    /// Returns the signed angle (in radians) of the rotation about `axis` that takes `self` to
+    /// the direction of `rhs`, in the range `[-π, +π]`.
+    ///
+    /// The angle is positive when the rotation from `self` to `rhs` is counter-clockwise when
+    /// looking down `axis` towards the origin, matching the sign convention of
+    /// [`Vec2::angle_between()`]. The magnitude is the same as
+    /// [`Self::angle_between()`] but is computed with `atan2` so it remains accurate for nearly
+    /// parallel vectors.
+    ///
+    /// The inputs do not need to be unit vectors however `self` and `rhs` must be non-zero.
+    /// `axis` only determines the sign and is typically perpendicular to both vectors.
+    #[inline]
+    #[must_use]
+    pub fn signed_angle_between(self, rhs: Self, axis: Self) -> f32 {
+        let cross = self.cross(rhs);
+        let angle = math::atan2(cross.length(), self.dot(rhs));
+        if cross.dot(axis) < 0.0 {
+            -angle
+        } else {
+            angle
+        }
+    }
+
+    /// Rotates `self` about `axis` towards the direction of `target` by at most `max_angle` (in
+    /// radians).
+    ///
+    /// The rotation angle is measured between the projections of `self` and `target` onto the
+    /// plane perpendicular to `axis`, so the component of `self` along `axis` and the length of
+    /// `self` are preserved. A negative `max_angle` rotates away from `target`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `axis` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn rotate_towards(self, target: Self, axis: Self, max_angle: f32) -> Self {
+        glam_assert!(axis.is_normalized());
+        // subtracting the axial components does not change the triple product
+        let sin = axis.dot(self.cross(target));
+        let cos = self.dot(target) - axis.dot(self) * axis.dot(target);
+        let angle = math::atan2(sin, cos);
+        let angle = math::abs(angle).min(max_angle) * math::signum(angle);
+        let (sin, cos) = math::sin_cos(angle);
+        self * cos + axis.cross(self) * sin + axis * (axis.dot(self) * (1.0 - cos))
+    }
+
     /// Returns some vector that is orthogonal to the given one.
     ///
     /// The input vector must be finite and non-zero.
@@ -891,7 +2019,7 @@ impl Vec3A {
     pub fn any_orthonormal_vector(&self) -> Self {
         glam_assert!(self.is_normalized());
         // From https://graphics.pixar.com/library/OrthonormalB/paper.pdf
-        let sign = math::signum(self.z);
+        let sign = math::copysign(1.0, self.z);
         let a = -1.0 / (sign + self.z);
         let b = self.x * self.y * a;
         Self::new(b, sign + self.y * self.y * a, -self.y)
@@ -908,7 +2036,7 @@ impl Vec3A {
     pub fn any_orthonormal_pair(&self) -> (Self, Self) {
         glam_assert!(self.is_normalized());
         // From https://graphics.pixar.com/library/OrthonormalB/paper.pdf
-        let sign = math::signum(self.z);
+        let sign = math::copysign(1.0, self.z);
         let a = -1.0 / (sign + self.z);
         let b = self.x * self.y * a;
         (
@@ -917,6 +2045,113 @@ impl Vec3A {
         )
     }
 
+    /// Returns the spherical coordinates `(radius, theta, phi)` of `self` using the physics (ISO
+    /// 80000-2) convention with `Z` up.
+    ///
+    /// `theta` is the polar angle from the positive `Z` axis in the range `[0, π]` and `phi` is
+    /// the azimuthal angle in the `XY` plane from the positive `X` axis towards the positive `Y`
+    /// axis in the range `[-π, +π]`. The angles of a zero vector are zero.
+    ///
+    /// See [`Self::to_spherical_y_up()`] for the `Y` up convention commonly used in graphics.
+    #[inline]
+    #[must_use]
+    pub fn to_spherical(self) -> (f32, f32, f32) {
+        let rho = math::sqrt(self.x * self.x + self.y * self.y);
+        (
+            self.length(),
+            math::atan2(rho, self.z),
+            math::atan2(self.y, self.x),
+        )
+    }
+
+    /// Creates a vector from spherical coordinates using the physics (ISO 80000-2) convention
+    /// with `Z` up.
+    ///
+    /// This is the inverse of [`Self::to_spherical()`], `theta` is the polar angle from the
+    /// positive `Z` axis and `phi` is the azimuthal angle from the positive `X` axis towards the
+    /// positive `Y` axis.
+    #[inline]
+    #[must_use]
+    pub fn from_spherical(radius: f32, theta: f32, phi: f32) -> Self {
+        let (sin_theta, cos_theta) = math::sin_cos(theta);
+        let (sin_phi, cos_phi) = math::sin_cos(phi);
+        Self::new(
+            radius * sin_theta * cos_phi,
+            radius * sin_theta * sin_phi,
+            radius * cos_theta,
+        )
+    }
+
+    /// Returns the spherical coordinates `(radius, theta, phi)` of `self` using the graphics
+    /// convention with `Y` up.
+    ///
+    /// `theta` is the polar angle from the positive `Y` axis in the range `[0, π]` and `phi` is
+    /// the azimuthal angle in the `ZX` plane from the positive `Z` axis towards the positive `X`
+    /// axis in the range `[-π, +π]`. The angles of a zero vector are zero.
+    ///
+    /// This is the same as [`Self::to_spherical()`] with the axes rotated so that `Z` maps to `Y`,
+    /// `X` maps to `Z` and `Y` maps to `X`.
+    #[inline]
+    #[must_use]
+    pub fn to_spherical_y_up(self) -> (f32, f32, f32) {
+        Self::new(self.z, self.x, self.y).to_spherical()
+    }
+
+    /// Creates a vector from spherical coordinates using the graphics convention with `Y` up.
+    ///
+    /// This is the inverse of [`Self::to_spherical_y_up()`], `theta` is the polar angle from the
+    /// positive `Y` axis and `phi` is the azimuthal angle from the positive `Z` axis towards the
+    /// positive `X` axis.
+    #[inline]
+    #[must_use]
+    pub fn from_spherical_y_up(radius: f32, theta: f32, phi: f32) -> Self {
+        let v = Self::from_spherical(radius, theta, phi);
+        Self::new(v.y, v.z, v.x)
+    }
+
+    /// Creates a unit direction vector from a latitude and longitude (in radians) with `Y` up.
+    ///
+    /// The latitude is the angle above the `XZ` plane towards the positive `Y` axis, in the range
+    /// `[-π/2, +π/2]`. The longitude is the angle around the `Y` axis from the positive `Z` axis
+    /// towards the positive `X` axis, in the range `[-π, +π]`. A latitude and longitude of zero
+    /// is the positive `Z` axis.
+    ///
+    /// The longitude matches the `phi` angle of [`Self::from_spherical_y_up()`] and the latitude
+    /// is `π/2 - theta`.
+    #[inline]
+    #[must_use]
+    pub fn from_lat_lon(lat: f32, lon: f32) -> Self {
+        let (sin_lat, cos_lat) = math::sin_cos(lat);
+        let (sin_lon, cos_lon) = math::sin_cos(lon);
+        Self::new(cos_lat * sin_lon, sin_lat, cos_lat * cos_lon)
+    }
+
+    /// Returns the latitude and longitude (in radians) of the direction of `self` with `Y` up.
+    ///
+    /// This is the inverse of [`Self::from_lat_lon()`], the input does not need to be a unit
+    /// vector. The latitude and longitude of a zero vector are zero.
+    #[inline]
+    #[must_use]
+    pub fn to_lat_lon(self) -> (f32, f32) {
+        let rho = math::sqrt(self.x * self.x + self.z * self.z);
+        (math::atan2(self.y, rho), math::atan2(self.x, self.z))
+    }
+
+    /// Returns the equirectangular texture coordinates of the direction of `self` with `Y` up.
+    ///
+    /// `u` increases with the longitude of [`Self::to_lat_lon()`] with `u = 0.5` in the direction
+    /// of the positive `Z` axis and the seam at the negative `Z` axis. `v` is `0.0` at the positive
+    /// `Y` axis and `1.0` at the negative `Y` axis. `u` is always in the range `[0, 1)` so that
+    /// directions on the seam map to the same texel.
+    #[inline]
+    #[must_use]
+    pub fn to_equirectangular_uv(self) -> Vec2 {
+        use core::f32::consts::{FRAC_1_PI, TAU};
+        let (lat, lon) = self.to_lat_lon();
+        let u = lon / TAU + 0.5;
+        Vec2::new(u - math::floor(u), 0.5 - lat * FRAC_1_PI)
+    }
+
     /// Casts all elements of `self` to `f64`.
     #[inline]
     #[must_use]
@@ -965,6 +2200,192 @@ impl Vec3A {
     pub fn as_u64vec3(&self) -> crate::U64Vec3 {
         crate::U64Vec3::new(self.x as u64, self.y as u64, self.z as u64)
     }
+
+    /// Rounds all elements of `self` using [`Self::round`] and casts them to `i32`.
+    ///
+    /// This is equivalent to `self.round().as_ivec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec3_round(&self) -> crate::IVec3 {
+        self.round().as_ivec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::floor`] and casts them to `i32`.
+    ///
+    /// This is equivalent to `self.floor().as_ivec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec3_floor(&self) -> crate::IVec3 {
+        self.floor().as_ivec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::ceil`] and casts them to `i32`.
+    ///
+    /// This is equivalent to `self.ceil().as_ivec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec3_ceil(&self) -> crate::IVec3 {
+        self.ceil().as_ivec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::round`] and casts them to `u32`.
+    ///
+    /// This is equivalent to `self.round().as_uvec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec3_round(&self) -> crate::UVec3 {
+        self.round().as_uvec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::floor`] and casts them to `u32`.
+    ///
+    /// This is equivalent to `self.floor().as_uvec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec3_floor(&self) -> crate::UVec3 {
+        self.floor().as_uvec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::ceil`] and casts them to `u32`.
+    ///
+    /// This is equivalent to `self.ceil().as_uvec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec3_ceil(&self) -> crate::UVec3 {
+        self.ceil().as_uvec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::round`] and casts them to `i64`.
+    ///
+    /// This is equivalent to `self.round().as_i64vec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec3_round(&self) -> crate::I64Vec3 {
+        self.round().as_i64vec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::floor`] and casts them to `i64`.
+    ///
+    /// This is equivalent to `self.floor().as_i64vec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec3_floor(&self) -> crate::I64Vec3 {
+        self.floor().as_i64vec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::ceil`] and casts them to `i64`.
+    ///
+    /// This is equivalent to `self.ceil().as_i64vec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec3_ceil(&self) -> crate::I64Vec3 {
+        self.ceil().as_i64vec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::round`] and casts them to `u64`.
+    ///
+    /// This is equivalent to `self.round().as_u64vec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec3_round(&self) -> crate::U64Vec3 {
+        self.round().as_u64vec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::floor`] and casts them to `u64`.
+    ///
+    /// This is equivalent to `self.floor().as_u64vec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec3_floor(&self) -> crate::U64Vec3 {
+        self.floor().as_u64vec3()
+    }
+
+    /// Rounds all elements of `self` using [`Self::ceil`] and casts them to `u64`.
+    ///
+    /// This is equivalent to `self.ceil().as_u64vec3()`. Out of range values saturate
+    /// and `NaN` elements become `0`, the same as an `as` cast.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec3_ceil(&self) -> crate::U64Vec3 {
+        self.ceil().as_u64vec3()
+    }
+
+    /// Casts all elements of `self` to `i16`, clamping values which are out of range to
+    /// `i16::MIN` or `i16::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_i16vec3`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_i16vec3_saturating(&self) -> crate::I16Vec3 {
+        self.as_i16vec3()
+    }
+
+    /// Casts all elements of `self` to `u16`, clamping values which are out of range to
+    /// `u16::MIN` or `u16::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_u16vec3`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_u16vec3_saturating(&self) -> crate::U16Vec3 {
+        self.as_u16vec3()
+    }
+
+    /// Casts all elements of `self` to `i32`, clamping values which are out of range to
+    /// `i32::MIN` or `i32::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_ivec3`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec3_saturating(&self) -> crate::IVec3 {
+        self.as_ivec3()
+    }
+
+    /// Casts all elements of `self` to `u32`, clamping values which are out of range to
+    /// `u32::MIN` or `u32::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_uvec3`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec3_saturating(&self) -> crate::UVec3 {
+        self.as_uvec3()
+    }
+
+    /// Casts all elements of `self` to `i64`, clamping values which are out of range to
+    /// `i64::MIN` or `i64::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_i64vec3`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec3_saturating(&self) -> crate::I64Vec3 {
+        self.as_i64vec3()
+    }
+
+    /// Casts all elements of `self` to `u64`, clamping values which are out of range to
+    /// `u64::MIN` or `u64::MAX` instead of wrapping.
+    ///
+    /// Float to integer `as` casts already saturate so this is the same as
+    /// [`Self::as_u64vec3`], `NaN` elements become `0`.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec3_saturating(&self) -> crate::U64Vec3 {
+        self.as_u64vec3()
+    }
 }
 
 impl Default for Vec3A {
@@ -1190,6 +2611,57 @@ impl AsMut<[f32; 3]> for Vec3A {
     }
 }
 
+impl IntoIterator for Vec3A {
+    type Item = f32;
+    type IntoIter = core::array::IntoIter<f32, 3>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a> IntoIterator for &'a Vec3A {
+    type Item = &'a f32;
+    type IntoIter = core::slice::Iter<'a, f32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a> IntoIterator for &'a mut Vec3A {
+    type Item = &'a mut f32;
+    type IntoIter = core::slice::IterMut<'a, f32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
+
+impl FromIterator<f32> for Vec3A {
+    /// Creates a vector from the first 3 values yielded by `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` yields fewer than 3 values, see [`Vec3A::try_from_iter`]
+    /// for a non-panicking version.
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        match Self::try_from_iter(iter) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
 impl Sum for Vec3A {
     #[inline]
     fn sum<I>(iter: I) -> Self
@@ -1263,14 +2735,93 @@ impl IndexMut<usize> for Vec3A {
     }
 }
 
+impl Index<crate::Axis> for Vec3A {
+    type Output = f32;
+    #[inline]
+    fn index(&self, axis: crate::Axis) -> &Self::Output {
+        &self[axis.index()]
+    }
+}
+
+impl IndexMut<crate::Axis> for Vec3A {
+    #[inline]
+    fn index_mut(&mut self, axis: crate::Axis) -> &mut Self::Output {
+        &mut self[axis.index()]
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Vec3A {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(f, "[{:.*}, {:.*}, {:.*}]", p, self.x, p, self.y, p, self.z)
-        } else {
-            write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
-        }
+        crate::display::fmt_list(f, &self.to_array())
+    }
+}
+
+impl PartialEq for crate::TotalOrd<Vec3A> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.cmp_total(rhs.0).is_eq()
+    }
+}
+
+impl Eq for crate::TotalOrd<Vec3A> {}
+
+impl PartialOrd for crate::TotalOrd<Vec3A> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for crate::TotalOrd<Vec3A> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+        self.0.cmp_total(rhs.0)
+    }
+}
+
+impl core::hash::Hash for crate::TotalOrd<Vec3A> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.x.to_bits().hash(state);
+        self.0.y.to_bits().hash(state);
+        self.0.z.to_bits().hash(state);
+    }
+}
+
+impl crate::Hashable<Vec3A> {
+    #[inline]
+    fn canonical_bits(&self) -> [u32; 3] {
+        [
+            crate::hashable::canonical_bits_f32(self.0.x),
+            crate::hashable::canonical_bits_f32(self.0.y),
+            crate::hashable::canonical_bits_f32(self.0.z),
+        ]
+    }
+}
+
+impl PartialEq for crate::Hashable<Vec3A> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.canonical_bits() == rhs.canonical_bits()
+    }
+}
+
+impl Eq for crate::Hashable<Vec3A> {}
+
+impl core::hash::Hash for crate::Hashable<Vec3A> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.canonical_bits().hash(state);
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Vec3A {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -104,6 +104,19 @@ impl Quat {
         Self::from_xyzw(slice[0], slice[1], slice[2], slice[3])
     }
 
+    /// Creates a rotation quaternion from the first 4 values in `slice`, returning an error if
+    /// `slice` is less than 4 elements long.
+    ///
+    /// # Preconditions
+    ///
+    /// This function does not check if the input is normalized, it is up to the user to
+    /// provide normalized input or to normalized the resulting quaternion.
+    #[inline]
+    pub fn from_slice_checked(slice: &[f32]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 4)?;
+        Ok(Self::from_slice(slice))
+    }
+
     /// Writes the quaternion to an unaligned slice.
     ///
     /// # Panics
@@ -264,6 +277,54 @@ impl Quat {
         )
     }
 
+    /// Creates a quaternion from the orthonormal basis of a rotation, returning an error if the
+    /// axes are not orthonormal or form a left-handed basis.
+    #[inline]
+    fn from_rotation_axes_checked(
+        x_axis: Vec3,
+        y_axis: Vec3,
+        z_axis: Vec3,
+    ) -> Result<Self, crate::error::Error> {
+        const MAX_DOT: f32 = 1e-4;
+        if x_axis.is_normalized()
+            && y_axis.is_normalized()
+            && z_axis.is_normalized()
+            && math::abs(x_axis.dot(y_axis)) <= MAX_DOT
+            && math::abs(y_axis.dot(z_axis)) <= MAX_DOT
+            && math::abs(z_axis.dot(x_axis)) <= MAX_DOT
+            && x_axis.cross(y_axis).dot(z_axis) > 0.0
+        {
+            Ok(Self::from_rotation_axes(x_axis, y_axis, z_axis))
+        } else {
+            Err(crate::error::Error::NotOrthonormal)
+        }
+    }
+
+    /// Creates a quaternion from a 3x3 rotation matrix, returning an error if the matrix is not
+    /// a pure rotation.
+    #[inline]
+    pub fn from_mat3_checked(mat: &Mat3) -> Result<Self, crate::error::Error> {
+        Self::from_rotation_axes_checked(mat.x_axis, mat.y_axis, mat.z_axis)
+    }
+
+    /// Creates a quaternion from a 3x3 SIMD aligned rotation matrix, returning an error if the
+    /// matrix is not a pure rotation.
+    #[inline]
+    pub fn from_mat3a_checked(mat: &Mat3A) -> Result<Self, crate::error::Error> {
+        Self::from_rotation_axes_checked(mat.x_axis.into(), mat.y_axis.into(), mat.z_axis.into())
+    }
+
+    /// Creates a quaternion from the 3x3 rotation matrix inside a homogeneous 4x4 matrix,
+    /// returning an error if it is not a pure rotation.
+    #[inline]
+    pub fn from_mat4_checked(mat: &Mat4) -> Result<Self, crate::error::Error> {
+        Self::from_rotation_axes_checked(
+            mat.x_axis.truncate(),
+            mat.y_axis.truncate(),
+            mat.z_axis.truncate(),
+        )
+    }
+
     /// Gets the minimal rotation for transforming `from` to `to`.  The rotation is in the
     /// plane spanned by the two vectors.  Will rotate at most 180 degrees.
     ///
@@ -397,6 +458,62 @@ impl Quat {
         [self.x, self.y, self.z, self.w]
     }
 
+    /// Creates a quaternion from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let mut a = [f32::default(); 4];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_array(a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a quaternion from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let mut a = [f32::default(); 4];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(4)) {
+            *dst = f32::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_array(a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (dst, src) in bytes.chunks_exact_mut(4).zip(self.to_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Returns the vector part of the quaternion.
     #[inline]
     #[must_use]
@@ -553,6 +670,19 @@ impl Quat {
         Vec4::from(self).abs_diff_eq(Vec4::from(rhs), max_abs_diff)
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_ulps: u32) -> bool {
+        Vec4::from(self).ulps_eq(Vec4::from(rhs), max_ulps)
+    }
+
     /// Performs a linear interpolation between `self` and `rhs` based on
     /// the value `s`.
     ///
@@ -762,15 +892,16 @@ impl fmt::Debug for Quat {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Quat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(
-                f,
-                "[{:.*}, {:.*}, {:.*}, {:.*}]",
-                p, self.x, p, self.y, p, self.z, p, self.w
-            )
-        } else {
-            write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
-        }
+        crate::display::fmt_list(f, &self.to_array())
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Quat {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
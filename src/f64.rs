@@ -1,21 +1,49 @@
-mod daffine2;
-mod daffine3;
+mod daabb3;
 mod dmat2;
 mod dmat3;
-mod dmat4;
-mod dquat;
+mod dplane;
+mod dray3;
 mod dvec2;
 mod dvec3;
-mod dvec4;
 mod float;
+pub mod geometry;
 pub(crate) mod math;
 
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+mod scalar;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod sse2;
+
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+use scalar::*;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use sse2::*;
+
+pub use daabb3::DAabb3;
 pub use daffine2::DAffine2;
 pub use daffine3::DAffine3;
 pub use dmat2::{dmat2, DMat2};
 pub use dmat3::{dmat3, DMat3};
 pub use dmat4::{dmat4, DMat4};
+pub use dplane::DPlane;
 pub use dquat::{dquat, DQuat};
+pub use dray3::{DRay3, DRayHit};
 pub use dvec2::{dvec2, DVec2};
 pub use dvec3::{dvec3, DVec3};
 pub use dvec4::{dvec4, DVec4};
@@ -0,0 +1,99 @@
+// Generated from aabb.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DAffine3, DMat4, DVec3};
+
+/// A 3D axis aligned bounding box defined by its `min` and `max` corners.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+pub struct DAabb3 {
+    pub min: DVec3,
+    pub max: DVec3,
+}
+
+impl DAabb3 {
+    /// Creates a new bounding box from its `min` and `max` corners.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(min: DVec3, max: DVec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a new bounding box from its `center` and `half_extents`.
+    #[inline]
+    #[must_use]
+    pub fn from_center_half_extents(center: DVec3, half_extents: DVec3) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Returns the center of the bounding box.
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> DVec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns half the size of the bounding box along each axis.
+    #[inline]
+    #[must_use]
+    pub fn half_extents(&self) -> DVec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns `true` if `point` is inside or on the boundary of the bounding box.
+    #[inline]
+    #[must_use]
+    pub fn contains_point(&self, point: DVec3) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// Returns the smallest axis aligned bounding box containing `self` transformed by
+    /// `transform`.
+    ///
+    /// This uses the method of Arvo which transforms the center and accumulates the absolute
+    /// value of the linear part of `transform` applied to the half extents, which is cheaper
+    /// than transforming the eight corners.
+    #[inline]
+    #[must_use]
+    pub fn transformed_by(&self, transform: &DAffine3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        self.transformed_by_parts(
+            transform.matrix3.x_axis.into(),
+            transform.matrix3.y_axis.into(),
+            transform.matrix3.z_axis.into(),
+            transform.translation.into(),
+        )
+    }
+
+    /// Returns the smallest axis aligned bounding box containing `self` transformed by the
+    /// affine transform `transform`, see [`Self::transformed_by()`].
+    ///
+    /// The last row of `transform` is ignored, so the result is not valid for projective
+    /// transforms.
+    #[inline]
+    #[must_use]
+    pub fn transformed_by_mat4(&self, transform: &DMat4) -> Self {
+        self.transformed_by_parts(
+            transform.x_axis.truncate(),
+            transform.y_axis.truncate(),
+            transform.z_axis.truncate(),
+            transform.w_axis.truncate(),
+        )
+    }
+
+    #[inline]
+    fn transformed_by_parts(
+        &self,
+        x_axis: DVec3,
+        y_axis: DVec3,
+        z_axis: DVec3,
+        translation: DVec3,
+    ) -> Self {
+        let center = self.center();
+        let half_extents = self.half_extents();
+        let center = x_axis * center.x + y_axis * center.y + z_axis * center.z + translation;
+        let half_extents = x_axis.abs() * half_extents.x
+            + y_axis.abs() * half_extents.y
+            + z_axis.abs() * half_extents.z;
+        Self::from_center_half_extents(center, half_extents)
+    }
+}
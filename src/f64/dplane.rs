@@ -0,0 +1,73 @@
+// Generated from plane.rs.tera template. Edit the template, not the generated file.
+
+use crate::DVec3;
+
+/// A plane in 3D space, the set of points `p` where `normal.dot(p) + d == 0`.
+///
+/// The `normal` does not need to be normalized, however distances computed from a plane with a
+/// non-unit normal are scaled by the length of the normal.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+pub struct DPlane {
+    pub normal: DVec3,
+    pub d: f64,
+}
+
+impl DPlane {
+    /// Creates a new plane from a `normal` and the distance term `d` of the plane equation.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(normal: DVec3, d: f64) -> Self {
+        Self { normal, d }
+    }
+
+    /// Creates a plane containing `point` with the given `normal`.
+    #[inline]
+    #[must_use]
+    pub fn from_point_normal(point: DVec3, normal: DVec3) -> Self {
+        Self::new(normal, -normal.dot(point))
+    }
+
+    /// Creates a plane containing the points `a`, `b` and `c`.
+    ///
+    /// The normal is normalized and faces the side that the points appear counter-clockwise
+    /// from.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the points are collinear when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_points(a: DVec3, b: DVec3, c: DVec3) -> Self {
+        Self::from_point_normal(a, (b - a).cross(c - a).normalize())
+    }
+
+    /// Returns the signed distance from `point` to the plane, which is positive on the side
+    /// that `normal` faces.
+    ///
+    /// The distance is scaled by the length of `normal` if it is not normalized.
+    #[inline]
+    #[must_use]
+    pub fn signed_distance(&self, point: DVec3) -> f64 {
+        self.normal.dot(point) + self.d
+    }
+
+    /// Tests the line segment from `start` to `end` for intersection with the plane.
+    ///
+    /// Returns the parameter `s` in the range `[0, 1]` of the intersection point
+    /// `start.lerp(end, s)`, or `None` if both end points are strictly on the same side of the
+    /// plane or the segment lies in the plane.
+    #[inline]
+    #[must_use]
+    pub fn intersect_segment(&self, start: DVec3, end: DVec3) -> Option<f64> {
+        let dist_start = self.signed_distance(start);
+        let dist_end = self.signed_distance(end);
+        if (dist_start > 0.0 && dist_end > 0.0)
+            || (dist_start < 0.0 && dist_end < 0.0)
+            || dist_start == dist_end
+        {
+            return None;
+        }
+        Some(dist_start / (dist_start - dist_end))
+    }
+}
@@ -0,0 +1,239 @@
+// Generated from ray.rs.tera template. Edit the template, not the generated file.
+
+use crate::f64::math;
+use crate::{DPlane, DVec3};
+
+/// A ray in 3D space defined by an `origin` and a `direction`.
+///
+/// The `direction` does not need to be normalized, the `t` values returned by intersection
+/// tests are in units of `direction`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+pub struct DRay3 {
+    pub origin: DVec3,
+    pub direction: DVec3,
+}
+
+/// The result of a successful ray-triangle intersection test.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DRayHit {
+    /// The ray parameter of the intersection point, see [`DRay3::at()`].
+    pub t: f64,
+    /// The barycentric coordinate of the intersection point for the triangle vertex `b`.
+    pub u: f64,
+    /// The barycentric coordinate of the intersection point for the triangle vertex `c`.
+    pub v: f64,
+    /// Is `true` if the ray hit the front face of the triangle, which is the side that the
+    /// triangle vertices appear counter-clockwise from.
+    pub front_face: bool,
+}
+
+impl DRayHit {
+    /// Returns the barycentric coordinates of the intersection point for the triangle vertices
+    /// `a`, `b` and `c` respectively.
+    #[inline]
+    #[must_use]
+    pub fn barycentric(&self) -> DVec3 {
+        DVec3::new(1.0 - self.u - self.v, self.u, self.v)
+    }
+}
+
+impl DRay3 {
+    /// Creates a new ray from an `origin` and a `direction`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(origin: DVec3, direction: DVec3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point at the ray parameter `t`, that is `origin + direction * t`.
+    #[inline]
+    #[must_use]
+    pub fn at(&self, t: f64) -> DVec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Tests the ray for intersection with the triangle `a`, `b`, `c` using the Möller-Trumbore
+    /// algorithm.
+    ///
+    /// Both faces of the triangle are tested. Returns `None` if the ray misses the triangle,
+    /// the intersection is behind the ray origin, or the ray is parallel to the triangle.
+    ///
+    /// This is fast but not watertight, rays that pass exactly through a shared edge of two
+    /// triangles may miss both. Use [`Self::intersect_triangle_watertight()`] when that matters.
+    #[inline]
+    #[must_use]
+    pub fn intersect_triangle(&self, a: DVec3, b: DVec3, c: DVec3) -> Option<DRayHit> {
+        let e1 = b - a;
+        let e2 = c - a;
+        let p = self.direction.cross(e2);
+        let det = e1.dot(p);
+        // `det` is the cosine of the angle between the ray and the triangle plane scaled by
+        // `|direction| * |e1 x e2|`, this also rejects degenerate triangles.
+        let scale_sq = self.direction.length_squared() * e1.cross(e2).length_squared();
+        if det * det <= f64::EPSILON * f64::EPSILON * scale_sq {
+            return None;
+        }
+        let inv_det = det.recip();
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(e1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = e2.dot(q) * inv_det;
+        if t < 0.0 {
+            return None;
+        }
+        Some(DRayHit {
+            t,
+            u,
+            v,
+            front_face: det > 0.0,
+        })
+    }
+
+    /// Tests the ray for intersection with the triangle `a`, `b`, `c` using the watertight
+    /// algorithm of Woop, Benthin and Wald.
+    ///
+    /// Both faces of the triangle are tested. Returns `None` if the ray misses the triangle,
+    /// the intersection is behind the ray origin, or the ray is parallel to the triangle.
+    ///
+    /// Unlike [`Self::intersect_triangle()`], rays that pass through a shared edge or vertex of
+    /// a closed mesh are guaranteed to hit at least one of the adjacent triangles.
+    #[inline]
+    #[must_use]
+    pub fn intersect_triangle_watertight(&self, a: DVec3, b: DVec3, c: DVec3) -> Option<DRayHit> {
+        // permute the axes so that the largest direction component is z
+        let abs_dir = self.direction.abs();
+        let kz = if abs_dir.x > abs_dir.y {
+            if abs_dir.x > abs_dir.z {
+                0
+            } else {
+                2
+            }
+        } else if abs_dir.y > abs_dir.z {
+            1
+        } else {
+            2
+        };
+        let mut kx = (kz + 1) % 3;
+        let mut ky = (kx + 1) % 3;
+        // preserve the winding of the triangle
+        if self.direction[kz] == 0.0 {
+            return None;
+        } else if self.direction[kz] < 0.0 {
+            core::mem::swap(&mut kx, &mut ky);
+        }
+
+        // shear and scale the vertices into ray space
+        let sz = self.direction[kz].recip();
+        let sx = self.direction[kx] * sz;
+        let sy = self.direction[ky] * sz;
+        let a = a - self.origin;
+        let b = b - self.origin;
+        let c = c - self.origin;
+        let ax = a[kx] - sx * a[kz];
+        let ay = a[ky] - sy * a[kz];
+        let bx = b[kx] - sx * b[kz];
+        let by = b[ky] - sy * b[kz];
+        let cx = c[kx] - sx * c[kz];
+        let cy = c[ky] - sy * c[kz];
+
+        // scaled barycentric coordinates
+        let u = cx * by - cy * bx;
+        let v = ax * cy - ay * cx;
+        let w = bx * ay - by * ax;
+        if (u < 0.0 || v < 0.0 || w < 0.0) && (u > 0.0 || v > 0.0 || w > 0.0) {
+            return None;
+        }
+        let det = u + v + w;
+        if det == 0.0 {
+            return None;
+        }
+
+        let t = (u * a[kz] + v * b[kz] + w * c[kz]) * sz;
+        let inv_det = det.recip();
+        let t = t * inv_det;
+        if t < 0.0 {
+            return None;
+        }
+        // `u`, `v` and `w` are the weights of `a`, `b` and `c`
+        Some(DRayHit {
+            t,
+            u: v * inv_det,
+            v: w * inv_det,
+            front_face: det > 0.0,
+        })
+    }
+
+    /// Tests the ray for intersection with the sphere at `center` with the given `radius`.
+    ///
+    /// Returns the ray parameters `(t0, t1)` where the ray enters and exits the sphere with
+    /// `t0 <= t1`, or `None` if the ray misses the sphere or the sphere is entirely behind the
+    /// ray origin. When the ray origin is inside the sphere `t0` is negative.
+    ///
+    /// This uses the numerically stable formulation from "Precision Improvements for Ray /
+    /// Sphere Intersection" (Haines et al. 2019), which remains accurate for small spheres far
+    /// from the ray origin.
+    #[inline]
+    #[must_use]
+    pub fn intersect_sphere(&self, center: DVec3, radius: f64) -> Option<(f64, f64)> {
+        let a = self.direction.length_squared();
+        if a == 0.0 {
+            return None;
+        }
+        let f = self.origin - center;
+        let b = -f.dot(self.direction);
+        let r_sq = radius * radius;
+        // the squared distance from the center to the ray is computed directly instead of
+        // from `b * b - a * c` which suffers from catastrophic cancellation
+        let discr = a * (r_sq - (f + self.direction * (b / a)).length_squared());
+        if discr < 0.0 {
+            return None;
+        }
+        let c = f.length_squared() - r_sq;
+        let q = b + math::copysign(math::sqrt(discr), b);
+        let (t0, t1) = if q == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let t0 = c / q;
+            let t1 = q / a;
+            if t0 <= t1 {
+                (t0, t1)
+            } else {
+                (t1, t0)
+            }
+        };
+        if t1 < 0.0 {
+            return None;
+        }
+        Some((t0, t1))
+    }
+
+    /// Tests the ray for intersection with `plane`.
+    ///
+    /// Returns the ray parameter of the intersection point, or `None` if the ray is parallel to
+    /// the plane or the plane is behind the ray origin. A ray that starts on the plane hits it at
+    /// `t == 0` unless it is parallel to the plane.
+    ///
+    /// Both sides of the plane are tested.
+    #[inline]
+    #[must_use]
+    pub fn intersect_plane(&self, plane: DPlane) -> Option<f64> {
+        let denom = plane.normal.dot(self.direction);
+        if denom == 0.0 {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t >= 0.0 {
+            Some(t)
+        } else {
+            None
+        }
+    }
+}
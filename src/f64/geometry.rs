@@ -0,0 +1,465 @@
+// Generated from geometry.rs.tera template. Edit the template, not the generated file.
+
+//! Free functions for geometric queries on `f64` points.
+
+use crate::f64::math;
+use crate::{DVec2, DVec3};
+
+/// The result of a 2D segment-segment intersection test, see [`segment_intersection()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SegmentIntersection {
+    /// The segments do not intersect.
+    Disjoint,
+    /// The segments intersect at a single point.
+    Point(DVec2),
+    /// The segments are collinear and overlap between the two points, ordered along the
+    /// direction of the first segment.
+    Overlap(DVec2, DVec2),
+}
+
+/// Tests the 2D line segments `a0` to `a1` and `b0` to `b1` for intersection.
+///
+/// Segment end points are included in the test. Collinear segments that touch at a single end
+/// point return [`SegmentIntersection::Point`] and collinear segments that share more than one
+/// point return [`SegmentIntersection::Overlap`]. Degenerate segments where both end points are
+/// equal are treated as points.
+#[must_use]
+pub fn segment_intersection(a0: DVec2, a1: DVec2, b0: DVec2, b1: DVec2) -> SegmentIntersection {
+    let r = a1 - a0;
+    let s = b1 - b0;
+    let qp = b0 - a0;
+    let denom = r.perp_dot(s);
+    if denom != 0.0 {
+        let t = qp.perp_dot(s) / denom;
+        let u = qp.perp_dot(r) / denom;
+        return if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            SegmentIntersection::Point(a0 + r * t)
+        } else {
+            SegmentIntersection::Disjoint
+        };
+    }
+
+    // the segments are parallel
+    let r_len_sq = r.length_squared();
+    if r_len_sq == 0.0 {
+        // `a` is a point, test whether it lies on `b`
+        let s_len_sq = s.length_squared();
+        let on_b = if s_len_sq == 0.0 {
+            a0 == b0
+        } else {
+            qp.perp_dot(s) == 0.0 && (0.0..=s_len_sq).contains(&-qp.dot(s))
+        };
+        return if on_b {
+            SegmentIntersection::Point(a0)
+        } else {
+            SegmentIntersection::Disjoint
+        };
+    }
+    if qp.perp_dot(r) != 0.0 {
+        return SegmentIntersection::Disjoint;
+    }
+
+    // the segments are collinear, find the overlap of `b` in terms of `a`
+    let t0 = qp.dot(r) / r_len_sq;
+    let t1 = t0 + s.dot(r) / r_len_sq;
+    let lo = t0.min(t1).max(0.0);
+    let hi = t0.max(t1).min(1.0);
+    if lo > hi {
+        SegmentIntersection::Disjoint
+    } else if lo == hi {
+        SegmentIntersection::Point(a0 + r * lo)
+    } else {
+        SegmentIntersection::Overlap(a0 + r * lo, a0 + r * hi)
+    }
+}
+
+/// Returns the intersection point of the 2D line segments `a0` to `a1` and `b0` to `b1`, or
+/// `None` if they do not intersect.
+///
+/// If the segments are collinear and overlap, the point of the overlap closest to `a0` is
+/// returned. Use [`segment_intersection()`] to distinguish this case.
+#[inline]
+#[must_use]
+pub fn segment_intersect(a0: DVec2, a1: DVec2, b0: DVec2, b1: DVec2) -> Option<DVec2> {
+    match segment_intersection(a0, a1, b0, b1) {
+        SegmentIntersection::Disjoint => None,
+        SegmentIntersection::Point(p) | SegmentIntersection::Overlap(p, _) => Some(p),
+    }
+}
+
+/// Returns `true` if the 2D point `p` is inside the triangle `a`, `b`, `c`.
+///
+/// The triangle may have either winding. Points on the edges or vertices are considered
+/// inside, and degenerate triangles with zero area contain no points.
+#[inline]
+#[must_use]
+pub fn point_in_triangle(p: DVec2, a: DVec2, b: DVec2, c: DVec2) -> bool {
+    let d0 = (b - a).perp_dot(p - a);
+    let d1 = (c - b).perp_dot(p - b);
+    let d2 = (a - c).perp_dot(p - c);
+    let has_neg = d0 < 0.0 || d1 < 0.0 || d2 < 0.0;
+    let has_pos = d0 > 0.0 || d1 > 0.0 || d2 > 0.0;
+    has_neg != has_pos
+}
+
+/// Returns `true` if the 2D point `p` is inside the convex polygon with the given `vertices`.
+///
+/// The polygon may have either winding and the last vertex is connected to the first. Points
+/// on the edges or vertices are considered inside, following the same rule as
+/// [`point_in_triangle()`]. Polygons with fewer than 3 vertices or zero area contain no points.
+///
+/// The result is unspecified if the polygon is not convex.
+#[must_use]
+pub fn point_in_convex_polygon(p: DVec2, vertices: &[DVec2]) -> bool {
+    if vertices.len() < 3 {
+        return false;
+    }
+    let mut has_neg = false;
+    let mut has_pos = false;
+    let mut prev = vertices[vertices.len() - 1];
+    for &v in vertices {
+        let d = (v - prev).perp_dot(p - prev);
+        has_neg |= d < 0.0;
+        has_pos |= d > 0.0;
+        if has_neg && has_pos {
+            return false;
+        }
+        prev = v;
+    }
+    has_neg != has_pos
+}
+
+/// Returns the signed area of the 2D polygon with the given `vertices` using the shoelace
+/// formula.
+///
+/// The last vertex is connected to the first. The area is positive if the vertices are
+/// counter-clockwise and negative if they are clockwise, so the sign can be used to detect the
+/// winding order. Polygons with fewer than 3 vertices have zero area.
+///
+/// The polygon does not need to be convex, however the result is unspecified if it is
+/// self-intersecting.
+#[must_use]
+pub fn polygon_area(vertices: &[DVec2]) -> f64 {
+    if vertices.len() < 3 {
+        return 0.0;
+    }
+    // vertices are made relative to the first to reduce cancellation for polygons far from the
+    // origin
+    let origin = vertices[0];
+    let mut sum = 0.0;
+    let mut prev = vertices[1] - origin;
+    for &v in &vertices[2..] {
+        let v = v - origin;
+        sum += prev.perp_dot(v);
+        prev = v;
+    }
+    sum * 0.5
+}
+
+/// Returns the centroid of the area of the 2D polygon with the given `vertices`, or `None` if
+/// the polygon has fewer than 3 vertices or zero area.
+///
+/// The last vertex is connected to the first and the polygon may have either winding. The
+/// polygon does not need to be convex, however the result is unspecified if it is
+/// self-intersecting.
+#[must_use]
+pub fn polygon_centroid(vertices: &[DVec2]) -> Option<DVec2> {
+    if vertices.len() < 3 {
+        return None;
+    }
+    let origin = vertices[0];
+    let mut sum = 0.0;
+    let mut weighted = DVec2::ZERO;
+    let mut prev = vertices[1] - origin;
+    for &v in &vertices[2..] {
+        let v = v - origin;
+        let cross = prev.perp_dot(v);
+        sum += cross;
+        weighted += (prev + v) * cross;
+        prev = v;
+    }
+    if sum == 0.0 {
+        return None;
+    }
+    // the centroid of each triangle fan is (origin + prev + v) / 3 weighted by its area
+    // cross / 2, divided by the total area sum / 2
+    Some(origin + weighted / (sum * 3.0))
+}
+
+/// Returns the point on the line segment `a` to `b` closest to `p`, and its parameter `t` in
+/// the range `[0, 1]` such that the point is `a.lerp(b, t)`.
+///
+/// If the segment is degenerate `a` is returned with `t == 0`.
+#[inline]
+#[must_use]
+pub fn closest_point_on_segment(p: DVec3, a: DVec3, b: DVec3) -> (DVec3, f64) {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq == 0.0 {
+        return (a, 0.0);
+    }
+    let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    (a + ab * t, t)
+}
+
+/// Returns the point on the triangle `a`, `b`, `c` closest to `p`, and its barycentric
+/// coordinates for the vertices `a`, `b` and `c` respectively.
+///
+/// This uses the Voronoi region method from "Real-Time Collision Detection" (Ericson 2004).
+#[must_use]
+pub fn closest_point_on_triangle(p: DVec3, a: DVec3, b: DVec3, c: DVec3) -> (DVec3, DVec3) {
+    let ab = b - a;
+    let ac = c - a;
+
+    // vertex region outside `a`
+    let ap = p - a;
+    let d1 = ab.dot(ap);
+    let d2 = ac.dot(ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return (a, DVec3::X);
+    }
+
+    // vertex region outside `b`
+    let bp = p - b;
+    let d3 = ab.dot(bp);
+    let d4 = ac.dot(bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return (b, DVec3::Y);
+    }
+
+    // edge region of `ab`
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return (a + ab * v, DVec3::new(1.0 - v, v, 0.0));
+    }
+
+    // vertex region outside `c`
+    let cp = p - c;
+    let d5 = ab.dot(cp);
+    let d6 = ac.dot(cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return (c, DVec3::Z);
+    }
+
+    // edge region of `ac`
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return (a + ac * w, DVec3::new(1.0 - w, 0.0, w));
+    }
+
+    // edge region of `bc`
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return (b + (c - b) * w, DVec3::new(0.0, 1.0 - w, w));
+    }
+
+    // inside the face region
+    let denom = (va + vb + vc).recip();
+    let v = vb * denom;
+    let w = vc * denom;
+    (a + ab * v + ac * w, DVec3::new(1.0 - v - w, v, w))
+}
+
+/// Returns the squared distance from the 3D point `p` to the infinite line through `origin`
+/// with direction `dir`.
+///
+/// `dir` does not need to be normalized. If `dir` is zero the distance to `origin` is returned.
+#[inline]
+#[must_use]
+pub fn distance_to_line_squared(p: DVec3, origin: DVec3, dir: DVec3) -> f64 {
+    let v = p - origin;
+    let dir_len_sq = dir.length_squared();
+    if dir_len_sq == 0.0 {
+        return v.length_squared();
+    }
+    (v - dir * (v.dot(dir) / dir_len_sq)).length_squared()
+}
+
+/// Returns the distance from the 3D point `p` to the infinite line through `origin` with
+/// direction `dir`.
+///
+/// `dir` does not need to be normalized. If `dir` is zero the distance to `origin` is returned.
+#[inline]
+#[must_use]
+pub fn distance_to_line(p: DVec3, origin: DVec3, dir: DVec3) -> f64 {
+    math::sqrt(distance_to_line_squared(p, origin, dir))
+}
+
+/// Returns the squared distance from the 3D point `p` to the line segment `a` to `b`.
+#[inline]
+#[must_use]
+pub fn distance_to_segment_squared(p: DVec3, a: DVec3, b: DVec3) -> f64 {
+    let ab = b - a;
+    let ap = p - a;
+    let len_sq = ab.length_squared();
+    if len_sq == 0.0 {
+        return ap.length_squared();
+    }
+    let t = (ap.dot(ab) / len_sq).clamp(0.0, 1.0);
+    (ap - ab * t).length_squared()
+}
+
+/// Returns the distance from the 3D point `p` to the line segment `a` to `b`.
+#[inline]
+#[must_use]
+pub fn distance_to_segment(p: DVec3, a: DVec3, b: DVec3) -> f64 {
+    math::sqrt(distance_to_segment_squared(p, a, b))
+}
+
+/// Returns the squared distance from the 2D point `p` to the infinite line through `origin`
+/// with direction `dir`.
+///
+/// `dir` does not need to be normalized. If `dir` is zero the distance to `origin` is returned.
+#[inline]
+#[must_use]
+pub fn distance_to_line_squared_2d(p: DVec2, origin: DVec2, dir: DVec2) -> f64 {
+    let v = p - origin;
+    let dir_len_sq = dir.length_squared();
+    if dir_len_sq == 0.0 {
+        return v.length_squared();
+    }
+    (v - dir * (v.dot(dir) / dir_len_sq)).length_squared()
+}
+
+/// Returns the distance from the 2D point `p` to the infinite line through `origin` with
+/// direction `dir`.
+///
+/// `dir` does not need to be normalized. If `dir` is zero the distance to `origin` is returned.
+#[inline]
+#[must_use]
+pub fn distance_to_line_2d(p: DVec2, origin: DVec2, dir: DVec2) -> f64 {
+    math::sqrt(distance_to_line_squared_2d(p, origin, dir))
+}
+
+/// Returns the squared distance from the 2D point `p` to the line segment `a` to `b`.
+#[inline]
+#[must_use]
+pub fn distance_to_segment_squared_2d(p: DVec2, a: DVec2, b: DVec2) -> f64 {
+    let ab = b - a;
+    let ap = p - a;
+    let len_sq = ab.length_squared();
+    if len_sq == 0.0 {
+        return ap.length_squared();
+    }
+    let t = (ap.dot(ab) / len_sq).clamp(0.0, 1.0);
+    (ap - ab * t).length_squared()
+}
+
+/// Returns the distance from the 2D point `p` to the line segment `a` to `b`.
+#[inline]
+#[must_use]
+pub fn distance_to_segment_2d(p: DVec2, a: DVec2, b: DVec2) -> f64 {
+    math::sqrt(distance_to_segment_squared_2d(p, a, b))
+}
+
+/// Functions for computing properties of triangles.
+///
+/// Triangles are given by their vertices `a`, `b` and `c`. The front side of a triangle is the
+/// side that the vertices appear counter-clockwise from, matching [`DPlane::from_points()`]
+/// and the `front_face` of ray-triangle intersection tests. Functions with a `_2d` suffix take
+/// 2D vertices.
+///
+/// [`DPlane::from_points()`]: crate::DPlane::from_points
+pub mod triangle {
+    use crate::f64::math;
+    use crate::{DVec2, DVec3};
+
+    /// Returns the area of the triangle.
+    #[inline]
+    #[must_use]
+    pub fn area(a: DVec3, b: DVec3, c: DVec3) -> f64 {
+        (b - a).cross(c - a).length() * 0.5
+    }
+
+    /// Returns the unit normal of the front side of the triangle, or zero if the triangle is
+    /// degenerate.
+    #[inline]
+    #[must_use]
+    pub fn normal(a: DVec3, b: DVec3, c: DVec3) -> DVec3 {
+        (b - a).cross(c - a).normalize_or_zero()
+    }
+
+    /// Returns the centroid of the triangle, the average of its vertices.
+    #[inline]
+    #[must_use]
+    pub fn centroid(a: DVec3, b: DVec3, c: DVec3) -> DVec3 {
+        (a + b + c) * (1.0 / 3.0)
+    }
+
+    /// Returns the circumcenter of the triangle, the point in the plane of the triangle that is
+    /// equidistant from all three vertices, or `None` if the triangle is degenerate.
+    #[inline]
+    #[must_use]
+    pub fn circumcenter(a: DVec3, b: DVec3, c: DVec3) -> Option<DVec3> {
+        let ab = b - a;
+        let ac = c - a;
+        let n = ab.cross(ac);
+        let denom = 2.0 * n.length_squared();
+        if denom == 0.0 {
+            return None;
+        }
+        let offset =
+            (n.cross(ab) * ac.length_squared() + ac.cross(n) * ab.length_squared()) / denom;
+        Some(a + offset)
+    }
+
+    /// Returns `true` if the triangle has no area within a tolerance relative to the length
+    /// of its edges.
+    #[inline]
+    #[must_use]
+    pub fn is_degenerate(a: DVec3, b: DVec3, c: DVec3) -> bool {
+        let ab = b - a;
+        let ac = c - a;
+        let scale = ab.length_squared().max(ac.length_squared()) * f64::EPSILON;
+        ab.cross(ac).length_squared() <= scale * scale
+    }
+
+    /// Returns the signed area of the 2D triangle, which is positive if the vertices are
+    /// counter-clockwise.
+    #[inline]
+    #[must_use]
+    pub fn signed_area_2d(a: DVec2, b: DVec2, c: DVec2) -> f64 {
+        (b - a).perp_dot(c - a) * 0.5
+    }
+
+    /// Returns the centroid of the 2D triangle, the average of its vertices.
+    #[inline]
+    #[must_use]
+    pub fn centroid_2d(a: DVec2, b: DVec2, c: DVec2) -> DVec2 {
+        (a + b + c) * (1.0 / 3.0)
+    }
+
+    /// Returns the circumcenter of the 2D triangle, the point that is equidistant from all
+    /// three vertices, or `None` if the triangle is degenerate.
+    #[inline]
+    #[must_use]
+    pub fn circumcenter_2d(a: DVec2, b: DVec2, c: DVec2) -> Option<DVec2> {
+        let ab = b - a;
+        let ac = c - a;
+        let denom = 2.0 * ab.perp_dot(ac);
+        if denom == 0.0 {
+            return None;
+        }
+        let ab_len_sq = ab.length_squared();
+        let ac_len_sq = ac.length_squared();
+        let offset = DVec2::new(
+            ac.y * ab_len_sq - ab.y * ac_len_sq,
+            ab.x * ac_len_sq - ac.x * ab_len_sq,
+        ) / denom;
+        Some(a + offset)
+    }
+
+    /// Returns `true` if the 2D triangle has no area within a tolerance relative to the length
+    /// of its edges.
+    #[inline]
+    #[must_use]
+    pub fn is_degenerate_2d(a: DVec2, b: DVec2, c: DVec2) -> bool {
+        let ab = b - a;
+        let ac = c - a;
+        let scale = ab.length_squared().max(ac.length_squared()) * f64::EPSILON;
+        math::abs(ab.perp_dot(ac)) <= scale
+    }
+}
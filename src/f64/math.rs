@@ -1,3 +1,110 @@
+/// Returns true if `a` and `b` are within `max_ulps` units in the last place of each other.
+///
+/// `NaN` is never equal to anything and values with different signs are only equal if they are
+/// both zero.
+#[inline]
+pub(crate) fn ulps_eq(a: f64, b: f64, max_ulps: u32) -> bool {
+    if a == b {
+        true
+    } else if a.is_nan() || b.is_nan() || a.is_sign_negative() != b.is_sign_negative() {
+        false
+    } else {
+        // the bit patterns of floats with the same sign are ordered by their magnitude
+        a.to_bits().abs_diff(b.to_bits()) <= u64::from(max_ulps)
+    }
+}
+
+/// Returns the minimum of `a` and `b`, propagating `NaN` and treating `-0.0` as less than `0.0`.
+#[inline]
+pub(crate) fn minimum(a: f64, b: f64) -> f64 {
+    if a < b {
+        a
+    } else if b < a {
+        b
+    } else if a == b {
+        if a.is_sign_negative() {
+            a
+        } else {
+            b
+        }
+    } else {
+        // at least one input is NaN
+        a + b
+    }
+}
+
+/// Returns the maximum of `a` and `b`, propagating `NaN` and treating `-0.0` as less than `0.0`.
+#[inline]
+pub(crate) fn maximum(a: f64, b: f64) -> f64 {
+    if a > b {
+        a
+    } else if b > a {
+        b
+    } else if a == b {
+        if a.is_sign_positive() {
+            a
+        } else {
+            b
+        }
+    } else {
+        // at least one input is NaN
+        a + b
+    }
+}
+
+/// Returns `a + b` and the rounding error of the addition, such that the sum of the two results
+/// is exactly `a + b`.
+#[inline]
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Returns `a * b` and the rounding error of the multiplication, such that the sum of the two
+/// results is exactly `a * b`.
+#[inline]
+pub(crate) fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, mul_add(a, b, -p))
+}
+
+/// Returns the hue in the range `[0, 1)` of an RGB color with the given maximum component and
+/// difference between the maximum and minimum components.
+#[inline]
+pub(crate) fn rgb_to_hue(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    if delta <= 0.0 {
+        0.0
+    } else if max == r {
+        let h = (g - b) / delta;
+        (if h < 0.0 { h + 6.0 } else { h }) / 6.0
+    } else if max == g {
+        ((b - r) / delta + 2.0) / 6.0
+    } else {
+        ((r - g) / delta + 4.0) / 6.0
+    }
+}
+
+/// Converts an sRGB encoded color component to linear using the piecewise sRGB transfer function.
+#[inline]
+pub(crate) fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c * (1.0 / 12.92)
+    } else {
+        powf((c + 0.055) * (1.0 / 1.055), 2.4)
+    }
+}
+
+/// Converts a linear color component to sRGB using the piecewise sRGB transfer function.
+#[inline]
+pub(crate) fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * powf(c, 1.0 / 2.4) - 0.055
+    }
+}
+
 #[cfg(feature = "libm")]
 mod libm_math {
     #[inline(always)]
@@ -10,16 +117,31 @@ mod libm_math {
         libm::acos(f.clamp(-1.0, 1.0))
     }
 
+    #[inline(always)]
+    pub(crate) fn acos(f: f64) -> f64 {
+        libm::acos(f)
+    }
+
     #[inline(always)]
     pub(crate) fn asin(f: f64) -> f64 {
         libm::asin(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn atan(f: f64) -> f64 {
+        libm::atan(f)
+    }
+
     #[inline(always)]
     pub(crate) fn atan2(f: f64, other: f64) -> f64 {
         libm::atan2(f, other)
     }
 
+    #[inline(always)]
+    pub(crate) fn cos(f: f64) -> f64 {
+        libm::cos(f)
+    }
+
     #[inline(always)]
     pub(crate) fn sin(f: f64) -> f64 {
         libm::sin(f)
@@ -59,6 +181,11 @@ mod libm_math {
         libm::round(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn round_ties_even(f: f64) -> f64 {
+        libm::rint(f)
+    }
+
     #[inline(always)]
     pub(crate) fn trunc(f: f64) -> f64 {
         libm::trunc(f)
@@ -79,6 +206,21 @@ mod libm_math {
         libm::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn exp2(f: f64) -> f64 {
+        libm::exp2(f)
+    }
+
+    #[inline(always)]
+    pub(crate) fn ln(f: f64) -> f64 {
+        libm::log(f)
+    }
+
+    #[inline(always)]
+    pub(crate) fn log2(f: f64) -> f64 {
+        libm::log2(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f64, n: f64) -> f64 {
         libm::pow(f, n)
@@ -122,16 +264,31 @@ mod std_math {
         f64::acos(f64::clamp(f, -1.0, 1.0))
     }
 
+    #[inline(always)]
+    pub(crate) fn acos(f: f64) -> f64 {
+        f64::acos(f)
+    }
+
     #[inline(always)]
     pub(crate) fn asin(f: f64) -> f64 {
         f64::asin(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn atan(f: f64) -> f64 {
+        f64::atan(f)
+    }
+
     #[inline(always)]
     pub(crate) fn atan2(f: f64, other: f64) -> f64 {
         f64::atan2(f, other)
     }
 
+    #[inline(always)]
+    pub(crate) fn cos(f: f64) -> f64 {
+        f64::cos(f)
+    }
+
     #[inline(always)]
     pub(crate) fn sin(f: f64) -> f64 {
         f64::sin(f)
@@ -167,6 +324,17 @@ mod std_math {
         f64::round(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn round_ties_even(f: f64) -> f64 {
+        // `f64::round_ties_even` requires Rust 1.77
+        let r = f64::round(f);
+        if f64::abs(f - r) == 0.5 {
+            2.0 * f64::round(f * 0.5)
+        } else {
+            r
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn trunc(f: f64) -> f64 {
         f64::trunc(f)
@@ -187,6 +355,21 @@ mod std_math {
         f64::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn exp2(f: f64) -> f64 {
+        f64::exp2(f)
+    }
+
+    #[inline(always)]
+    pub(crate) fn ln(f: f64) -> f64 {
+        f64::ln(f)
+    }
+
+    #[inline(always)]
+    pub(crate) fn log2(f: f64) -> f64 {
+        f64::log2(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f64, n: f64) -> f64 {
         f64::powf(f, n)
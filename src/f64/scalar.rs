@@ -0,0 +1,5 @@
+pub mod daffine2;
+pub mod daffine3;
+pub mod dmat4;
+pub mod dquat;
+pub mod dvec4;
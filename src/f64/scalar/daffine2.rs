@@ -65,6 +65,62 @@ impl DAffine2 {
         [x.x, x.y, y.x, y.y, z.x, z.y]
     }
 
+    /// Creates an affine transform from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 48]) -> Self {
+        let mut a = [f64::default(); 6];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(8)) {
+            *dst = f64::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 48] {
+        let mut bytes = [0; 48];
+        for (dst, src) in bytes.chunks_exact_mut(8).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates an affine transform from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 48]) -> Self {
+        let mut a = [f64::default(); 6];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(8)) {
+            *dst = f64::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 48] {
+        let mut bytes = [0; 48];
+        for (dst, src) in bytes.chunks_exact_mut(8).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates an affine transform from a `[[f64; 2]; 3]`
     /// 2D array stored in column major order.
     /// If your data is in row major order you will need to `transpose` the returned
@@ -105,6 +161,14 @@ impl DAffine2 {
         }
     }
 
+    /// Creates an affine transform from the first 6 values in `slice`, returning an
+    /// error if `slice` is less than 6 elements long.
+    #[inline]
+    pub fn from_cols_slice_checked(slice: &[f64]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 6)?;
+        Ok(Self::from_cols_slice(slice))
+    }
+
     /// Writes the columns of `self` to the first 6 elements in `slice`.
     ///
     /// # Panics
@@ -205,7 +269,7 @@ impl DAffine2 {
     pub fn from_mat3(m: DMat3) -> Self {
         use crate::swizzles::Vec3Swizzles;
         Self {
-            matrix2: DMat2::from_cols(m.x_axis.xy(), m.y_axis.xy()),
+            matrix2: DMat2::from_cols(m.x_axis.xy(), m.y_axis.truncate()),
             translation: m.z_axis.xy(),
         }
     }
@@ -287,6 +351,30 @@ impl DAffine2 {
             && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(&self, rhs: Self, max_ulps: u32) -> bool {
+        self.matrix2.ulps_eq(rhs.matrix2, max_ulps)
+            && self.translation.ulps_eq(rhs.translation, max_ulps)
+    }
+
+    /// Returns `true` if this transform mirrors space, reversing the winding order of
+    /// transformed triangles.
+    ///
+    /// See [`DMat2::is_flipping()`].
+    #[inline]
+    #[must_use]
+    pub fn is_flipping(&self) -> bool {
+        self.matrix2.is_flipping()
+    }
+
     /// Return the inverse of this transform.
     ///
     /// Note that if the transform is not invertible the result will be invalid.
@@ -302,6 +390,38 @@ impl DAffine2 {
             translation,
         }
     }
+
+    /// Returns `true` if this transform can be inverted.
+    ///
+    /// See [`DMat2::is_invertible()`].
+    #[inline]
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        self.matrix2.is_invertible()
+    }
+
+    /// Returns the inverse of this transform, or `None` if the transform is not invertible.
+    ///
+    /// See [`DMat2::try_inverse()`].
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let matrix2 = self.matrix2.try_inverse()?;
+        // transform negative translation by the matrix inverse:
+        let translation = -(matrix2 * self.translation);
+        Some(Self {
+            matrix2,
+            translation,
+        })
+    }
+
+    /// Returns the inverse of this transform, or an error if the transform is not invertible.
+    ///
+    /// See [`DMat2::try_inverse()`].
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, crate::error::Error> {
+        self.try_inverse().ok_or(crate::error::Error::Singular)
+    }
 }
 
 impl Default for DAffine2 {
@@ -346,22 +466,26 @@ impl core::fmt::Debug for DAffine2 {
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for DAffine2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(
-                f,
-                "[{:.*}, {:.*}, {:.*}]",
-                p, self.matrix2.x_axis, p, self.matrix2.y_axis, p, self.translation
-            )
+        if f.alternate() {
+            crate::display::fmt_grid(f, &self.to_cols_array_2d())
         } else {
-            write!(
+            crate::display::fmt_list(
                 f,
-                "[{}, {}, {}]",
-                self.matrix2.x_axis, self.matrix2.y_axis, self.translation
+                &[self.matrix2.x_axis, self.matrix2.y_axis, self.translation],
             )
         }
     }
 }
 
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for DAffine2 {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|a| Self::from_cols_array(&a))
+    }
+}
+
 impl<'a> core::iter::Product<&'a Self> for DAffine2 {
     fn product<I>(iter: I) -> Self
     where
@@ -66,6 +66,62 @@ impl DAffine3 {
         [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z, w.x, w.y, w.z]
     }
 
+    /// Creates an affine transform from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 96]) -> Self {
+        let mut a = [f64::default(); 12];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(8)) {
+            *dst = f64::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0; 96];
+        for (dst, src) in bytes.chunks_exact_mut(8).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates an affine transform from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 96]) -> Self {
+        let mut a = [f64::default(); 12];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(8)) {
+            *dst = f64::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0; 96];
+        for (dst, src) in bytes.chunks_exact_mut(8).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates an affine transform from a `[[f64; 3]; 4]`
     /// 3D array stored in column major order.
     /// If your data is in row major order you will need to `transpose` the returned
@@ -107,6 +163,14 @@ impl DAffine3 {
         }
     }
 
+    /// Creates an affine transform from the first 12 values in `slice`, returning an
+    /// error if `slice` is less than 12 elements long.
+    #[inline]
+    pub fn from_cols_slice_checked(slice: &[f64]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 12)?;
+        Ok(Self::from_cols_slice(slice))
+    }
+
     /// Writes the columns of `self` to the first 12 elements in `slice`.
     ///
     /// # Panics
@@ -392,6 +456,23 @@ impl DAffine3 {
             .into()
     }
 
+    /// Returns a [`Mat4`](crate::Mat4) transform relative to the given `origin`.
+    ///
+    /// The translation is computed as `self.translation - origin` in `f64` before being
+    /// truncated to `f32`, so the result stays precise when both `self` and `origin` are far from
+    /// the world origin. This is typically used to build camera relative model matrices for
+    /// rendering where `origin` is the camera position.
+    #[inline]
+    #[must_use]
+    pub fn to_mat4_relative_to(&self, origin: DVec3) -> crate::Mat4 {
+        crate::Mat4::from_cols(
+            self.matrix3.x_axis.as_vec3().extend(0.0),
+            self.matrix3.y_axis.as_vec3().extend(0.0),
+            self.matrix3.z_axis.as_vec3().extend(0.0),
+            self.translation.to_render_relative(origin).extend(1.0),
+        )
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     ///
     /// If any element is either `NaN`, positive or negative infinity, this will return
@@ -425,6 +506,30 @@ impl DAffine3 {
             && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
     }
 
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(&self, rhs: Self, max_ulps: u32) -> bool {
+        self.matrix3.ulps_eq(rhs.matrix3, max_ulps)
+            && self.translation.ulps_eq(rhs.translation, max_ulps)
+    }
+
+    /// Returns `true` if this transform mirrors space, reversing the winding order of
+    /// transformed triangles.
+    ///
+    /// See [`DMat3::is_flipping()`].
+    #[inline]
+    #[must_use]
+    pub fn is_flipping(&self) -> bool {
+        self.matrix3.is_flipping()
+    }
+
     /// Return the inverse of this transform.
     ///
     /// Note that if the transform is not invertible the result will be invalid.
@@ -440,6 +545,38 @@ impl DAffine3 {
             translation,
         }
     }
+
+    /// Returns `true` if this transform can be inverted.
+    ///
+    /// See [`DMat3::is_invertible()`].
+    #[inline]
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        self.matrix3.is_invertible()
+    }
+
+    /// Returns the inverse of this transform, or `None` if the transform is not invertible.
+    ///
+    /// See [`DMat3::try_inverse()`].
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let matrix3 = self.matrix3.try_inverse()?;
+        // transform negative translation by the matrix inverse:
+        let translation = -(matrix3 * self.translation);
+        Some(Self {
+            matrix3,
+            translation,
+        })
+    }
+
+    /// Returns the inverse of this transform, or an error if the transform is not invertible.
+    ///
+    /// See [`DMat3::try_inverse()`].
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, crate::error::Error> {
+        self.try_inverse().ok_or(crate::error::Error::Singular)
+    }
 }
 
 impl Default for DAffine3 {
@@ -484,29 +621,31 @@ impl core::fmt::Debug for DAffine3 {
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for DAffine3 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(
-                f,
-                "[{:.*}, {:.*}, {:.*}, {:.*}]",
-                p,
-                self.matrix3.x_axis,
-                p,
-                self.matrix3.y_axis,
-                p,
-                self.matrix3.z_axis,
-                p,
-                self.translation
-            )
+        if f.alternate() {
+            crate::display::fmt_grid(f, &self.to_cols_array_2d())
         } else {
-            write!(
+            crate::display::fmt_list(
                 f,
-                "[{}, {}, {}, {}]",
-                self.matrix3.x_axis, self.matrix3.y_axis, self.matrix3.z_axis, self.translation
+                &[
+                    self.matrix3.x_axis,
+                    self.matrix3.y_axis,
+                    self.matrix3.z_axis,
+                    self.translation,
+                ],
             )
         }
     }
 }
 
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for DAffine3 {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|a| Self::from_cols_array(&a))
+    }
+}
+
 impl<'a> core::iter::Product<&'a Self> for DAffine3 {
     fn product<I>(iter: I) -> Self
     where
@@ -561,6 +700,13 @@ impl Mul<DAffine3> for DMat4 {
 
     #[inline]
     fn mul(self, rhs: DAffine3) -> Self::Output {
-        self * DMat4::from(rhs)
+        self.mul_mat4_affine(&DMat4::from(rhs))
+    }
+}
+
+impl MulAssign<DAffine3> for DMat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DAffine3) {
+        *self = self.mul(rhs);
     }
 }
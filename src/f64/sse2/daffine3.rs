@@ -0,0 +1,777 @@
+// Generated from affine.rs.tera template. Edit the template, not the generated file.
+
+use crate::{
+    sse2::{dvec2_from_m128d, dvec2_to_m128d},
+    DMat3, DMat4, DQuat, DVec3,
+};
+use core::ops::{Deref, DerefMut, Mul, MulAssign};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// A 3D affine transform, which can represent translation, rotation, scaling and shear.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct DAffine3 {
+    pub matrix3: DMat3,
+    pub translation: DVec3,
+}
+
+impl DAffine3 {
+    /// The degenerate zero transform.
+    ///
+    /// This transforms any finite vector and point to zero.
+    /// The zero transform is non-invertible.
+    pub const ZERO: Self = Self {
+        matrix3: DMat3::ZERO,
+        translation: DVec3::ZERO,
+    };
+
+    /// The identity transform.
+    ///
+    /// Multiplying a vector with this returns the same vector.
+    pub const IDENTITY: Self = Self {
+        matrix3: DMat3::IDENTITY,
+        translation: DVec3::ZERO,
+    };
+
+    /// All NAN:s.
+    pub const NAN: Self = Self {
+        matrix3: DMat3::NAN,
+        translation: DVec3::NAN,
+    };
+
+    /// Creates an affine transform from three column vectors.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_cols(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3, w_axis: DVec3) -> Self {
+        Self {
+            matrix3: DMat3::from_cols(x_axis, y_axis, z_axis),
+            translation: w_axis,
+        }
+    }
+
+    /// Creates an affine transform from a `[f64; 12]` array stored in column major order.
+    #[inline]
+    #[must_use]
+    pub fn from_cols_array(m: &[f64; 12]) -> Self {
+        Self {
+            matrix3: DMat3::from_cols_slice(&m[0..9]),
+            translation: DVec3::from_slice(&m[9..12]),
+        }
+    }
+
+    /// Creates a `[f64; 12]` array storing data in column major order.
+    #[inline]
+    #[must_use]
+    pub fn to_cols_array(&self) -> [f64; 12] {
+        let x = &self.matrix3.x_axis;
+        let y = &self.matrix3.y_axis;
+        let z = &self.matrix3.z_axis;
+        let w = &self.translation;
+        [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z, w.x, w.y, w.z]
+    }
+
+    /// Creates an affine transform from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 96]) -> Self {
+        let mut a = [f64::default(); 12];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(8)) {
+            *dst = f64::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0; 96];
+        for (dst, src) in bytes.chunks_exact_mut(8).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates an affine transform from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 96]) -> Self {
+        let mut a = [f64::default(); 12];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(8)) {
+            *dst = f64::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_cols_array(&a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in column major order, matching [`Self::to_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0; 96];
+        for (dst, src) in bytes.chunks_exact_mut(8).zip(self.to_cols_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Creates an affine transform from a `[[f64; 3]; 4]`
+    /// 3D array stored in column major order.
+    /// If your data is in row major order you will need to `transpose` the returned
+    /// matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_cols_array_2d(m: &[[f64; 3]; 4]) -> Self {
+        Self {
+            matrix3: DMat3::from_cols(m[0].into(), m[1].into(), m[2].into()),
+            translation: m[3].into(),
+        }
+    }
+
+    /// Creates a `[[f64; 3]; 4]` 3D array storing data in
+    /// column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    #[must_use]
+    pub fn to_cols_array_2d(&self) -> [[f64; 3]; 4] {
+        [
+            self.matrix3.x_axis.into(),
+            self.matrix3.y_axis.into(),
+            self.matrix3.z_axis.into(),
+            self.translation.into(),
+        ]
+    }
+
+    /// Creates an affine transform from the first 12 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 12 elements long.
+    #[inline]
+    #[must_use]
+    pub fn from_cols_slice(slice: &[f64]) -> Self {
+        Self {
+            matrix3: DMat3::from_cols_slice(&slice[0..9]),
+            translation: DVec3::from_slice(&slice[9..12]),
+        }
+    }
+
+    /// Creates an affine transform from the first 12 values in `slice`, returning an
+    /// error if `slice` is less than 12 elements long.
+    #[inline]
+    pub fn from_cols_slice_checked(slice: &[f64]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 12)?;
+        Ok(Self::from_cols_slice(slice))
+    }
+
+    /// Writes the columns of `self` to the first 12 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 12 elements long.
+    #[inline]
+    pub fn write_cols_to_slice(self, slice: &mut [f64]) {
+        self.matrix3.write_cols_to_slice(&mut slice[0..9]);
+        self.translation.write_to_slice(&mut slice[9..12]);
+    }
+
+    /// Creates an affine transform that changes scale.
+    /// Note that if any scale is zero the transform will be non-invertible.
+    #[inline]
+    #[must_use]
+    pub fn from_scale(scale: DVec3) -> Self {
+        Self {
+            matrix3: DMat3::from_diagonal(scale),
+            translation: DVec3::ZERO,
+        }
+    }
+    /// Creates an affine transform from the given `rotation` quaternion.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(rotation: DQuat) -> Self {
+        Self {
+            matrix3: DMat3::from_quat(rotation),
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates an affine transform containing a 3D rotation around a normalized
+    /// rotation `axis` of `angle` (in radians).
+    #[inline]
+    #[must_use]
+    pub fn from_axis_angle(axis: DVec3, angle: f64) -> Self {
+        Self {
+            matrix3: DMat3::from_axis_angle(axis, angle),
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates an affine transform containing a 3D rotation around the x axis of
+    /// `angle` (in radians).
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        Self {
+            matrix3: DMat3::from_rotation_x(angle),
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates an affine transform containing a 3D rotation around the y axis of
+    /// `angle` (in radians).
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        Self {
+            matrix3: DMat3::from_rotation_y(angle),
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates an affine transform containing a 3D rotation around the z axis of
+    /// `angle` (in radians).
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        Self {
+            matrix3: DMat3::from_rotation_z(angle),
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates an affine transformation from the given 3D `translation`.
+    #[inline]
+    #[must_use]
+    pub fn from_translation(translation: DVec3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self {
+            matrix3: DMat3::IDENTITY,
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an affine transform from a 3x3 matrix (expressing scale, shear and
+    /// rotation)
+    #[inline]
+    #[must_use]
+    pub fn from_mat3(mat3: DMat3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self {
+            matrix3: mat3.into(),
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates an affine transform from a 3x3 matrix (expressing scale, shear and rotation)
+    /// and a translation vector.
+    ///
+    /// Equivalent to `DAffine3::from_translation(translation) * DAffine3::from_mat3(mat3)`
+    #[inline]
+    #[must_use]
+    pub fn from_mat3_translation(mat3: DMat3, translation: DVec3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self {
+            matrix3: mat3.into(),
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an affine transform from the given 3D `scale`, `rotation` and
+    /// `translation`.
+    ///
+    /// Equivalent to `DAffine3::from_translation(translation) *
+    /// DAffine3::from_quat(rotation) * DAffine3::from_scale(scale)`
+    #[inline]
+    #[must_use]
+    pub fn from_scale_rotation_translation(
+        scale: DVec3,
+        rotation: DQuat,
+        translation: DVec3,
+    ) -> Self {
+        let rotation = DMat3::from_quat(rotation);
+        #[allow(clippy::useless_conversion)]
+        Self {
+            matrix3: DMat3::from_cols(
+                rotation.x_axis * scale.x,
+                rotation.y_axis * scale.y,
+                rotation.z_axis * scale.z,
+            ),
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an affine transform from the given 3D `rotation` and `translation`.
+    ///
+    /// Equivalent to `DAffine3::from_translation(translation) * DAffine3::from_quat(rotation)`
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_translation(rotation: DQuat, translation: DVec3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self {
+            matrix3: DMat3::from_quat(rotation),
+            translation: translation.into(),
+        }
+    }
+
+    /// The given `DMat4` must be an affine transform,
+    /// i.e. contain no perspective transform.
+    #[inline]
+    #[must_use]
+    pub fn from_mat4(m: DMat4) -> Self {
+        Self {
+            matrix3: DMat3::from_cols(
+                DVec3::from_vec4(m.x_axis),
+                DVec3::from_vec4(m.y_axis),
+                DVec3::from_vec4(m.z_axis),
+            ),
+            translation: DVec3::from_vec4(m.w_axis),
+        }
+    }
+
+    /// Extracts `scale`, `rotation` and `translation` from `self`.
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant `self.matrix3` is zero or if the resulting scale
+    /// vector contains any zero elements when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn to_scale_rotation_translation(&self) -> (DVec3, DQuat, DVec3) {
+        use crate::f64::math;
+        let det = self.matrix3.determinant();
+        glam_assert!(det != 0.0);
+
+        let scale = DVec3::new(
+            self.matrix3.x_axis.length() * math::signum(det),
+            self.matrix3.y_axis.length(),
+            self.matrix3.z_axis.length(),
+        );
+
+        glam_assert!(scale.cmpne(DVec3::ZERO).all());
+
+        let inv_scale = scale.recip();
+
+        #[allow(clippy::useless_conversion)]
+        let rotation = DQuat::from_mat3(&DMat3::from_cols(
+            (self.matrix3.x_axis * inv_scale.x).into(),
+            (self.matrix3.y_axis * inv_scale.y).into(),
+            (self.matrix3.z_axis * inv_scale.z).into(),
+        ));
+
+        #[allow(clippy::useless_conversion)]
+        (scale, rotation, self.translation.into())
+    }
+
+    /// Creates a left-handed view transform using a camera position, an up direction, and a facing
+    /// direction.
+    ///
+    /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=forward`.
+    #[inline]
+    #[must_use]
+    pub fn look_to_lh(eye: DVec3, dir: DVec3, up: DVec3) -> Self {
+        Self::look_to_rh(eye, -dir, up)
+    }
+
+    /// Creates a right-handed view transform using a camera position, an up direction, and a facing
+    /// direction.
+    ///
+    /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=back`.
+    #[inline]
+    #[must_use]
+    pub fn look_to_rh(eye: DVec3, dir: DVec3, up: DVec3) -> Self {
+        let f = dir.normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+
+        Self {
+            matrix3: DMat3::from_cols(
+                DVec3::new(s.x, u.x, -f.x),
+                DVec3::new(s.y, u.y, -f.y),
+                DVec3::new(s.z, u.z, -f.z),
+            ),
+            translation: DVec3::new(-eye.dot(s), -eye.dot(u), eye.dot(f)),
+        }
+    }
+
+    /// Creates a left-handed view transform using a camera position, an up direction, and a focal
+    /// point.
+    /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=forward`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `up` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn look_at_lh(eye: DVec3, center: DVec3, up: DVec3) -> Self {
+        glam_assert!(up.is_normalized());
+        Self::look_to_lh(eye, center - eye, up)
+    }
+
+    /// Creates a right-handed view transform using a camera position, an up direction, and a focal
+    /// point.
+    /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=back`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `up` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn look_at_rh(eye: DVec3, center: DVec3, up: DVec3) -> Self {
+        glam_assert!(up.is_normalized());
+        Self::look_to_rh(eye, center - eye, up)
+    }
+
+    /// Transforms the given 3D points, applying shear, scale, rotation and translation.
+    #[inline]
+    pub fn transform_point3(&self, rhs: DVec3) -> DVec3 {
+        transform_f64x2(&self.matrix3, rhs, Some(self.translation))
+    }
+
+    /// Transforms the given 3D vector, applying shear, scale and rotation (but NOT
+    /// translation).
+    ///
+    /// To also apply translation, use [`Self::transform_point3()`] instead.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(&self, rhs: DVec3) -> DVec3 {
+        transform_f64x2(&self.matrix3, rhs, None)
+    }
+
+    /// Returns a [`Mat4`](crate::Mat4) transform relative to the given `origin`.
+    ///
+    /// The translation is computed as `self.translation - origin` in `f64` before being
+    /// truncated to `f32`, so the result stays precise when both `self` and `origin` are far from
+    /// the world origin. This is typically used to build camera relative model matrices for
+    /// rendering where `origin` is the camera position.
+    #[inline]
+    #[must_use]
+    pub fn to_mat4_relative_to(&self, origin: DVec3) -> crate::Mat4 {
+        crate::Mat4::from_cols(
+            self.matrix3.x_axis.as_vec3().extend(0.0),
+            self.matrix3.y_axis.as_vec3().extend(0.0),
+            self.matrix3.z_axis.as_vec3().extend(0.0),
+            self.translation.to_render_relative(origin).extend(1.0),
+        )
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    ///
+    /// If any element is either `NaN`, positive or negative infinity, this will return
+    /// `false`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.matrix3.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        self.matrix3.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two 3x4 matrices contain similar elements. It works
+    /// best when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.matrix3.abs_diff_eq(rhs.matrix3, max_abs_diff)
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+    }
+
+    /// Returns true if all elements of `self` and `rhs` are within `max_ulps` units in the last
+    /// place of each other.
+    ///
+    /// Unlike [`Self::abs_diff_eq`] the tolerance scales with the magnitude of the elements being
+    /// compared. `NaN` elements are never equal and elements with different signs are only equal
+    /// if they are both zero, so comparisons with values near zero are better made with
+    /// [`Self::abs_diff_eq`].
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(&self, rhs: Self, max_ulps: u32) -> bool {
+        self.matrix3.ulps_eq(rhs.matrix3, max_ulps)
+            && self.translation.ulps_eq(rhs.translation, max_ulps)
+    }
+
+    /// Returns `true` if this transform mirrors space, reversing the winding order of
+    /// transformed triangles.
+    ///
+    /// See [`DMat3::is_flipping()`].
+    #[inline]
+    #[must_use]
+    pub fn is_flipping(&self) -> bool {
+        self.matrix3.is_flipping()
+    }
+
+    /// Return the inverse of this transform.
+    ///
+    /// Note that if the transform is not invertible the result will be invalid.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let matrix3 = inverse_f64x2(&self.matrix3);
+        // transform negative translation by the matrix inverse:
+        let translation = -transform_f64x2(&matrix3, self.translation, None);
+
+        Self {
+            matrix3,
+            translation,
+        }
+    }
+
+    /// Returns `true` if this transform can be inverted.
+    ///
+    /// See [`DMat3::is_invertible()`].
+    #[inline]
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        self.matrix3.is_invertible()
+    }
+
+    /// Returns the inverse of this transform, or `None` if the transform is not invertible.
+    ///
+    /// See [`DMat3::try_inverse()`].
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let matrix3 = self.matrix3.try_inverse()?;
+        // transform negative translation by the matrix inverse:
+        let translation = -transform_f64x2(&matrix3, self.translation, None);
+        Some(Self {
+            matrix3,
+            translation,
+        })
+    }
+
+    /// Returns the inverse of this transform, or an error if the transform is not invertible.
+    ///
+    /// See [`DMat3::try_inverse()`].
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, crate::error::Error> {
+        self.try_inverse().ok_or(crate::error::Error::Singular)
+    }
+}
+
+impl Default for DAffine3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Deref for DAffine3 {
+    type Target = crate::deref::Cols4<DVec3>;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self as *const Self::Target) }
+    }
+}
+
+impl DerefMut for DAffine3 {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self as *mut Self::Target) }
+    }
+}
+
+impl PartialEq for DAffine3 {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.matrix3.eq(&rhs.matrix3) && self.translation.eq(&rhs.translation)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::fmt::Debug for DAffine3 {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.debug_struct(stringify!(DAffine3))
+            .field("matrix3", &self.matrix3)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::fmt::Display for DAffine3 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            crate::display::fmt_grid(f, &self.to_cols_array_2d())
+        } else {
+            crate::display::fmt_list(
+                f,
+                &[
+                    self.matrix3.x_axis,
+                    self.matrix3.y_axis,
+                    self.matrix3.z_axis,
+                    self.translation,
+                ],
+            )
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for DAffine3 {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|a| Self::from_cols_array(&a))
+    }
+}
+
+impl<'a> core::iter::Product<&'a Self> for DAffine3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, &b| a * b)
+    }
+}
+
+impl Mul for DAffine3 {
+    type Output = DAffine3;
+
+    #[inline]
+    fn mul(self, rhs: DAffine3) -> Self::Output {
+        let m = &self.matrix3;
+        Self {
+            matrix3: DMat3::from_cols(
+                transform_f64x2(m, rhs.matrix3.x_axis, None),
+                transform_f64x2(m, rhs.matrix3.y_axis, None),
+                transform_f64x2(m, rhs.matrix3.z_axis, None),
+            ),
+            translation: transform_f64x2(m, rhs.translation, Some(self.translation)),
+        }
+    }
+}
+
+impl MulAssign for DAffine3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DAffine3) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl From<DAffine3> for DMat4 {
+    #[inline]
+    fn from(m: DAffine3) -> DMat4 {
+        DMat4::from_cols(
+            m.matrix3.x_axis.extend(0.0),
+            m.matrix3.y_axis.extend(0.0),
+            m.matrix3.z_axis.extend(0.0),
+            m.translation.extend(1.0),
+        )
+    }
+}
+
+impl Mul<DMat4> for DAffine3 {
+    type Output = DMat4;
+
+    #[inline]
+    fn mul(self, rhs: DMat4) -> Self::Output {
+        DMat4::from(self) * rhs
+    }
+}
+
+impl Mul<DAffine3> for DMat4 {
+    type Output = DMat4;
+
+    #[inline]
+    fn mul(self, rhs: DAffine3) -> Self::Output {
+        self.mul_mat4_affine(&DMat4::from(rhs))
+    }
+}
+
+impl MulAssign<DAffine3> for DMat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DAffine3) {
+        *self = self.mul(rhs);
+    }
+}
+
+/// Returns `m * v`, adding `t` if given, with the `xy` lanes in a single `__m128d`.
+///
+/// The operations are performed in the same order as the scalar path so the results are
+/// identical.
+#[inline(always)]
+fn transform_f64x2(m: &DMat3, v: DVec3, t: Option<DVec3>) -> DVec3 {
+    unsafe {
+        let mut xy = _mm_mul_pd(dvec2_to_m128d(m.x_axis.truncate()), _mm_set1_pd(v.x));
+        xy = _mm_add_pd(
+            xy,
+            _mm_mul_pd(dvec2_to_m128d(m.y_axis.truncate()), _mm_set1_pd(v.y)),
+        );
+        xy = _mm_add_pd(
+            xy,
+            _mm_mul_pd(dvec2_to_m128d(m.z_axis.truncate()), _mm_set1_pd(v.z)),
+        );
+        let mut z = m.x_axis.z * v.x + m.y_axis.z * v.y + m.z_axis.z * v.z;
+        if let Some(t) = t {
+            xy = _mm_add_pd(xy, dvec2_to_m128d(t.truncate()));
+            z += t.z;
+        }
+        dvec2_from_m128d(xy).extend(z)
+    }
+}
+
+/// Returns the `xy` lanes and `z` of `a.cross(b)`.
+#[inline(always)]
+unsafe fn cross_f64x2(a: DVec3, b: DVec3) -> (__m128d, f64) {
+    // xy = a.yz * b.zx - b.yz * a.zx
+    let a_yz = _mm_set_pd(a.z, a.y);
+    let b_yz = _mm_set_pd(b.z, b.y);
+    let a_zx = _mm_set_pd(a.x, a.z);
+    let b_zx = _mm_set_pd(b.x, b.z);
+    let xy = _mm_sub_pd(_mm_mul_pd(a_yz, b_zx), _mm_mul_pd(b_yz, a_zx));
+    (xy, a.x * b.y - b.x * a.y)
+}
+
+/// Returns the inverse of `m`, see [`DMat3::inverse()`].
+#[inline(always)]
+fn inverse_f64x2(m: &DMat3) -> DMat3 {
+    unsafe {
+        let (tmp0_xy, tmp0_z) = cross_f64x2(m.y_axis, m.z_axis);
+        let (tmp1_xy, tmp1_z) = cross_f64x2(m.z_axis, m.x_axis);
+        let (tmp2_xy, tmp2_z) = cross_f64x2(m.x_axis, m.y_axis);
+        let det = m.z_axis.dot(dvec2_from_m128d(tmp2_xy).extend(tmp2_z));
+        glam_assert!(det != 0.0);
+        let inv_det = det.recip();
+        let inv_det_xy = _mm_set1_pd(inv_det);
+
+        // the rows of the inverse are the cross products, so transpose while scaling
+        let tmp2 = dvec2_from_m128d(_mm_mul_pd(tmp2_xy, inv_det_xy));
+        let x_axis = _mm_mul_pd(_mm_unpacklo_pd(tmp0_xy, tmp1_xy), inv_det_xy);
+        let y_axis = _mm_mul_pd(_mm_unpackhi_pd(tmp0_xy, tmp1_xy), inv_det_xy);
+        DMat3::from_cols(
+            dvec2_from_m128d(x_axis).extend(tmp2.x),
+            dvec2_from_m128d(y_axis).extend(tmp2.y),
+            DVec3::new(tmp0_z * inv_det, tmp1_z * inv_det, tmp2_z * inv_det),
+        )
+    }
+}
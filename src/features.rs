@@ -1,17 +1,35 @@
 #[cfg(feature = "approx")]
 pub mod impl_approx;
 
+#[cfg(feature = "borsh")]
+pub mod impl_borsh;
+
 #[cfg(feature = "bytemuck")]
 pub mod impl_bytemuck;
 
+#[cfg(feature = "fixed")]
+pub mod impl_fixed;
+
 #[cfg(feature = "mint")]
 pub mod impl_mint;
 
+#[cfg(feature = "num-traits")]
+pub mod impl_num_traits;
+
+#[cfg(feature = "quickcheck")]
+pub mod impl_quickcheck;
+
 #[cfg(feature = "rand")]
 pub mod impl_rand;
 
 #[cfg(feature = "serde")]
 pub mod impl_serde;
 
+#[cfg(feature = "simba")]
+pub mod impl_simba;
+
+#[cfg(feature = "speedy")]
+pub mod impl_speedy;
+
 #[cfg(feature = "rkyv")]
 pub mod impl_rkyv;
@@ -1,6 +1,8 @@
 use crate::{
-    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2,
-    Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4,
+    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4,
+    I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A,
+    Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, UVec2, UVec3, UVec4, Vec2,
+    Vec3, Vec3A, Vec4,
 };
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
@@ -42,6 +44,22 @@ macro_rules! impl_approx_as_ref {
     };
 }
 
+// `approx` only implements `RelativeEq` and `UlpsEq` for floating point types so integer vectors
+// only implement `AbsDiffEq`.
+macro_rules! impl_abs_diff_eq_as_ref {
+    ($prim:ident, $type:ty) => {
+        impl AbsDiffEq for $type {
+            type Epsilon = <$prim as AbsDiffEq>::Epsilon;
+            fn default_epsilon() -> Self::Epsilon {
+                $prim::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.as_ref().abs_diff_eq(other.as_ref(), epsilon)
+            }
+        }
+    };
+}
+
 macro_rules! impl_approx_xzy_axes {
     ($prim:ident, $type:ty) => {
         impl AbsDiffEq for $type {
@@ -154,6 +172,30 @@ impl_approx_as_ref!(f64, DVec2);
 impl_approx_as_ref!(f64, DVec3);
 impl_approx_as_ref!(f64, DVec4);
 
+impl_abs_diff_eq_as_ref!(i16, I16Vec2);
+impl_abs_diff_eq_as_ref!(i16, I16Vec3);
+impl_abs_diff_eq_as_ref!(i16, I16Vec4);
+
+impl_abs_diff_eq_as_ref!(i32, IVec2);
+impl_abs_diff_eq_as_ref!(i32, IVec3);
+impl_abs_diff_eq_as_ref!(i32, IVec4);
+
+impl_abs_diff_eq_as_ref!(i64, I64Vec2);
+impl_abs_diff_eq_as_ref!(i64, I64Vec3);
+impl_abs_diff_eq_as_ref!(i64, I64Vec4);
+
+impl_abs_diff_eq_as_ref!(u16, U16Vec2);
+impl_abs_diff_eq_as_ref!(u16, U16Vec3);
+impl_abs_diff_eq_as_ref!(u16, U16Vec4);
+
+impl_abs_diff_eq_as_ref!(u32, UVec2);
+impl_abs_diff_eq_as_ref!(u32, UVec3);
+impl_abs_diff_eq_as_ref!(u32, UVec4);
+
+impl_abs_diff_eq_as_ref!(u64, U64Vec2);
+impl_abs_diff_eq_as_ref!(u64, U64Vec3);
+impl_abs_diff_eq_as_ref!(u64, U64Vec4);
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -209,4 +251,44 @@ mod test {
         impl_approx_test!(f64, DMat3, DMat3::from_cols_slice(&ONESF64));
         impl_approx_test!(f64, DMat4, DMat4::from_cols_slice(&ONESF64));
     }
+
+    macro_rules! impl_approx_affine_test {
+        ($prim:ident, $type:ident, $n:literal) => {
+            let ones = $type::from_cols_array(&[1.0; $n]);
+            let eps = $prim::default_epsilon();
+            let one_eps = $type::from_cols_array(&[1.0 + eps; $n]);
+            let two_eps = $type::from_cols_array(&[1.0 + eps + eps + eps; $n]);
+            let one_ulp = $type::from_cols_array(&[$prim::from_bits($prim::to_bits(1.0) + 1); $n]);
+            let four_ulp =
+                $type::from_cols_array(&[$prim::from_bits($prim::to_bits(1.0) + 16); $n]);
+
+            approx::assert_abs_diff_eq!(ones, ones);
+            approx::assert_abs_diff_eq!(ones, one_eps);
+            approx::assert_abs_diff_ne!(ones, two_eps);
+
+            approx::assert_relative_eq!(ones, ones);
+            approx::assert_relative_ne!(ones, $type::ZERO);
+
+            approx::assert_ulps_eq!(ones, one_ulp);
+            approx::assert_ulps_ne!(ones, four_ulp);
+        };
+    }
+
+    #[test]
+    fn test_approx_affine() {
+        impl_approx_affine_test!(f32, Affine2, 6);
+        impl_approx_affine_test!(f32, Affine3A, 12);
+        impl_approx_affine_test!(f64, DAffine2, 6);
+        impl_approx_affine_test!(f64, DAffine3, 12);
+    }
+
+    #[test]
+    fn test_approx_int() {
+        approx::assert_abs_diff_eq!(IVec2::new(1, -2), IVec2::new(1, -2));
+        approx::assert_abs_diff_ne!(IVec2::new(1, -2), IVec2::new(1, -1));
+        approx::assert_abs_diff_eq!(IVec3::ONE, IVec3::ZERO, epsilon = 1);
+        approx::assert_abs_diff_ne!(I64Vec4::ONE, I64Vec4::NEG_ONE, epsilon = 1);
+        approx::assert_abs_diff_eq!(UVec3::new(4, 5, 6), UVec3::new(5, 4, 6), epsilon = 1);
+        approx::assert_abs_diff_ne!(U16Vec2::new(0, 3), U16Vec2::new(2, 3), epsilon = 1);
+    }
 }
@@ -0,0 +1,269 @@
+// All types are encoded as a fixed-size sequence of their scalar components in
+// little endian byte order with no length prefix or padding. Vectors and
+// quaternions are written in `x, y, z, w` order, matrices and affine transforms
+// are written column by column in the same order as `to_cols_array`.
+//
+// Note that `borsh` does not allow NaN floating point values to be serialized.
+
+macro_rules! impl_borsh_vec {
+    ($t:ty, $n:literal, $type:ident) => {
+        impl BorshSerialize for $type {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                self.to_array().serialize(writer)
+            }
+        }
+
+        impl BorshDeserialize for $type {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                Ok(Self::from_array(<[$t; $n]>::deserialize_reader(reader)?))
+            }
+        }
+    };
+}
+
+macro_rules! impl_borsh_cols {
+    ($t:ty, $n:literal, $type:ident) => {
+        impl BorshSerialize for $type {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                self.to_cols_array().serialize(writer)
+            }
+        }
+
+        impl BorshDeserialize for $type {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                Ok(Self::from_cols_array(&<[$t; $n]>::deserialize_reader(
+                    reader,
+                )?))
+            }
+        }
+    };
+}
+
+macro_rules! impl_borsh_vec_types {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_borsh_vec!($t, 2, $vec2);
+        impl_borsh_vec!($t, 3, $vec3);
+        impl_borsh_vec!($t, 4, $vec4);
+    };
+}
+
+macro_rules! impl_borsh_float_types {
+    ($t:ty, $affine2:ident, $affine3:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_borsh_cols!($t, 6, $affine2);
+        impl_borsh_cols!($t, 12, $affine3);
+        impl_borsh_cols!($t, 4, $mat2);
+        impl_borsh_cols!($t, 9, $mat3);
+        impl_borsh_cols!($t, 16, $mat4);
+        impl_borsh_vec!($t, 4, $quat);
+        impl_borsh_vec_types!($t, $vec2, $vec3, $vec4);
+    };
+}
+
+mod f32 {
+    use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    use borsh::io::{Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    impl_borsh_float_types!(f32, Affine2, Affine3A, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+    impl_borsh_cols!(f32, 9, Mat3A);
+    impl_borsh_vec!(f32, 3, Vec3A);
+}
+
+mod f64 {
+    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+    use borsh::io::{Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    impl_borsh_float_types!(
+        f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
+    );
+}
+
+mod i16 {
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use borsh::io::{Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    impl_borsh_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+}
+
+mod i32 {
+    use crate::{IVec2, IVec3, IVec4};
+    use borsh::io::{Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    impl_borsh_vec_types!(i32, IVec2, IVec3, IVec4);
+}
+
+mod i64 {
+    use crate::{I64Vec2, I64Vec3, I64Vec4};
+    use borsh::io::{Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    impl_borsh_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+}
+
+mod u16 {
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use borsh::io::{Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    impl_borsh_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+}
+
+mod u32 {
+    use crate::{UVec2, UVec3, UVec4};
+    use borsh::io::{Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    impl_borsh_vec_types!(u32, UVec2, UVec3, UVec4);
+}
+
+mod u64 {
+    use crate::{U64Vec2, U64Vec3, U64Vec4};
+    use borsh::io::{Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    impl_borsh_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+}
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    fn test_roundtrip<T>(value: &T, expected_len: usize)
+    where
+        T: core::fmt::Debug + PartialEq + BorshSerialize + BorshDeserialize,
+    {
+        let bytes = borsh::to_vec(value).unwrap();
+        assert_eq!(bytes.len(), expected_len);
+        assert_eq!(&borsh::from_slice::<T>(&bytes).unwrap(), value);
+        // truncated input should fail
+        assert!(borsh::from_slice::<T>(&bytes[..expected_len - 1]).is_err());
+    }
+
+    #[test]
+    fn test_borsh_layout() {
+        use crate::{Affine2, Mat2, Vec3};
+        let bytes = borsh::to_vec(&Vec3::new(1.0, 2.0, 3.0)).unwrap();
+        let expected: Vec<u8> = [1.0_f32, 2.0, 3.0]
+            .iter()
+            .flat_map(|f| f.to_le_bytes())
+            .collect();
+        assert_eq!(bytes, expected);
+
+        let bytes = borsh::to_vec(&Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0])).unwrap();
+        let expected: Vec<u8> = [1.0_f32, 2.0, 3.0, 4.0]
+            .iter()
+            .flat_map(|f| f.to_le_bytes())
+            .collect();
+        assert_eq!(bytes, expected);
+
+        // Affine2 is written without the internal padding of its SIMD Mat2
+        let bytes =
+            borsh::to_vec(&Affine2::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])).unwrap();
+        let expected: Vec<u8> = [1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0]
+            .iter()
+            .flat_map(|f| f.to_le_bytes())
+            .collect();
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_borsh() {
+        use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+        test_roundtrip(
+            &Affine2::from_cols_array(&[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]),
+            24,
+        );
+        test_roundtrip(
+            &Affine3A::from_cols_array(&[
+                1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0,
+            ]),
+            48,
+        );
+        test_roundtrip(&Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]), 16);
+        test_roundtrip(
+            &Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            36,
+        );
+        test_roundtrip(
+            &Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            36,
+        );
+        test_roundtrip(
+            &Mat4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0,
+            ]),
+            64,
+        );
+        test_roundtrip(&Quat::from_xyzw(1.0, 2.0, 3.0, 4.0), 16);
+        test_roundtrip(&Vec2::new(1.0, 2.0), 8);
+        test_roundtrip(&Vec3::new(1.0, 2.0, 3.0), 12);
+        test_roundtrip(&Vec3A::new(1.0, 2.0, 3.0), 12);
+        test_roundtrip(&Vec4::new(1.0, 2.0, 3.0, 4.0), 16);
+
+        use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+        test_roundtrip(
+            &DAffine2::from_cols_array(&[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]),
+            48,
+        );
+        test_roundtrip(
+            &DAffine3::from_cols_array(&[
+                1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0,
+            ]),
+            96,
+        );
+        test_roundtrip(&DMat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]), 32);
+        test_roundtrip(
+            &DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            72,
+        );
+        test_roundtrip(
+            &DMat4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0,
+            ]),
+            128,
+        );
+        test_roundtrip(&DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0), 32);
+        test_roundtrip(&DVec2::new(1.0, 2.0), 16);
+        test_roundtrip(&DVec3::new(1.0, 2.0, 3.0), 24);
+        test_roundtrip(&DVec4::new(1.0, 2.0, 3.0, 4.0), 32);
+
+        use crate::{I16Vec2, I16Vec3, I16Vec4};
+        test_roundtrip(&I16Vec2::new(-1, 2), 4);
+        test_roundtrip(&I16Vec3::new(-1, 2, 3), 6);
+        test_roundtrip(&I16Vec4::new(-1, 2, 3, 4), 8);
+
+        use crate::{IVec2, IVec3, IVec4};
+        test_roundtrip(&IVec2::new(-1, 2), 8);
+        test_roundtrip(&IVec3::new(-1, 2, 3), 12);
+        test_roundtrip(&IVec4::new(-1, 2, 3, 4), 16);
+
+        use crate::{I64Vec2, I64Vec3, I64Vec4};
+        test_roundtrip(&I64Vec2::new(-1, 2), 16);
+        test_roundtrip(&I64Vec3::new(-1, 2, 3), 24);
+        test_roundtrip(&I64Vec4::new(-1, 2, 3, 4), 32);
+
+        use crate::{U16Vec2, U16Vec3, U16Vec4};
+        test_roundtrip(&U16Vec2::new(1, 2), 4);
+        test_roundtrip(&U16Vec3::new(1, 2, 3), 6);
+        test_roundtrip(&U16Vec4::new(1, 2, 3, 4), 8);
+
+        use crate::{UVec2, UVec3, UVec4};
+        test_roundtrip(&UVec2::new(1, 2), 8);
+        test_roundtrip(&UVec3::new(1, 2, 3), 12);
+        test_roundtrip(&UVec4::new(1, 2, 3, 4), 16);
+
+        use crate::{U64Vec2, U64Vec3, U64Vec4};
+        test_roundtrip(&U64Vec2::new(1, 2), 16);
+        test_roundtrip(&U64Vec3::new(1, 2, 3), 24);
+        test_roundtrip(&U64Vec4::new(1, 2, 3, 4), 32);
+    }
+}
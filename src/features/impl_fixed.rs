@@ -0,0 +1,142 @@
+use fixed::traits::Fixed;
+
+macro_rules! impl_fixed_vec {
+    ($t:ty, $n:literal, $vec:ident) => {
+        impl $vec {
+            /// Creates a vector from an array of fixed-point values.
+            #[inline]
+            #[must_use]
+            pub fn from_fixed<F: Fixed>(a: [F; $n]) -> Self {
+                Self::from_array(a.map(|f| f.to_num::<$t>()))
+            }
+
+            /// Converts `self` to an array of fixed-point values, rounding each element to the
+            /// nearest representable value with ties rounded to even.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any element is NaN or out of range for `F`.
+            #[inline]
+            #[must_use]
+            pub fn to_fixed_round<F: Fixed>(self) -> [F; $n] {
+                self.to_array()
+                    .map(|x| F::checked_from_num(x).expect("out of range"))
+            }
+
+            /// Converts `self` to an array of fixed-point values, rounding each element towards
+            /// negative infinity.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any element is NaN or out of range for `F`.
+            #[inline]
+            #[must_use]
+            pub fn to_fixed_floor<F: Fixed>(self) -> [F; $n] {
+                self.to_array().map(|x| {
+                    let f = F::checked_from_num(x).expect("out of range");
+                    if f > x {
+                        // `x` rounded up to `F::MIN` if this overflows
+                        f.checked_sub(F::DELTA).expect("out of range")
+                    } else {
+                        f
+                    }
+                })
+            }
+
+            /// Converts `self` to an array of fixed-point values, rounding each element towards
+            /// positive infinity.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any element is NaN or out of range for `F`.
+            #[inline]
+            #[must_use]
+            pub fn to_fixed_ceil<F: Fixed>(self) -> [F; $n] {
+                self.to_array().map(|x| {
+                    let f = F::checked_from_num(x).expect("out of range");
+                    if f < x {
+                        // `x` rounded down to `F::MAX` if this overflows
+                        f.checked_add(F::DELTA).expect("out of range")
+                    } else {
+                        f
+                    }
+                })
+            }
+        }
+    };
+}
+
+mod f32 {
+    use super::Fixed;
+    use crate::{Vec2, Vec3, Vec3A, Vec4};
+
+    impl_fixed_vec!(f32, 2, Vec2);
+    impl_fixed_vec!(f32, 3, Vec3);
+    impl_fixed_vec!(f32, 3, Vec3A);
+    impl_fixed_vec!(f32, 4, Vec4);
+}
+
+mod f64 {
+    use super::Fixed;
+    use crate::{DVec2, DVec3, DVec4};
+
+    impl_fixed_vec!(f64, 2, DVec2);
+    impl_fixed_vec!(f64, 3, DVec3);
+    impl_fixed_vec!(f64, 4, DVec4);
+}
+
+#[cfg(test)]
+mod test {
+    use fixed::types::{I16F16, I32F32, U8F8};
+
+    #[test]
+    fn test_fixed_round_trip() {
+        use crate::{DVec3, Vec2};
+        let v = Vec2::new(1.5, -2.25);
+        let f = v.to_fixed_round::<I16F16>();
+        assert_eq!(f, [I16F16::from_num(1.5), I16F16::from_num(-2.25)]);
+        assert_eq!(Vec2::from_fixed(f), v);
+
+        let v = DVec3::new(0.5, -0.125, 1024.0);
+        assert_eq!(DVec3::from_fixed(v.to_fixed_round::<I32F32>()), v);
+    }
+
+    #[test]
+    fn test_fixed_rounding_modes() {
+        use crate::Vec4;
+        let delta = I16F16::DELTA.to_num::<f32>();
+        let v = Vec4::new(1.0 + delta * 0.25, 1.0 + delta * 0.75, -delta * 0.25, 3.0);
+        let one = I16F16::ONE;
+        let d = I16F16::DELTA;
+        assert_eq!(
+            v.to_fixed_round::<I16F16>(),
+            [one, one + d, I16F16::ZERO, I16F16::from_num(3)]
+        );
+        assert_eq!(
+            v.to_fixed_floor::<I16F16>(),
+            [one, one, -d, I16F16::from_num(3)]
+        );
+        assert_eq!(
+            v.to_fixed_ceil::<I16F16>(),
+            [one + d, one + d, I16F16::ZERO, I16F16::from_num(3)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_fixed_out_of_range() {
+        let _ = crate::Vec2::new(1.0, 300.0).to_fixed_round::<U8F8>();
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_fixed_ceil_out_of_range() {
+        let _ = crate::DVec2::new(32767.99999, 0.0).to_fixed_ceil::<I16F16>();
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_fixed_floor_out_of_range() {
+        let _ = crate::DVec2::new(-32768.000005, 0.0).to_fixed_floor::<I16F16>();
+    }
+}
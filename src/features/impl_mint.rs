@@ -1,9 +1,10 @@
 use mint::IntoMint;
 
 use crate::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3,
-    I64Vec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4,
-    U64Vec2, U64Vec3, U64Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4,
+    I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A,
+    Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, UVec2, UVec3, UVec4, Vec2,
+    Vec3, Vec3A, Vec4,
 };
 
 macro_rules! impl_vec_types {
@@ -235,6 +236,75 @@ macro_rules! impl_float_types {
     };
 }
 
+macro_rules! impl_affine_types {
+    ($t:ty, $affine2:ident, $affine3:ident) => {
+        impl From<mint::ColumnMatrix2x3<$t>> for $affine2 {
+            fn from(m: mint::ColumnMatrix2x3<$t>) -> Self {
+                Self::from_cols(m.x.into(), m.y.into(), m.z.into())
+            }
+        }
+
+        impl From<$affine2> for mint::ColumnMatrix2x3<$t> {
+            fn from(m: $affine2) -> Self {
+                Self {
+                    x: m.matrix2.x_axis.into(),
+                    y: m.matrix2.y_axis.into(),
+                    z: m.translation.into(),
+                }
+            }
+        }
+
+        impl From<mint::RowMatrix2x3<$t>> for $affine2 {
+            fn from(m: mint::RowMatrix2x3<$t>) -> Self {
+                mint::ColumnMatrix2x3::from(m).into()
+            }
+        }
+
+        impl From<$affine2> for mint::RowMatrix2x3<$t> {
+            fn from(m: $affine2) -> Self {
+                mint::ColumnMatrix2x3::from(m).into()
+            }
+        }
+
+        impl IntoMint for $affine2 {
+            type MintType = mint::ColumnMatrix2x3<$t>;
+        }
+
+        impl From<mint::ColumnMatrix3x4<$t>> for $affine3 {
+            fn from(m: mint::ColumnMatrix3x4<$t>) -> Self {
+                Self::from_cols(m.x.into(), m.y.into(), m.z.into(), m.w.into())
+            }
+        }
+
+        impl From<$affine3> for mint::ColumnMatrix3x4<$t> {
+            fn from(m: $affine3) -> Self {
+                Self {
+                    x: m.matrix3.x_axis.into(),
+                    y: m.matrix3.y_axis.into(),
+                    z: m.matrix3.z_axis.into(),
+                    w: m.translation.into(),
+                }
+            }
+        }
+
+        impl From<mint::RowMatrix3x4<$t>> for $affine3 {
+            fn from(m: mint::RowMatrix3x4<$t>) -> Self {
+                mint::ColumnMatrix3x4::from(m).into()
+            }
+        }
+
+        impl From<$affine3> for mint::RowMatrix3x4<$t> {
+            fn from(m: $affine3) -> Self {
+                mint::ColumnMatrix3x4::from(m).into()
+            }
+        }
+
+        impl IntoMint for $affine3 {
+            type MintType = mint::ColumnMatrix3x4<$t>;
+        }
+    };
+}
+
 impl From<mint::Point3<f32>> for Vec3A {
     fn from(v: mint::Point3<f32>) -> Self {
         Self::new(v.x, v.y, v.z)
@@ -310,6 +380,8 @@ impl IntoMint for Mat3A {
 
 impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
 impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+impl_affine_types!(f32, Affine2, Affine3A);
+impl_affine_types!(f64, DAffine2, DAffine3);
 impl_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
 impl_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
 impl_vec_types!(i32, IVec2, IVec3, IVec4);
@@ -455,8 +527,63 @@ mod test {
         };
     }
 
+    macro_rules! impl_affine_tests {
+        ($t:ty, $affine2:ident, $affine3:ident) => {
+            use crate::{$affine2, $affine3};
+
+            #[test]
+            fn test_affine2() {
+                let g = $affine2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+                let m = mint::ColumnMatrix2x3::from(g);
+                assert_eq!(
+                    m,
+                    mint::ColumnMatrix2x3::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]])
+                );
+                assert_eq!(g, $affine2::from(m));
+                let mt = mint::RowMatrix2x3::from(g);
+                assert_eq!(
+                    mt,
+                    mint::RowMatrix2x3::from([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]])
+                );
+                assert_eq!(g, $affine2::from(mt));
+            }
+
+            #[test]
+            fn test_affine3() {
+                let g = $affine3::from_cols_array_2d(&[
+                    [1.0, 2.0, 3.0],
+                    [4.0, 5.0, 6.0],
+                    [7.0, 8.0, 9.0],
+                    [10.0, 11.0, 12.0],
+                ]);
+                let m = mint::ColumnMatrix3x4::from(g);
+                assert_eq!(
+                    m,
+                    mint::ColumnMatrix3x4::from([
+                        [1.0, 2.0, 3.0],
+                        [4.0, 5.0, 6.0],
+                        [7.0, 8.0, 9.0],
+                        [10.0, 11.0, 12.0]
+                    ])
+                );
+                assert_eq!(g, $affine3::from(m));
+                let mt = mint::RowMatrix3x4::from(g);
+                assert_eq!(
+                    mt,
+                    mint::RowMatrix3x4::from([
+                        [1.0, 4.0, 7.0, 10.0],
+                        [2.0, 5.0, 8.0, 11.0],
+                        [3.0, 6.0, 9.0, 12.0]
+                    ])
+                );
+                assert_eq!(g, $affine3::from(mt));
+            }
+        };
+    }
+
     mod f32 {
         impl_float_tests!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+        impl_affine_tests!(f32, Affine2, Affine3A);
 
         #[test]
         fn test_point3a() {
@@ -521,6 +648,7 @@ mod test {
 
     mod f64 {
         impl_float_tests!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+        impl_affine_tests!(f64, DAffine2, DAffine3);
     }
 
     mod i32 {
@@ -0,0 +1,202 @@
+// Vectors implement `Zero`, `One` and `Num` using their component-wise operators, `Num` is not
+// implemented for matrices as they do not support division. `Signed` is not implemented as
+// `is_positive` and `is_negative` have no meaningful definition for vectors.
+//
+// `One` is implemented for matrices, affine transforms and quaternions as their identity since
+// `Mul` for these types is composition rather than a component-wise product.
+
+macro_rules! impl_num_traits_vec {
+    ($t:ty, $type:ident) => {
+        impl Zero for $type {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == Self::ZERO
+            }
+        }
+
+        impl One for $type {
+            #[inline]
+            fn one() -> Self {
+                Self::ONE
+            }
+
+            #[inline]
+            fn is_one(&self) -> bool {
+                *self == Self::ONE
+            }
+        }
+
+        impl Num for $type {
+            type FromStrRadixErr = <$t as Num>::FromStrRadixErr;
+
+            /// Parses a single scalar value which is used for all components.
+            #[inline]
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                <$t>::from_str_radix(str, radix).map(Self::splat)
+            }
+        }
+    };
+}
+
+macro_rules! impl_num_traits_one {
+    ($type:ident) => {
+        impl One for $type {
+            #[inline]
+            fn one() -> Self {
+                Self::IDENTITY
+            }
+
+            #[inline]
+            fn is_one(&self) -> bool {
+                *self == Self::IDENTITY
+            }
+        }
+    };
+}
+
+macro_rules! impl_num_traits_mat {
+    ($type:ident) => {
+        impl Zero for $type {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == Self::ZERO
+            }
+        }
+
+        impl_num_traits_one!($type);
+    };
+}
+
+macro_rules! impl_num_traits_vec_types {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_num_traits_vec!($t, $vec2);
+        impl_num_traits_vec!($t, $vec3);
+        impl_num_traits_vec!($t, $vec4);
+    };
+}
+
+macro_rules! impl_num_traits_float_types {
+    ($t:ty, $affine2:ident, $affine3:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_num_traits_one!($affine2);
+        impl_num_traits_one!($affine3);
+        impl_num_traits_mat!($mat2);
+        impl_num_traits_mat!($mat3);
+        impl_num_traits_mat!($mat4);
+        impl_num_traits_one!($quat);
+        impl_num_traits_vec_types!($t, $vec2, $vec3, $vec4);
+    };
+}
+
+mod f32 {
+    use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    use num_traits::{Num, One, Zero};
+
+    impl_num_traits_float_types!(f32, Affine2, Affine3A, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+    impl_num_traits_mat!(Mat3A);
+    impl_num_traits_vec!(f32, Vec3A);
+}
+
+mod f64 {
+    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+    use num_traits::{Num, One, Zero};
+
+    impl_num_traits_float_types!(
+        f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
+    );
+}
+
+mod i16 {
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use num_traits::{Num, One, Zero};
+
+    impl_num_traits_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+}
+
+mod i32 {
+    use crate::{IVec2, IVec3, IVec4};
+    use num_traits::{Num, One, Zero};
+
+    impl_num_traits_vec_types!(i32, IVec2, IVec3, IVec4);
+}
+
+mod i64 {
+    use crate::{I64Vec2, I64Vec3, I64Vec4};
+    use num_traits::{Num, One, Zero};
+
+    impl_num_traits_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+}
+
+mod u16 {
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use num_traits::{Num, One, Zero};
+
+    impl_num_traits_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+}
+
+mod u32 {
+    use crate::{UVec2, UVec3, UVec4};
+    use num_traits::{Num, One, Zero};
+
+    impl_num_traits_vec_types!(u32, UVec2, UVec3, UVec4);
+}
+
+mod u64 {
+    use crate::{U64Vec2, U64Vec3, U64Vec4};
+    use num_traits::{Num, One, Zero};
+
+    impl_num_traits_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+}
+
+#[cfg(test)]
+mod test {
+    use num_traits::{Num, One, Zero};
+
+    // a generic function which only relies on `num-traits`
+    fn horner<T: Num + Copy>(coefficients: &[T], x: T) -> T {
+        coefficients
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, &c| acc * x + c)
+    }
+
+    #[test]
+    fn test_num_traits_vec() {
+        use crate::{IVec3, UVec2, Vec3A, Vec4};
+        assert_eq!(Vec4::zero(), Vec4::ZERO);
+        assert_eq!(Vec4::one(), Vec4::ONE);
+        assert!(Vec3A::zero().is_zero());
+        assert!(!Vec3A::X.is_zero());
+        assert!(IVec3::one().is_one());
+
+        assert_eq!(
+            horner(&[IVec3::ONE, IVec3::splat(2)], IVec3::new(1, 2, 3)),
+            IVec3::new(3, 5, 7)
+        );
+
+        assert_eq!(Vec4::from_str_radix("1.5", 10).unwrap(), Vec4::splat(1.5));
+        assert_eq!(UVec2::from_str_radix("ff", 16), Ok(UVec2::splat(255)));
+        assert!(UVec2::from_str_radix("-1", 10).is_err());
+    }
+
+    #[test]
+    fn test_num_traits_identity() {
+        use crate::{Affine3A, DMat3, DQuat, Mat4};
+        assert_eq!(Mat4::zero(), Mat4::ZERO);
+        assert_eq!(Mat4::one(), Mat4::IDENTITY);
+        assert!(DMat3::one().is_one());
+        assert!(DMat3::zero().is_zero());
+        assert_eq!(DQuat::one(), DQuat::IDENTITY);
+        assert!(Affine3A::one().is_one());
+        assert!(!Affine3A::ZERO.is_one());
+    }
+}
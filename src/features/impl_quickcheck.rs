@@ -0,0 +1,202 @@
+// Vectors, matrices and affine transforms are generated from arbitrary arrays of their
+// components and shrink one component at a time towards zero.
+//
+// Quaternions are always generated normalized so that they represent a valid rotation and shrink
+// to the identity quaternion.
+
+macro_rules! impl_quickcheck {
+    ($t:ty, $n:literal, $type:ident) => {
+        impl Arbitrary for $type {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Self::from_array(<[$t; $n]>::arbitrary(g))
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(<[$t; $n]>::from(*self).shrink().map(Self::from_array))
+            }
+        }
+    };
+
+    (@cols $t:ty, $n:literal, $type:ident) => {
+        impl Arbitrary for $type {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Self::from_cols_array(&<[$t; $n]>::arbitrary(g))
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(
+                    self.to_cols_array()
+                        .shrink()
+                        .map(|a| Self::from_cols_array(&a)),
+                )
+            }
+        }
+    };
+
+    (@quat $t:ident, $quat:ident, $vec4:ident) => {
+        impl Arbitrary for $quat {
+            fn arbitrary(g: &mut Gen) -> Self {
+                // map each component into [-1, 1] before normalizing
+                let v =
+                    $vec4::from_array(<[i32; 4]>::arbitrary(g).map(|i| i as $t)) / i32::MAX as $t;
+                Self::from_vec4(v.normalize_or($vec4::W))
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                if *self == Self::IDENTITY {
+                    empty_shrinker()
+                } else {
+                    single_shrinker(Self::IDENTITY)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_quickcheck_vec_types {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_quickcheck!($t, 2, $vec2);
+        impl_quickcheck!($t, 3, $vec3);
+        impl_quickcheck!($t, 4, $vec4);
+    };
+}
+
+macro_rules! impl_quickcheck_float_types {
+    ($t:ident, $affine2:ident, $affine3:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_quickcheck!(@cols $t, 6, $affine2);
+        impl_quickcheck!(@cols $t, 12, $affine3);
+        impl_quickcheck!(@cols $t, 4, $mat2);
+        impl_quickcheck!(@cols $t, 9, $mat3);
+        impl_quickcheck!(@cols $t, 16, $mat4);
+        impl_quickcheck!(@quat $t, $quat, $vec4);
+        impl_quickcheck_vec_types!($t, $vec2, $vec3, $vec4);
+    };
+}
+
+mod bool {
+    #[cfg(not(feature = "scalar-math"))]
+    use crate::BVec4A;
+    use crate::{BVec2, BVec3, BVec3A, BVec4};
+    use quickcheck::{Arbitrary, Gen};
+
+    impl_quickcheck_vec_types!(bool, BVec2, BVec3, BVec4);
+    impl_quickcheck!(bool, 3, BVec3A);
+    #[cfg(not(feature = "scalar-math"))]
+    impl_quickcheck!(bool, 4, BVec4A);
+}
+
+mod f32 {
+    use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    use quickcheck::{empty_shrinker, single_shrinker, Arbitrary, Gen};
+
+    impl_quickcheck_float_types!(f32, Affine2, Affine3A, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+    impl_quickcheck!(@cols f32, 9, Mat3A);
+    impl_quickcheck!(f32, 3, Vec3A);
+}
+
+mod f64 {
+    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+    use quickcheck::{empty_shrinker, single_shrinker, Arbitrary, Gen};
+
+    impl_quickcheck_float_types!(
+        f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
+    );
+}
+
+mod i16 {
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use quickcheck::{Arbitrary, Gen};
+
+    impl_quickcheck_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+}
+
+mod i32 {
+    use crate::{IVec2, IVec3, IVec4};
+    use quickcheck::{Arbitrary, Gen};
+
+    impl_quickcheck_vec_types!(i32, IVec2, IVec3, IVec4);
+}
+
+mod i64 {
+    use crate::{I64Vec2, I64Vec3, I64Vec4};
+    use quickcheck::{Arbitrary, Gen};
+
+    impl_quickcheck_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+}
+
+mod u16 {
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use quickcheck::{Arbitrary, Gen};
+
+    impl_quickcheck_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+}
+
+mod u32 {
+    use crate::{UVec2, UVec3, UVec4};
+    use quickcheck::{Arbitrary, Gen};
+
+    impl_quickcheck_vec_types!(u32, UVec2, UVec3, UVec4);
+}
+
+mod u64 {
+    use crate::{U64Vec2, U64Vec3, U64Vec4};
+    use quickcheck::{Arbitrary, Gen};
+
+    impl_quickcheck_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+}
+
+#[cfg(test)]
+mod test {
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+
+    #[test]
+    fn test_quickcheck_quat() {
+        use crate::{DQuat, Quat};
+        fn is_normalized(q: Quat, d: DQuat) -> bool {
+            q.is_normalized() && d.is_normalized()
+        }
+        quickcheck(is_normalized as fn(Quat, DQuat) -> bool);
+
+        let q = Quat::from_rotation_x(1.0);
+        assert_eq!(q.shrink().collect::<Vec<_>>(), [Quat::IDENTITY]);
+        assert_eq!(Quat::IDENTITY.shrink().count(), 0);
+    }
+
+    #[test]
+    fn test_quickcheck_shrink() {
+        use crate::{IVec3, Mat2, Vec2};
+        let mut g = Gen::new(10);
+        let v = Vec2::arbitrary(&mut g);
+        assert!(v.shrink().all(|s| s != v));
+
+        // every shrunk value changes a single component towards zero
+        let v = IVec3::new(0, 4, 0);
+        assert!(v.shrink().all(|s| s.x == 0 && s.z == 0 && s.y.abs() < 4));
+        assert!(v.shrink().any(|s| s == IVec3::ZERO));
+        assert_eq!(IVec3::ZERO.shrink().count(), 0);
+
+        let m = Mat2::from_cols_array(&[0.0, 2.0, 0.0, 0.0]);
+        assert!(m.shrink().any(|s| s == Mat2::ZERO));
+        assert!(m
+            .shrink()
+            .all(|s| s.x_axis.x == 0.0 && s.y_axis == Vec2::ZERO));
+    }
+
+    #[test]
+    fn test_quickcheck_property() {
+        use crate::{Mat4, Vec3};
+        fn dot_commutes(a: Vec3, b: Vec3) -> bool {
+            let (ab, ba) = (a.dot(b), b.dot(a));
+            ab == ba || (ab.is_nan() && ba.is_nan())
+        }
+        quickcheck(dot_commutes as fn(Vec3, Vec3) -> bool);
+
+        fn transpose_involution(m: Mat4) -> bool {
+            let t = m.transpose().transpose().to_cols_array();
+            t.iter()
+                .zip(m.to_cols_array())
+                .all(|(a, b)| a.to_bits() == b.to_bits())
+        }
+        quickcheck(transpose_involution as fn(Mat4) -> bool);
+    }
+}
@@ -138,8 +138,301 @@ macro_rules! impl_float_types {
     };
 }
 
-mod f32 {
-    use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+macro_rules! impl_affine_types {
+    ($t:ty, $affine2:ident, $affine3:ident) => {
+        impl Distribution<$affine2> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $affine2 {
+                $affine2::from_cols_array(&rng.gen())
+            }
+        }
+
+        impl Distribution<$affine3> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $affine3 {
+                $affine3::from_cols_array(&rng.gen())
+            }
+        }
+
+        #[test]
+        fn test_affine2_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let a = $affine2::from_cols_array(&rng1.gen::<[$t; 6]>());
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let b = rng2.gen::<$affine2>();
+            assert_eq!(a, b);
+        }
+
+        #[test]
+        fn test_affine3_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let a = $affine3::from_cols_array(&rng1.gen::<[$t; 12]>());
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let b = rng2.gen::<$affine3>();
+            assert_eq!(a, b);
+        }
+    };
+}
+
+macro_rules! impl_transform_types {
+    ($t:ident, $transform2:ident, $transform3:ident, $affine2:ident, $affine3:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident) => {
+        /// A distribution of 2D transforms composed of a scale, a uniformly distributed rotation
+        /// and a translation.
+        ///
+        /// Each component of the scale and translation is sampled uniformly from the half-open
+        /// range `[min, max)`. The default distribution has a scale of one and no translation so
+        /// that only the rotation is random.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $transform2 {
+            /// The minimum scale of sampled transforms.
+            pub min_scale: $vec2,
+            /// The maximum scale of sampled transforms.
+            pub max_scale: $vec2,
+            /// The minimum translation of sampled transforms.
+            pub min_translation: $vec2,
+            /// The maximum translation of sampled transforms.
+            pub max_translation: $vec2,
+        }
+
+        impl $transform2 {
+            /// A distribution of rotations with a scale of one and no translation.
+            pub const ROTATION: Self = Self::new($vec2::ONE, $vec2::ONE, $vec2::ZERO, $vec2::ZERO);
+
+            /// Creates a distribution of transforms with scale and translation sampled from the
+            /// given ranges.
+            #[inline]
+            #[must_use]
+            pub const fn new(
+                min_scale: $vec2,
+                max_scale: $vec2,
+                min_translation: $vec2,
+                max_translation: $vec2,
+            ) -> Self {
+                Self {
+                    min_scale,
+                    max_scale,
+                    min_translation,
+                    max_translation,
+                }
+            }
+
+            #[inline]
+            fn sample_parts<R: Rng + ?Sized>(&self, rng: &mut R) -> ($vec2, $t, $vec2) {
+                let scale = self.min_scale + (self.max_scale - self.min_scale) * rng.gen::<$vec2>();
+                let angle = -PI + rng.gen::<$t>() * 2.0 * PI;
+                let translation = self.min_translation
+                    + (self.max_translation - self.min_translation) * rng.gen::<$vec2>();
+                (scale, angle, translation)
+            }
+        }
+
+        impl Default for $transform2 {
+            #[inline]
+            fn default() -> Self {
+                Self::ROTATION
+            }
+        }
+
+        /// Samples the scale and rotation, the translation is ignored.
+        impl Distribution<$mat2> for $transform2 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $mat2 {
+                let (scale, angle, _) = self.sample_parts(rng);
+                $mat2::from_scale_angle(scale, angle)
+            }
+        }
+
+        impl Distribution<$mat3> for $transform2 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $mat3 {
+                let (scale, angle, translation) = self.sample_parts(rng);
+                $mat3::from_scale_angle_translation(scale, angle, translation)
+            }
+        }
+
+        impl Distribution<$affine2> for $transform2 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $affine2 {
+                let (scale, angle, translation) = self.sample_parts(rng);
+                $affine2::from_scale_angle_translation(scale, angle, translation)
+            }
+        }
+
+        /// A distribution of 3D transforms composed of a scale, a uniformly distributed rotation
+        /// and a translation.
+        ///
+        /// Rotations are distributed uniformly over all orientations. Each component of the
+        /// scale and translation is sampled uniformly from the half-open range `[min, max)`. The
+        /// default distribution has a scale of one and no translation so that only the rotation
+        /// is random.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $transform3 {
+            /// The minimum scale of sampled transforms.
+            pub min_scale: $vec3,
+            /// The maximum scale of sampled transforms.
+            pub max_scale: $vec3,
+            /// The minimum translation of sampled transforms.
+            pub min_translation: $vec3,
+            /// The maximum translation of sampled transforms.
+            pub max_translation: $vec3,
+        }
+
+        impl $transform3 {
+            /// A distribution of rotations with a scale of one and no translation.
+            pub const ROTATION: Self = Self::new($vec3::ONE, $vec3::ONE, $vec3::ZERO, $vec3::ZERO);
+
+            /// Creates a distribution of transforms with scale and translation sampled from the
+            /// given ranges.
+            #[inline]
+            #[must_use]
+            pub const fn new(
+                min_scale: $vec3,
+                max_scale: $vec3,
+                min_translation: $vec3,
+                max_translation: $vec3,
+            ) -> Self {
+                Self {
+                    min_scale,
+                    max_scale,
+                    min_translation,
+                    max_translation,
+                }
+            }
+
+            #[inline]
+            fn sample_parts<R: Rng + ?Sized>(&self, rng: &mut R) -> ($vec3, $quat, $vec3) {
+                let scale = self.min_scale + (self.max_scale - self.min_scale) * rng.gen::<$vec3>();
+                let rotation = uniform_rotation(rng);
+                let translation = self.min_translation
+                    + (self.max_translation - self.min_translation) * rng.gen::<$vec3>();
+                (scale, rotation, translation)
+            }
+        }
+
+        impl Default for $transform3 {
+            #[inline]
+            fn default() -> Self {
+                Self::ROTATION
+            }
+        }
+
+        /// Samples the rotation, the scale and translation are ignored.
+        impl Distribution<$quat> for $transform3 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $quat {
+                uniform_rotation(rng)
+            }
+        }
+
+        /// Samples the scale and rotation, the translation is ignored.
+        impl Distribution<$mat3> for $transform3 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $mat3 {
+                let (scale, rotation, _) = self.sample_parts(rng);
+                $mat3::from_quat(rotation) * $mat3::from_diagonal(scale)
+            }
+        }
+
+        impl Distribution<$mat4> for $transform3 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $mat4 {
+                let (scale, rotation, translation) = self.sample_parts(rng);
+                $mat4::from_scale_rotation_translation(scale, rotation, translation)
+            }
+        }
+
+        impl Distribution<$affine3> for $transform3 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $affine3 {
+                let (scale, rotation, translation) = self.sample_parts(rng);
+                $affine3::from_scale_rotation_translation(scale, rotation, translation)
+            }
+        }
+
+        // Uniformly distributed rotations using the method from Ken Shoemake's "Uniform Random
+        // Rotations" in Graphics Gems III.
+        #[inline]
+        fn uniform_rotation<R: Rng + ?Sized>(rng: &mut R) -> $quat {
+            let u1 = rng.gen::<$t>();
+            let (s2, c2) = math::sin_cos(rng.gen::<$t>() * 2.0 * PI);
+            let (s3, c3) = math::sin_cos(rng.gen::<$t>() * 2.0 * PI);
+            let r1 = math::sqrt(1.0 - u1);
+            let r2 = math::sqrt(u1);
+            $quat::from_xyzw(r1 * s2, r1 * c2, r2 * s3, r2 * c3)
+        }
+
+        #[test]
+        fn test_transform2_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let dist = $transform2::new(
+                $vec2::new(1.0, 2.0),
+                $vec2::new(2.0, 3.0),
+                $vec2::splat(-10.0),
+                $vec2::splat(10.0),
+            );
+            for _ in 0..100 {
+                let m: $affine2 = rng.sample(dist);
+                let (scale, _, translation) = m.to_scale_angle_translation();
+                assert!(scale.cmpge(dist.min_scale - 1e-4).all());
+                assert!(scale.cmple(dist.max_scale + 1e-4).all());
+                assert!(translation.cmpge(dist.min_translation).all());
+                assert!(translation.cmplt(dist.max_translation).all());
+            }
+
+            let m: $mat2 = rng.sample($transform2::ROTATION);
+            assert!((m.determinant() - 1.0).abs() < 1e-5);
+            assert_eq!(
+                rng.sample::<$mat3, _>($transform2::default()).z_axis,
+                $mat3::IDENTITY.z_axis
+            );
+        }
+
+        #[test]
+        fn test_transform3_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let dist = $transform3::new(
+                $vec3::splat(0.5),
+                $vec3::splat(2.0),
+                $vec3::splat(-10.0),
+                $vec3::splat(10.0),
+            );
+            let mut sum = $vec3::ZERO;
+            for _ in 0..1000 {
+                let q: $quat = rng.sample(dist);
+                assert!(q.is_normalized());
+                // the rotated x axis should be evenly distributed over the sphere
+                sum += q * $vec3::X;
+
+                let m: $affine3 = rng.sample(dist);
+                let (scale, rotation, translation) = m.to_scale_rotation_translation();
+                assert!(rotation.is_normalized());
+                assert!(scale.cmpge(dist.min_scale - 1e-4).all());
+                assert!(scale.cmple(dist.max_scale + 1e-4).all());
+                assert!(translation.cmpge(dist.min_translation).all());
+                assert!(translation.cmplt(dist.max_translation).all());
+
+                let m: $mat4 = rng.sample(dist);
+                assert_eq!(m.row(3), $mat4::IDENTITY.row(3));
+            }
+            assert!(sum.length() / 1000.0 < 0.1);
+
+            let m: $mat3 = rng.sample($transform3::default());
+            assert!((m.determinant() - 1.0).abs() < 1e-5);
+        }
+    };
+}
+
+pub(crate) mod f32 {
+    use crate::f32::math;
+    use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
     use core::f32::consts::PI;
     use rand::{
         distributions::{Distribution, Standard},
@@ -147,6 +440,36 @@ mod f32 {
     };
 
     impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+    impl_affine_types!(f32, Affine2, Affine3A);
+    impl_transform_types!(
+        f32,
+        UniformTransform2,
+        UniformTransform3,
+        Affine2,
+        Affine3A,
+        Mat2,
+        Mat3,
+        Mat4,
+        Quat,
+        Vec2,
+        Vec3
+    );
+
+    impl Distribution<Mat3A> for Standard {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat3A {
+            Mat3A::from_cols_array(&rng.gen())
+        }
+    }
+
+    /// Samples the scale and rotation, the translation is ignored.
+    impl Distribution<Mat3A> for UniformTransform3 {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat3A {
+            let (scale, rotation, _) = self.sample_parts(rng);
+            Mat3A::from_quat(rotation) * Mat3A::from_diagonal(scale)
+        }
+    }
 
     impl Distribution<Vec3A> for Standard {
         #[inline]
@@ -167,8 +490,9 @@ mod f32 {
     }
 }
 
-mod f64 {
-    use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+pub(crate) mod f64 {
+    use crate::f64::math;
+    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
     use core::f64::consts::PI;
     use rand::{
         distributions::{Distribution, Standard},
@@ -176,6 +500,20 @@ mod f64 {
     };
 
     impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+    impl_affine_types!(f64, DAffine2, DAffine3);
+    impl_transform_types!(
+        f64,
+        DUniformTransform2,
+        DUniformTransform3,
+        DAffine2,
+        DAffine3,
+        DMat2,
+        DMat3,
+        DMat4,
+        DQuat,
+        DVec2,
+        DVec3
+    );
 }
 
 mod i16 {
@@ -1270,3 +1270,501 @@ mod euler {
         assert_eq!(a, deserialized);
     }
 }
+
+/// Conversion to and from a flat array of scalar components.
+///
+/// This is implemented for all `glam` vector, quaternion, matrix and affine types and is used
+/// by the alternative serde representations in [`crate::serde`]. Matrices and affine transforms
+/// use column-major order.
+pub trait SerdeArray: Sized + serde::Serialize + for<'de> serde::Deserialize<'de> {
+    /// The fixed-size array type holding the scalar components of `Self`.
+    type Array: serde::Serialize + for<'de> serde::Deserialize<'de>;
+
+    /// Returns the scalar components of `self` as a flat array.
+    fn to_serde_array(&self) -> Self::Array;
+
+    /// Creates a value from a flat array of scalar components.
+    fn from_serde_array(a: Self::Array) -> Self;
+}
+
+macro_rules! impl_serde_array {
+    ($t:ty, $n:literal, $type:ident) => {
+        impl SerdeArray for $type {
+            type Array = [$t; $n];
+
+            #[inline]
+            fn to_serde_array(&self) -> Self::Array {
+                (*self).into()
+            }
+
+            #[inline]
+            fn from_serde_array(a: Self::Array) -> Self {
+                Self::from_array(a)
+            }
+        }
+    };
+
+    (@cols $t:ty, $n:literal, $type:ident) => {
+        impl SerdeArray for $type {
+            type Array = [$t; $n];
+
+            #[inline]
+            fn to_serde_array(&self) -> Self::Array {
+                self.to_cols_array()
+            }
+
+            #[inline]
+            fn from_serde_array(a: Self::Array) -> Self {
+                Self::from_cols_array(&a)
+            }
+        }
+    };
+}
+
+macro_rules! impl_serde_array_vec_types {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_serde_array!($t, 2, $vec2);
+        impl_serde_array!($t, 3, $vec3);
+        impl_serde_array!($t, 4, $vec4);
+    };
+}
+
+macro_rules! impl_serde_array_float_types {
+    ($t:ty, $affine2:ident, $affine3:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_serde_array!(@cols $t, 6, $affine2);
+        impl_serde_array!(@cols $t, 12, $affine3);
+        impl_serde_array!(@cols $t, 4, $mat2);
+        impl_serde_array!(@cols $t, 9, $mat3);
+        impl_serde_array!(@cols $t, 16, $mat4);
+        impl_serde_array!($t, 4, $quat);
+        impl_serde_array_vec_types!($t, $vec2, $vec3, $vec4);
+    };
+}
+
+mod serde_array {
+    use super::SerdeArray;
+    use crate::{
+        Affine2, Affine3A, BVec2, BVec3, BVec4, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat,
+        DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, IVec2, IVec3,
+        IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4,
+        UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    };
+    #[cfg(not(feature = "scalar-math"))]
+    use crate::{BVec3A, BVec4A};
+
+    impl_serde_array_vec_types!(bool, BVec2, BVec3, BVec4);
+    #[cfg(not(feature = "scalar-math"))]
+    impl_serde_array!(bool, 3, BVec3A);
+    #[cfg(not(feature = "scalar-math"))]
+    impl_serde_array!(bool, 4, BVec4A);
+
+    impl_serde_array_float_types!(f32, Affine2, Affine3A, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+    impl_serde_array!(@cols f32, 9, Mat3A);
+    impl_serde_array!(f32, 3, Vec3A);
+
+    impl_serde_array_float_types!(
+        f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
+    );
+
+    impl_serde_array_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+    impl_serde_array_vec_types!(i32, IVec2, IVec3, IVec4);
+    impl_serde_array_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+    impl_serde_array_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+    impl_serde_array_vec_types!(u32, UVec2, UVec3, UVec4);
+    impl_serde_array_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+}
+
+/// Serializes `glam` types as a flat tuple of scalar components when the format is not human
+/// readable, and using the default representation otherwise.
+///
+/// For use with `#[serde(with = "glam::serde::compact")]`. Binary formats can use this to avoid
+/// emitting type names, human readable formats such as JSON are unaffected.
+pub mod compact {
+    use super::SerdeArray;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `value` as a flat tuple if the serializer is not human readable.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: SerdeArray,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            value.serialize(serializer)
+        } else {
+            value.to_serde_array().serialize(serializer)
+        }
+    }
+
+    /// Deserializes a value written by [`serialize`].
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: SerdeArray,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            T::deserialize(deserializer)
+        } else {
+            T::Array::deserialize(deserializer).map(T::from_serde_array)
+        }
+    }
+}
+
+/// Serialization of vector and quaternion types as structs with named fields.
+///
+/// This is implemented for all `glam` float and integer vector types and quaternions and is
+/// used by [`crate::serde::named`].
+pub trait SerdeNamed: Sized {
+    /// Serializes `self` as a struct with the fields `x`, `y`, `z` and `w`.
+    fn serialize_named<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    /// Deserializes a struct with the fields `x`, `y`, `z` and `w`.
+    fn deserialize_named<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+}
+
+macro_rules! impl_serde_named {
+    ($t:ty, $type:ident, $($field:ident),+) => {
+        impl SerdeNamed for $type {
+            fn serialize_named<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),+];
+                let mut state = serializer.serialize_struct(stringify!($type), FIELDS.len())?;
+                $(state.serialize_field(stringify!($field), &self.$field)?;)+
+                state.end()
+            }
+
+            fn deserialize_named<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),+];
+
+                #[allow(non_camel_case_types)]
+                enum Field {
+                    $($field),+
+                }
+
+                impl<'de> Deserialize<'de> for Field {
+                    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        struct FieldVisitor;
+
+                        impl<'de> Visitor<'de> for FieldVisitor {
+                            type Value = Field;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                                formatter.write_str("a field name")
+                            }
+
+                            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                                match value {
+                                    $(stringify!($field) => Ok(Field::$field),)+
+                                    _ => Err(de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+
+                        deserializer.deserialize_identifier(FieldVisitor)
+                    }
+                }
+
+                struct NamedVisitor;
+
+                impl<'de> Visitor<'de> for NamedVisitor {
+                    type Value = $type;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(concat!("struct ", stringify!($type)))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$type, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let mut a = [<$t>::default(); FIELDS.len()];
+                        for (i, v) in a.iter_mut().enumerate() {
+                            *v = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                        }
+                        Ok($type::from_array(a))
+                    }
+
+                    fn visit_map<V>(self, mut map: V) -> Result<$type, V::Error>
+                    where
+                        V: MapAccess<'de>,
+                    {
+                        $(let mut $field: Option<$t> = None;)+
+                        while let Some(key) = map.next_key()? {
+                            match key {
+                                $(Field::$field => {
+                                    if $field.is_some() {
+                                        return Err(de::Error::duplicate_field(stringify!($field)));
+                                    }
+                                    $field = Some(map.next_value()?);
+                                })+
+                            }
+                        }
+                        $(let $field = $field.ok_or_else(|| de::Error::missing_field(stringify!($field)))?;)+
+                        Ok($type::from_array([$($field),+]))
+                    }
+                }
+
+                deserializer.deserialize_struct(stringify!($type), FIELDS, NamedVisitor)
+            }
+        }
+    };
+}
+
+macro_rules! impl_serde_named_vec_types {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_serde_named!($t, $vec2, x, y);
+        impl_serde_named!($t, $vec3, x, y, z);
+        impl_serde_named!($t, $vec4, x, y, z, w);
+    };
+}
+
+mod serde_named {
+    use super::SerdeNamed;
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
+        ser::{SerializeStruct, Serializer},
+    };
+
+    mod f32 {
+        use super::*;
+        use crate::{Quat, Vec2, Vec3, Vec3A, Vec4};
+
+        impl_serde_named_vec_types!(f32, Vec2, Vec3, Vec4);
+        impl_serde_named!(f32, Vec3A, x, y, z);
+        impl_serde_named!(f32, Quat, x, y, z, w);
+    }
+
+    mod f64 {
+        use super::*;
+        use crate::{DQuat, DVec2, DVec3, DVec4};
+
+        impl_serde_named_vec_types!(f64, DVec2, DVec3, DVec4);
+        impl_serde_named!(f64, DQuat, x, y, z, w);
+    }
+
+    mod i16 {
+        use super::*;
+        use crate::{I16Vec2, I16Vec3, I16Vec4};
+
+        impl_serde_named_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+    }
+
+    mod i32 {
+        use super::*;
+        use crate::{IVec2, IVec3, IVec4};
+
+        impl_serde_named_vec_types!(i32, IVec2, IVec3, IVec4);
+    }
+
+    mod i64 {
+        use super::*;
+        use crate::{I64Vec2, I64Vec3, I64Vec4};
+
+        impl_serde_named_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+    }
+
+    mod u16 {
+        use super::*;
+        use crate::{U16Vec2, U16Vec3, U16Vec4};
+
+        impl_serde_named_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+    }
+
+    mod u32 {
+        use super::*;
+        use crate::{UVec2, UVec3, UVec4};
+
+        impl_serde_named_vec_types!(u32, UVec2, UVec3, UVec4);
+    }
+
+    mod u64 {
+        use super::*;
+        use crate::{U64Vec2, U64Vec3, U64Vec4};
+
+        impl_serde_named_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+    }
+}
+
+/// Serializes vector and quaternion types as structs with named `x`, `y`, `z` and `w` fields.
+///
+/// For use with `#[serde(with = "glam::serde::named")]`, e.g. when a JSON schema requires
+/// `{"x":1.0,"y":2.0,"z":3.0}` rather than the default `[1.0,2.0,3.0]`. Deserialization also
+/// accepts a sequence of components.
+pub mod named {
+    use super::SerdeNamed;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes `value` as a struct with named fields.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: SerdeNamed,
+        S: Serializer,
+    {
+        value.serialize_named(serializer)
+    }
+
+    /// Deserializes a value written by [`serialize`].
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: SerdeNamed,
+        D: Deserializer<'de>,
+    {
+        T::deserialize_named(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod test_compact {
+    use super::SerdeArray;
+    use crate::{Mat2, Quat, UVec3, Vec3};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_test::{assert_tokens, Configure, Token};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Compact<T>(T);
+
+    impl<T: SerdeArray> Serialize for Compact<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::compact::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de, T: SerdeArray> Deserialize<'de> for Compact<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::compact::deserialize(deserializer).map(Compact)
+        }
+    }
+
+    #[test]
+    fn test_compact_vec3() {
+        let v = Compact(Vec3::new(1.0, 2.0, 3.0));
+        assert_tokens(
+            &v.compact(),
+            &[
+                Token::Tuple { len: 3 },
+                Token::F32(1.0),
+                Token::F32(2.0),
+                Token::F32(3.0),
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(
+            &v.readable(),
+            &[
+                Token::TupleStruct {
+                    name: "Vec3",
+                    len: 3,
+                },
+                Token::F32(1.0),
+                Token::F32(2.0),
+                Token::F32(3.0),
+                Token::TupleStructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_compact_other() {
+        assert_tokens(
+            &Compact(UVec3::new(1, 2, 3)).compact(),
+            &[
+                Token::Tuple { len: 3 },
+                Token::U32(1),
+                Token::U32(2),
+                Token::U32(3),
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(
+            &Compact(Quat::from_xyzw(1.0, 2.0, 3.0, 4.0)).compact(),
+            &[
+                Token::Tuple { len: 4 },
+                Token::F32(1.0),
+                Token::F32(2.0),
+                Token::F32(3.0),
+                Token::F32(4.0),
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(
+            &Compact(Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0])).compact(),
+            &[
+                Token::Tuple { len: 4 },
+                Token::F32(1.0),
+                Token::F32(2.0),
+                Token::F32(3.0),
+                Token::F32(4.0),
+                Token::TupleEnd,
+            ],
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_named {
+    use super::SerdeNamed;
+    use crate::{DQuat, I16Vec2, Quat, UVec4, Vec3, Vec3A};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Debug, PartialEq)]
+    struct Named<T>(T);
+
+    impl<T: SerdeNamed> Serialize for Named<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::named::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de, T: SerdeNamed> Deserialize<'de> for Named<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::named::deserialize(deserializer).map(Named)
+        }
+    }
+
+    fn test_named<T: SerdeNamed + core::fmt::Debug + PartialEq>(value: T, expected: &str) {
+        let a = Named(value);
+        let serialized = serde_json::to_string(&a).unwrap();
+        assert_eq!(expected, serialized);
+        let deserialized: Named<T> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(a, deserialized);
+    }
+
+    #[test]
+    fn test_named_serde() {
+        test_named(Vec3::new(1.0, 2.0, 3.0), r#"{"x":1.0,"y":2.0,"z":3.0}"#);
+        test_named(Vec3A::new(1.0, 2.0, 3.0), r#"{"x":1.0,"y":2.0,"z":3.0}"#);
+        test_named(
+            Quat::from_xyzw(1.0, 2.0, 3.0, 4.0),
+            r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#,
+        );
+        test_named(
+            DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0),
+            r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#,
+        );
+        test_named(I16Vec2::new(-1, 2), r#"{"x":-1,"y":2}"#);
+        test_named(UVec4::new(1, 2, 3, 4), r#"{"x":1,"y":2,"z":3,"w":4}"#);
+    }
+
+    #[test]
+    fn test_named_deserialize() {
+        // fields may be in any order
+        let v: Named<Vec3> = serde_json::from_str(r#"{"z":3.0,"x":1.0,"y":2.0}"#).unwrap();
+        assert_eq!(v.0, Vec3::new(1.0, 2.0, 3.0));
+        // sequences are also accepted
+        let v: Named<Vec3> = serde_json::from_str("[1.0,2.0,3.0]").unwrap();
+        assert_eq!(v.0, Vec3::new(1.0, 2.0, 3.0));
+
+        assert!(serde_json::from_str::<Named<Vec3>>(r#"{"x":1.0,"y":2.0}"#).is_err());
+        assert!(
+            serde_json::from_str::<Named<Vec3>>(r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Named<Vec3>>(r#"{"x":1.0,"x":2.0,"z":3.0}"#).is_err());
+        assert!(serde_json::from_str::<Named<Vec3>>("[1.0,2.0]").is_err());
+    }
+}
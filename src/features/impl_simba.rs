@@ -0,0 +1,531 @@
+// `Vec3A` and `Vec4` implement `SimdRealField` with 3 and 4 `f32` lanes respectively, using their
+// boolean mask types as `SimdBool`. Operations that `glam` provides natively map directly to the
+// vector methods, transcendental functions are evaluated lane by lane using `simba`'s `f32`
+// implementations.
+
+#[cfg(feature = "scalar-math")]
+use crate::BVec4 as BVec4A;
+#[cfg(not(feature = "scalar-math"))]
+use crate::BVec4A;
+use crate::{BVec3A, Vec3A, Vec4};
+use simba::scalar::{Field, SubsetOf};
+use simba::simd::{
+    SimdBool, SimdComplexField, SimdPartialOrd, SimdRealField, SimdSigned, SimdValue,
+};
+
+macro_rules! impl_simba_bool {
+    ($bvec:ident) => {
+        impl SimdBool for $bvec {
+            #[inline]
+            fn bitmask(self) -> u64 {
+                self.bitmask() as u64
+            }
+
+            #[inline]
+            fn and(self) -> bool {
+                self.all()
+            }
+
+            #[inline]
+            fn or(self) -> bool {
+                self.any()
+            }
+
+            #[inline]
+            fn xor(self) -> bool {
+                self.bitmask().count_ones() % 2 == 1
+            }
+
+            #[inline]
+            fn all(self) -> bool {
+                self.all()
+            }
+
+            #[inline]
+            fn any(self) -> bool {
+                self.any()
+            }
+
+            #[inline]
+            fn none(self) -> bool {
+                !self.any()
+            }
+
+            #[inline]
+            fn if_else<Res: SimdValue<SimdBool = Self>>(
+                self,
+                if_value: impl FnOnce() -> Res,
+                else_value: impl FnOnce() -> Res,
+            ) -> Res {
+                if_value().select(self, else_value())
+            }
+
+            #[inline]
+            fn if_else2<Res: SimdValue<SimdBool = Self>>(
+                self,
+                if_value: impl FnOnce() -> Res,
+                else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res),
+                else_value: impl FnOnce() -> Res,
+            ) -> Res {
+                let else_value = else_if.1().select(else_if.0(), else_value());
+                if_value().select(self, else_value)
+            }
+
+            #[inline]
+            fn if_else3<Res: SimdValue<SimdBool = Self>>(
+                self,
+                if_value: impl FnOnce() -> Res,
+                else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res),
+                else_else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res),
+                else_value: impl FnOnce() -> Res,
+            ) -> Res {
+                let else_value = else_else_if.1().select(else_else_if.0(), else_value());
+                let else_value = else_if.1().select(else_if.0(), else_value);
+                if_value().select(self, else_value)
+            }
+        }
+    };
+}
+
+macro_rules! impl_simba_lanes {
+    ($($name:ident),*) => {
+        $(
+            #[inline]
+            fn $name(self) -> Self {
+                Self::from_array(self.to_array().map(<f32 as SimdComplexField>::$name))
+            }
+        )*
+    };
+    (@binary $($name:ident),*) => {
+        $(
+            #[inline]
+            fn $name(self, rhs: Self) -> Self {
+                let rhs = rhs.to_array();
+                let mut out = self.to_array();
+                for (a, b) in out.iter_mut().zip(rhs) {
+                    *a = <f32 as SimdComplexField>::$name(*a, b);
+                }
+                Self::from_array(out)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_simba_consts {
+    ($($name:ident = $value:ident),*) => {
+        $(
+            #[inline]
+            fn $name() -> Self {
+                Self::splat(core::f32::consts::$value)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_simba_vec {
+    ($n:literal, $vec:ident, $bvec:ident) => {
+        impl SimdValue for $vec {
+            const LANES: usize = $n;
+            type Element = f32;
+            type SimdBool = $bvec;
+
+            #[inline]
+            fn splat(val: f32) -> Self {
+                Self::splat(val)
+            }
+
+            #[inline]
+            fn extract(&self, i: usize) -> f32 {
+                self[i]
+            }
+
+            #[inline]
+            unsafe fn extract_unchecked(&self, i: usize) -> f32 {
+                self[i]
+            }
+
+            #[inline]
+            fn replace(&mut self, i: usize, val: f32) {
+                self[i] = val;
+            }
+
+            #[inline]
+            unsafe fn replace_unchecked(&mut self, i: usize, val: f32) {
+                self[i] = val;
+            }
+
+            #[inline]
+            fn select(self, cond: $bvec, other: Self) -> Self {
+                Self::select(cond, self, other)
+            }
+        }
+
+        impl SubsetOf<$vec> for $vec {
+            #[inline]
+            fn to_superset(&self) -> $vec {
+                *self
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$vec) -> Self {
+                *element
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$vec) -> bool {
+                true
+            }
+        }
+
+        impl SubsetOf<$vec> for f32 {
+            #[inline]
+            fn to_superset(&self) -> $vec {
+                $vec::splat(*self)
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$vec) -> Self {
+                element.x
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$vec) -> bool {
+                element.cmpeq($vec::splat(element.x)).all()
+            }
+        }
+
+        impl SubsetOf<$vec> for f64 {
+            #[inline]
+            fn to_superset(&self) -> $vec {
+                $vec::splat(*self as f32)
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$vec) -> Self {
+                element.x as f64
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$vec) -> bool {
+                element.cmpeq($vec::splat(element.x)).all()
+            }
+        }
+
+        impl Field for $vec {}
+
+        impl SimdPartialOrd for $vec {
+            #[inline]
+            fn simd_gt(self, other: Self) -> $bvec {
+                self.cmpgt(other)
+            }
+
+            #[inline]
+            fn simd_lt(self, other: Self) -> $bvec {
+                self.cmplt(other)
+            }
+
+            #[inline]
+            fn simd_ge(self, other: Self) -> $bvec {
+                self.cmpge(other)
+            }
+
+            #[inline]
+            fn simd_le(self, other: Self) -> $bvec {
+                self.cmple(other)
+            }
+
+            #[inline]
+            fn simd_eq(self, other: Self) -> $bvec {
+                self.cmpeq(other)
+            }
+
+            #[inline]
+            fn simd_ne(self, other: Self) -> $bvec {
+                self.cmpne(other)
+            }
+
+            #[inline]
+            fn simd_max(self, other: Self) -> Self {
+                self.max(other)
+            }
+
+            #[inline]
+            fn simd_min(self, other: Self) -> Self {
+                self.min(other)
+            }
+
+            #[inline]
+            fn simd_clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+
+            #[inline]
+            fn simd_horizontal_min(self) -> f32 {
+                self.min_element()
+            }
+
+            #[inline]
+            fn simd_horizontal_max(self) -> f32 {
+                self.max_element()
+            }
+        }
+
+        impl SimdSigned for $vec {
+            #[inline]
+            fn simd_abs(&self) -> Self {
+                self.abs()
+            }
+
+            #[inline]
+            fn simd_abs_sub(&self, other: &Self) -> Self {
+                (*self - *other).max(Self::ZERO)
+            }
+
+            #[inline]
+            fn simd_signum(&self) -> Self {
+                self.signum()
+            }
+
+            #[inline]
+            fn is_simd_positive(&self) -> $bvec {
+                self.cmpgt(Self::ZERO)
+            }
+
+            #[inline]
+            fn is_simd_negative(&self) -> $bvec {
+                self.cmplt(Self::ZERO)
+            }
+        }
+
+        impl SimdComplexField for $vec {
+            type SimdRealField = Self;
+
+            #[inline]
+            fn from_simd_real(re: Self) -> Self {
+                re
+            }
+
+            #[inline]
+            fn simd_real(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn simd_imaginary(self) -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn simd_modulus(self) -> Self {
+                self.abs()
+            }
+
+            #[inline]
+            fn simd_modulus_squared(self) -> Self {
+                self * self
+            }
+
+            #[inline]
+            fn simd_norm1(self) -> Self {
+                self.abs()
+            }
+
+            #[inline]
+            fn simd_scale(self, factor: Self) -> Self {
+                self * factor
+            }
+
+            #[inline]
+            fn simd_unscale(self, factor: Self) -> Self {
+                self / factor
+            }
+
+            #[inline]
+            fn simd_floor(self) -> Self {
+                self.floor()
+            }
+
+            #[inline]
+            fn simd_ceil(self) -> Self {
+                self.ceil()
+            }
+
+            #[inline]
+            fn simd_round(self) -> Self {
+                self.round()
+            }
+
+            #[inline]
+            fn simd_trunc(self) -> Self {
+                self.trunc()
+            }
+
+            #[inline]
+            fn simd_fract(self) -> Self {
+                self - self.trunc()
+            }
+
+            #[inline]
+            fn simd_mul_add(self, a: Self, b: Self) -> Self {
+                self.mul_add(a, b)
+            }
+
+            #[inline]
+            fn simd_abs(self) -> Self {
+                self.abs()
+            }
+
+            #[inline]
+            fn simd_recip(self) -> Self {
+                self.recip()
+            }
+
+            #[inline]
+            fn simd_conjugate(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn simd_sin_cos(self) -> (Self, Self) {
+                (self.simd_sin(), self.simd_cos())
+            }
+
+            #[inline]
+            fn simd_powi(self, n: i32) -> Self {
+                Self::from_array(self.to_array().map(|a| a.simd_powi(n)))
+            }
+
+            #[inline]
+            fn simd_horizontal_sum(self) -> f32 {
+                self.element_sum()
+            }
+
+            #[inline]
+            fn simd_horizontal_product(self) -> f32 {
+                self.element_product()
+            }
+
+            impl_simba_lanes!(
+                simd_argument,
+                simd_cbrt,
+                simd_sin,
+                simd_cos,
+                simd_tan,
+                simd_asin,
+                simd_acos,
+                simd_atan,
+                simd_sinh,
+                simd_cosh,
+                simd_tanh,
+                simd_asinh,
+                simd_acosh,
+                simd_atanh,
+                simd_log2,
+                simd_log10,
+                simd_ln,
+                simd_ln_1p,
+                simd_exp,
+                simd_exp2,
+                simd_exp_m1,
+                simd_sqrt
+            );
+            impl_simba_lanes!(@binary simd_hypot, simd_log, simd_powf, simd_powc);
+        }
+
+        impl SimdRealField for $vec {
+            #[inline]
+            fn simd_copysign(self, sign: Self) -> Self {
+                self.copysign(sign)
+            }
+
+            #[inline]
+            fn simd_atan2(self, other: Self) -> Self {
+                let other = other.to_array();
+                let mut out = self.to_array();
+                for (a, b) in out.iter_mut().zip(other) {
+                    *a = a.simd_atan2(b);
+                }
+                Self::from_array(out)
+            }
+
+            #[inline]
+            fn simd_default_epsilon() -> Self {
+                Self::splat(f32::EPSILON)
+            }
+
+            impl_simba_consts!(
+                simd_pi = PI,
+                simd_two_pi = TAU,
+                simd_frac_pi_2 = FRAC_PI_2,
+                simd_frac_pi_3 = FRAC_PI_3,
+                simd_frac_pi_4 = FRAC_PI_4,
+                simd_frac_pi_6 = FRAC_PI_6,
+                simd_frac_pi_8 = FRAC_PI_8,
+                simd_frac_1_pi = FRAC_1_PI,
+                simd_frac_2_pi = FRAC_2_PI,
+                simd_frac_2_sqrt_pi = FRAC_2_SQRT_PI,
+                simd_e = E,
+                simd_log2_e = LOG2_E,
+                simd_log10_e = LOG10_E,
+                simd_ln_2 = LN_2,
+                simd_ln_10 = LN_10
+            );
+        }
+    };
+}
+
+impl_simba_bool!(BVec3A);
+impl_simba_bool!(BVec4A);
+impl_simba_vec!(3, Vec3A, BVec3A);
+impl_simba_vec!(4, Vec4, BVec4A);
+
+#[cfg(test)]
+mod test {
+    use crate::{Vec3A, Vec4};
+    use simba::simd::{SimdBool, SimdComplexField, SimdPartialOrd, SimdRealField, SimdValue};
+
+    // a generic function which only relies on `simba`
+    fn hypot_generic<T: SimdRealField>(x: T, y: T) -> T {
+        (x.clone() * x + y.clone() * y).simd_sqrt()
+    }
+
+    #[test]
+    fn test_simba_value() {
+        let mut v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(<Vec4 as SimdValue>::LANES, 4);
+        assert_eq!(<Vec3A as SimdValue>::LANES, 3);
+        assert_eq!(v.extract(2), 3.0);
+        v.replace(2, 5.0);
+        assert_eq!(v, Vec4::new(1.0, 2.0, 5.0, 4.0));
+        let mask = v.simd_gt(Vec4::splat(2.0));
+        assert_eq!(mask.bitmask(), 0b1100);
+        assert!(mask.any() && !mask.all() && !mask.none() && !mask.xor());
+        assert_eq!(v.select(mask, Vec4::ZERO), Vec4::new(0.0, 0.0, 5.0, 4.0));
+        assert_eq!(
+            mask.if_else(|| Vec4::ONE, || Vec4::NEG_ONE),
+            Vec4::new(-1.0, -1.0, 1.0, 1.0)
+        );
+        assert_eq!(v.simd_horizontal_max(), 5.0);
+        assert_eq!(v.simd_horizontal_sum(), 12.0);
+    }
+
+    #[test]
+    fn test_simba_real_field() {
+        let x = Vec3A::new(3.0, 5.0, 8.0);
+        let y = Vec3A::new(4.0, 12.0, 15.0);
+        assert_eq!(hypot_generic(x, y), Vec3A::new(5.0, 13.0, 17.0));
+        assert_eq!(hypot_generic(3.0_f32, 4.0), 5.0);
+
+        let a = Vec4::new(0.0, 0.5, 1.0, -1.0);
+        let sin = a.simd_sin();
+        for i in 0..4 {
+            assert_eq!(sin[i], a[i].simd_sin());
+        }
+        assert_eq!(
+            Vec4::new(1.0, 4.0, 9.0, 16.0).simd_sqrt(),
+            Vec4::new(1.0, 2.0, 3.0, 4.0)
+        );
+        assert_eq!(Vec4::simd_pi(), Vec4::splat(core::f32::consts::PI));
+        assert_eq!(a.simd_powi(2), a * a);
+    }
+}
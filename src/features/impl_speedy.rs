@@ -0,0 +1,284 @@
+// Types are read and written as a fixed-size array of their scalar components
+// using the endianness of the speedy context. Vectors and quaternions are in
+// `x, y, z, w` order, matrices and affine transforms are in column-major order.
+
+macro_rules! impl_speedy {
+    ($t:ty, $n:literal, $type:ident) => {
+        impl<'a, C: Context> Readable<'a, C> for $type {
+            #[inline]
+            fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+                Ok(Self::from_array(<[$t; $n]>::read_from(reader)?))
+            }
+
+            #[inline]
+            fn minimum_bytes_needed() -> usize {
+                <[$t; $n] as Readable<'a, C>>::minimum_bytes_needed()
+            }
+        }
+
+        impl<C: Context> Writable<C> for $type {
+            #[inline]
+            fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+                <[$t; $n]>::from(*self).write_to(writer)
+            }
+
+            #[inline]
+            fn bytes_needed(&self) -> Result<usize, C::Error> {
+                Ok(core::mem::size_of::<[$t; $n]>())
+            }
+        }
+    };
+
+    (@cols $t:ty, $n:literal, $type:ident) => {
+        impl<'a, C: Context> Readable<'a, C> for $type {
+            #[inline]
+            fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+                Ok(Self::from_cols_array(&<[$t; $n]>::read_from(reader)?))
+            }
+
+            #[inline]
+            fn minimum_bytes_needed() -> usize {
+                <[$t; $n] as Readable<'a, C>>::minimum_bytes_needed()
+            }
+        }
+
+        impl<C: Context> Writable<C> for $type {
+            #[inline]
+            fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+                self.to_cols_array().write_to(writer)
+            }
+
+            #[inline]
+            fn bytes_needed(&self) -> Result<usize, C::Error> {
+                Ok(core::mem::size_of::<[$t; $n]>())
+            }
+        }
+    };
+}
+
+macro_rules! impl_speedy_vec_types {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_speedy!($t, 2, $vec2);
+        impl_speedy!($t, 3, $vec3);
+        impl_speedy!($t, 4, $vec4);
+    };
+}
+
+macro_rules! impl_speedy_float_types {
+    ($t:ty, $affine2:ident, $affine3:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_speedy!(@cols $t, 6, $affine2);
+        impl_speedy!(@cols $t, 12, $affine3);
+        impl_speedy!(@cols $t, 4, $mat2);
+        impl_speedy!(@cols $t, 9, $mat3);
+        impl_speedy!(@cols $t, 16, $mat4);
+        impl_speedy!($t, 4, $quat);
+        impl_speedy_vec_types!($t, $vec2, $vec3, $vec4);
+    };
+}
+
+mod bool {
+    #[cfg(not(feature = "scalar-math"))]
+    use crate::BVec4A;
+    use crate::{BVec2, BVec3, BVec3A, BVec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_vec_types!(bool, BVec2, BVec3, BVec4);
+    impl_speedy!(bool, 3, BVec3A);
+    #[cfg(not(feature = "scalar-math"))]
+    impl_speedy!(bool, 4, BVec4A);
+}
+
+mod f32 {
+    use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_float_types!(f32, Affine2, Affine3A, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+    impl_speedy!(@cols f32, 9, Mat3A);
+    impl_speedy!(f32, 3, Vec3A);
+}
+
+mod f64 {
+    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_float_types!(
+        f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
+    );
+}
+
+mod i16 {
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+}
+
+mod i32 {
+    use crate::{IVec2, IVec3, IVec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_vec_types!(i32, IVec2, IVec3, IVec4);
+}
+
+mod i64 {
+    use crate::{I64Vec2, I64Vec3, I64Vec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+}
+
+mod u16 {
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+}
+
+mod u32 {
+    use crate::{UVec2, UVec3, UVec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_vec_types!(u32, UVec2, UVec3, UVec4);
+}
+
+mod u64 {
+    use crate::{U64Vec2, U64Vec3, U64Vec4};
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+
+    impl_speedy_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+}
+
+#[cfg(test)]
+mod test {
+    use speedy::{Endianness, LittleEndian, Readable, Writable};
+
+    fn test_roundtrip<T>(value: &T, expected_len: usize)
+    where
+        T: core::fmt::Debug
+            + PartialEq
+            + for<'a> Readable<'a, LittleEndian>
+            + Writable<LittleEndian>,
+    {
+        let bytes = value.write_to_vec().unwrap();
+        assert_eq!(bytes.len(), expected_len);
+        assert_eq!(value.bytes_needed().unwrap(), expected_len);
+        assert_eq!(&T::read_from_buffer(&bytes).unwrap(), value);
+        assert!(T::read_from_buffer(&bytes[..expected_len - 1]).is_err());
+    }
+
+    #[test]
+    fn test_speedy_endianness() {
+        use crate::Vec2;
+        let v = Vec2::new(1.0, 2.0);
+        let le = v.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap();
+        let be = v.write_to_vec_with_ctx(Endianness::BigEndian).unwrap();
+        assert_eq!(&le[..4], &1.0_f32.to_le_bytes());
+        assert_eq!(&be[..4], &1.0_f32.to_be_bytes());
+        assert_eq!(
+            Vec2::read_from_buffer_with_ctx(Endianness::BigEndian, &be).unwrap(),
+            v
+        );
+    }
+
+    #[test]
+    fn test_speedy() {
+        use crate::{BVec2, BVec3, BVec3A, BVec4};
+        test_roundtrip(&BVec2::new(true, false), 2);
+        test_roundtrip(&BVec3::new(true, false, true), 3);
+        test_roundtrip(&BVec3A::new(true, false, true), 3);
+        test_roundtrip(&BVec4::new(true, false, true, false), 4);
+        #[cfg(not(feature = "scalar-math"))]
+        test_roundtrip(&crate::BVec4A::new(true, false, true, false), 4);
+
+        use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+        test_roundtrip(
+            &Affine2::from_cols_array(&[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]),
+            24,
+        );
+        test_roundtrip(
+            &Affine3A::from_cols_array(&[
+                1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0,
+            ]),
+            48,
+        );
+        test_roundtrip(&Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]), 16);
+        test_roundtrip(
+            &Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            36,
+        );
+        test_roundtrip(
+            &Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            36,
+        );
+        test_roundtrip(
+            &Mat4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0,
+            ]),
+            64,
+        );
+        test_roundtrip(&Quat::from_xyzw(1.0, 2.0, 3.0, 4.0), 16);
+        test_roundtrip(&Vec2::new(1.0, 2.0), 8);
+        test_roundtrip(&Vec3::new(1.0, 2.0, 3.0), 12);
+        test_roundtrip(&Vec3A::new(1.0, 2.0, 3.0), 12);
+        test_roundtrip(&Vec4::new(1.0, 2.0, 3.0, 4.0), 16);
+
+        use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+        test_roundtrip(
+            &DAffine2::from_cols_array(&[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]),
+            48,
+        );
+        test_roundtrip(
+            &DAffine3::from_cols_array(&[
+                1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0,
+            ]),
+            96,
+        );
+        test_roundtrip(&DMat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]), 32);
+        test_roundtrip(
+            &DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            72,
+        );
+        test_roundtrip(
+            &DMat4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0,
+            ]),
+            128,
+        );
+        test_roundtrip(&DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0), 32);
+        test_roundtrip(&DVec2::new(1.0, 2.0), 16);
+        test_roundtrip(&DVec3::new(1.0, 2.0, 3.0), 24);
+        test_roundtrip(&DVec4::new(1.0, 2.0, 3.0, 4.0), 32);
+
+        use crate::{I16Vec2, I16Vec3, I16Vec4};
+        test_roundtrip(&I16Vec2::new(-1, 2), 4);
+        test_roundtrip(&I16Vec3::new(-1, 2, 3), 6);
+        test_roundtrip(&I16Vec4::new(-1, 2, 3, 4), 8);
+
+        use crate::{IVec2, IVec3, IVec4};
+        test_roundtrip(&IVec2::new(-1, 2), 8);
+        test_roundtrip(&IVec3::new(-1, 2, 3), 12);
+        test_roundtrip(&IVec4::new(-1, 2, 3, 4), 16);
+
+        use crate::{I64Vec2, I64Vec3, I64Vec4};
+        test_roundtrip(&I64Vec2::new(-1, 2), 16);
+        test_roundtrip(&I64Vec3::new(-1, 2, 3), 24);
+        test_roundtrip(&I64Vec4::new(-1, 2, 3, 4), 32);
+
+        use crate::{U16Vec2, U16Vec3, U16Vec4};
+        test_roundtrip(&U16Vec2::new(1, 2), 4);
+        test_roundtrip(&U16Vec3::new(1, 2, 3), 6);
+        test_roundtrip(&U16Vec4::new(1, 2, 3, 4), 8);
+
+        use crate::{UVec2, UVec3, UVec4};
+        test_roundtrip(&UVec2::new(1, 2), 8);
+        test_roundtrip(&UVec3::new(1, 2, 3), 12);
+        test_roundtrip(&UVec4::new(1, 2, 3, 4), 16);
+
+        use crate::{U64Vec2, U64Vec3, U64Vec4};
+        test_roundtrip(&U64Vec2::new(1, 2), 16);
+        test_roundtrip(&U64Vec3::new(1, 2, 3), 24);
+        test_roundtrip(&U64Vec4::new(1, 2, 3, 4), 32);
+    }
+}
@@ -0,0 +1,56 @@
+/// A wrapper which compares and hashes a floating point vector using the bit patterns of its
+/// elements after canonicalizing them.
+///
+/// `-0.0` is treated as `0.0` and all `NaN` values are treated as the same `NaN`, so two wrapped
+/// vectors are equal if each pair of elements is either equal with `==` or both `NaN`. Equal
+/// wrapped vectors always hash the same.
+///
+/// This makes it possible to use float vectors as keys in a `HashMap`, for example to
+/// deduplicate mesh vertices. Use [`TotalOrd`](crate::TotalOrd) instead if `-0.0` and `0.0`
+/// need to be distinct or an ordering is required.
+///
+/// `Hashable` values are usually created using the `hashable()` method of a float vector.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct Hashable<T>(pub T);
+
+impl<T> Hashable<T> {
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Hashable<T> {
+    #[inline]
+    fn from(t: T) -> Self {
+        Self(t)
+    }
+}
+
+/// Returns the bits of `x` with `-0.0` mapped to `0.0` and all `NaN` values mapped to
+/// [`f32::NAN`].
+#[inline]
+pub(crate) fn canonical_bits_f32(x: f32) -> u32 {
+    if x.is_nan() {
+        f32::NAN.to_bits()
+    } else if x == 0.0 {
+        0
+    } else {
+        x.to_bits()
+    }
+}
+
+/// Returns the bits of `x` with `-0.0` mapped to `0.0` and all `NaN` values mapped to
+/// [`f64::NAN`].
+#[inline]
+pub(crate) fn canonical_bits_f64(x: f64) -> u64 {
+    if x.is_nan() {
+        f64::NAN.to_bits()
+    } else if x == 0.0 {
+        0
+    } else {
+        x.to_bits()
+    }
+}
@@ -17,8 +17,12 @@ pub const fn i16vec4(x: i16, y: i16, z: i16, w: i16) -> I16Vec4 {
 }
 
 /// A 4-dimensional vector.
+///
+/// Vectors are ordered lexicographically by their elements, which allows sorting them or using
+/// them as keys in a `BTreeMap`. Use methods such as [`Self::cmplt`] for element-wise
+/// comparisons.
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "cuda", repr(align(8)))]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 #[cfg_attr(target_arch = "spirv", repr(simd))]
@@ -72,6 +76,17 @@ impl I16Vec4 {
     /// The unit axes.
     pub const AXES: [Self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
 
+    /// Returns the unit vector pointing along `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not one of the 4 axes of this vector.
+    #[inline]
+    #[must_use]
+    pub const fn axis(axis: crate::Axis) -> Self {
+        Self::AXES[axis.index()]
+    }
+
     /// Creates a new vector.
     #[inline(always)]
     #[must_use]
@@ -94,6 +109,44 @@ impl I16Vec4 {
         }
     }
 
+    /// Creates a vector where each element is the result of calling `f` with its index.
+    ///
+    /// `f` is called for each element in order, starting from index 0.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> i16,
+    {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(i16) -> i16,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z), f(self.w))
+    }
+
+    /// Returns a vector containing the result of calling `f` with each element of `self` and the
+    /// corresponding element of `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn zip_with<F>(self, rhs: Self, f: F) -> Self
+    where
+        F: Fn(i16, i16) -> i16,
+    {
+        Self::new(
+            f(self.x, rhs.x),
+            f(self.y, rhs.y),
+            f(self.z, rhs.z),
+            f(self.w, rhs.w),
+        )
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -135,6 +188,34 @@ impl I16Vec4 {
         Self::new(slice[0], slice[1], slice[2], slice[3])
     }
 
+    /// Creates a vector from the first 4 values in `slice`, returning an error if
+    /// `slice` is less than 4 elements long.
+    #[inline]
+    pub fn from_slice_checked(slice: &[i16]) -> Result<Self, crate::error::Error> {
+        crate::error::check_slice_len(slice, 4)?;
+        Ok(Self::from_slice(slice))
+    }
+
+    /// Creates a vector from the first 4 values yielded by `iter`, returning an error if
+    /// `iter` yields fewer than 4 values.
+    ///
+    /// Any remaining values are not consumed. The [`FromIterator`] implementation behaves the
+    /// same but panics instead of returning an error.
+    #[inline]
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, crate::error::Error>
+    where
+        I: IntoIterator<Item = i16>,
+    {
+        let mut iter = iter.into_iter();
+        let mut out = Self::ZERO.to_array();
+        for (found, e) in out.iter_mut().enumerate() {
+            *e = iter
+                .next()
+                .ok_or(crate::error::Error::IterTooShort { required: 4, found })?;
+        }
+        Ok(Self::from_array(out))
+    }
+
     /// Writes the elements of `self` to the first 4 elements in `slice`.
     ///
     /// # Panics
@@ -148,6 +229,132 @@ impl I16Vec4 {
         slice[3] = self.w;
     }
 
+    /// Reinterprets a slice of vectors as a slice of their elements without copying.
+    ///
+    /// This is useful for passing vertex data to APIs expecting a flat slice of
+    /// `i16`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice(vectors: &[Self]) -> &[i16] {
+        // SAFETY: `Self` consists of 4 `i16` elements with no padding
+        unsafe { crate::cast::flatten(vectors) }
+    }
+
+    /// Reinterprets a mutable slice of vectors as a mutable slice of their elements without
+    /// copying.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice_mut(vectors: &mut [Self]) -> &mut [i16] {
+        // SAFETY: see `as_flat_slice`
+        unsafe { crate::cast::flatten_mut(vectors) }
+    }
+
+    /// Reinterprets a slice of elements as a slice of vectors without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 4 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice(flat: &[i16]) -> &[Self] {
+        Self::from_flat_slice_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of vectors without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `flat` is not a multiple of 4 or if `flat` is not aligned
+    /// to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice_mut(flat: &mut [i16]) -> &mut [Self] {
+        Self::from_flat_slice_mut_checked(flat).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reinterprets a slice of elements as a slice of vectors without copying, returning an
+    /// error if the length of `flat` is not a multiple of 4 or if `flat` is not aligned to
+    /// `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_checked(flat: &[i16]) -> Result<&[Self], crate::error::Error> {
+        // SAFETY: see `as_flat_slice`, any bit pattern is a valid `i16`
+        unsafe { crate::cast::unflatten(flat) }
+    }
+
+    /// Reinterprets a mutable slice of elements as a mutable slice of vectors without copying,
+    /// returning an error if the length of `flat` is not a multiple of 4 or if `flat` is
+    /// not aligned to `align_of::<Self>()`.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_flat_slice_mut_checked(
+        flat: &mut [i16],
+    ) -> Result<&mut [Self], crate::error::Error> {
+        // SAFETY: see `from_flat_slice_checked`
+        unsafe { crate::cast::unflatten_mut(flat) }
+    }
+
+    /// Creates a vector from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        let mut a = [i16::default(); 4];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(2)) {
+            *dst = i16::from_le_bytes(src.try_into().unwrap());
+        }
+        Self::from_array(a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        for (dst, src) in bytes.chunks_exact_mut(2).zip(self.to_array()) {
+            dst.copy_from_slice(&src.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a vector from its representation as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        let mut a = [i16::default(); 4];
+        for (dst, src) in a.iter_mut().zip(bytes.chunks_exact(2)) {
+            *dst = i16::from_be_bytes(src.try_into().unwrap());
+        }
+        Self::from_array(a)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian byte
+    /// order.
+    ///
+    /// Elements are stored in `[x, y, z, w]` order.
+    #[inline]
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        for (dst, src) in bytes.chunks_exact_mut(2).zip(self.to_array()) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+        bytes
+    }
+
     /// Creates a 3D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
     ///
     /// Truncation to [`I16Vec3`] may also be performed by using [`self.xyz()`][crate::swizzles::Vec4Swizzles::xyz()].
@@ -193,14 +400,14 @@ impl I16Vec4 {
     /// Computes the dot product of `self` and `rhs`.
     #[inline]
     #[must_use]
-    pub fn dot(self, rhs: Self) -> i16 {
+    pub const fn dot(self, rhs: Self) -> i16 {
         (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z) + (self.w * rhs.w)
     }
 
     /// Returns a vector where every component is the dot product of `self` and `rhs`.
     #[inline]
     #[must_use]
-    pub fn dot_into_vec(self, rhs: Self) -> Self {
+    pub const fn dot_into_vec(self, rhs: Self) -> Self {
         Self::splat(self.dot(rhs))
     }
 
@@ -232,6 +439,17 @@ impl I16Vec4 {
         }
     }
 
+    /// Returns the element-wise minimum and maximum of `self` and `rhs` as a pair, using a single
+    /// comparison.
+    ///
+    /// This orders the near and far distances of each axis in a slab test.
+    #[inline]
+    #[must_use]
+    pub fn min_max(self, rhs: Self) -> (Self, Self) {
+        let mask = self.cmplt(rhs);
+        (Self::select(mask, self, rhs), Self::select(mask, rhs, self))
+    }
+
     /// Component-wise clamping of values, similar to [`i16::clamp`].
     ///
     /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
@@ -264,12 +482,107 @@ impl I16Vec4 {
         self.x.max(self.y.max(self.z.max(self.w)))
     }
 
+    /// Returns the index of the first minimum element of `self`.
+    #[doc(alias = "argmin")]
+    #[inline]
+    #[must_use]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+
+        if self.y < min {
+            min = self.y;
+            index = 1;
+        }
+
+        if self.z < min {
+            min = self.z;
+            index = 2;
+        }
+
+        if self.w < min {
+            index = 3;
+        }
+
+        index
+    }
+
+    /// Returns the index of the first element of `self` with the smallest absolute value.
+    #[inline]
+    #[must_use]
+    pub fn abs_min_position(self) -> usize {
+        self.unsigned_abs().min_position()
+    }
+
+    /// Returns the index of the first maximum element of `self`.
+    #[doc(alias = "argmax")]
+    #[inline]
+    #[must_use]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+
+        if self.y > max {
+            max = self.y;
+            index = 1;
+        }
+
+        if self.z > max {
+            max = self.z;
+            index = 2;
+        }
+
+        if self.w > max {
+            index = 3;
+        }
+
+        index
+    }
+
+    /// Returns the index of the first element of `self` with the largest absolute value.
+    ///
+    /// This selects the dominant axis of a direction vector.
+    #[inline]
+    #[must_use]
+    pub fn abs_max_position(self) -> usize {
+        self.unsigned_abs().max_position()
+    }
+
+    /// Returns a vector containing the elements of `self` sorted in ascending order.
+    #[inline]
+    #[must_use]
+    pub fn sorted(self) -> Self {
+        let mut a = self.to_array();
+
+        if a[1] < a[0] {
+            a.swap(0, 1);
+        }
+
+        if a[3] < a[2] {
+            a.swap(2, 3);
+        }
+
+        if a[2] < a[0] {
+            a.swap(0, 2);
+        }
+
+        if a[3] < a[1] {
+            a.swap(1, 3);
+        }
+
+        if a[2] < a[1] {
+            a.swap(1, 2);
+        }
+
+        Self::from_array(a)
+    }
+
     /// Returns the sum of all elements of `self`.
     ///
     /// In other words, this computes `self.x + self.y + ..`.
     #[inline]
     #[must_use]
-    pub fn element_sum(self) -> i16 {
+    pub const fn element_sum(self) -> i16 {
         self.x + self.y + self.z + self.w
     }
 
@@ -278,7 +591,7 @@ impl I16Vec4 {
     /// In other words, this computes `self.x * self.y * ..`.
     #[inline]
     #[must_use]
-    pub fn element_product(self) -> i16 {
+    pub const fn element_product(self) -> i16 {
         self.x * self.y * self.z * self.w
     }
 
@@ -423,7 +736,7 @@ impl I16Vec4 {
     #[doc(alias = "magnitude2")]
     #[inline]
     #[must_use]
-    pub fn length_squared(self) -> i16 {
+    pub const fn length_squared(self) -> i16 {
         self.dot(self)
     }
 
@@ -466,6 +779,56 @@ impl I16Vec4 {
         )
     }
 
+    /// Returns the element-wise quotient of `self` divided by `rhs`, rounded towards negative
+    /// infinity.
+    ///
+    /// This differs from `/`, which rounds towards zero, when the signs of `self` and `rhs`
+    /// differ.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn div_floor(self, rhs: Self) -> Self {
+        let q = self / rhs;
+        let r = self - q * rhs;
+        // round down when there is a remainder and the exact quotient is negative
+        let round_down = r.cmpne(Self::ZERO) & (r ^ rhs).cmplt(Self::ZERO);
+        q - Self::select(round_down, Self::ONE, Self::ZERO)
+    }
+
+    /// Returns a vector containing each element of `self` rounded to the nearest multiple of the
+    /// corresponding element of `step`.
+    ///
+    /// This snaps `self` to a grid with cells of size `step` and a corner at the origin.
+    /// Half-way cases are rounded up and the sign of `step` is ignored.
+    ///
+    /// # Panics
+    /// This function will panic if any `step` element is 0 or the result overflows.
+    #[inline]
+    #[must_use]
+    pub fn snap(self, step: Self) -> Self {
+        let step = step.abs();
+        let r = self.rem_euclid(step);
+
+        let down = self - r;
+        Self::select((step - r).cmple(r), down + step, down)
+    }
+
+    /// Returns a vector containing each element of `self` rounded to the nearest multiple of the
+    /// corresponding element of `step`, offset by `origin`.
+    ///
+    /// This snaps `self` to a grid with cells of size `step` and a corner at `origin`. See
+    /// [`Self::snap()`] for how half-way cases are rounded.
+    ///
+    /// # Panics
+    /// This function will panic if any `step` element is 0 or the result overflows.
+    #[inline]
+    #[must_use]
+    pub fn snap_to(self, step: Self, origin: Self) -> Self {
+        (self - origin).snap(step) + origin
+    }
+
     /// Casts all elements of `self` to `f32`.
     #[inline]
     #[must_use]
@@ -515,6 +878,61 @@ impl I16Vec4 {
         crate::U64Vec4::new(self.x as u64, self.y as u64, self.z as u64, self.w as u64)
     }
 
+    /// Casts all elements of `self` to `u16`, clamping values which are out of range to
+    /// `u16::MIN` or `u16::MAX` instead of wrapping.
+    #[inline]
+    #[must_use]
+    pub fn as_u16vec4_saturating(&self) -> crate::U16Vec4 {
+        crate::U16Vec4::new(
+            u16::try_from(self.x).unwrap_or(if self.x < 0 { u16::MIN } else { u16::MAX }),
+            u16::try_from(self.y).unwrap_or(if self.y < 0 { u16::MIN } else { u16::MAX }),
+            u16::try_from(self.z).unwrap_or(if self.z < 0 { u16::MIN } else { u16::MAX }),
+            u16::try_from(self.w).unwrap_or(if self.w < 0 { u16::MIN } else { u16::MAX }),
+        )
+    }
+
+    /// Casts all elements of `self` to `i32`, clamping values which are out of range to
+    /// `i32::MIN` or `i32::MAX` instead of wrapping.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec4_saturating(&self) -> crate::IVec4 {
+        self.as_ivec4()
+    }
+
+    /// Casts all elements of `self` to `u32`, clamping values which are out of range to
+    /// `u32::MIN` or `u32::MAX` instead of wrapping.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec4_saturating(&self) -> crate::UVec4 {
+        crate::UVec4::new(
+            u32::try_from(self.x).unwrap_or(if self.x < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.y).unwrap_or(if self.y < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.z).unwrap_or(if self.z < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.w).unwrap_or(if self.w < 0 { u32::MIN } else { u32::MAX }),
+        )
+    }
+
+    /// Casts all elements of `self` to `i64`, clamping values which are out of range to
+    /// `i64::MIN` or `i64::MAX` instead of wrapping.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec4_saturating(&self) -> crate::I64Vec4 {
+        self.as_i64vec4()
+    }
+
+    /// Casts all elements of `self` to `u64`, clamping values which are out of range to
+    /// `u64::MIN` or `u64::MAX` instead of wrapping.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec4_saturating(&self) -> crate::U64Vec4 {
+        crate::U64Vec4::new(
+            u64::try_from(self.x).unwrap_or(if self.x < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.y).unwrap_or(if self.y < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.z).unwrap_or(if self.z < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.w).unwrap_or(if self.w < 0 { u64::MIN } else { u64::MAX }),
+        )
+    }
+
     /// Returns a vector containing the wrapping addition of `self` and `rhs`.
     ///
     /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
@@ -627,6 +1045,220 @@ impl I16Vec4 {
         }
     }
 
+    /// Returns a vector containing the overflowing addition of `self` and `rhs`, along with a
+    /// mask of the elements where an arithmetic overflow occurred.
+    ///
+    /// In other words this computes `[self.x.overflowing_add(rhs.x), self.y.overflowing_add(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, BVec4) {
+        let (x, x_overflow) = self.x.overflowing_add(rhs.x);
+        let (y, y_overflow) = self.y.overflowing_add(rhs.y);
+        let (z, z_overflow) = self.z.overflowing_add(rhs.z);
+        let (w, w_overflow) = self.w.overflowing_add(rhs.w);
+        (
+            Self::new(x, y, z, w),
+            BVec4::new(x_overflow, y_overflow, z_overflow, w_overflow),
+        )
+    }
+
+    /// Returns a vector containing the overflowing subtraction of `self` and `rhs`, along with a
+    /// mask of the elements where an arithmetic overflow occurred.
+    ///
+    /// In other words this computes `[self.x.overflowing_sub(rhs.x), self.y.overflowing_sub(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, BVec4) {
+        let (x, x_overflow) = self.x.overflowing_sub(rhs.x);
+        let (y, y_overflow) = self.y.overflowing_sub(rhs.y);
+        let (z, z_overflow) = self.z.overflowing_sub(rhs.z);
+        let (w, w_overflow) = self.w.overflowing_sub(rhs.w);
+        (
+            Self::new(x, y, z, w),
+            BVec4::new(x_overflow, y_overflow, z_overflow, w_overflow),
+        )
+    }
+
+    /// Returns a vector containing the overflowing multiplication of `self` and `rhs`, along with
+    /// a mask of the elements where an arithmetic overflow occurred.
+    ///
+    /// In other words this computes `[self.x.overflowing_mul(rhs.x), self.y.overflowing_mul(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, BVec4) {
+        let (x, x_overflow) = self.x.overflowing_mul(rhs.x);
+        let (y, y_overflow) = self.y.overflowing_mul(rhs.y);
+        let (z, z_overflow) = self.z.overflowing_mul(rhs.z);
+        let (w, w_overflow) = self.w.overflowing_mul(rhs.w);
+        (
+            Self::new(x, y, z, w),
+            BVec4::new(x_overflow, y_overflow, z_overflow, w_overflow),
+        )
+    }
+
+    /// Returns the full precision product of `self` and `rhs` as a vector of the low bits and a
+    /// vector of the high bits of each element.
+    ///
+    /// In other words this computes `[self.x as i32 * rhs.x as i32, ..]` and
+    /// splits each product into its low and high 16 bits.
+    #[inline]
+    #[must_use]
+    pub const fn widening_mul(self, rhs: Self) -> (U16Vec4, Self) {
+        let x = self.x as i32 * rhs.x as i32;
+        let y = self.y as i32 * rhs.y as i32;
+        let z = self.z as i32 * rhs.z as i32;
+        let w = self.w as i32 * rhs.w as i32;
+        (
+            U16Vec4::new(x as u16, y as u16, z as u16, w as u16),
+            Self::new(
+                (x >> 16) as i16,
+                (y >> 16) as i16,
+                (z >> 16) as i16,
+                (w >> 16) as i16,
+            ),
+        )
+    }
+
+    /// Calculates the midpoint between `self` and `rhs` without overflowing.
+    ///
+    /// Each element is rounded towards zero, in other words this computes
+    /// `[self.x.midpoint(rhs.x), self.y.midpoint(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // the average rounded towards negative infinity
+        let floor: Self = (self & rhs) + ((self ^ rhs) >> 1);
+        // round negative averages with a half-way remainder up, towards zero
+        let odd = (self ^ rhs) & Self::ONE;
+        floor + Self::select(floor.cmplt(Self::ZERO), odd, Self::ZERO)
+    }
+
+    /// Returns a vector containing the absolute value of each element of `self` as the
+    /// unsigned vector type, without overflowing for `i16::MIN`.
+    ///
+    /// In other words this computes `[self.x.unsigned_abs(), self.y.unsigned_abs(), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn unsigned_abs(self) -> U16Vec4 {
+        U16Vec4::new(
+            self.x.unsigned_abs(),
+            self.y.unsigned_abs(),
+            self.z.unsigned_abs(),
+            self.w.unsigned_abs(),
+        )
+    }
+
+    /// Returns a vector containing the number of ones in the binary representation of each element of
+    /// `self`.
+    ///
+    /// In other words this computes `[self.x.count_ones(), self.y.count_ones(), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn count_ones(self) -> crate::UVec4 {
+        crate::UVec4::new(
+            self.x.count_ones(),
+            self.y.count_ones(),
+            self.z.count_ones(),
+            self.w.count_ones(),
+        )
+    }
+
+    /// Returns a vector containing the number of leading zeros in the binary representation of each element of
+    /// `self`.
+    ///
+    /// In other words this computes `[self.x.leading_zeros(), self.y.leading_zeros(), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn leading_zeros(self) -> crate::UVec4 {
+        crate::UVec4::new(
+            self.x.leading_zeros(),
+            self.y.leading_zeros(),
+            self.z.leading_zeros(),
+            self.w.leading_zeros(),
+        )
+    }
+
+    /// Returns a vector containing the number of trailing zeros in the binary representation of each element of
+    /// `self`.
+    ///
+    /// In other words this computes `[self.x.trailing_zeros(), self.y.trailing_zeros(), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn trailing_zeros(self) -> crate::UVec4 {
+        crate::UVec4::new(
+            self.x.trailing_zeros(),
+            self.y.trailing_zeros(),
+            self.z.trailing_zeros(),
+            self.w.trailing_zeros(),
+        )
+    }
+
+    /// Returns a vector containing the bits of each element of `self` rotated left by the matching
+    /// element of `n`, wrapping the truncated bits to the end.
+    ///
+    /// In other words this computes `[self.x.rotate_left(n.x), self.y.rotate_left(n.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_left(self, n: crate::UVec4) -> Self {
+        Self {
+            x: self.x.rotate_left(n.x),
+            y: self.y.rotate_left(n.y),
+            z: self.z.rotate_left(n.z),
+            w: self.w.rotate_left(n.w),
+        }
+    }
+
+    /// Returns a vector containing the bits of each element of `self` rotated right by the matching
+    /// element of `n`, wrapping the truncated bits to the beginning.
+    ///
+    /// In other words this computes `[self.x.rotate_right(n.x), self.y.rotate_right(n.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_right(self, n: crate::UVec4) -> Self {
+        Self {
+            x: self.x.rotate_right(n.x),
+            y: self.y.rotate_right(n.y),
+            z: self.z.rotate_right(n.z),
+            w: self.w.rotate_right(n.w),
+        }
+    }
+
+    /// Returns a vector containing each element of `self` shifted left by the matching element of
+    /// `rhs`.
+    ///
+    /// Unlike `self << rhs` this never panics, instead each shift amount is masked to the bit
+    /// width of the element type.
+    ///
+    /// In other words this computes `[self.x.wrapping_shl(rhs.x), self.y.wrapping_shl(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_shl(self, rhs: crate::UVec4) -> Self {
+        Self {
+            x: self.x.wrapping_shl(rhs.x),
+            y: self.y.wrapping_shl(rhs.y),
+            z: self.z.wrapping_shl(rhs.z),
+            w: self.w.wrapping_shl(rhs.w),
+        }
+    }
+
+    /// Returns a vector containing each element of `self` shifted right by the matching element of
+    /// `rhs`.
+    ///
+    /// Unlike `self >> rhs` this never panics, instead each shift amount is masked to the bit
+    /// width of the element type.
+    ///
+    /// In other words this computes `[self.x.wrapping_shr(rhs.x), self.y.wrapping_shr(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_shr(self, rhs: crate::UVec4) -> Self {
+        Self {
+            x: self.x.wrapping_shr(rhs.x),
+            y: self.y.wrapping_shr(rhs.y),
+            z: self.z.wrapping_shr(rhs.z),
+            w: self.w.wrapping_shr(rhs.w),
+        }
+    }
+
     /// Returns a vector containing the wrapping addition of `self` and unsigned vector `rhs`.
     ///
     /// In other words this computes `[self.x.wrapping_add_unsigned(rhs.x), self.y.wrapping_add_unsigned(rhs.y), ..]`.
@@ -1002,6 +1634,57 @@ impl AsMut<[i16; 4]> for I16Vec4 {
     }
 }
 
+impl IntoIterator for I16Vec4 {
+    type Item = i16;
+    type IntoIter = core::array::IntoIter<i16, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a> IntoIterator for &'a I16Vec4 {
+    type Item = &'a i16;
+    type IntoIter = core::slice::Iter<'a, i16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a> IntoIterator for &'a mut I16Vec4 {
+    type Item = &'a mut i16;
+    type IntoIter = core::slice::IterMut<'a, i16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
+
+impl FromIterator<i16> for I16Vec4 {
+    /// Creates a vector from the first 4 values yielded by `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` yields fewer than 4 values, see [`I16Vec4::try_from_iter`]
+    /// for a non-panicking version.
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = i16>,
+    {
+        match Self::try_from_iter(iter) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
 impl Sum for I16Vec4 {
     #[inline]
     fn sum<I>(iter: I) -> Self
@@ -1433,10 +2116,34 @@ impl IndexMut<usize> for I16Vec4 {
     }
 }
 
+impl Index<crate::Axis> for I16Vec4 {
+    type Output = i16;
+    #[inline]
+    fn index(&self, axis: crate::Axis) -> &Self::Output {
+        &self[axis.index()]
+    }
+}
+
+impl IndexMut<crate::Axis> for I16Vec4 {
+    #[inline]
+    fn index_mut(&mut self, axis: crate::Axis) -> &mut Self::Output {
+        &mut self[axis.index()]
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for I16Vec4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+        crate::display::fmt_list(f, &self.to_array())
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for I16Vec4 {
+    type Err = crate::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -1509,75 +2216,117 @@ impl From<(I16Vec2, I16Vec2)> for I16Vec4 {
 }
 
 impl TryFrom<U16Vec4> for I16Vec4 {
-    type Error = core::num::TryFromIntError;
+    type Error = crate::error::TryFromVecError;
 
     #[inline]
     fn try_from(v: U16Vec4) -> Result<Self, Self::Error> {
         Ok(Self::new(
-            i16::try_from(v.x)?,
-            i16::try_from(v.y)?,
-            i16::try_from(v.z)?,
-            i16::try_from(v.w)?,
+            i16::try_from(v.x).map_err(|_| crate::error::TryFromVecError { index: 0 })?,
+            i16::try_from(v.y).map_err(|_| crate::error::TryFromVecError { index: 1 })?,
+            i16::try_from(v.z).map_err(|_| crate::error::TryFromVecError { index: 2 })?,
+            i16::try_from(v.w).map_err(|_| crate::error::TryFromVecError { index: 3 })?,
         ))
     }
 }
 
 impl TryFrom<IVec4> for I16Vec4 {
-    type Error = core::num::TryFromIntError;
+    type Error = crate::error::TryFromVecError;
 
     #[inline]
     fn try_from(v: IVec4) -> Result<Self, Self::Error> {
         Ok(Self::new(
-            i16::try_from(v.x)?,
-            i16::try_from(v.y)?,
-            i16::try_from(v.z)?,
-            i16::try_from(v.w)?,
+            i16::try_from(v.x).map_err(|_| crate::error::TryFromVecError { index: 0 })?,
+            i16::try_from(v.y).map_err(|_| crate::error::TryFromVecError { index: 1 })?,
+            i16::try_from(v.z).map_err(|_| crate::error::TryFromVecError { index: 2 })?,
+            i16::try_from(v.w).map_err(|_| crate::error::TryFromVecError { index: 3 })?,
         ))
     }
 }
 
 impl TryFrom<UVec4> for I16Vec4 {
-    type Error = core::num::TryFromIntError;
+    type Error = crate::error::TryFromVecError;
 
     #[inline]
     fn try_from(v: UVec4) -> Result<Self, Self::Error> {
         Ok(Self::new(
-            i16::try_from(v.x)?,
-            i16::try_from(v.y)?,
-            i16::try_from(v.z)?,
-            i16::try_from(v.w)?,
+            i16::try_from(v.x).map_err(|_| crate::error::TryFromVecError { index: 0 })?,
+            i16::try_from(v.y).map_err(|_| crate::error::TryFromVecError { index: 1 })?,
+            i16::try_from(v.z).map_err(|_| crate::error::TryFromVecError { index: 2 })?,
+            i16::try_from(v.w).map_err(|_| crate::error::TryFromVecError { index: 3 })?,
         ))
     }
 }
 
 impl TryFrom<I64Vec4> for I16Vec4 {
-    type Error = core::num::TryFromIntError;
+    type Error = crate::error::TryFromVecError;
 
     #[inline]
     fn try_from(v: I64Vec4) -> Result<Self, Self::Error> {
         Ok(Self::new(
-            i16::try_from(v.x)?,
-            i16::try_from(v.y)?,
-            i16::try_from(v.z)?,
-            i16::try_from(v.w)?,
+            i16::try_from(v.x).map_err(|_| crate::error::TryFromVecError { index: 0 })?,
+            i16::try_from(v.y).map_err(|_| crate::error::TryFromVecError { index: 1 })?,
+            i16::try_from(v.z).map_err(|_| crate::error::TryFromVecError { index: 2 })?,
+            i16::try_from(v.w).map_err(|_| crate::error::TryFromVecError { index: 3 })?,
         ))
     }
 }
 
 impl TryFrom<U64Vec4> for I16Vec4 {
-    type Error = core::num::TryFromIntError;
+    type Error = crate::error::TryFromVecError;
 
     #[inline]
     fn try_from(v: U64Vec4) -> Result<Self, Self::Error> {
         Ok(Self::new(
-            i16::try_from(v.x)?,
-            i16::try_from(v.y)?,
-            i16::try_from(v.z)?,
-            i16::try_from(v.w)?,
+            i16::try_from(v.x).map_err(|_| crate::error::TryFromVecError { index: 0 })?,
+            i16::try_from(v.y).map_err(|_| crate::error::TryFromVecError { index: 1 })?,
+            i16::try_from(v.z).map_err(|_| crate::error::TryFromVecError { index: 2 })?,
+            i16::try_from(v.w).map_err(|_| crate::error::TryFromVecError { index: 3 })?,
         ))
     }
 }
 
+impl TryFrom<crate::Vec4> for I16Vec4 {
+    type Error = crate::error::TryFromVecError;
+
+    /// Converts `v` if every component is an integer within the range of `i16`.
+    #[inline]
+    fn try_from(v: crate::Vec4) -> Result<Self, Self::Error> {
+        // both bounds are zero or a power of two so they are exactly representable
+        const MIN: f32 = i16::MIN as f32;
+        const END: f32 = (i16::MAX / 2 + 1) as f32 * 2.0;
+        let mut out = [0; 4];
+        for (index, (f, i)) in v.to_array().into_iter().zip(out.iter_mut()).enumerate() {
+            // `NaN` is never contained in the range
+            if !(MIN..END).contains(&f) || f as i16 as f32 != f {
+                return Err(crate::error::TryFromVecError { index });
+            }
+            *i = f as i16;
+        }
+        Ok(Self::from_array(out))
+    }
+}
+
+impl TryFrom<crate::DVec4> for I16Vec4 {
+    type Error = crate::error::TryFromVecError;
+
+    /// Converts `v` if every component is an integer within the range of `i16`.
+    #[inline]
+    fn try_from(v: crate::DVec4) -> Result<Self, Self::Error> {
+        // both bounds are zero or a power of two so they are exactly representable
+        const MIN: f64 = i16::MIN as f64;
+        const END: f64 = (i16::MAX / 2 + 1) as f64 * 2.0;
+        let mut out = [0; 4];
+        for (index, (f, i)) in v.to_array().into_iter().zip(out.iter_mut()).enumerate() {
+            // `NaN` is never contained in the range
+            if !(MIN..END).contains(&f) || f as i16 as f64 != f {
+                return Err(crate::error::TryFromVecError { index });
+            }
+            *i = f as i16;
+        }
+        Ok(Self::from_array(out))
+    }
+}
+
 impl From<BVec4> for I16Vec4 {
     #[inline]
     fn from(v: BVec4) -> Self {
@@ -10,11 +10,15 @@
   * square matrices: [`Mat2`], [`Mat3`], [`Mat3A`] and [`Mat4`]
   * a quaternion type: [`Quat`]
   * affine transformation types: [`Affine2`] and [`Affine3A`]
+  * geometric primitives: [`Aabb3`], [`Plane`], [`Ray3`] and [`Frustum`], and queries in
+    [`f32::geometry`]
 * [`f64`](mod@f64) types
   * vectors: [`DVec2`], [`DVec3`] and [`DVec4`]
   * square matrices: [`DMat2`], [`DMat3`] and [`DMat4`]
   * a quaternion type: [`DQuat`]
   * affine transformation types: [`DAffine2`] and [`DAffine3`]
+  * geometric primitives: [`DAabb3`], [`DPlane`] and [`DRay3`], and queries in
+    [`f64::geometry`]
 * [`i16`](mod@i16) types
   * vectors: [`I16Vec2`], [`I16Vec3`] and [`I16Vec4`]
 * [`u16`](mod@u16) types
@@ -158,6 +162,13 @@ that axis is a valid normalized vector. To help catch unintended misuse of `glam
 `debug-glam-assert` or `glam-assert` features can be enabled to add checks ensure that inputs to
 are valid.
 
+Failed assertions panic by default, a different handler can be installed at runtime with
+[`assert::set_handler()`], for example to log invalid input and continue.
+
+Some methods have a `_checked` variant such as [`Vec3::normalize_checked()`] or
+[`Mat4::inverse_checked()`] which validates its input and returns an [`error::Error`] instead,
+allowing invalid input to be handled at runtime.
+
 ## Vector swizzles
 
 `glam` vector types have functions allowing elements of vectors to be reordered, this includes
@@ -221,17 +232,35 @@ and benchmarks.
 
 * `std` - the default feature, has no dependencies.
 * `approx` - traits and macros for approximate float comparisons
+* `borsh` - implementations of `BorshSerialize` and `BorshDeserialize` for all
+  `glam` vector, quaternion, matrix and affine types. Types are encoded as their
+  scalar components in little endian order with no padding, matrices and affine
+  transforms are encoded in column-major order
 * `bytemuck` - for casting into slices of bytes
+* `fixed` - conversions between float vectors and arrays of `fixed` point values with round,
+  floor and ceil modes
 * `libm` - uses `libm` math functions instead of `std`, required to compile with `no_std`
 * `mint` - for interoperating with other 3D math libraries
-* `rand` - implementations of `Distribution` trait for all `glam` types.
+* `num-traits` - implementations of `Zero` and `One` for vector, quaternion, matrix and affine
+  types and `Num` for vector types
+* `quickcheck` - implementations of `Arbitrary` for all `glam` types. Quaternions are always
+  normalized. Requires `std`
+* `rand` - implementations of `Distribution` trait for all `glam` types and
+  distributions of random transforms in `glam::rand`.
 * `rkyv` - implementations of `Archive`, `Serialize` and `Deserialize` for all
   `glam` types. Note that serialization is not interoperable with and without the
   `scalar-math` feature. It should work between all other builds of `glam`.
   Endian conversion is currently not supported
 * `bytecheck` - to perform archive validation when using the `rkyv` feature
 * `serde` - implementations of `Serialize` and `Deserialize` for all `glam`
-  types. Note that serialization should work between builds of `glam` with and without SIMD enabled
+  types. Note that serialization should work between builds of `glam` with and without SIMD enabled.
+  Alternative representations are available in the `glam::serde` module for use with
+  `#[serde(with = "...")]`
+* `simba` - implementations of `SimdValue` and `SimdRealField` for `Vec3A` and `Vec4` so they
+  can be used with `simba` generic code. Enables `num-traits` and requires `std`
+* `speedy` - implementations of `Readable` and `Writable` for all `glam` types.
+  Types are encoded as arrays of their components, matrices and affine transforms
+  are encoded in column-major order. Requires `std`
 * `scalar-math` - disables SIMD support and uses native alignment for all types.
 * `debug-glam-assert` - adds assertions in debug builds which check the validity of parameters
   passed to `glam` to help catch runtime errors.
@@ -243,6 +272,12 @@ and benchmarks.
   optimizations that may not be identical to other platforms. **Intermediate
   libraries should not use this feature and defer the decision to the final
   binary build**.
+* `deterministic` - guarantees bit-for-bit identical results on all platforms by using `libm`
+  for all floating point functions and disabling SIMD, since SIMD implementations may perform
+  operations in a different order or use approximations. This enables the `libm` and
+  `scalar-math` features and so also changes the alignment of some types. It takes precedence
+  over `fast-math`, which has no effect when both are enabled. Targets which do not use IEEE 754
+  single and double precision arithmetic, such as x86 without SSE2, are not supported.
 * `core-simd` - enables SIMD support via the portable simd module. This is an
   unstable feature which requires a nightly Rust toolchain and `std` support.
 
@@ -271,7 +306,10 @@ The minimum supported Rust version is `1.68.2`.
 mod macros;
 
 mod align16;
+mod axis;
+mod cast;
 mod deref;
+mod display;
 mod euler;
 mod features;
 
@@ -311,6 +349,9 @@ pub use self::f32::*;
 pub mod f64;
 pub use self::f64::*;
 
+// Both `f32` and `f64` have a `geometry` module, the `f32` one is re-exported like the types.
+pub use self::f32::geometry;
+
 /** `i16` vector types. */
 pub mod i16;
 pub use self::i16::*;
@@ -342,6 +383,45 @@ pub use self::swizzles::{Vec2Swizzles, Vec3Swizzles, Vec4Swizzles};
 /** Rotation Helper */
 pub use euler::EulerRot;
 
+/** Named coordinate axes */
+pub use axis::Axis;
+
+/** Additional `rand` distributions for sampling transforms. */
+#[cfg(feature = "rand")]
+pub mod rand {
+    pub use crate::features::impl_rand::f32::{UniformTransform2, UniformTransform3};
+    pub use crate::features::impl_rand::f64::{DUniformTransform2, DUniformTransform3};
+}
+
+/** Alternative `serde` representations for use with `#[serde(with = "...")]`. */
+#[cfg(feature = "serde")]
+pub mod serde {
+    pub use crate::features::impl_serde::{compact, named, SerdeArray, SerdeNamed};
+}
+
 /** A trait for extending [`prim@f32`] and [`prim@f64`] with extra methods. */
 mod float;
 pub use float::FloatExt;
+
+/** An error type returned when parsing a `glam` type from a string. */
+mod parse;
+pub use parse::ParseError;
+
+mod total_ord;
+pub use total_ord::TotalOrd;
+
+mod hashable;
+pub use hashable::Hashable;
+
+#[cfg(not(target_arch = "spirv"))]
+pub mod assert;
+
+#[cfg(not(target_arch = "spirv"))]
+pub mod denormals;
+
+pub mod error;
+
+#[doc(hidden)]
+pub mod construct;
+
+pub mod packing;
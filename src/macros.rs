@@ -1,6 +1,30 @@
-#[cfg(any(
-    all(debug_assertions, feature = "debug-glam-assert"),
-    feature = "glam-assert"
+#[cfg(all(
+    any(
+        all(debug_assertions, feature = "debug-glam-assert"),
+        feature = "glam-assert"
+    ),
+    not(target_arch = "spirv")
+))]
+macro_rules! glam_assert {
+    ($cond:expr $(,)?) => {
+        let cond: bool = $cond;
+        if !cond {
+            crate::assert::fail(stringify!($cond), None);
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        let cond: bool = $cond;
+        if !cond {
+            crate::assert::fail(stringify!($cond), Some(format_args!($($arg)+)));
+        }
+    };
+}
+#[cfg(all(
+    any(
+        all(debug_assertions, feature = "debug-glam-assert"),
+        feature = "glam-assert"
+    ),
+    target_arch = "spirv"
 ))]
 macro_rules! glam_assert {
     ($($arg:tt)*) => ( assert!($($arg)*); )
@@ -0,0 +1,500 @@
+//! Functions for packing vectors into integers, matching the GLSL built-in functions of the same
+//! name.
+//!
+//! The first component of the vector is always stored in the least significant bits of the
+//! result. Normalized values are rounded to the nearest integer after clamping, so packing is
+//! bit-exact with GLSL implementations that round halfway cases away from zero. Half precision
+//! floats are rounded to nearest with halfway cases rounded to even.
+
+use crate::{Vec2, Vec3, Vec4};
+
+/// Converts each component of `v` to an 8-bit unsigned normalized integer and packs them into a
+/// `u32`.
+///
+/// Each component is computed as `round(clamp(c, 0.0, 1.0) * 255.0)`.
+#[inline]
+#[must_use]
+pub fn pack_unorm_4x8(v: Vec4) -> u32 {
+    let u = (v.clamp(Vec4::ZERO, Vec4::ONE) * 255.0).round().as_uvec4();
+    u.x | u.y << 8 | u.z << 16 | u.w << 24
+}
+
+/// Unpacks four 8-bit unsigned normalized integers from `p`, the inverse of [`pack_unorm_4x8`].
+///
+/// Each component is computed as `c / 255.0`.
+#[inline]
+#[must_use]
+pub fn unpack_unorm_4x8(p: u32) -> Vec4 {
+    Vec4::new(
+        (p & 0xff) as f32,
+        (p >> 8 & 0xff) as f32,
+        (p >> 16 & 0xff) as f32,
+        (p >> 24) as f32,
+    ) / 255.0
+}
+
+/// Converts each component of `v` to an 8-bit signed normalized integer and packs them into a
+/// `u32`.
+///
+/// Each component is computed as `round(clamp(c, -1.0, 1.0) * 127.0)`.
+#[inline]
+#[must_use]
+pub fn pack_snorm_4x8(v: Vec4) -> u32 {
+    let i = (v.clamp(Vec4::NEG_ONE, Vec4::ONE) * 127.0)
+        .round()
+        .as_ivec4();
+    (i.x as u8 as u32)
+        | (i.y as u8 as u32) << 8
+        | (i.z as u8 as u32) << 16
+        | (i.w as u8 as u32) << 24
+}
+
+/// Unpacks four 8-bit signed normalized integers from `p`, the inverse of [`pack_snorm_4x8`].
+///
+/// Each component is computed as `clamp(c / 127.0, -1.0, 1.0)`.
+#[inline]
+#[must_use]
+pub fn unpack_snorm_4x8(p: u32) -> Vec4 {
+    (Vec4::new(
+        p as i8 as f32,
+        (p >> 8) as i8 as f32,
+        (p >> 16) as i8 as f32,
+        (p >> 24) as i8 as f32,
+    ) / 127.0)
+        .max(Vec4::NEG_ONE)
+}
+
+/// Converts each component of `v` to a 16-bit unsigned normalized integer and packs them into a
+/// `u32`.
+///
+/// Each component is computed as `round(clamp(c, 0.0, 1.0) * 65535.0)`.
+#[inline]
+#[must_use]
+pub fn pack_unorm_2x16(v: Vec2) -> u32 {
+    let u = (v.clamp(Vec2::ZERO, Vec2::ONE) * 65535.0)
+        .round()
+        .as_uvec2();
+    u.x | u.y << 16
+}
+
+/// Unpacks two 16-bit unsigned normalized integers from `p`, the inverse of [`pack_unorm_2x16`].
+///
+/// Each component is computed as `c / 65535.0`.
+#[inline]
+#[must_use]
+pub fn unpack_unorm_2x16(p: u32) -> Vec2 {
+    Vec2::new((p & 0xffff) as f32, (p >> 16) as f32) / 65535.0
+}
+
+/// Converts each component of `v` to a 16-bit signed normalized integer and packs them into a
+/// `u32`.
+///
+/// Each component is computed as `round(clamp(c, -1.0, 1.0) * 32767.0)`.
+#[inline]
+#[must_use]
+pub fn pack_snorm_2x16(v: Vec2) -> u32 {
+    let i = (v.clamp(Vec2::NEG_ONE, Vec2::ONE) * 32767.0)
+        .round()
+        .as_ivec2();
+    (i.x as u16 as u32) | (i.y as u16 as u32) << 16
+}
+
+/// Unpacks two 16-bit signed normalized integers from `p`, the inverse of [`pack_snorm_2x16`].
+///
+/// Each component is computed as `clamp(c / 32767.0, -1.0, 1.0)`.
+#[inline]
+#[must_use]
+pub fn unpack_snorm_2x16(p: u32) -> Vec2 {
+    (Vec2::new(p as i16 as f32, (p >> 16) as i16 as f32) / 32767.0).max(Vec2::NEG_ONE)
+}
+
+/// Converts the components of `v` to 10-bit unsigned normalized integers for `x`, `y` and `z`
+/// and a 2-bit unsigned normalized integer for `w`, packed into a `u32`.
+///
+/// `x` is stored in the least significant bits and `w` in the two most significant bits, matching
+/// the `GL_UNSIGNED_INT_2_10_10_10_REV` and `DXGI_FORMAT_R10G10B10A2_UNORM` formats. Each
+/// component is computed as `round(clamp(c, 0.0, 1.0) * max)` where `max` is `1023.0` or `3.0`.
+#[inline]
+#[must_use]
+pub fn pack_unorm_10_10_10_2(v: Vec4) -> u32 {
+    let u = (v.clamp(Vec4::ZERO, Vec4::ONE) * Vec4::new(1023.0, 1023.0, 1023.0, 3.0))
+        .round()
+        .as_uvec4();
+    u.x | u.y << 10 | u.z << 20 | u.w << 30
+}
+
+/// Unpacks three 10-bit and one 2-bit unsigned normalized integers from `p`, the inverse of
+/// [`pack_unorm_10_10_10_2`].
+#[inline]
+#[must_use]
+pub fn unpack_unorm_10_10_10_2(p: u32) -> Vec4 {
+    Vec4::new(
+        (p & 0x3ff) as f32,
+        (p >> 10 & 0x3ff) as f32,
+        (p >> 20 & 0x3ff) as f32,
+        (p >> 30) as f32,
+    ) / Vec4::new(1023.0, 1023.0, 1023.0, 3.0)
+}
+
+/// Converts the components of `v` to 10-bit signed normalized integers for `x`, `y` and `z` and
+/// a 2-bit signed normalized integer for `w`, packed into a `u32`.
+///
+/// `x` is stored in the least significant bits and `w` in the two most significant bits, matching
+/// the `GL_INT_2_10_10_10_REV` format. Each component is computed as
+/// `round(clamp(c, -1.0, 1.0) * max)` where `max` is `511.0` or `1.0`.
+#[inline]
+#[must_use]
+pub fn pack_snorm_10_10_10_2(v: Vec4) -> u32 {
+    let i = (v.clamp(Vec4::NEG_ONE, Vec4::ONE) * Vec4::new(511.0, 511.0, 511.0, 1.0))
+        .round()
+        .as_ivec4();
+    (i.x as u32 & 0x3ff)
+        | (i.y as u32 & 0x3ff) << 10
+        | (i.z as u32 & 0x3ff) << 20
+        | (i.w as u32) << 30
+}
+
+/// Unpacks three 10-bit and one 2-bit signed normalized integers from `p`, the inverse of
+/// [`pack_snorm_10_10_10_2`].
+///
+/// Each component is computed as `clamp(c / max, -1.0, 1.0)` where `max` is `511.0` or `1.0`.
+#[inline]
+#[must_use]
+pub fn unpack_snorm_10_10_10_2(p: u32) -> Vec4 {
+    // shift each field to the top of an i32 and back down to sign extend it
+    let p = p as i32;
+    (Vec4::new(
+        (p << 22 >> 22) as f32,
+        (p << 12 >> 22) as f32,
+        (p << 2 >> 22) as f32,
+        (p >> 30) as f32,
+    ) / Vec4::new(511.0, 511.0, 511.0, 1.0))
+    .max(Vec4::NEG_ONE)
+}
+
+/// Converts the absolute value of `f` to the nearest float with a 5-bit exponent and `man_bits`
+/// bits of mantissa, rounding halfway cases to even.
+///
+/// Values too large become infinity, `NaN` is preserved as a quiet `NaN`.
+#[inline]
+fn f32_to_small_float_bits(f: f32, man_bits: u32) -> u32 {
+    let x = f.to_bits();
+    let exp = (x >> 23) & 0xff;
+    let man = x & 0x7f_ffff;
+    let drop = 23 - man_bits;
+    let inf = 0x1f << man_bits;
+    if exp == 0xff {
+        let nan = if man != 0 {
+            (1 << (man_bits - 1)) | (man >> drop)
+        } else {
+            0
+        };
+        return inf | nan;
+    }
+    let small_exp = exp as i32 - 127 + 15;
+    if small_exp >= 0x1f {
+        return inf;
+    }
+    let (h, shift) = if small_exp <= 0 {
+        // subnormal, include the implicit leading bit
+        let shift = drop + (1 - small_exp) as u32;
+        if shift > 24 {
+            return 0;
+        }
+        ((man | 0x80_0000) >> shift, shift)
+    } else {
+        (((small_exp as u32) << man_bits) | (man >> drop), drop)
+    };
+    let rem = (man | 0x80_0000) & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    // a carry out of the mantissa correctly increments the exponent
+    if rem > halfway || (rem == halfway && h & 1 != 0) {
+        h + 1
+    } else {
+        h
+    }
+}
+
+/// Converts the bits of a float with a 5-bit exponent and `man_bits` bits of mantissa to a
+/// positive `f32`, this is always exact.
+#[inline]
+fn small_float_bits_to_f32(h: u32, man_bits: u32) -> f32 {
+    let exp = (h >> man_bits) & 0x1f;
+    let man = h & ((1 << man_bits) - 1);
+    let drop = 23 - man_bits;
+    match exp {
+        // man * 2^-(14 + man_bits)
+        0 => man as f32 * f32::from_bits((127 - 14 - man_bits) << 23),
+        0x1f => f32::from_bits(0x7f80_0000 | (man << drop)),
+        _ => f32::from_bits(((exp + 112) << 23) | (man << drop)),
+    }
+}
+
+/// Converts `f` to the bits of the nearest IEEE 754 half precision float, rounding halfway cases
+/// to even.
+///
+/// Values too large for a half float become infinity, `NaN` is preserved as a quiet `NaN`.
+#[inline]
+#[must_use]
+pub(crate) fn f32_to_f16_bits(f: f32) -> u16 {
+    let sign = (f.to_bits() >> 16) & 0x8000;
+    (sign | f32_to_small_float_bits(f, 10)) as u16
+}
+
+/// Converts the bits of an IEEE 754 half precision float to `f32`, this is always exact.
+#[inline]
+#[must_use]
+pub(crate) fn f16_bits_to_f32(h: u16) -> f32 {
+    let sign = ((h as u32) & 0x8000) << 16;
+    f32::from_bits(sign | small_float_bits_to_f32(h as u32, 10).to_bits())
+}
+
+/// Converts `f` to an unsigned float with a 5-bit exponent and `man_bits` bits of mantissa.
+///
+/// Negative values become zero.
+#[inline]
+fn f32_to_unsigned_small_float_bits(f: f32, man_bits: u32) -> u32 {
+    if f.is_sign_negative() && !f.is_nan() {
+        0
+    } else {
+        f32_to_small_float_bits(f, man_bits)
+    }
+}
+
+/// Converts each component of `v` to a half precision float and packs them into a `u32`.
+///
+/// Components are rounded to the nearest half float with halfway cases rounded to even.
+#[inline]
+#[must_use]
+pub fn pack_half_2x16(v: Vec2) -> u32 {
+    f32_to_f16_bits(v.x) as u32 | (f32_to_f16_bits(v.y) as u32) << 16
+}
+
+/// Unpacks two half precision floats from `p`, the inverse of [`pack_half_2x16`].
+#[inline]
+#[must_use]
+pub fn unpack_half_2x16(p: u32) -> Vec2 {
+    Vec2::new(f16_bits_to_f32(p as u16), f16_bits_to_f32((p >> 16) as u16))
+}
+
+/// Converts each component of `v` to a half precision float and packs them into a `u64`.
+///
+/// Components are rounded to the nearest half float with halfway cases rounded to even.
+#[inline]
+#[must_use]
+pub fn pack_half_4x16(v: Vec4) -> u64 {
+    pack_half_2x16(Vec2::new(v.x, v.y)) as u64 | (pack_half_2x16(Vec2::new(v.z, v.w)) as u64) << 32
+}
+
+/// Unpacks four half precision floats from `p`, the inverse of [`pack_half_4x16`].
+#[inline]
+#[must_use]
+pub fn unpack_half_4x16(p: u64) -> Vec4 {
+    let xy = unpack_half_2x16(p as u32);
+    let zw = unpack_half_2x16((p >> 32) as u32);
+    Vec4::new(xy.x, xy.y, zw.x, zw.y)
+}
+
+/// Converts each component of `v` to an unsigned small float and packs them into a `u32` using
+/// the `R11G11B10` format.
+///
+/// `x` and `y` are stored as 11-bit floats with 6 mantissa bits in the least significant bits and
+/// `z` as a 10-bit float with 5 mantissa bits in the most significant bits, all with a 5-bit
+/// exponent. This matches the `DXGI_FORMAT_R11G11B10_FLOAT` and `GL_R11F_G11F_B10F` formats.
+///
+/// Components are rounded to the nearest representable value with halfway cases rounded to even,
+/// negative values become zero and values too large become infinity.
+#[inline]
+#[must_use]
+pub fn pack_r11g11b10(v: Vec3) -> u32 {
+    f32_to_unsigned_small_float_bits(v.x, 6)
+        | f32_to_unsigned_small_float_bits(v.y, 6) << 11
+        | f32_to_unsigned_small_float_bits(v.z, 5) << 22
+}
+
+/// Unpacks three unsigned small floats from `p`, the inverse of [`pack_r11g11b10`].
+#[inline]
+#[must_use]
+pub fn unpack_r11g11b10(p: u32) -> Vec3 {
+    Vec3::new(
+        small_float_bits_to_f32(p & 0x7ff, 6),
+        small_float_bits_to_f32((p >> 11) & 0x7ff, 6),
+        small_float_bits_to_f32(p >> 22, 5),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unorm_4x8() {
+        assert_eq!(pack_unorm_4x8(Vec4::new(0.0, 1.0, 0.5, 0.25)), 0x4080_ff00);
+        assert_eq!(
+            pack_unorm_4x8(Vec4::new(-1.0, 2.0, f32::NAN, 1.0)),
+            0xff00_ff00
+        );
+        assert_eq!(
+            unpack_unorm_4x8(0x4080_ff00),
+            Vec4::new(0.0, 1.0, 128.0 / 255.0, 64.0 / 255.0)
+        );
+        for p in [0, 1, 0x1234_5678, 0x8080_8080, u32::MAX] {
+            assert_eq!(pack_unorm_4x8(unpack_unorm_4x8(p)), p);
+        }
+    }
+
+    #[test]
+    fn test_snorm_4x8() {
+        assert_eq!(pack_snorm_4x8(Vec4::new(0.0, 1.0, -1.0, 0.5)), 0x4081_7f00);
+        assert_eq!(pack_snorm_4x8(Vec4::new(-2.0, 2.0, -0.5, 0.0)), 0x00c0_7f81);
+        assert_eq!(
+            unpack_snorm_4x8(0x4081_7f00),
+            Vec4::new(0.0, 1.0, -1.0, 64.0 / 127.0)
+        );
+        // -128 is clamped to -1.0
+        assert_eq!(unpack_snorm_4x8(0x80), Vec4::new(-1.0, 0.0, 0.0, 0.0));
+        for p in [0, 1, 0x1234_5678, 0x8181_8181, 0x7f7f_7f7f] {
+            assert_eq!(pack_snorm_4x8(unpack_snorm_4x8(p)), p);
+        }
+    }
+
+    #[test]
+    fn test_unorm_2x16() {
+        assert_eq!(pack_unorm_2x16(Vec2::new(1.0, 0.5)), 0x8000_ffff);
+        assert_eq!(pack_unorm_2x16(Vec2::new(-1.0, 2.0)), 0xffff_0000);
+        assert_eq!(unpack_unorm_2x16(0xffff_0000), Vec2::new(0.0, 1.0));
+        for p in [0, 1, 0x1234_5678, 0x8000_8000, u32::MAX] {
+            assert_eq!(pack_unorm_2x16(unpack_unorm_2x16(p)), p);
+        }
+    }
+
+    #[test]
+    fn test_snorm_2x16() {
+        assert_eq!(pack_snorm_2x16(Vec2::new(1.0, -1.0)), 0x8001_7fff);
+        assert_eq!(pack_snorm_2x16(Vec2::new(-2.0, 0.5)), 0x4000_8001);
+        assert_eq!(unpack_snorm_2x16(0x8001_7fff), Vec2::new(1.0, -1.0));
+        assert_eq!(unpack_snorm_2x16(0x8000), Vec2::new(-1.0, 0.0));
+        for p in [0, 1, 0x1234_5678, 0x8001_8001, 0x7fff_7fff] {
+            assert_eq!(pack_snorm_2x16(unpack_snorm_2x16(p)), p);
+        }
+    }
+
+    #[test]
+    fn test_half_conversion() {
+        // every half float survives a round trip through f32
+        for h in 0..=u16::MAX {
+            let f = f16_bits_to_f32(h);
+            if f.is_nan() {
+                assert!(f16_bits_to_f32(f32_to_f16_bits(f)).is_nan());
+            } else {
+                assert_eq!(f32_to_f16_bits(f), h);
+            }
+        }
+        assert_eq!(f16_bits_to_f32(0x3c00), 1.0);
+        assert_eq!(f16_bits_to_f32(0x7bff), 65504.0);
+        assert_eq!(f16_bits_to_f32(0x0001), 2.0_f32.powi(-24));
+        assert_eq!(f16_bits_to_f32(0x8000).to_bits(), (-0.0_f32).to_bits());
+        // halfway cases round to even
+        assert_eq!(f32_to_f16_bits(1.0 + 2.0_f32.powi(-11)), 0x3c00);
+        assert_eq!(f32_to_f16_bits(1.0 + 3.0 * 2.0_f32.powi(-11)), 0x3c02);
+        assert_eq!(f32_to_f16_bits(65519.0), 0x7bff);
+        assert_eq!(f32_to_f16_bits(65520.0), 0x7c00);
+        assert_eq!(f32_to_f16_bits(2.0_f32.powi(-25)), 0x0000);
+        assert_eq!(f32_to_f16_bits(3.0 * 2.0_f32.powi(-25)), 0x0002);
+        assert_eq!(f32_to_f16_bits(2.0_f32.powi(-26)), 0x0000);
+        // subnormal rounding up to the smallest normal
+        assert_eq!(
+            f32_to_f16_bits(2.0_f32.powi(-14) - 2.0_f32.powi(-26)),
+            0x0400
+        );
+        assert_eq!(f32_to_f16_bits(-1e10), 0xfc00);
+        assert_eq!(f32_to_f16_bits(f32::INFINITY), 0x7c00);
+        assert_eq!(f32_to_f16_bits(f32::NAN) & 0x7e00, 0x7e00);
+    }
+
+    #[test]
+    fn test_half_2x16() {
+        assert_eq!(pack_half_2x16(Vec2::new(1.0, -2.0)), 0xc000_3c00);
+        assert_eq!(unpack_half_2x16(0xc000_3c00), Vec2::new(1.0, -2.0));
+        assert_eq!(
+            pack_half_4x16(Vec4::new(1.0, -2.0, 0.5, 65504.0)),
+            0x7bff_3800_c000_3c00
+        );
+        assert_eq!(
+            unpack_half_4x16(0x7bff_3800_c000_3c00),
+            Vec4::new(1.0, -2.0, 0.5, 65504.0)
+        );
+    }
+
+    #[test]
+    fn test_r11g11b10() {
+        // every representable value survives a round trip through f32
+        for p in (0..1 << 11).chain((0..1 << 10).map(|b| b << 22)) {
+            let v = unpack_r11g11b10(p);
+            if !v.is_nan() {
+                assert_eq!(pack_r11g11b10(v), p);
+            }
+        }
+        assert_eq!(
+            unpack_r11g11b10(pack_r11g11b10(Vec3::new(1.0, 0.5, 64600.0))),
+            Vec3::new(1.0, 0.5, 64512.0)
+        );
+        assert_eq!(pack_r11g11b10(Vec3::new(1.0, 0.0, 0.0)), 0x3c0);
+        assert_eq!(pack_r11g11b10(Vec3::new(0.0, 1.0, 0.0)), 0x3c0 << 11);
+        assert_eq!(pack_r11g11b10(Vec3::new(0.0, 0.0, 1.0)), 0x1e0 << 22);
+        assert_eq!(pack_r11g11b10(Vec3::new(65024.0, 0.0, 0.0)), 0x7bf);
+        assert_eq!(pack_r11g11b10(Vec3::new(1e6, 0.0, 0.0)), 0x7c0);
+        // 1 + 1/128 is halfway between 1.0 and the next 11-bit float
+        assert_eq!(
+            pack_r11g11b10(Vec3::new(1.0 + 1.0 / 128.0, 0.0, 0.0)),
+            0x3c0
+        );
+        assert_eq!(pack_r11g11b10(Vec3::new(-1.0, -0.0, -1e6)), 0);
+        let nan = unpack_r11g11b10(pack_r11g11b10(Vec3::new(f32::NAN, 1.0, 1.0)));
+        assert!(nan.x.is_nan());
+        assert_eq!(unpack_r11g11b10(1), Vec3::new(2.0_f32.powi(-20), 0.0, 0.0));
+        assert_eq!(
+            unpack_r11g11b10(1 << 22),
+            Vec3::new(0.0, 0.0, 2.0_f32.powi(-19))
+        );
+    }
+
+    #[test]
+    fn test_unorm_10_10_10_2() {
+        assert_eq!(
+            pack_unorm_10_10_10_2(Vec4::new(1.0, 0.0, 0.5, 1.0)),
+            0xe000_03ff
+        );
+        assert_eq!(
+            pack_unorm_10_10_10_2(Vec4::new(-1.0, 2.0, 0.0, 0.5)),
+            0x800f_fc00
+        );
+        assert_eq!(
+            unpack_unorm_10_10_10_2(0xe000_03ff),
+            Vec4::new(1.0, 0.0, 512.0 / 1023.0, 1.0)
+        );
+        for p in [0, 1, 0x1234_5678, 0x4010_0401, u32::MAX] {
+            assert_eq!(pack_unorm_10_10_10_2(unpack_unorm_10_10_10_2(p)), p);
+        }
+    }
+
+    #[test]
+    fn test_snorm_10_10_10_2() {
+        assert_eq!(
+            pack_snorm_10_10_10_2(Vec4::new(1.0, -1.0, 0.0, -1.0)),
+            0xc008_05ff
+        );
+        assert_eq!(
+            unpack_snorm_10_10_10_2(0xc008_05ff),
+            Vec4::new(1.0, -1.0, 0.0, -1.0)
+        );
+        // the most negative values are clamped to -1.0
+        assert_eq!(
+            unpack_snorm_10_10_10_2(0x8000_0200),
+            Vec4::new(-1.0, 0.0, 0.0, -1.0)
+        );
+        for p in [0, 1, 0x1234_5678, 0x4010_0401, 0x7fdf_f7fd] {
+            assert_eq!(pack_snorm_10_10_10_2(unpack_snorm_10_10_10_2(p)), p);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use core::fmt;
+use core::str::FromStr;
+
+/// An error which can be returned when parsing a `glam` type from a string.
+///
+/// The accepted input format is the output of the `Display` and `Debug` implementations, e.g.
+/// `"[1, 2, 3]"` or `"Vec3(1.0, 2.0, 3.0)"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input has mismatched brackets or a missing component between separators.
+    InvalidSyntax,
+    /// A component could not be parsed as the scalar type.
+    InvalidComponent,
+    /// The input does not contain the expected number of components.
+    WrongComponentCount {
+        /// The number of components the type requires.
+        expected: usize,
+        /// The number of components that were found in the input.
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::InvalidSyntax => f.write_str("invalid syntax"),
+            Self::InvalidComponent => f.write_str("invalid component"),
+            Self::WrongComponentCount { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Parses `N` comma separated scalar values from `s`.
+///
+/// Brackets of any kind may be used for grouping and are required to be balanced. Type names
+/// which are followed by an opening bracket and field names which are followed by a `:` are
+/// ignored and trailing commas are allowed so that `Debug` output may also be parsed.
+pub(crate) fn parse_array<T, const N: usize>(s: &str) -> Result<[T; N], ParseError>
+where
+    T: Copy + Default + FromStr,
+{
+    let mut out = [T::default(); N];
+    let mut found = 0;
+    let mut depth = [0_u8; 16];
+    let mut depth_len = 0;
+    // true if a component or group is expected before the next separator
+    let mut expect_value = true;
+    let mut after_comma = false;
+    let mut rest = s;
+
+    loop {
+        rest = rest.trim_start();
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+        match c {
+            '[' | '(' | '{' => {
+                if !expect_value || depth_len == depth.len() {
+                    return Err(ParseError::InvalidSyntax);
+                }
+                depth[depth_len] = c as u8;
+                depth_len += 1;
+                rest = &rest[1..];
+            }
+            ']' | ')' | '}' => {
+                let open = match c {
+                    ']' => b'[',
+                    ')' => b'(',
+                    _ => b'{',
+                };
+                if depth_len == 0 || depth[depth_len - 1] != open {
+                    return Err(ParseError::InvalidSyntax);
+                }
+                depth_len -= 1;
+                expect_value = false;
+                rest = &rest[1..];
+            }
+            ',' => {
+                if expect_value {
+                    return Err(ParseError::InvalidSyntax);
+                }
+                expect_value = true;
+                after_comma = true;
+                rest = &rest[1..];
+                continue;
+            }
+            _ => {
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || "[](){},:".contains(c))
+                    .unwrap_or(rest.len());
+                let token = &rest[..end];
+                let next = rest[end..].trim_start();
+                if let Some(after) = next.strip_prefix(':') {
+                    // a field name, skip the name and the `:`
+                    rest = after;
+                } else if next.starts_with(['(', '{'])
+                    && token.starts_with(|c: char| c.is_ascii_alphabetic())
+                {
+                    // a type name, the following group holds the components
+                    rest = next;
+                } else {
+                    if !expect_value || token.is_empty() {
+                        return Err(ParseError::InvalidSyntax);
+                    }
+                    if found < N {
+                        out[found] = token.parse().map_err(|_| ParseError::InvalidComponent)?;
+                    }
+                    found += 1;
+                    expect_value = false;
+                    rest = &rest[end..];
+                }
+            }
+        }
+        after_comma = false;
+    }
+
+    if depth_len != 0 || after_comma {
+        return Err(ParseError::InvalidSyntax);
+    }
+    if found != N {
+        return Err(ParseError::WrongComponentCount { expected: N, found });
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_array, ParseError};
+
+    #[test]
+    fn test_parse_array() {
+        assert_eq!(Ok([1.0, 2.0, 3.0]), parse_array::<f32, 3>("[1, 2, 3]"));
+        assert_eq!(Ok([1.0, 2.0, 3.0]), parse_array::<f32, 3>("1,2,3"));
+        assert_eq!(Ok([1, -2]), parse_array::<i32, 2>("IVec2(1, -2)"));
+        assert_eq!(
+            Ok([1.0, 2.0, 3.0, 4.0]),
+            parse_array::<f64, 4>("DMat2 { x_axis: DVec2(1.0, 2.0), y_axis: DVec2(3.0, 4.0) }")
+        );
+        assert_eq!(
+            Ok([1.0, 2.0, 3.0, 4.0]),
+            parse_array::<f64, 4>("[[1, 2], [3, 4]]")
+        );
+        // pretty printed `Debug` output has trailing commas
+        assert_eq!(
+            Ok([1, 2]),
+            parse_array::<u8, 2>("U8Vec2(\n    1,\n    2,\n)")
+        );
+        let a = parse_array::<f32, 3>("[inf, -inf, NaN]").unwrap();
+        assert!(a[0] == f32::INFINITY && a[1] == f32::NEG_INFINITY && a[2].is_nan());
+
+        assert_eq!(
+            Err(ParseError::WrongComponentCount {
+                expected: 3,
+                found: 2
+            }),
+            parse_array::<f32, 3>("[1, 2]")
+        );
+        assert_eq!(
+            Err(ParseError::WrongComponentCount {
+                expected: 2,
+                found: 3
+            }),
+            parse_array::<f32, 2>("[1, 2, 3]")
+        );
+        assert_eq!(
+            Err(ParseError::WrongComponentCount {
+                expected: 2,
+                found: 0
+            }),
+            parse_array::<f32, 2>("")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidComponent),
+            parse_array::<u32, 2>("[1, -2]")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidComponent),
+            parse_array::<f32, 2>("[1, a]")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidSyntax),
+            parse_array::<f32, 2>("[1, 2")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidSyntax),
+            parse_array::<f32, 2>("[1, 2)")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidSyntax),
+            parse_array::<f32, 2>("[1,, 2]")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidSyntax),
+            parse_array::<f32, 2>("[1 2]")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidSyntax),
+            parse_array::<f32, 2>("1, 2,")
+        );
+    }
+}
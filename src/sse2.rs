@@ -79,6 +79,45 @@ pub(crate) unsafe fn dot4_into_m128(lhs: __m128, rhs: __m128) -> __m128 {
     _mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00)
 }
 
+/// Returns the x, y and z axes of the rotation matrix of the normalized quaternion `q`, with the w
+/// lane of each axis set to zero.
+///
+/// The products and sums are computed in the same order as the scalar implementation, so the
+/// result is identical to it.
+#[inline]
+pub(crate) unsafe fn m128_quat_to_axes(q: __m128) -> [__m128; 3] {
+    const TWO_XYZ: __m128 = m128_from_f32x4([2.0, 2.0, 2.0, 0.0]);
+    const ONE_XYZ: __m128 = m128_from_f32x4([1.0, 1.0, 1.0, 0.0]);
+    // 2x 2y 2z 0
+    let q2 = _mm_mul_ps(q, TWO_XYZ);
+    // 2xx 2yy 2zz 0
+    let sq = _mm_mul_ps(q, q2);
+    let diag = _mm_sub_ps(
+        ONE_XYZ,
+        _mm_add_ps(
+            _mm_shuffle_ps(sq, sq, 0b11_00_00_01),
+            _mm_shuffle_ps(sq, sq, 0b11_01_10_10),
+        ),
+    );
+    // 2xy 2xz 2yz 0
+    let v0 = _mm_mul_ps(
+        _mm_shuffle_ps(q, q, 0b11_01_00_00),
+        _mm_shuffle_ps(q2, q2, 0b11_10_10_01),
+    );
+    // 2wz 2wy 2wx 0
+    let v1 = _mm_mul_ps(
+        _mm_shuffle_ps(q, q, 0b11_11_11_11),
+        _mm_shuffle_ps(q2, q2, 0b11_00_01_10),
+    );
+    let r1 = _mm_add_ps(v0, v1);
+    let r2 = _mm_sub_ps(v0, v1);
+    [
+        _mm_shuffle_ps(_mm_unpacklo_ps(diag, r1), r2, 0b11_01_01_00),
+        _mm_shuffle_ps(_mm_shuffle_ps(r2, diag, 0b01_01_00_00), r1, 0b11_10_10_00),
+        _mm_shuffle_ps(_mm_shuffle_ps(r1, r2, 0b10_10_01_01), diag, 0b11_10_10_00),
+    ]
+}
+
 #[inline]
 pub(crate) unsafe fn m128_floor(v: __m128) -> __m128 {
     // Based on https://github.com/microsoft/DirectXMath `XMVectorFloor`
@@ -126,14 +165,22 @@ pub(crate) unsafe fn m128_abs(v: __m128) -> __m128 {
 
 #[inline(always)]
 pub(crate) unsafe fn m128_mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
-    // Only enable fused multiply-adds here if "fast-math" is enabled and the
-    // platform supports it. Otherwise this may break cross-platform determinism.
-    #[cfg(all(feature = "fast-math", target_feature = "fma"))]
+    // Only enable fused multiply-adds here if "fast-math" is enabled, "deterministic" is not and
+    // the platform supports it. Otherwise this may break cross-platform determinism.
+    #[cfg(all(
+        feature = "fast-math",
+        not(feature = "deterministic"),
+        target_feature = "fma"
+    ))]
     {
         _mm_fmadd_ps(a, b, c)
     }
 
-    #[cfg(any(not(feature = "fast-math"), not(target_feature = "fma")))]
+    #[cfg(any(
+        not(feature = "fast-math"),
+        feature = "deterministic",
+        not(target_feature = "fma")
+    ))]
     {
         _mm_add_ps(_mm_mul_ps(a, b), c)
     }
@@ -235,6 +282,60 @@ pub(crate) unsafe fn m128_sin(v: __m128) -> __m128 {
     result
 }
 
+/// Loads a [`DVec2`](crate::DVec2) into a `__m128d` register.
+#[inline(always)]
+pub(crate) fn dvec2_to_m128d(v: crate::DVec2) -> __m128d {
+    // SAFETY: `DVec2` is `repr(C)` with two `f64` elements, the same size as `__m128d`
+    unsafe { core::mem::transmute::<crate::DVec2, __m128d>(v) }
+}
+
+/// Stores a `__m128d` register as a [`DVec2`](crate::DVec2).
+#[inline(always)]
+pub(crate) fn dvec2_from_m128d(v: __m128d) -> crate::DVec2 {
+    // SAFETY: see `dvec2_to_m128d`
+    unsafe { core::mem::transmute::<__m128d, crate::DVec2>(v) }
+}
+
+/// Loads the `xy` and `zw` pairs of a [`DVec4`](crate::DVec4) into two `__m128d` registers.
+#[inline(always)]
+pub(crate) fn dvec4_to_m128d(v: crate::DVec4) -> [__m128d; 2] {
+    // SAFETY: `DVec4` is `repr(C)` with four `f64` elements, the same size as `[__m128d; 2]`
+    unsafe { core::mem::transmute::<crate::DVec4, [__m128d; 2]>(v) }
+}
+
+/// Stores two `__m128d` registers holding the `xy` and `zw` pairs as a
+/// [`DVec4`](crate::DVec4).
+#[inline(always)]
+pub(crate) fn dvec4_from_m128d(v: [__m128d; 2]) -> crate::DVec4 {
+    // SAFETY: see `dvec4_to_m128d`
+    unsafe { core::mem::transmute::<[__m128d; 2], crate::DVec4>(v) }
+}
+
+/// Applies `f` to the `xy` and `zw` pairs of `lhs` and `rhs`.
+#[inline(always)]
+pub(crate) fn dvec4_map2(
+    lhs: crate::DVec4,
+    rhs: crate::DVec4,
+    f: impl Fn(__m128d, __m128d) -> __m128d,
+) -> crate::DVec4 {
+    let [a0, a1] = dvec4_to_m128d(lhs);
+    let [b0, b1] = dvec4_to_m128d(rhs);
+    dvec4_from_m128d([f(a0, b0), f(a1, b1)])
+}
+
+/// Calculates the vector 4 dot product of two `xy`, `zw` register pairs.
+///
+/// The products are summed in the same order as the scalar implementation, so the result is
+/// identical to it.
+#[inline]
+pub(crate) unsafe fn dot4_pd(lhs: [__m128d; 2], rhs: [__m128d; 2]) -> f64 {
+    let xy = _mm_mul_pd(lhs[0], rhs[0]);
+    let zw = _mm_mul_pd(lhs[1], rhs[1]);
+    let x_y = _mm_add_sd(xy, _mm_unpackhi_pd(xy, xy));
+    let xy_z = _mm_add_sd(x_y, zw);
+    _mm_cvtsd_f64(_mm_add_sd(xy_z, _mm_unpackhi_pd(zw, zw)))
+}
+
 #[test]
 fn test_sse2_m128_sin() {
     use crate::Vec4;
@@ -739,4 +739,59 @@ impl Vec3Swizzles for Vec3A {
     fn zzzz(self) -> Vec4 {
         Vec4(simd_swizzle!(self.0, [2, 2, 2, 2]))
     }
+
+    #[inline]
+    fn with_xy(self, rhs: Vec2) -> Self {
+        Self::new(rhs.x, rhs.y, self.z)
+    }
+
+    #[inline]
+    fn with_xz(self, rhs: Vec2) -> Self {
+        Self::new(rhs.x, self.y, rhs.y)
+    }
+
+    #[inline]
+    fn with_xzy(self, rhs: Vec3A) -> Self {
+        Self::new(rhs.x, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_yx(self, rhs: Vec2) -> Self {
+        Self::new(rhs.y, rhs.x, self.z)
+    }
+
+    #[inline]
+    fn with_yxz(self, rhs: Vec3A) -> Self {
+        Self::new(rhs.y, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_yz(self, rhs: Vec2) -> Self {
+        Self::new(self.x, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_yzx(self, rhs: Vec3A) -> Self {
+        Self::new(rhs.z, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zx(self, rhs: Vec2) -> Self {
+        Self::new(rhs.y, self.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_zxy(self, rhs: Vec3A) -> Self {
+        Self::new(rhs.y, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_zy(self, rhs: Vec2) -> Self {
+        Self::new(self.x, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_zyx(self, rhs: Vec3A) -> Self {
+        Self::new(rhs.z, rhs.y, rhs.x)
+    }
 }
@@ -2330,4 +2330,299 @@ impl Vec4Swizzles for Vec4 {
     fn wwww(self) -> Vec4 {
         Vec4(simd_swizzle!(self.0, [3, 3, 3, 3]))
     }
+
+    #[inline]
+    fn with_xy(self, rhs: Vec2) -> Self {
+        Self::new(rhs.x, rhs.y, self.z, self.w)
+    }
+
+    #[inline]
+    fn with_xyz(self, rhs: Vec3) -> Self {
+        Self::new(rhs.x, rhs.y, rhs.z, self.w)
+    }
+
+    #[inline]
+    fn with_xyw(self, rhs: Vec3) -> Self {
+        Self::new(rhs.x, rhs.y, self.z, rhs.z)
+    }
+
+    #[inline]
+    fn with_xywz(self, rhs: Vec4) -> Self {
+        Self::new(rhs.x, rhs.y, rhs.w, rhs.z)
+    }
+
+    #[inline]
+    fn with_xz(self, rhs: Vec2) -> Self {
+        Self::new(rhs.x, self.y, rhs.y, self.w)
+    }
+
+    #[inline]
+    fn with_xzy(self, rhs: Vec3) -> Self {
+        Self::new(rhs.x, rhs.z, rhs.y, self.w)
+    }
+
+    #[inline]
+    fn with_xzyw(self, rhs: Vec4) -> Self {
+        Self::new(rhs.x, rhs.z, rhs.y, rhs.w)
+    }
+
+    #[inline]
+    fn with_xzw(self, rhs: Vec3) -> Self {
+        Self::new(rhs.x, self.y, rhs.y, rhs.z)
+    }
+
+    #[inline]
+    fn with_xzwy(self, rhs: Vec4) -> Self {
+        Self::new(rhs.x, rhs.w, rhs.y, rhs.z)
+    }
+
+    #[inline]
+    fn with_xw(self, rhs: Vec2) -> Self {
+        Self::new(rhs.x, self.y, self.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_xwy(self, rhs: Vec3) -> Self {
+        Self::new(rhs.x, rhs.z, self.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_xwyz(self, rhs: Vec4) -> Self {
+        Self::new(rhs.x, rhs.z, rhs.w, rhs.y)
+    }
+
+    #[inline]
+    fn with_xwz(self, rhs: Vec3) -> Self {
+        Self::new(rhs.x, self.y, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_xwzy(self, rhs: Vec4) -> Self {
+        Self::new(rhs.x, rhs.w, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_yx(self, rhs: Vec2) -> Self {
+        Self::new(rhs.y, rhs.x, self.z, self.w)
+    }
+
+    #[inline]
+    fn with_yxz(self, rhs: Vec3) -> Self {
+        Self::new(rhs.y, rhs.x, rhs.z, self.w)
+    }
+
+    #[inline]
+    fn with_yxzw(self, rhs: Vec4) -> Self {
+        Self::new(rhs.y, rhs.x, rhs.z, rhs.w)
+    }
+
+    #[inline]
+    fn with_yxw(self, rhs: Vec3) -> Self {
+        Self::new(rhs.y, rhs.x, self.z, rhs.z)
+    }
+
+    #[inline]
+    fn with_yxwz(self, rhs: Vec4) -> Self {
+        Self::new(rhs.y, rhs.x, rhs.w, rhs.z)
+    }
+
+    #[inline]
+    fn with_yz(self, rhs: Vec2) -> Self {
+        Self::new(self.x, rhs.x, rhs.y, self.w)
+    }
+
+    #[inline]
+    fn with_yzx(self, rhs: Vec3) -> Self {
+        Self::new(rhs.z, rhs.x, rhs.y, self.w)
+    }
+
+    #[inline]
+    fn with_yzxw(self, rhs: Vec4) -> Self {
+        Self::new(rhs.z, rhs.x, rhs.y, rhs.w)
+    }
+
+    #[inline]
+    fn with_yzw(self, rhs: Vec3) -> Self {
+        Self::new(self.x, rhs.x, rhs.y, rhs.z)
+    }
+
+    #[inline]
+    fn with_yzwx(self, rhs: Vec4) -> Self {
+        Self::new(rhs.w, rhs.x, rhs.y, rhs.z)
+    }
+
+    #[inline]
+    fn with_yw(self, rhs: Vec2) -> Self {
+        Self::new(self.x, rhs.x, self.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_ywx(self, rhs: Vec3) -> Self {
+        Self::new(rhs.z, rhs.x, self.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_ywxz(self, rhs: Vec4) -> Self {
+        Self::new(rhs.z, rhs.x, rhs.w, rhs.y)
+    }
+
+    #[inline]
+    fn with_ywz(self, rhs: Vec3) -> Self {
+        Self::new(self.x, rhs.x, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_ywzx(self, rhs: Vec4) -> Self {
+        Self::new(rhs.w, rhs.x, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_zx(self, rhs: Vec2) -> Self {
+        Self::new(rhs.y, self.y, rhs.x, self.w)
+    }
+
+    #[inline]
+    fn with_zxy(self, rhs: Vec3) -> Self {
+        Self::new(rhs.y, rhs.z, rhs.x, self.w)
+    }
+
+    #[inline]
+    fn with_zxyw(self, rhs: Vec4) -> Self {
+        Self::new(rhs.y, rhs.z, rhs.x, rhs.w)
+    }
+
+    #[inline]
+    fn with_zxw(self, rhs: Vec3) -> Self {
+        Self::new(rhs.y, self.y, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_zxwy(self, rhs: Vec4) -> Self {
+        Self::new(rhs.y, rhs.w, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_zy(self, rhs: Vec2) -> Self {
+        Self::new(self.x, rhs.y, rhs.x, self.w)
+    }
+
+    #[inline]
+    fn with_zyx(self, rhs: Vec3) -> Self {
+        Self::new(rhs.z, rhs.y, rhs.x, self.w)
+    }
+
+    #[inline]
+    fn with_zyxw(self, rhs: Vec4) -> Self {
+        Self::new(rhs.z, rhs.y, rhs.x, rhs.w)
+    }
+
+    #[inline]
+    fn with_zyw(self, rhs: Vec3) -> Self {
+        Self::new(self.x, rhs.y, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_zywx(self, rhs: Vec4) -> Self {
+        Self::new(rhs.w, rhs.y, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_zw(self, rhs: Vec2) -> Self {
+        Self::new(self.x, self.y, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zwx(self, rhs: Vec3) -> Self {
+        Self::new(rhs.z, self.y, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zwxy(self, rhs: Vec4) -> Self {
+        Self::new(rhs.z, rhs.w, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zwy(self, rhs: Vec3) -> Self {
+        Self::new(self.x, rhs.z, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zwyx(self, rhs: Vec4) -> Self {
+        Self::new(rhs.w, rhs.z, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_wx(self, rhs: Vec2) -> Self {
+        Self::new(rhs.y, self.y, self.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wxy(self, rhs: Vec3) -> Self {
+        Self::new(rhs.y, rhs.z, self.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wxyz(self, rhs: Vec4) -> Self {
+        Self::new(rhs.y, rhs.z, rhs.w, rhs.x)
+    }
+
+    #[inline]
+    fn with_wxz(self, rhs: Vec3) -> Self {
+        Self::new(rhs.y, self.y, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wxzy(self, rhs: Vec4) -> Self {
+        Self::new(rhs.y, rhs.w, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wy(self, rhs: Vec2) -> Self {
+        Self::new(self.x, rhs.y, self.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wyx(self, rhs: Vec3) -> Self {
+        Self::new(rhs.z, rhs.y, self.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wyxz(self, rhs: Vec4) -> Self {
+        Self::new(rhs.z, rhs.y, rhs.w, rhs.x)
+    }
+
+    #[inline]
+    fn with_wyz(self, rhs: Vec3) -> Self {
+        Self::new(self.x, rhs.y, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wyzx(self, rhs: Vec4) -> Self {
+        Self::new(rhs.w, rhs.y, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wz(self, rhs: Vec2) -> Self {
+        Self::new(self.x, self.y, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_wzx(self, rhs: Vec3) -> Self {
+        Self::new(rhs.z, self.y, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_wzxy(self, rhs: Vec4) -> Self {
+        Self::new(rhs.z, rhs.w, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_wzy(self, rhs: Vec3) -> Self {
+        Self::new(self.x, rhs.z, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_wzyx(self, rhs: Vec4) -> Self {
+        Self::new(rhs.w, rhs.z, rhs.y, rhs.x)
+    }
 }
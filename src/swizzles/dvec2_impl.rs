@@ -218,4 +218,9 @@ impl Vec2Swizzles for DVec2 {
     fn yyyy(self) -> DVec4 {
         DVec4::new(self.y, self.y, self.y, self.y)
     }
+
+    #[inline]
+    fn with_yx(self, rhs: DVec2) -> Self {
+        Self::new(rhs.y, rhs.x)
+    }
 }
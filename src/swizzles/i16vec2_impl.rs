@@ -218,4 +218,9 @@ impl Vec2Swizzles for I16Vec2 {
     fn yyyy(self) -> I16Vec4 {
         I16Vec4::new(self.y, self.y, self.y, self.y)
     }
+
+    #[inline]
+    fn with_yx(self, rhs: I16Vec2) -> Self {
+        Self::new(rhs.y, rhs.x)
+    }
 }
@@ -218,4 +218,9 @@ impl Vec2Swizzles for I64Vec2 {
     fn yyyy(self) -> I64Vec4 {
         I64Vec4::new(self.y, self.y, self.y, self.y)
     }
+
+    #[inline]
+    fn with_yx(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.y, rhs.x)
+    }
 }
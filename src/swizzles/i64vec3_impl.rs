@@ -843,4 +843,59 @@ impl Vec3Swizzles for I64Vec3 {
     fn zzzz(self) -> I64Vec4 {
         I64Vec4::new(self.z, self.z, self.z, self.z)
     }
+
+    #[inline]
+    fn with_xy(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.x, rhs.y, self.z)
+    }
+
+    #[inline]
+    fn with_xz(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.x, self.y, rhs.y)
+    }
+
+    #[inline]
+    fn with_xzy(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.x, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_yx(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.y, rhs.x, self.z)
+    }
+
+    #[inline]
+    fn with_yxz(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.y, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_yz(self, rhs: I64Vec2) -> Self {
+        Self::new(self.x, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_yzx(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.z, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zx(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.y, self.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_zxy(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.y, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_zy(self, rhs: I64Vec2) -> Self {
+        Self::new(self.x, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_zyx(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.z, rhs.y, rhs.x)
+    }
 }
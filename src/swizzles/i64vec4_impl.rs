@@ -2326,4 +2326,299 @@ impl Vec4Swizzles for I64Vec4 {
     fn wwww(self) -> I64Vec4 {
         I64Vec4::new(self.w, self.w, self.w, self.w)
     }
+
+    #[inline]
+    fn with_xy(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.x, rhs.y, self.z, self.w)
+    }
+
+    #[inline]
+    fn with_xyz(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.x, rhs.y, rhs.z, self.w)
+    }
+
+    #[inline]
+    fn with_xyw(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.x, rhs.y, self.z, rhs.z)
+    }
+
+    #[inline]
+    fn with_xywz(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.x, rhs.y, rhs.w, rhs.z)
+    }
+
+    #[inline]
+    fn with_xz(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.x, self.y, rhs.y, self.w)
+    }
+
+    #[inline]
+    fn with_xzy(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.x, rhs.z, rhs.y, self.w)
+    }
+
+    #[inline]
+    fn with_xzyw(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.x, rhs.z, rhs.y, rhs.w)
+    }
+
+    #[inline]
+    fn with_xzw(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.x, self.y, rhs.y, rhs.z)
+    }
+
+    #[inline]
+    fn with_xzwy(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.x, rhs.w, rhs.y, rhs.z)
+    }
+
+    #[inline]
+    fn with_xw(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.x, self.y, self.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_xwy(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.x, rhs.z, self.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_xwyz(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.x, rhs.z, rhs.w, rhs.y)
+    }
+
+    #[inline]
+    fn with_xwz(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.x, self.y, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_xwzy(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.x, rhs.w, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_yx(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.y, rhs.x, self.z, self.w)
+    }
+
+    #[inline]
+    fn with_yxz(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.y, rhs.x, rhs.z, self.w)
+    }
+
+    #[inline]
+    fn with_yxzw(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.y, rhs.x, rhs.z, rhs.w)
+    }
+
+    #[inline]
+    fn with_yxw(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.y, rhs.x, self.z, rhs.z)
+    }
+
+    #[inline]
+    fn with_yxwz(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.y, rhs.x, rhs.w, rhs.z)
+    }
+
+    #[inline]
+    fn with_yz(self, rhs: I64Vec2) -> Self {
+        Self::new(self.x, rhs.x, rhs.y, self.w)
+    }
+
+    #[inline]
+    fn with_yzx(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.z, rhs.x, rhs.y, self.w)
+    }
+
+    #[inline]
+    fn with_yzxw(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.z, rhs.x, rhs.y, rhs.w)
+    }
+
+    #[inline]
+    fn with_yzw(self, rhs: I64Vec3) -> Self {
+        Self::new(self.x, rhs.x, rhs.y, rhs.z)
+    }
+
+    #[inline]
+    fn with_yzwx(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.w, rhs.x, rhs.y, rhs.z)
+    }
+
+    #[inline]
+    fn with_yw(self, rhs: I64Vec2) -> Self {
+        Self::new(self.x, rhs.x, self.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_ywx(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.z, rhs.x, self.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_ywxz(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.z, rhs.x, rhs.w, rhs.y)
+    }
+
+    #[inline]
+    fn with_ywz(self, rhs: I64Vec3) -> Self {
+        Self::new(self.x, rhs.x, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_ywzx(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.w, rhs.x, rhs.z, rhs.y)
+    }
+
+    #[inline]
+    fn with_zx(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.y, self.y, rhs.x, self.w)
+    }
+
+    #[inline]
+    fn with_zxy(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.y, rhs.z, rhs.x, self.w)
+    }
+
+    #[inline]
+    fn with_zxyw(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.y, rhs.z, rhs.x, rhs.w)
+    }
+
+    #[inline]
+    fn with_zxw(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.y, self.y, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_zxwy(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.y, rhs.w, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_zy(self, rhs: I64Vec2) -> Self {
+        Self::new(self.x, rhs.y, rhs.x, self.w)
+    }
+
+    #[inline]
+    fn with_zyx(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.z, rhs.y, rhs.x, self.w)
+    }
+
+    #[inline]
+    fn with_zyxw(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.z, rhs.y, rhs.x, rhs.w)
+    }
+
+    #[inline]
+    fn with_zyw(self, rhs: I64Vec3) -> Self {
+        Self::new(self.x, rhs.y, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_zywx(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.w, rhs.y, rhs.x, rhs.z)
+    }
+
+    #[inline]
+    fn with_zw(self, rhs: I64Vec2) -> Self {
+        Self::new(self.x, self.y, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zwx(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.z, self.y, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zwxy(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.z, rhs.w, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zwy(self, rhs: I64Vec3) -> Self {
+        Self::new(self.x, rhs.z, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_zwyx(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.w, rhs.z, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn with_wx(self, rhs: I64Vec2) -> Self {
+        Self::new(rhs.y, self.y, self.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wxy(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.y, rhs.z, self.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wxyz(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.y, rhs.z, rhs.w, rhs.x)
+    }
+
+    #[inline]
+    fn with_wxz(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.y, self.y, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wxzy(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.y, rhs.w, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wy(self, rhs: I64Vec2) -> Self {
+        Self::new(self.x, rhs.y, self.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wyx(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.z, rhs.y, self.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wyxz(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.z, rhs.y, rhs.w, rhs.x)
+    }
+
+    #[inline]
+    fn with_wyz(self, rhs: I64Vec3) -> Self {
+        Self::new(self.x, rhs.y, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wyzx(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.w, rhs.y, rhs.z, rhs.x)
+    }
+
+    #[inline]
+    fn with_wz(self, rhs: I64Vec2) -> Self {
+        Self::new(self.x, self.y, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_wzx(self, rhs: I64Vec3) -> Self {
+        Self::new(rhs.z, self.y, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_wzxy(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.z, rhs.w, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_wzy(self, rhs: I64Vec3) -> Self {
+        Self::new(self.x, rhs.z, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn with_wzyx(self, rhs: I64Vec4) -> Self {
+        Self::new(rhs.w, rhs.z, rhs.y, rhs.x)
+    }
 }
@@ -218,4 +218,9 @@ impl Vec2Swizzles for IVec2 {
     fn yyyy(self) -> IVec4 {
         IVec4::new(self.y, self.y, self.y, self.y)
     }
+
+    #[inline]
+    fn with_yx(self, rhs: IVec2) -> Self {
+        Self::new(rhs.y, rhs.x)
+    }
 }
@@ -218,4 +218,9 @@ impl Vec2Swizzles for U16Vec2 {
     fn yyyy(self) -> U16Vec4 {
         U16Vec4::new(self.y, self.y, self.y, self.y)
     }
+
+    #[inline]
+    fn with_yx(self, rhs: U16Vec2) -> Self {
+        Self::new(rhs.y, rhs.x)
+    }
 }
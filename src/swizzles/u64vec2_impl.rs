@@ -218,4 +218,9 @@ impl Vec2Swizzles for U64Vec2 {
     fn yyyy(self) -> U64Vec4 {
         U64Vec4::new(self.y, self.y, self.y, self.y)
     }
+
+    #[inline]
+    fn with_yx(self, rhs: U64Vec2) -> Self {
+        Self::new(rhs.y, rhs.x)
+    }
 }
@@ -218,4 +218,9 @@ impl Vec2Swizzles for UVec2 {
     fn yyyy(self) -> UVec4 {
         UVec4::new(self.y, self.y, self.y, self.y)
     }
+
+    #[inline]
+    fn with_yx(self, rhs: UVec2) -> Self {
+        Self::new(rhs.y, rhs.x)
+    }
 }
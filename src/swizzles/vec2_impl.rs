@@ -218,4 +218,9 @@ impl Vec2Swizzles for Vec2 {
     fn yyyy(self) -> Vec4 {
         Vec4::new(self.y, self.y, self.y, self.y)
     }
+
+    #[inline]
+    fn with_yx(self, rhs: Vec2) -> Self {
+        Self::new(rhs.y, rhs.x)
+    }
 }
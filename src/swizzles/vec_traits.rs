@@ -64,6 +64,14 @@ pub trait Vec2Swizzles: Sized + Copy + Clone {
     fn yyyx(self) -> Self::Vec4;
 
     fn yyyy(self) -> Self::Vec4;
+
+    #[must_use]
+    fn with_yx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_yx(&mut self, rhs: Self) {
+        *self = self.with_yx(rhs);
+    }
 }
 
 pub trait Vec3Swizzles: Sized + Copy + Clone {
@@ -308,6 +316,94 @@ pub trait Vec3Swizzles: Sized + Copy + Clone {
     fn zzzy(self) -> Self::Vec4;
 
     fn zzzz(self) -> Self::Vec4;
+
+    #[must_use]
+    fn with_xy(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_xy(&mut self, rhs: Self::Vec2) {
+        *self = self.with_xy(rhs);
+    }
+
+    #[must_use]
+    fn with_xz(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_xz(&mut self, rhs: Self::Vec2) {
+        *self = self.with_xz(rhs);
+    }
+
+    #[must_use]
+    fn with_xzy(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_xzy(&mut self, rhs: Self) {
+        *self = self.with_xzy(rhs);
+    }
+
+    #[must_use]
+    fn with_yx(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_yx(&mut self, rhs: Self::Vec2) {
+        *self = self.with_yx(rhs);
+    }
+
+    #[must_use]
+    fn with_yxz(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_yxz(&mut self, rhs: Self) {
+        *self = self.with_yxz(rhs);
+    }
+
+    #[must_use]
+    fn with_yz(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_yz(&mut self, rhs: Self::Vec2) {
+        *self = self.with_yz(rhs);
+    }
+
+    #[must_use]
+    fn with_yzx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_yzx(&mut self, rhs: Self) {
+        *self = self.with_yzx(rhs);
+    }
+
+    #[must_use]
+    fn with_zx(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_zx(&mut self, rhs: Self::Vec2) {
+        *self = self.with_zx(rhs);
+    }
+
+    #[must_use]
+    fn with_zxy(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_zxy(&mut self, rhs: Self) {
+        *self = self.with_zxy(rhs);
+    }
+
+    #[must_use]
+    fn with_zy(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_zy(&mut self, rhs: Self::Vec2) {
+        *self = self.with_zy(rhs);
+    }
+
+    #[must_use]
+    fn with_zyx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_zyx(&mut self, rhs: Self) {
+        *self = self.with_zyx(rhs);
+    }
 }
 
 pub trait Vec4Swizzles: Sized + Copy + Clone {
@@ -990,4 +1086,476 @@ pub trait Vec4Swizzles: Sized + Copy + Clone {
     fn wwwz(self) -> Self;
 
     fn wwww(self) -> Self;
+
+    #[must_use]
+    fn with_xy(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_xy(&mut self, rhs: Self::Vec2) {
+        *self = self.with_xy(rhs);
+    }
+
+    #[must_use]
+    fn with_xyz(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_xyz(&mut self, rhs: Self::Vec3) {
+        *self = self.with_xyz(rhs);
+    }
+
+    #[must_use]
+    fn with_xyw(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_xyw(&mut self, rhs: Self::Vec3) {
+        *self = self.with_xyw(rhs);
+    }
+
+    #[must_use]
+    fn with_xywz(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_xywz(&mut self, rhs: Self) {
+        *self = self.with_xywz(rhs);
+    }
+
+    #[must_use]
+    fn with_xz(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_xz(&mut self, rhs: Self::Vec2) {
+        *self = self.with_xz(rhs);
+    }
+
+    #[must_use]
+    fn with_xzy(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_xzy(&mut self, rhs: Self::Vec3) {
+        *self = self.with_xzy(rhs);
+    }
+
+    #[must_use]
+    fn with_xzyw(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_xzyw(&mut self, rhs: Self) {
+        *self = self.with_xzyw(rhs);
+    }
+
+    #[must_use]
+    fn with_xzw(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_xzw(&mut self, rhs: Self::Vec3) {
+        *self = self.with_xzw(rhs);
+    }
+
+    #[must_use]
+    fn with_xzwy(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_xzwy(&mut self, rhs: Self) {
+        *self = self.with_xzwy(rhs);
+    }
+
+    #[must_use]
+    fn with_xw(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_xw(&mut self, rhs: Self::Vec2) {
+        *self = self.with_xw(rhs);
+    }
+
+    #[must_use]
+    fn with_xwy(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_xwy(&mut self, rhs: Self::Vec3) {
+        *self = self.with_xwy(rhs);
+    }
+
+    #[must_use]
+    fn with_xwyz(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_xwyz(&mut self, rhs: Self) {
+        *self = self.with_xwyz(rhs);
+    }
+
+    #[must_use]
+    fn with_xwz(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_xwz(&mut self, rhs: Self::Vec3) {
+        *self = self.with_xwz(rhs);
+    }
+
+    #[must_use]
+    fn with_xwzy(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_xwzy(&mut self, rhs: Self) {
+        *self = self.with_xwzy(rhs);
+    }
+
+    #[must_use]
+    fn with_yx(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_yx(&mut self, rhs: Self::Vec2) {
+        *self = self.with_yx(rhs);
+    }
+
+    #[must_use]
+    fn with_yxz(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_yxz(&mut self, rhs: Self::Vec3) {
+        *self = self.with_yxz(rhs);
+    }
+
+    #[must_use]
+    fn with_yxzw(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_yxzw(&mut self, rhs: Self) {
+        *self = self.with_yxzw(rhs);
+    }
+
+    #[must_use]
+    fn with_yxw(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_yxw(&mut self, rhs: Self::Vec3) {
+        *self = self.with_yxw(rhs);
+    }
+
+    #[must_use]
+    fn with_yxwz(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_yxwz(&mut self, rhs: Self) {
+        *self = self.with_yxwz(rhs);
+    }
+
+    #[must_use]
+    fn with_yz(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_yz(&mut self, rhs: Self::Vec2) {
+        *self = self.with_yz(rhs);
+    }
+
+    #[must_use]
+    fn with_yzx(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_yzx(&mut self, rhs: Self::Vec3) {
+        *self = self.with_yzx(rhs);
+    }
+
+    #[must_use]
+    fn with_yzxw(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_yzxw(&mut self, rhs: Self) {
+        *self = self.with_yzxw(rhs);
+    }
+
+    #[must_use]
+    fn with_yzw(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_yzw(&mut self, rhs: Self::Vec3) {
+        *self = self.with_yzw(rhs);
+    }
+
+    #[must_use]
+    fn with_yzwx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_yzwx(&mut self, rhs: Self) {
+        *self = self.with_yzwx(rhs);
+    }
+
+    #[must_use]
+    fn with_yw(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_yw(&mut self, rhs: Self::Vec2) {
+        *self = self.with_yw(rhs);
+    }
+
+    #[must_use]
+    fn with_ywx(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_ywx(&mut self, rhs: Self::Vec3) {
+        *self = self.with_ywx(rhs);
+    }
+
+    #[must_use]
+    fn with_ywxz(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_ywxz(&mut self, rhs: Self) {
+        *self = self.with_ywxz(rhs);
+    }
+
+    #[must_use]
+    fn with_ywz(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_ywz(&mut self, rhs: Self::Vec3) {
+        *self = self.with_ywz(rhs);
+    }
+
+    #[must_use]
+    fn with_ywzx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_ywzx(&mut self, rhs: Self) {
+        *self = self.with_ywzx(rhs);
+    }
+
+    #[must_use]
+    fn with_zx(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_zx(&mut self, rhs: Self::Vec2) {
+        *self = self.with_zx(rhs);
+    }
+
+    #[must_use]
+    fn with_zxy(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_zxy(&mut self, rhs: Self::Vec3) {
+        *self = self.with_zxy(rhs);
+    }
+
+    #[must_use]
+    fn with_zxyw(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_zxyw(&mut self, rhs: Self) {
+        *self = self.with_zxyw(rhs);
+    }
+
+    #[must_use]
+    fn with_zxw(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_zxw(&mut self, rhs: Self::Vec3) {
+        *self = self.with_zxw(rhs);
+    }
+
+    #[must_use]
+    fn with_zxwy(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_zxwy(&mut self, rhs: Self) {
+        *self = self.with_zxwy(rhs);
+    }
+
+    #[must_use]
+    fn with_zy(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_zy(&mut self, rhs: Self::Vec2) {
+        *self = self.with_zy(rhs);
+    }
+
+    #[must_use]
+    fn with_zyx(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_zyx(&mut self, rhs: Self::Vec3) {
+        *self = self.with_zyx(rhs);
+    }
+
+    #[must_use]
+    fn with_zyxw(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_zyxw(&mut self, rhs: Self) {
+        *self = self.with_zyxw(rhs);
+    }
+
+    #[must_use]
+    fn with_zyw(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_zyw(&mut self, rhs: Self::Vec3) {
+        *self = self.with_zyw(rhs);
+    }
+
+    #[must_use]
+    fn with_zywx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_zywx(&mut self, rhs: Self) {
+        *self = self.with_zywx(rhs);
+    }
+
+    #[must_use]
+    fn with_zw(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_zw(&mut self, rhs: Self::Vec2) {
+        *self = self.with_zw(rhs);
+    }
+
+    #[must_use]
+    fn with_zwx(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_zwx(&mut self, rhs: Self::Vec3) {
+        *self = self.with_zwx(rhs);
+    }
+
+    #[must_use]
+    fn with_zwxy(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_zwxy(&mut self, rhs: Self) {
+        *self = self.with_zwxy(rhs);
+    }
+
+    #[must_use]
+    fn with_zwy(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_zwy(&mut self, rhs: Self::Vec3) {
+        *self = self.with_zwy(rhs);
+    }
+
+    #[must_use]
+    fn with_zwyx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_zwyx(&mut self, rhs: Self) {
+        *self = self.with_zwyx(rhs);
+    }
+
+    #[must_use]
+    fn with_wx(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_wx(&mut self, rhs: Self::Vec2) {
+        *self = self.with_wx(rhs);
+    }
+
+    #[must_use]
+    fn with_wxy(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_wxy(&mut self, rhs: Self::Vec3) {
+        *self = self.with_wxy(rhs);
+    }
+
+    #[must_use]
+    fn with_wxyz(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_wxyz(&mut self, rhs: Self) {
+        *self = self.with_wxyz(rhs);
+    }
+
+    #[must_use]
+    fn with_wxz(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_wxz(&mut self, rhs: Self::Vec3) {
+        *self = self.with_wxz(rhs);
+    }
+
+    #[must_use]
+    fn with_wxzy(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_wxzy(&mut self, rhs: Self) {
+        *self = self.with_wxzy(rhs);
+    }
+
+    #[must_use]
+    fn with_wy(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_wy(&mut self, rhs: Self::Vec2) {
+        *self = self.with_wy(rhs);
+    }
+
+    #[must_use]
+    fn with_wyx(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_wyx(&mut self, rhs: Self::Vec3) {
+        *self = self.with_wyx(rhs);
+    }
+
+    #[must_use]
+    fn with_wyxz(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_wyxz(&mut self, rhs: Self) {
+        *self = self.with_wyxz(rhs);
+    }
+
+    #[must_use]
+    fn with_wyz(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_wyz(&mut self, rhs: Self::Vec3) {
+        *self = self.with_wyz(rhs);
+    }
+
+    #[must_use]
+    fn with_wyzx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_wyzx(&mut self, rhs: Self) {
+        *self = self.with_wyzx(rhs);
+    }
+
+    #[must_use]
+    fn with_wz(self, rhs: Self::Vec2) -> Self;
+
+    #[inline]
+    fn set_wz(&mut self, rhs: Self::Vec2) {
+        *self = self.with_wz(rhs);
+    }
+
+    #[must_use]
+    fn with_wzx(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_wzx(&mut self, rhs: Self::Vec3) {
+        *self = self.with_wzx(rhs);
+    }
+
+    #[must_use]
+    fn with_wzxy(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_wzxy(&mut self, rhs: Self) {
+        *self = self.with_wzxy(rhs);
+    }
+
+    #[must_use]
+    fn with_wzy(self, rhs: Self::Vec3) -> Self;
+
+    #[inline]
+    fn set_wzy(&mut self, rhs: Self::Vec3) {
+        *self = self.with_wzy(rhs);
+    }
+
+    #[must_use]
+    fn with_wzyx(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn set_wzyx(&mut self, rhs: Self) {
+        *self = self.with_wzyx(rhs);
+    }
 }
@@ -0,0 +1,30 @@
+/// A wrapper which orders, compares and hashes a floating point vector using the IEEE 754
+/// `totalOrder` predicate.
+///
+/// Vectors are ordered lexicographically by their elements, with each element compared using
+/// [`f32::total_cmp`] or [`f64::total_cmp`]. Two wrapped vectors are equal only if their
+/// elements have identical bit patterns, so `-0.0` and `0.0` are distinct and `NaN` is equal to
+/// itself.
+///
+/// This makes it possible to use float vectors as keys in a `BTreeMap` or `HashMap`, or to sort
+/// them deterministically.
+///
+/// `TotalOrd` values are usually created using the `total_ord()` method of a float vector.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct TotalOrd<T>(pub T);
+
+impl<T> TotalOrd<T> {
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for TotalOrd<T> {
+    #[inline]
+    fn from(t: T) -> Self {
+        Self(t)
+    }
+}
@@ -1,3 +1,5 @@
+mod hilbert;
+mod morton;
 mod uvec2;
 mod uvec3;
 mod uvec4;
@@ -0,0 +1,163 @@
+//! Hilbert curve encoding of unsigned integer coordinates.
+//!
+//! This uses John Skilling's algorithm from "Programming the Hilbert curve" (2004) which works on
+//! the coordinates in place, the resulting transposed index is then interleaved with the Morton
+//! encoding functions.
+
+use super::morton;
+
+/// Converts coordinates in `[0, 2^order)` to the transposed Hilbert index.
+#[inline]
+fn axes_to_transpose<const N: usize>(x: &mut [u32; N], order: u32) {
+    // inverse undo
+    for bit in (1..order).rev() {
+        let q = 1 << bit;
+        let p = q - 1;
+        for i in 0..N {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+    }
+    // gray encode
+    for i in 1..N {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    for bit in (1..order).rev() {
+        let q = 1 << bit;
+        if x[N - 1] & q != 0 {
+            t ^= q - 1;
+        }
+    }
+    for v in x.iter_mut() {
+        *v ^= t;
+    }
+}
+
+/// Converts a transposed Hilbert index back to coordinates.
+#[inline]
+fn transpose_to_axes<const N: usize>(x: &mut [u32; N], order: u32) {
+    // gray decode
+    let t = x[N - 1] >> 1;
+    for i in (1..N).rev() {
+        x[i] ^= x[i - 1];
+    }
+    x[0] ^= t;
+    // undo excess work
+    for bit in 1..order {
+        let q = 1 << bit;
+        let p = q - 1;
+        for i in (0..N).rev() {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+    }
+}
+
+// In the transposed index the most significant bit of each level comes from the first axis, so
+// the axes are interleaved in reverse order.
+
+#[inline]
+pub(crate) fn encode2(x: u32, y: u32, order: u32) -> u64 {
+    let mut axes = [x, y];
+    axes_to_transpose(&mut axes, order);
+    morton::encode2(axes[1], axes[0])
+}
+
+#[inline]
+pub(crate) fn decode2(index: u64, order: u32) -> (u32, u32) {
+    let (a1, a0) = morton::decode2(index);
+    let mut axes = [a0, a1];
+    transpose_to_axes(&mut axes, order);
+    (axes[0], axes[1])
+}
+
+#[inline]
+pub(crate) fn encode3(x: u32, y: u32, z: u32, order: u32) -> u64 {
+    let mut axes = [x, y, z];
+    axes_to_transpose(&mut axes, order);
+    morton::encode3(axes[2], axes[1], axes[0])
+}
+
+#[inline]
+pub(crate) fn decode3(index: u64, order: u32) -> (u32, u32, u32) {
+    let (a2, a1, a0) = morton::decode3(index);
+    let mut axes = [a0, a1, a2];
+    transpose_to_axes(&mut axes, order);
+    (axes[0], axes[1], axes[2])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn is_adjacent(a: &[u32], b: &[u32]) -> bool {
+        a.iter().zip(b).map(|(a, b)| a.abs_diff(*b)).sum::<u32>() == 1
+    }
+
+    #[test]
+    fn test_hilbert2_walk() {
+        for order in 1..=5 {
+            let count = 1_u64 << (2 * order);
+            let mut prev = decode2(0, order);
+            assert_eq!(prev, (0, 0));
+            let mut seen = [false; 1 << 10];
+            for index in 0..count {
+                let (x, y) = decode2(index, order);
+                assert!(x < 1 << order && y < 1 << order);
+                assert_eq!(encode2(x, y, order), index);
+                if index > 0 {
+                    assert!(is_adjacent(&[x, y], &[prev.0, prev.1]));
+                }
+                let cell = (y << order | x) as usize;
+                assert!(!seen[cell]);
+                seen[cell] = true;
+                prev = (x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hilbert3_walk() {
+        for order in 1..=3 {
+            let count = 1_u64 << (3 * order);
+            let mut prev = decode3(0, order);
+            assert_eq!(prev, (0, 0, 0));
+            for index in 0..count {
+                let (x, y, z) = decode3(index, order);
+                assert!(x < 1 << order && y < 1 << order && z < 1 << order);
+                assert_eq!(encode3(x, y, z, order), index);
+                if index > 0 {
+                    assert!(is_adjacent(&[x, y, z], &[prev.0, prev.1, prev.2]));
+                }
+                prev = (x, y, z);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hilbert_max_order() {
+        for (x, y) in [
+            (0, 0),
+            (u32::MAX, 0),
+            (0x1234_5678, 0x9abc_def0),
+            (u32::MAX, u32::MAX),
+        ] {
+            assert_eq!(decode2(encode2(x, y, 32), 32), (x, y));
+        }
+        let m = 0x1f_ffff;
+        for (x, y, z) in [(0, 0, 0), (m, 0, 1), (0x12345, m, 0xabcde), (m, m, m)] {
+            assert_eq!(decode3(encode3(x, y, z, 21), 21), (x, y, z));
+        }
+    }
+}
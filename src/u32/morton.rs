@@ -0,0 +1,146 @@
+//! Morton (Z-order) encoding of unsigned integer coordinates.
+//!
+//! On `x86_64` targets with the `bmi2` target feature enabled the `PDEP` and `PEXT` instructions
+//! are used, otherwise the bits are interleaved with shifts and masks.
+
+const MASK2: u64 = 0x5555_5555_5555_5555;
+const MASK3: u64 = 0x1249_2492_4924_9249;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+#[inline]
+fn deposit(v: u64, mask: u64) -> u64 {
+    // SAFETY: the `bmi2` target feature is enabled
+    unsafe { core::arch::x86_64::_pdep_u64(v, mask) }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+#[inline]
+fn extract(v: u64, mask: u64) -> u64 {
+    // SAFETY: the `bmi2` target feature is enabled
+    unsafe { core::arch::x86_64::_pext_u64(v, mask) }
+}
+
+/// Inserts a zero bit between each of the low 32 bits of `v`.
+#[inline]
+fn spread2(v: u32) -> u64 {
+    #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+    {
+        deposit(v as u64, MASK2)
+    }
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+    {
+        let mut v = v as u64;
+        v = (v | (v << 16)) & 0x0000_ffff_0000_ffff;
+        v = (v | (v << 8)) & 0x00ff_00ff_00ff_00ff;
+        v = (v | (v << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+        v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+        (v | (v << 1)) & MASK2
+    }
+}
+
+/// Inverse of [`spread2`], ignores the odd bits of `v`.
+#[inline]
+fn compact2(v: u64) -> u32 {
+    #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+    {
+        extract(v, MASK2) as u32
+    }
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+    {
+        let mut v = v & MASK2;
+        v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+        v = (v | (v >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+        v = (v | (v >> 4)) & 0x00ff_00ff_00ff_00ff;
+        v = (v | (v >> 8)) & 0x0000_ffff_0000_ffff;
+        (v | (v >> 16)) as u32
+    }
+}
+
+/// Inserts two zero bits between each of the low 21 bits of `v`.
+#[inline]
+fn spread3(v: u32) -> u64 {
+    #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+    {
+        deposit(v as u64, MASK3)
+    }
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+    {
+        let mut v = v as u64 & 0x1f_ffff;
+        v = (v | (v << 32)) & 0x001f_0000_0000_ffff;
+        v = (v | (v << 16)) & 0x001f_0000_ff00_00ff;
+        v = (v | (v << 8)) & 0x100f_00f0_0f00_f00f;
+        v = (v | (v << 4)) & 0x10c3_0c30_c30c_30c3;
+        (v | (v << 2)) & MASK3
+    }
+}
+
+/// Inverse of [`spread3`], ignores all bits of `v` not in the low bit of each triple.
+#[inline]
+fn compact3(v: u64) -> u32 {
+    #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+    {
+        extract(v, MASK3) as u32
+    }
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+    {
+        let mut v = v & MASK3;
+        v = (v | (v >> 2)) & 0x10c3_0c30_c30c_30c3;
+        v = (v | (v >> 4)) & 0x100f_00f0_0f00_f00f;
+        v = (v | (v >> 8)) & 0x001f_0000_ff00_00ff;
+        v = (v | (v >> 16)) & 0x001f_0000_0000_ffff;
+        ((v | (v >> 32)) & 0x1f_ffff) as u32
+    }
+}
+
+#[inline]
+pub(crate) fn encode2(x: u32, y: u32) -> u64 {
+    spread2(x) | (spread2(y) << 1)
+}
+
+#[inline]
+pub(crate) fn decode2(code: u64) -> (u32, u32) {
+    (compact2(code), compact2(code >> 1))
+}
+
+#[inline]
+pub(crate) fn encode3(x: u32, y: u32, z: u32) -> u64 {
+    spread3(x) | (spread3(y) << 1) | (spread3(z) << 2)
+}
+
+#[inline]
+pub(crate) fn decode3(code: u64) -> (u32, u32, u32) {
+    (compact3(code), compact3(code >> 1), compact3(code >> 2))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_morton_reference() {
+        fn encode_bitwise(coords: &[u32], bits: u32) -> u64 {
+            let n = coords.len() as u32;
+            let mut code = 0;
+            for bit in 0..bits {
+                for (axis, c) in coords.iter().enumerate() {
+                    code |= (((c >> bit) & 1) as u64) << (bit * n + axis as u32);
+                }
+            }
+            code
+        }
+        let values = [0, 1, 2, 3, 0x5a5a5, 0x1f_ffff, 0x1234_5678, u32::MAX];
+        for &x in &values {
+            for &y in &values {
+                let code = encode2(x, y);
+                assert_eq!(code, encode_bitwise(&[x, y], 32));
+                assert_eq!(decode2(code), (x, y));
+                for &z in &values {
+                    let (x, y, z) = (x & 0x1f_ffff, y & 0x1f_ffff, z & 0x1f_ffff);
+                    let code = encode3(x, y, z);
+                    assert_eq!(code, encode_bitwise(&[x, y, z], 21));
+                    assert_eq!(decode3(code), (x, y, z));
+                }
+            }
+        }
+    }
+}
@@ -45,3 +45,40 @@ pub(crate) fn dot4_into_v128(lhs: v128, rhs: v128) -> v128 {
     let dot_in_x = dot4_in_x(lhs, rhs);
     i32x4_shuffle::<0, 0, 0, 0>(dot_in_x, dot_in_x)
 }
+
+/// Returns the x, y and z axes of the rotation matrix of the normalized quaternion `q`, with the w
+/// lane of each axis set to zero.
+///
+/// The products and sums are computed in the same order as the scalar implementation, so the
+/// result is identical to it.
+#[inline]
+pub(crate) fn v128_quat_to_axes(q: v128) -> [v128; 3] {
+    // 2x 2y 2z 0
+    let q2 = f32x4_mul(q, f32x4(2.0, 2.0, 2.0, 0.0));
+    // 2xx 2yy 2zz 0
+    let sq = f32x4_mul(q, q2);
+    let diag = f32x4_sub(
+        f32x4(1.0, 1.0, 1.0, 0.0),
+        f32x4_add(
+            i32x4_shuffle::<1, 0, 0, 3>(sq, sq),
+            i32x4_shuffle::<2, 2, 1, 3>(sq, sq),
+        ),
+    );
+    // 2xy 2xz 2yz 0
+    let v0 = f32x4_mul(
+        i32x4_shuffle::<0, 0, 1, 3>(q, q),
+        i32x4_shuffle::<1, 2, 2, 3>(q2, q2),
+    );
+    // 2wz 2wy 2wx 0
+    let v1 = f32x4_mul(
+        i32x4_shuffle::<3, 3, 3, 3>(q, q),
+        i32x4_shuffle::<2, 1, 0, 3>(q2, q2),
+    );
+    let r1 = f32x4_add(v0, v1);
+    let r2 = f32x4_sub(v0, v1);
+    [
+        i32x4_shuffle::<0, 1, 5, 7>(i32x4_shuffle::<0, 4, 1, 5>(diag, r1), r2),
+        i32x4_shuffle::<0, 2, 6, 7>(i32x4_shuffle::<0, 0, 5, 5>(r2, diag), r1),
+        i32x4_shuffle::<0, 2, 6, 7>(i32x4_shuffle::<1, 1, 6, 6>(r1, r2), diag),
+    ]
+}
@@ -0,0 +1,87 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_aabb3_tests {
+    ($t:ident, $aabb3:ident, $vec3:ident, $affine3:ident, $mat4:ident, $quat:ident) => {
+        glam_test!(test_new, {
+            let min = $vec3::new(-1.0, 0.0, 2.0);
+            let max = $vec3::new(3.0, 1.0, 4.0);
+            let a = $aabb3::new(min, max);
+            assert_eq!(min, a.min);
+            assert_eq!(max, a.max);
+            assert_eq!($vec3::new(1.0, 0.5, 3.0), a.center());
+            assert_eq!($vec3::new(2.0, 0.5, 1.0), a.half_extents());
+            assert_eq!(
+                a,
+                $aabb3::from_center_half_extents(a.center(), a.half_extents())
+            );
+            assert!(a.contains_point($vec3::new(0.0, 0.5, 3.0)));
+            assert!(a.contains_point(min));
+            assert!(a.contains_point(max));
+            assert!(!a.contains_point($vec3::new(0.0, 1.5, 3.0)));
+        });
+
+        fn corners(a: &$aabb3) -> impl Iterator<Item = $vec3> + '_ {
+            (0..8).map(move |i| {
+                $vec3::new(
+                    if i & 1 == 0 { a.min.x } else { a.max.x },
+                    if i & 2 == 0 { a.min.y } else { a.max.y },
+                    if i & 4 == 0 { a.min.z } else { a.max.z },
+                )
+            })
+        }
+
+        glam_test!(test_transformed_by, {
+            let a = $aabb3::new($vec3::new(-1.0, 0.0, 2.0), $vec3::new(3.0, 1.0, 4.0));
+
+            // translation and scale
+            let t = $affine3::from_scale_rotation_translation(
+                $vec3::new(2.0, -1.0, 1.0),
+                $quat::IDENTITY,
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let b = a.transformed_by(&t);
+            assert_approx_eq!($vec3::new(-1.0, 1.0, 5.0), b.min);
+            assert_approx_eq!($vec3::new(7.0, 2.0, 7.0), b.max);
+
+            // compare with transforming the corners
+            let t = $affine3::from_scale_rotation_translation(
+                $vec3::new(1.0, 2.0, 0.5),
+                $quat::from_euler(glam::EulerRot::YXZ, 0.3, -1.2, 2.0),
+                $vec3::new(-4.0, 5.0, 6.0),
+            );
+            let b = a.transformed_by(&t);
+            let (min, max) = corners(&a).map(|p| t.transform_point3(p)).fold(
+                ($vec3::splat($t::MAX), $vec3::splat($t::MIN)),
+                |(min, max), p| (min.min(p), max.max(p)),
+            );
+            assert_approx_eq!(min, b.min, 1e-5);
+            assert_approx_eq!(max, b.max, 1e-5);
+            assert_eq!(b, a.transformed_by_mat4(&$mat4::from(t)));
+        });
+    };
+}
+
+mod aabb3 {
+    use glam::{Aabb3, Affine3A, Mat4, Quat, Vec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(24, mem::size_of::<Aabb3>());
+        assert_eq!(mem::align_of::<f32>(), mem::align_of::<Aabb3>());
+    });
+
+    impl_aabb3_tests!(f32, Aabb3, Vec3, Affine3A, Mat4, Quat);
+}
+
+mod daabb3 {
+    use glam::{DAabb3, DAffine3, DMat4, DQuat, DVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(48, mem::size_of::<DAabb3>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DAabb3>());
+    });
+
+    impl_aabb3_tests!(f64, DAabb3, DVec3, DAffine3, DMat4, DQuat);
+}
@@ -9,6 +9,36 @@ macro_rules! impl_affine2_tests {
         use core::$t::NAN;
         use core::$t::NEG_INFINITY;
 
+        glam_test!(test_from_str, {
+            let a = $affine2::from_cols_array(&MATRIX1D);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 6,
+                    found: 5
+                }),
+                "[[1, 2], [3, 4], [5]]".parse::<$affine2>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$affine2>()
+            );
+        });
+
+        glam_test!(test_bytes, {
+            let m = $affine2::from_cols_array(&MATRIX1D);
+            let size = core::mem::size_of::<$t>();
+            let le = m.to_le_bytes();
+            let be = m.to_be_bytes();
+            assert_eq!(le.len(), size * 6);
+            assert_eq!(&le[size..size * 2], &MATRIX1D[1].to_le_bytes());
+            assert_eq!(&be[size..size * 2], &MATRIX1D[1].to_be_bytes());
+            assert_eq!(m, $affine2::from_le_bytes(le));
+            assert_eq!(m, $affine2::from_be_bytes(be));
+        });
+
         glam_test!(test_affine2_identity, {
             assert_eq!($affine2::IDENTITY, $affine2::IDENTITY * $affine2::IDENTITY);
             assert_eq!($affine2::IDENTITY, $affine2::default());
@@ -111,6 +141,23 @@ macro_rules! impl_affine2_tests {
             );
         });
 
+        glam_test!(test_affine2_try_inverse, {
+            let a = $affine2::from_scale_angle_translation(
+                $vec2::new(4.0, 5.0),
+                deg(30.0),
+                $vec2::new(1.0, 2.0),
+            );
+            assert!(a.is_invertible());
+            assert_eq!(Some(a.inverse()), a.try_inverse());
+            let singular = $affine2::from_scale($vec2::new(0.0, 1.0));
+            assert!(!singular.is_invertible());
+            assert_eq!(None, singular.try_inverse());
+            assert_eq!(None, $affine2::NAN.try_inverse());
+            let tiny = $affine2::from_scale($vec2::splat($t::MIN_POSITIVE.sqrt() * 0.25));
+            assert!(!tiny.is_invertible());
+            assert_eq!(None, tiny.try_inverse());
+        });
+
         glam_test!(test_affine2_inverse, {
             let inv = $affine2::IDENTITY.inverse();
             assert_approx_eq!($affine2::IDENTITY, inv);
@@ -233,6 +280,7 @@ macro_rules! impl_affine2_tests {
             let a = $affine2::from_cols_array_2d(&MATRIX2D);
             assert_eq!(format!("{}", a), "[[1, 2], [3, 4], [5, 6]]");
             assert_eq!(format!("{:.1}", a), "[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]");
+            assert_eq!(format!("{:#}", a), "[1, 3, 5]\n[2, 4, 6]");
         });
 
         glam_test!(test_affine2_to_from_slice, {
@@ -342,5 +390,31 @@ mod daffine2 {
         assert_eq!(16, mem::align_of::<DAffine2>());
     });
 
+    glam_test!(test_scalar_parity, {
+        // DAffine2 may use f64x2 SIMD, which must give the same results as the scalar DMat2 path
+        let a = DAffine2::from_cols_array(&[1.0, -2.0, 3.5, 0.25, 5.0, 6.0]);
+        let b = DAffine2::from_scale_angle_translation(
+            DVec2::new(1.5, -0.5),
+            0.7,
+            DVec2::new(-3.0, 4.0),
+        );
+        let v = DVec2::new(0.5, -1.5);
+        for m in [a, b, a * b] {
+            assert_eq!(m.matrix2 * v + m.translation, m.transform_point2(v));
+            assert_eq!(m.matrix2 * v, m.transform_vector2(v));
+            let inv = m.inverse();
+            assert_eq!(m.matrix2.inverse(), inv.matrix2);
+            assert_eq!(-(inv.matrix2 * m.translation), inv.translation);
+            assert_eq!(Some(inv), m.try_inverse());
+        }
+        assert_eq!(
+            DAffine2::from_mat2_translation(
+                a.matrix2 * b.matrix2,
+                a.matrix2 * b.translation + a.translation
+            ),
+            a * b
+        );
+    });
+
     impl_affine2_tests!(f64, DAffine2, DVec2, DMat2, DMat3);
 }
@@ -16,6 +16,63 @@ macro_rules! impl_affine3_tests {
         use core::$t::NAN;
         use core::$t::NEG_INFINITY;
 
+        glam_test!(test_inverse_checked, {
+            let a = $affine3::from_scale_rotation_translation(
+                $vec3::new(1.0, 2.0, 3.0),
+                $quat::from_rotation_y(1.0),
+                $vec3::new(4.0, 5.0, 6.0),
+            );
+            assert_eq!(a.inverse_checked(), Ok(a.inverse()));
+            assert_eq!(
+                $affine3::from_scale($vec3::new(1.0, 0.0, 1.0)).inverse_checked(),
+                Err(glam::error::Error::Singular)
+            );
+            assert_eq!(
+                $affine3::from_cols_slice_checked(&MATRIX1D),
+                Ok($affine3::from_cols_array(&MATRIX1D))
+            );
+            assert!($affine3::from_cols_slice_checked(&MATRIX1D[..11]).is_err());
+        });
+
+        glam_test!(test_ulps_eq, {
+            let a = $affine3::from_cols_array_2d(&MATRIX2D);
+            let mut b = a.to_cols_array();
+            b[10] = $t::from_bits(b[10].to_bits() + 2);
+            let b = $affine3::from_cols_array(&b);
+            assert!(a.ulps_eq(b, 2));
+            assert!(!a.ulps_eq(b, 1));
+        });
+
+        glam_test!(test_from_str, {
+            let a = $affine3::from_cols_array(&MATRIX1D);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 12,
+                    found: 11
+                }),
+                "[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11]]".parse::<$affine3>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$affine3>()
+            );
+        });
+
+        glam_test!(test_bytes, {
+            let m = $affine3::from_cols_array(&MATRIX1D);
+            let size = core::mem::size_of::<$t>();
+            let le = m.to_le_bytes();
+            let be = m.to_be_bytes();
+            assert_eq!(le.len(), size * 12);
+            assert_eq!(&le[size..size * 2], &MATRIX1D[1].to_le_bytes());
+            assert_eq!(&be[size..size * 2], &MATRIX1D[1].to_be_bytes());
+            assert_eq!(m, $affine3::from_le_bytes(le));
+            assert_eq!(m, $affine3::from_be_bytes(be));
+        });
+
         glam_test!(test_affine3_identity, {
             assert_eq!($affine3::IDENTITY, $affine3::IDENTITY * $affine3::IDENTITY);
             assert_eq!($affine3::IDENTITY, $affine3::default());
@@ -301,6 +358,46 @@ macro_rules! impl_affine3_tests {
             let mat4 = $mat4::from(m0);
             assert_approx_eq!(mat4, $affine3::IDENTITY * mat4);
             assert_approx_eq!(mat4, mat4 * $affine3::IDENTITY);
+
+            let m2 = $affine3::from_scale_rotation_translation(
+                $vec3::new(2.0, 1.0, 3.0),
+                $quat::from_rotation_y(deg(30.0)),
+                $vec3::new(-4.0, 5.0, 6.0),
+            );
+            let proj = $mat4::perspective_rh(deg(60.0), 1.5, 0.1, 100.0);
+            assert_approx_eq!(proj * $mat4::from(m2), proj * m2, 1.0e-5);
+            let mut mat4 = proj;
+            mat4 *= m2;
+            assert_approx_eq!(proj * $mat4::from(m2), mat4, 1.0e-5);
+        });
+
+        glam_test!(test_affine3_is_flipping, {
+            let srt = $affine3::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, 2.0),
+                $quat::from_rotation_x(deg(90.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            assert!(!srt.is_flipping());
+            assert!((srt * $affine3::from_scale($vec3::new(1.0, 1.0, -2.0))).is_flipping());
+            assert!(!$affine3::ZERO.is_flipping());
+        });
+
+        glam_test!(test_affine3_try_inverse, {
+            let a = $affine3::from_scale_rotation_translation(
+                $vec3::new(1.0, 2.0, 3.0),
+                $quat::from_rotation_y(1.0),
+                $vec3::new(4.0, 5.0, 6.0),
+            );
+            assert!(a.is_invertible());
+            assert_eq!(Some(a.inverse()), a.try_inverse());
+            let singular = $affine3::from_scale($vec3::new(1.0, 0.0, 1.0));
+            assert!(!singular.is_invertible());
+            assert_eq!(None, singular.try_inverse());
+            assert_eq!(None, $affine3::NAN.try_inverse());
+            let tiny = $affine3::from_scale($vec3::splat($t::MIN_POSITIVE.cbrt() * 0.25));
+            assert!(!tiny.is_invertible());
+            assert_eq!(None, tiny.try_inverse());
+            assert_eq!(Err(glam::error::Error::Singular), tiny.inverse_checked());
         });
 
         glam_test!(test_affine3_fmt, {
@@ -419,5 +516,50 @@ mod daffine3 {
         assert_eq!(mem::align_of::<f64>(), mem::align_of::<DAffine3>());
     });
 
+    glam_test!(test_to_mat4_relative_to, {
+        let origin = DVec3::new(6_378_136.0, -2.0e7, 0.0);
+        let a = DAffine3::from_scale_rotation_translation(
+            DVec3::splat(2.0),
+            DQuat::from_rotation_y(deg(90.0)),
+            origin + DVec3::new(0.123, 4.5, -0.001),
+        );
+        let m = a.to_mat4_relative_to(origin);
+        assert!(m.abs_diff_eq(
+            (DMat4::from_translation(-origin) * DMat4::from(a)).as_mat4(),
+            1e-6
+        ));
+        assert!(m
+            .w_axis
+            .abs_diff_eq(glam::Vec4::new(0.123, 4.5, -0.001, 1.0), 1e-6));
+    });
+
+    glam_test!(test_scalar_parity, {
+        // DAffine3 may use f64x2 SIMD, which must give the same results as the scalar DMat3 path
+        let a = DAffine3::from_cols_array(&[
+            1.0, -2.0, 3.5, 0.25, 5.0, 6.0, -7.0, 8.0, 9.5, 10.0, 11.0, -12.0,
+        ]);
+        let b = DAffine3::from_scale_rotation_translation(
+            DVec3::new(1.5, 2.0, -0.5),
+            DQuat::from_rotation_y(0.7),
+            DVec3::new(-3.0, 4.0, 5.0),
+        );
+        let v = DVec3::new(0.5, -1.5, 2.0);
+        for m in [a, b, a * b] {
+            assert_eq!(m.matrix3 * v + m.translation, m.transform_point3(v));
+            assert_eq!(m.matrix3 * v, m.transform_vector3(v));
+            let inv = m.inverse();
+            assert_eq!(m.matrix3.inverse(), inv.matrix3);
+            assert_eq!(-(inv.matrix3 * m.translation), inv.translation);
+            assert_eq!(Some(inv), m.try_inverse());
+        }
+        assert_eq!(
+            DAffine3::from_mat3_translation(
+                a.matrix3 * b.matrix3,
+                a.matrix3 * b.translation + a.translation
+            ),
+            a * b
+        );
+    });
+
     impl_affine3_tests!(f64, DAffine3, DQuat, DVec3, DMat3, DMat4);
 }
@@ -0,0 +1,44 @@
+// The assertion handler is global so these tests are in their own test binary.
+#![cfg(any(
+    feature = "glam-assert",
+    all(debug_assertions, feature = "debug-glam-assert")
+))]
+
+use glam::assert::AssertInfo;
+use glam::{Mat4, Vec3};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static FAILURES: AtomicUsize = AtomicUsize::new(0);
+
+fn count_failures(info: &AssertInfo<'_>) {
+    assert!(!info.condition().is_empty());
+    assert!(info.location().file().ends_with(".rs"));
+    FAILURES.fetch_add(1, Ordering::Relaxed);
+}
+
+#[test]
+fn test_assert_handler() {
+    // the default handler panics with the location of the assertion
+    let err = std::panic::catch_unwind(|| Vec3::ZERO.normalize()).unwrap_err();
+    let message = err.downcast_ref::<String>().unwrap();
+    assert!(message.contains(".rs:"), "{}", message);
+
+    glam::assert::set_handler(count_failures);
+    let v = Vec3::ZERO.normalize();
+    assert!(!v.is_finite());
+    assert_eq!(FAILURES.load(Ordering::Relaxed), 1);
+
+    let _ = Mat4::ZERO.inverse();
+    assert_eq!(FAILURES.load(Ordering::Relaxed), 2);
+
+    // valid input does not call the handler
+    let _ = Vec3::X.normalize();
+    assert_eq!(FAILURES.load(Ordering::Relaxed), 2);
+
+    glam::assert::set_handler(glam::assert::ignore_handler);
+    let _ = Vec3::ZERO.normalize();
+    assert_eq!(FAILURES.load(Ordering::Relaxed), 2);
+
+    glam::assert::reset_handler();
+    assert!(std::panic::catch_unwind(|| Vec3::ZERO.normalize()).is_err());
+}
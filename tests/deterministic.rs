@@ -0,0 +1,36 @@
+// These results must be bit-identical on every platform when the `deterministic` feature is
+// enabled.
+#![cfg(feature = "deterministic")]
+
+use glam::{DQuat, DVec3, EulerRot, Mat4, Quat, Vec2, Vec3};
+
+#[test]
+fn test_deterministic_results() {
+    let q = Quat::from_euler(EulerRot::YXZ, 0.1, 0.2, 0.3);
+    let m = Mat4::from_rotation_translation(q, Vec3::new(1.0, 2.0, 3.0));
+    let v = m.transform_point3(Vec3::new(0.5, -0.25, 2.0)).normalize();
+    let a = Vec2::from_angle(2.5).angle_between(Vec2::new(0.3, 0.7));
+    let s = q.slerp(Quat::from_rotation_z(1.0), 0.3);
+    let d = DQuat::from_axis_angle(DVec3::Y, 1.0) * DVec3::new(1.0, 2.0, 3.0);
+    assert_eq!(
+        q.to_array().map(f32::to_bits),
+        [1037640009, 1024221064, 1041433750, 1065073833]
+    );
+    assert_eq!(
+        v.to_array().map(f32::to_bits),
+        [1051024332, 1049598596, 1063740843]
+    );
+    assert_eq!(a.to_bits(), 3215639460);
+    assert_eq!(
+        s.to_array().map(f32::to_bits),
+        [1033489387, 1019665252, 1048480649, 1064772575]
+    );
+    assert_eq!(
+        d.to_array().map(f64::to_bits),
+        [
+            4614083544052140886,
+            4611686018427387904,
+            4605195754298279606
+        ]
+    );
+}
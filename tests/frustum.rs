@@ -0,0 +1,125 @@
+#[macro_use]
+mod support;
+
+mod frustum {
+    use glam::{Frustum, Mat4, Plane, Vec3};
+
+    fn perspective() -> Frustum {
+        Frustum::from_mat4(&Mat4::perspective_rh(
+            core::f32::consts::FRAC_PI_2,
+            1.0,
+            1.0,
+            100.0,
+        ))
+    }
+
+    glam_test!(test_from_mat4, {
+        let f = perspective();
+        let s = core::f32::consts::FRAC_1_SQRT_2;
+        // left, right, bottom, top, near, far
+        let expected = [
+            Plane::new(Vec3::new(s, 0.0, -s), 0.0),
+            Plane::new(Vec3::new(-s, 0.0, -s), 0.0),
+            Plane::new(Vec3::new(0.0, s, -s), 0.0),
+            Plane::new(Vec3::new(0.0, -s, -s), 0.0),
+            Plane::new(Vec3::new(0.0, 0.0, -1.0), -1.0),
+            Plane::new(Vec3::new(0.0, 0.0, 1.0), 100.0),
+        ];
+        for (plane, expected) in f.planes.iter().zip(expected) {
+            assert!(plane.normal.abs_diff_eq(expected.normal, 1e-5));
+            assert_approx_eq!(expected.d, plane.d, 1e-3);
+        }
+
+        let f = Frustum::from_mat4(&Mat4::orthographic_rh(-1.0, 1.0, -2.0, 2.0, 0.0, 10.0));
+        assert!(f.contains_point(Vec3::new(1.0, 2.0, -10.0)));
+        assert!(!f.contains_point(Vec3::new(1.1, 0.0, -5.0)));
+        assert!(!f.contains_point(Vec3::new(0.0, -2.1, -5.0)));
+        assert!(!f.contains_point(Vec3::new(0.0, 0.0, 0.1)));
+
+        // the far plane of an infinite projection is degenerate
+        let f = Frustum::from_mat4(&Mat4::perspective_infinite_reverse_rh(1.0, 1.0, 0.1));
+        assert!(f.contains_point(Vec3::new(0.0, 0.0, -1e30)));
+        assert!(!f.contains_point(Vec3::new(0.0, 0.0, -0.01)));
+    });
+
+    glam_test!(test_contains_point, {
+        let f = perspective();
+        assert!(f.contains_point(Vec3::new(0.0, 0.0, -50.0)));
+        assert!(f.contains_point(Vec3::new(49.0, -49.0, -50.0)));
+        assert!(!f.contains_point(Vec3::new(51.0, 0.0, -50.0)));
+        assert!(!f.contains_point(Vec3::new(0.0, 0.0, -0.5)));
+        assert!(!f.contains_point(Vec3::new(0.0, 0.0, -101.0)));
+        assert!(!f.contains_point(Vec3::new(0.0, 0.0, 50.0)));
+    });
+
+    glam_test!(test_intersects_aabb, {
+        let f = perspective();
+        let half = Vec3::splat(1.0);
+        let aabb = |c: Vec3| f.intersects_aabb(c - half, c + half);
+        assert!(aabb(Vec3::new(0.0, 0.0, -50.0)));
+        // partially inside
+        assert!(aabb(Vec3::new(50.5, 0.0, -50.0)));
+        assert!(aabb(Vec3::new(0.0, 0.0, -100.5)));
+        assert!(aabb(Vec3::new(0.0, 0.0, 0.0)));
+        // outside
+        assert!(!aabb(Vec3::new(53.0, 0.0, -50.0)));
+        assert!(!aabb(Vec3::new(0.0, -53.0, -50.0)));
+        assert!(!aabb(Vec3::new(0.0, 0.0, -102.0)));
+        assert!(!aabb(Vec3::new(0.0, 0.0, 5.0)));
+        // containing the whole frustum
+        assert!(f.intersects_aabb(Vec3::splat(-1000.0), Vec3::splat(1000.0)));
+    });
+
+    glam_test!(test_intersects_aabbs_soa, {
+        let f = Frustum::from_mat4(&Mat4::perspective_rh(1.0, 1.5, 0.1, 100.0));
+        let mut min = [vec![], vec![], vec![]];
+        let mut max = [vec![], vec![], vec![]];
+        let mut expected = vec![];
+        let mut state = 1_u32;
+        let mut rand = || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 8) as f32 / (1 << 24) as f32
+        };
+        for _ in 0..71 {
+            let center = Vec3::new(rand() * 80.0 - 40.0, rand() * 80.0 - 40.0, -rand() * 120.0);
+            let half = Vec3::new(rand(), rand(), rand()) * 4.0;
+            for j in 0..3 {
+                min[j].push((center - half)[j]);
+                max[j].push((center + half)[j]);
+            }
+            expected.push(f.intersects_aabb(center - half, center + half));
+        }
+        assert!(expected.iter().any(|&v| v) && expected.iter().any(|&v| !v));
+
+        let mut visible = [u32::MAX; 4];
+        f.intersects_aabbs_soa(
+            [&min[0], &min[1], &min[2]],
+            [&max[0], &max[1], &max[2]],
+            &mut visible,
+        );
+        for (i, &e) in expected.iter().enumerate() {
+            assert_eq!(e, visible[i / 32] & (1 << (i % 32)) != 0, "box {}", i);
+        }
+        // unused bits are cleared and unused words are untouched
+        assert_eq!(0, visible[2] >> 7);
+        assert_eq!(u32::MAX, visible[3]);
+
+        let mut visible = [];
+        f.intersects_aabbs_soa([&[]; 3], [&[]; 3], &mut visible);
+
+        should_panic!({
+            f.intersects_aabbs_soa(
+                [&min[0], &min[1], &min[2][1..]],
+                [&max[0], &max[1], &max[2]],
+                &mut [0; 3],
+            )
+        });
+        should_panic!({
+            f.intersects_aabbs_soa(
+                [&min[0], &min[1], &min[2]],
+                [&max[0], &max[1], &max[2]],
+                &mut [0; 2],
+            )
+        });
+    });
+}
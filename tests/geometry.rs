@@ -0,0 +1,429 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_geometry_tests {
+    ($t:ident, $vec2:ident, $vec3:ident, $plane:ident) => {
+        glam_test!(test_segment_intersection, {
+            use geometry::SegmentIntersection::*;
+            let v = |x, y| $vec2::new(x, y);
+
+            // crossing
+            let a0 = v(0.0, 0.0);
+            let a1 = v(4.0, 4.0);
+            let b0 = v(0.0, 4.0);
+            let b1 = v(4.0, 0.0);
+            assert_eq!(Point(v(2.0, 2.0)), segment_intersection(a0, a1, b0, b1));
+            assert_eq!(Point(v(2.0, 2.0)), segment_intersection(b1, b0, a1, a0));
+            assert_eq!(Some(v(2.0, 2.0)), segment_intersect(a0, a1, b0, b1));
+
+            // touching at end points
+            assert_eq!(Point(a1), segment_intersection(a0, a1, a1, v(5.0, 0.0)));
+            assert_eq!(
+                Point(v(2.0, 2.0)),
+                segment_intersection(a0, a1, v(2.0, 2.0), b1)
+            );
+
+            // not intersecting
+            assert_eq!(Disjoint, segment_intersection(a0, v(1.0, 1.0), b0, b1));
+            assert_eq!(None, segment_intersect(a0, v(1.0, 1.0), b0, b1));
+
+            // parallel
+            assert_eq!(
+                Disjoint,
+                segment_intersection(a0, a1, v(1.0, 0.0), v(5.0, 4.0))
+            );
+
+            // collinear
+            assert_eq!(
+                Overlap(v(1.0, 1.0), v(3.0, 3.0)),
+                segment_intersection(a0, v(3.0, 3.0), v(1.0, 1.0), a1)
+            );
+            assert_eq!(
+                Overlap(v(3.0, 3.0), v(1.0, 1.0)),
+                segment_intersection(v(3.0, 3.0), a0, v(1.0, 1.0), a1)
+            );
+            assert_eq!(
+                Overlap(v(1.0, 1.0), v(2.0, 2.0)),
+                segment_intersection(a0, a1, v(2.0, 2.0), v(1.0, 1.0))
+            );
+            assert_eq!(
+                Some(v(1.0, 1.0)),
+                segment_intersect(a0, a1, v(2.0, 2.0), v(1.0, 1.0))
+            );
+            assert_eq!(Point(a1), segment_intersection(a0, a1, a1, v(5.0, 5.0)));
+            assert_eq!(
+                Disjoint,
+                segment_intersection(a0, v(1.0, 1.0), v(2.0, 2.0), a1)
+            );
+
+            // degenerate segments
+            assert_eq!(
+                Point(v(1.0, 1.0)),
+                segment_intersection(v(1.0, 1.0), v(1.0, 1.0), a0, a1)
+            );
+            assert_eq!(
+                Point(v(1.0, 1.0)),
+                segment_intersection(a0, a1, v(1.0, 1.0), v(1.0, 1.0))
+            );
+            assert_eq!(
+                Disjoint,
+                segment_intersection(v(1.0, 0.0), v(1.0, 0.0), a0, a1)
+            );
+            assert_eq!(
+                Disjoint,
+                segment_intersection(a0, a1, v(5.0, 5.0), v(5.0, 5.0))
+            );
+            assert_eq!(Point(a0), segment_intersection(a0, a0, a0, a0));
+            assert_eq!(Disjoint, segment_intersection(a0, a0, a1, a1));
+        });
+
+        glam_test!(test_closest_point_on_segment, {
+            let a = $vec3::new(1.0, 0.0, 0.0);
+            let b = $vec3::new(3.0, 0.0, 0.0);
+            assert_eq!(
+                ($vec3::new(2.5, 0.0, 0.0), 0.75),
+                closest_point_on_segment($vec3::new(2.5, 4.0, -1.0), a, b)
+            );
+            assert_eq!(
+                (a, 0.0),
+                closest_point_on_segment($vec3::new(-1.0, 1.0, 0.0), a, b)
+            );
+            assert_eq!(
+                (b, 1.0),
+                closest_point_on_segment($vec3::new(5.0, 0.0, 1.0), a, b)
+            );
+            assert_eq!((a, 0.0), closest_point_on_segment($vec3::ONE, a, a));
+        });
+
+        glam_test!(test_closest_point_on_triangle, {
+            let a = $vec3::new(0.0, 0.0, 0.0);
+            let b = $vec3::new(2.0, 0.0, 0.0);
+            let c = $vec3::new(0.0, 2.0, 0.0);
+            let check = |p: $vec3, expected: $vec3, bary: $vec3| {
+                let (q, uvw) = closest_point_on_triangle(p, a, b, c);
+                assert_approx_eq!(expected, q);
+                assert_approx_eq!(bary, uvw);
+                assert_approx_eq!(q, a * uvw.x + b * uvw.y + c * uvw.z);
+            };
+            // vertex regions
+            check($vec3::new(-1.0, -1.0, 1.0), a, $vec3::X);
+            check($vec3::new(3.0, -1.0, -1.0), b, $vec3::Y);
+            check($vec3::new(-1.0, 3.0, 0.0), c, $vec3::Z);
+            // edge regions
+            check(
+                $vec3::new(0.5, -1.0, 2.0),
+                $vec3::new(0.5, 0.0, 0.0),
+                $vec3::new(0.75, 0.25, 0.0),
+            );
+            check(
+                $vec3::new(-1.0, 1.5, 0.0),
+                $vec3::new(0.0, 1.5, 0.0),
+                $vec3::new(0.25, 0.0, 0.75),
+            );
+            check(
+                $vec3::new(2.0, 2.0, 1.0),
+                $vec3::new(1.0, 1.0, 0.0),
+                $vec3::new(0.0, 0.5, 0.5),
+            );
+            // face region
+            check(
+                $vec3::new(0.5, 0.5, -3.0),
+                $vec3::new(0.5, 0.5, 0.0),
+                $vec3::new(0.5, 0.25, 0.25),
+            );
+            // a point on the triangle is its own closest point
+            check(a, a, $vec3::X);
+            check(
+                $vec3::new(1.0, 0.5, 0.0),
+                $vec3::new(1.0, 0.5, 0.0),
+                $vec3::new(0.25, 0.5, 0.25),
+            );
+
+            // compare with a brute force search over a tilted triangle
+            let a = $vec3::new(1.0, -2.0, 0.5);
+            let b = $vec3::new(-3.0, 1.0, 2.0);
+            let c = $vec3::new(2.0, 3.0, -1.0);
+            for p in [
+                $vec3::new(0.0, 0.0, 5.0),
+                $vec3::new(-5.0, 0.0, 0.0),
+                $vec3::new(4.0, 4.0, 4.0),
+                $vec3::new(1.0, -5.0, -2.0),
+            ] {
+                let (q, _) = closest_point_on_triangle(p, a, b, c);
+                let n = 64;
+                let mut best = $t::MAX;
+                for i in 0..=n {
+                    for j in 0..=(n - i) {
+                        let v = i as $t / n as $t;
+                        let w = j as $t / n as $t;
+                        let x = a * (1.0 - v - w) + b * v + c * w;
+                        best = best.min(x.distance(p));
+                    }
+                }
+                assert!(q.distance(p) <= best + 1e-5);
+                assert!(q.distance(p) >= best - 0.2);
+            }
+        });
+
+        glam_test!(test_point_in_triangle, {
+            let v = |x, y| $vec2::new(x, y);
+            let a = v(0.0, 0.0);
+            let b = v(4.0, 0.0);
+            let c = v(0.0, 4.0);
+            for (a, b, c) in [(a, b, c), (a, c, b), (c, a, b)] {
+                assert!(point_in_triangle(v(1.0, 1.0), a, b, c));
+                // edges and vertices are inside
+                assert!(point_in_triangle(v(2.0, 0.0), a, b, c));
+                assert!(point_in_triangle(v(2.0, 2.0), a, b, c));
+                assert!(point_in_triangle(v(0.0, 3.0), a, b, c));
+                assert!(point_in_triangle(a, a, b, c));
+                assert!(point_in_triangle(b, a, b, c));
+                // outside
+                assert!(!point_in_triangle(v(2.1, 2.0), a, b, c));
+                assert!(!point_in_triangle(v(-0.1, 1.0), a, b, c));
+                assert!(!point_in_triangle(v(5.0, 0.0), a, b, c));
+                assert!(!point_in_triangle(v(-1.0, -1.0), a, b, c));
+            }
+            // degenerate
+            assert!(!point_in_triangle(v(1.0, 0.0), a, b, v(2.0, 0.0)));
+            assert!(!point_in_triangle(a, a, a, a));
+        });
+
+        glam_test!(test_point_in_convex_polygon, {
+            let v = |x, y| $vec2::new(x, y);
+            let square = [v(0.0, 0.0), v(2.0, 0.0), v(2.0, 2.0), v(0.0, 2.0)];
+            let mut reversed = square;
+            reversed.reverse();
+            for poly in [&square[..], &reversed[..]] {
+                assert!(point_in_convex_polygon(v(1.0, 1.0), poly));
+                assert!(point_in_convex_polygon(v(2.0, 1.0), poly));
+                assert!(point_in_convex_polygon(v(0.0, 1.0), poly));
+                assert!(point_in_convex_polygon(v(2.0, 2.0), poly));
+                assert!(!point_in_convex_polygon(v(2.5, 1.0), poly));
+                assert!(!point_in_convex_polygon(v(1.0, -0.5), poly));
+                assert!(!point_in_convex_polygon(v(3.0, 3.0), poly));
+            }
+            // consistent with triangles
+            let tri = [v(0.0, 0.0), v(4.0, 0.0), v(0.0, 4.0)];
+            for p in [
+                v(1.0, 1.0),
+                v(2.0, 2.0),
+                v(2.1, 2.0),
+                v(0.0, 0.0),
+                v(-1.0, 2.0),
+            ] {
+                assert_eq!(
+                    point_in_triangle(p, tri[0], tri[1], tri[2]),
+                    point_in_convex_polygon(p, &tri)
+                );
+            }
+            // degenerate
+            assert!(!point_in_convex_polygon(v(0.0, 0.0), &[]));
+            assert!(!point_in_convex_polygon(v(0.0, 0.0), &square[..2]));
+            assert!(!point_in_convex_polygon(
+                v(1.0, 0.0),
+                &[v(0.0, 0.0), v(1.0, 0.0), v(2.0, 0.0)]
+            ));
+        });
+
+        glam_test!(test_triangle, {
+            use geometry::triangle::*;
+            let a = $vec3::new(1.0, 0.0, 1.0);
+            let b = $vec3::new(4.0, 0.0, 1.0);
+            let c = $vec3::new(1.0, 4.0, 1.0);
+            assert_eq!(6.0, area(a, b, c));
+            assert_eq!(6.0, area(a, c, b));
+            assert_eq!($vec3::Z, normal(a, b, c));
+            assert_eq!(-$vec3::Z, normal(a, c, b));
+            assert_eq!(normal(a, b, c), glam::$plane::from_points(a, b, c).normal);
+            assert_approx_eq!($vec3::new(2.0, 4.0 / 3.0, 1.0), centroid(a, b, c));
+            // the circumcenter of a right triangle is the middle of the hypotenuse
+            assert_approx_eq!($vec3::new(2.5, 2.0, 1.0), circumcenter(a, b, c).unwrap());
+            let d = $vec3::new(-2.0, 3.0, 0.5);
+            let o = circumcenter(a, b, d).unwrap();
+            assert_approx_eq!(o.distance(a), o.distance(b), 1e-5);
+            assert_approx_eq!(o.distance(a), o.distance(d), 1e-5);
+            assert_approx_eq!(0.0, normal(a, b, d).dot(o - a), 1e-5);
+            assert!(!is_degenerate(a, b, c));
+
+            // degenerate triangles
+            let e = $vec3::new(7.0, 0.0, 1.0);
+            assert_eq!(0.0, area(a, b, e));
+            assert_eq!($vec3::ZERO, normal(a, b, e));
+            assert_eq!(None, circumcenter(a, b, e));
+            assert_eq!(None, circumcenter(a, a, a));
+            assert!(is_degenerate(a, b, e));
+            assert!(is_degenerate(a, a, c));
+            assert!(is_degenerate(a, a, a));
+            assert!(is_degenerate(a, b, e + $vec3::Y * $t::EPSILON));
+            // the tolerance is relative to the size of the triangle
+            let s = 1e-3;
+            assert!(!is_degenerate(a * s, b * s, c * s));
+        });
+
+        glam_test!(test_triangle_2d, {
+            use geometry::triangle::*;
+            let a = $vec2::new(1.0, 0.0);
+            let b = $vec2::new(4.0, 0.0);
+            let c = $vec2::new(1.0, 4.0);
+            assert_eq!(6.0, signed_area_2d(a, b, c));
+            assert_eq!(-6.0, signed_area_2d(a, c, b));
+            assert_approx_eq!($vec2::new(2.0, 4.0 / 3.0), centroid_2d(a, b, c));
+            assert_approx_eq!($vec2::new(2.5, 2.0), circumcenter_2d(a, b, c).unwrap());
+            let d = $vec2::new(-2.0, 3.0);
+            let o = circumcenter_2d(a, b, d).unwrap();
+            assert_approx_eq!(o.distance(a), o.distance(b), 1e-5);
+            assert_approx_eq!(o.distance(a), o.distance(d), 1e-5);
+            assert!(!is_degenerate_2d(a, b, c));
+            let e = $vec2::new(7.0, 0.0);
+            assert_eq!(0.0, signed_area_2d(a, b, e));
+            assert_eq!(None, circumcenter_2d(a, b, e));
+            assert!(is_degenerate_2d(a, b, e));
+            assert!(is_degenerate_2d(a, a, a));
+            let s = 1e-3;
+            assert!(!is_degenerate_2d(a * s, b * s, c * s));
+        });
+
+        glam_test!(test_polygon_area, {
+            let v = |x, y| $vec2::new(x, y);
+            let square = [v(1.0, 1.0), v(3.0, 1.0), v(3.0, 3.0), v(1.0, 3.0)];
+            assert_eq!(4.0, polygon_area(&square));
+            let mut reversed = square;
+            reversed.reverse();
+            assert_eq!(-4.0, polygon_area(&reversed));
+            // concave L shape
+            let l = [
+                v(0.0, 0.0),
+                v(2.0, 0.0),
+                v(2.0, 1.0),
+                v(1.0, 1.0),
+                v(1.0, 3.0),
+                v(0.0, 3.0),
+            ];
+            assert_eq!(4.0, polygon_area(&l));
+            // matches triangle area
+            let tri = [v(0.0, 0.0), v(4.0, 1.0), v(1.0, 3.0)];
+            assert_eq!(
+                geometry::triangle::signed_area_2d(tri[0], tri[1], tri[2]),
+                polygon_area(&tri)
+            );
+            // far from the origin
+            let offset = v(1.0e6, -1.0e6);
+            assert_eq!(4.0, polygon_area(&square.map(|p| p + offset)));
+            // degenerate
+            assert_eq!(0.0, polygon_area(&[]));
+            assert_eq!(0.0, polygon_area(&square[..2]));
+            assert_eq!(0.0, polygon_area(&[v(0.0, 0.0), v(1.0, 1.0), v(2.0, 2.0)]));
+        });
+
+        glam_test!(test_polygon_centroid, {
+            let v = |x, y| $vec2::new(x, y);
+            let square = [v(1.0, 1.0), v(3.0, 1.0), v(3.0, 3.0), v(1.0, 3.0)];
+            assert_eq!(Some(v(2.0, 2.0)), polygon_centroid(&square));
+            let mut reversed = square;
+            reversed.reverse();
+            assert_eq!(Some(v(2.0, 2.0)), polygon_centroid(&reversed));
+            // the centroid of the area, not the average of the vertices
+            let l = [
+                v(0.0, 0.0),
+                v(2.0, 0.0),
+                v(2.0, 1.0),
+                v(1.0, 1.0),
+                v(1.0, 3.0),
+                v(0.0, 3.0),
+            ];
+            assert_approx_eq!(v(0.75, 1.25), polygon_centroid(&l).unwrap());
+            let tri = [v(0.0, 0.0), v(4.0, 1.0), v(1.0, 3.0)];
+            assert_approx_eq!(
+                geometry::triangle::centroid_2d(tri[0], tri[1], tri[2]),
+                polygon_centroid(&tri).unwrap()
+            );
+            // degenerate
+            assert_eq!(None, polygon_centroid(&[]));
+            assert_eq!(None, polygon_centroid(&square[..2]));
+            assert_eq!(
+                None,
+                polygon_centroid(&[v(0.0, 0.0), v(1.0, 1.0), v(2.0, 2.0)])
+            );
+        });
+
+        glam_test!(test_distance_to_line, {
+            let p = $vec3::new(1.0, 3.0, 4.0);
+            let origin = $vec3::new(5.0, 0.0, 0.0);
+            assert_eq!(25.0, distance_to_line_squared(p, origin, $vec3::X * 2.0));
+            assert_eq!(5.0, distance_to_line(p, origin, -$vec3::X));
+            assert_approx_eq!(
+                core::$t::consts::SQRT_2 * 4.0,
+                distance_to_line(p, origin, $vec3::Y)
+            );
+            assert_approx_eq!(0.0, distance_to_line(origin * 3.0, origin, origin));
+            // degenerate direction
+            assert_approx_eq!(p.distance(origin), distance_to_line(p, origin, $vec3::ZERO));
+
+            let p = $vec2::new(1.0, 3.0);
+            let origin = $vec2::new(5.0, 0.0);
+            assert_eq!(9.0, distance_to_line_squared_2d(p, origin, $vec2::X * 2.0));
+            assert_eq!(3.0, distance_to_line_2d(p, origin, -$vec2::X));
+            assert_approx_eq!(
+                core::$t::consts::SQRT_2 * 3.5,
+                distance_to_line_2d(p, origin, $vec2::ONE)
+            );
+            assert_eq!(5.0, distance_to_line_2d(p, origin, $vec2::ZERO));
+        });
+
+        glam_test!(test_distance_to_segment, {
+            let a = $vec3::new(1.0, 0.0, 0.0);
+            let b = $vec3::new(3.0, 0.0, 0.0);
+            assert_eq!(
+                16.0,
+                distance_to_segment_squared($vec3::new(2.0, 4.0, 0.0), a, b)
+            );
+            assert_eq!(5.0, distance_to_segment($vec3::new(2.0, 4.0, 3.0), a, b));
+            assert_eq!(2.0, distance_to_segment($vec3::new(-1.0, 0.0, 0.0), a, b));
+            assert_eq!(5.0, distance_to_segment($vec3::new(6.0, 4.0, 0.0), a, b));
+            assert_eq!(0.0, distance_to_segment($vec3::new(2.5, 0.0, 0.0), a, b));
+            assert_eq!(2.0, distance_to_segment($vec3::new(-1.0, 0.0, 0.0), a, a));
+            let p = $vec3::new(2.2, -1.0, 0.5);
+            assert_approx_eq!(
+                closest_point_on_segment(p, a, b).0.distance(p),
+                distance_to_segment(p, a, b)
+            );
+
+            let a = $vec2::new(1.0, 0.0);
+            let b = $vec2::new(3.0, 0.0);
+            assert_eq!(
+                16.0,
+                distance_to_segment_squared_2d($vec2::new(2.0, 4.0), a, b)
+            );
+            assert_eq!(2.0, distance_to_segment_2d($vec2::new(-1.0, 0.0), a, b));
+            assert_eq!(5.0, distance_to_segment_2d($vec2::new(6.0, 4.0), a, b));
+            assert_eq!(5.0, distance_to_segment_2d($vec2::new(6.0, 4.0), b, b));
+        });
+    };
+}
+
+mod f32 {
+    use glam::f32::geometry::{
+        self, closest_point_on_segment, closest_point_on_triangle, distance_to_line,
+        distance_to_line_2d, distance_to_line_squared, distance_to_line_squared_2d,
+        distance_to_segment, distance_to_segment_2d, distance_to_segment_squared,
+        distance_to_segment_squared_2d, point_in_convex_polygon, point_in_triangle, polygon_area,
+        polygon_centroid, segment_intersect, segment_intersection,
+    };
+    use glam::{Vec2, Vec3};
+
+    impl_geometry_tests!(f32, Vec2, Vec3, Plane);
+}
+
+mod f64 {
+    use glam::f64::geometry::{
+        self, closest_point_on_segment, closest_point_on_triangle, distance_to_line,
+        distance_to_line_2d, distance_to_line_squared, distance_to_line_squared_2d,
+        distance_to_segment, distance_to_segment_2d, distance_to_segment_squared,
+        distance_to_segment_squared_2d, point_in_convex_polygon, point_in_triangle, polygon_area,
+        polygon_centroid, segment_intersect, segment_intersection,
+    };
+    use glam::{DVec2, DVec3};
+
+    impl_geometry_tests!(f64, DVec2, DVec3, DPlane);
+}
@@ -19,6 +19,36 @@ macro_rules! impl_mat2_tests {
             assert_eq!(MATRIX1D, M2.to_cols_array());
         });
 
+        glam_test!(test_from_str, {
+            let a = $mat2::from_cols_array(&MATRIX1D);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 4,
+                    found: 3
+                }),
+                "[[1, 2], [3]]".parse::<$mat2>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$mat2>()
+            );
+        });
+
+        glam_test!(test_bytes, {
+            let m = $mat2::from_cols_array(&MATRIX1D);
+            let size = core::mem::size_of::<$t>();
+            let le = m.to_le_bytes();
+            let be = m.to_be_bytes();
+            assert_eq!(le.len(), size * 4);
+            assert_eq!(&le[size..size * 2], &MATRIX1D[1].to_le_bytes());
+            assert_eq!(&be[size..size * 2], &MATRIX1D[1].to_be_bytes());
+            assert_eq!(m, $mat2::from_le_bytes(le));
+            assert_eq!(m, $mat2::from_be_bytes(be));
+        });
+
         glam_test!(test_mat2_identity, {
             assert_eq!($mat2::IDENTITY, $mat2::from_cols_array(&[1., 0., 0., 1.]));
             let identity = $mat2::IDENTITY;
@@ -91,6 +121,13 @@ macro_rules! impl_mat2_tests {
             assert_approx_eq!($vec2::Y * -2.0, rot * $vec2::Y, 1.0e-6);
         });
 
+        glam_test!(test_from_outer_product, {
+            let m = $mat2::from_outer_product($vec2::new(1.0, 2.0), $vec2::new(3.0, 4.0));
+            assert_eq!(m, $mat2::from_cols_array_2d(&[[3.0, 6.0], [4.0, 8.0]]));
+            let v = $vec2::new(-5.0, 0.5);
+            assert_eq!(m * v, $vec2::new(1.0, 2.0) * $vec2::new(3.0, 4.0).dot(v));
+        });
+
         glam_test!(test_from_diagonal, {
             let m = $mat2::from_diagonal($vec2::new(2 as $t, 4 as $t));
             assert_eq!(
@@ -109,6 +146,83 @@ macro_rules! impl_mat2_tests {
             assert_eq!($mat2::from_cols_array_2d(&[[1.0, 2.0], [4.0, 5.0]]), m2);
         });
 
+        glam_test!(test_mat2_rows_array_and_tuples, {
+            let m = $mat2::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.transpose().to_cols_array_2d(), m.to_rows_array_2d());
+            assert_eq!($mat2::from_rows_array_2d(&MATRIX), m.transpose());
+            assert_eq!($mat2::from_rows_array_2d(&m.to_rows_array_2d()), m);
+
+            let t: (($t, $t), ($t, $t)) = m.into();
+            assert_eq!(t.0, m.x_axis.into());
+            assert_eq!(t.1, m.y_axis.into());
+            assert_eq!($mat2::from(t), m);
+        });
+
+        glam_test!(test_mat2_col_and_row_iters, {
+            let mut m = $mat2::from_cols_array_2d(&MATRIX);
+            assert!(m.cols().eq((0..2).map(|i| m.col(i))));
+            assert!(m.rows().eq((0..2).map(|i| m.row(i))));
+            assert!(m.rows().eq(m.transpose().cols()));
+
+            for col in m.cols_mut() {
+                *col *= 2.0;
+            }
+            assert_eq!(m, $mat2::from_cols_array_2d(&MATRIX) * 2.0);
+        });
+
+        glam_test!(test_mat2_element_and_row_mut, {
+            let mut m = $mat2::from_cols_array_2d(&MATRIX);
+            for col in 0..2 {
+                for row in 0..2 {
+                    assert_eq!(m.element(col, row), MATRIX[col][row]);
+                }
+            }
+            *m.element_mut(1, 0) = -1.0;
+            assert_eq!(m.y_axis.x, -1.0);
+
+            let r = m.row(1) * 2.0;
+            m.set_row(1, r);
+            assert_eq!(m.row(1), r);
+            m.row_mut(0, |row| *row = -*row);
+            assert_eq!(
+                m.row(0),
+                (-$mat2::from_cols_array_2d(&MATRIX).row(0)).with_y(1.0)
+            );
+
+            should_panic!({ $mat2::ZERO.element(2, 0) });
+            should_panic!({ $mat2::ZERO.element(0, 2) });
+            should_panic!({
+                let mut m = $mat2::ZERO;
+                m.set_row(2, m.x_axis);
+            });
+        });
+
+        glam_test!(test_mat2_col_axis, {
+            use glam::Axis;
+            let mut m = $mat2::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.col_axis(Axis::X), m.col(0));
+            assert_eq!(m.col_axis(Axis::Y), m.col(1));
+            *m.col_axis_mut(Axis::X) = m.col(1);
+            assert_eq!(m.x_axis, m.y_axis);
+            should_panic!({ m.col_axis(Axis::Z) });
+        });
+
+        glam_test!(test_mat2_from_fn, {
+            let m = $mat2::from_cols_array_2d(&MATRIX);
+            assert_eq!($mat2::from_fn(|col, row| MATRIX[col][row]), m);
+            assert_eq!($mat2::from_fn(|col, row| m.row(row)[col]), m);
+            assert_eq!(
+                $mat2::from_fn(|col, row| if col == row { 1.0 } else { 0.0 }),
+                $mat2::IDENTITY
+            );
+        });
+
+        glam_test!(test_mat2_map, {
+            let m = $mat2::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.map(|e| e * 2.0), m * 2.0);
+            assert_eq!(m.map(|e| -e), -m);
+        });
+
         glam_test!(test_mat2_transpose, {
             let m = $newmat2($newvec2(1.0, 2.0), $newvec2(3.0, 4.0));
             let mt = m.transpose();
@@ -132,6 +246,44 @@ macro_rules! impl_mat2_tests {
             );
         });
 
+        glam_test!(test_mat2_is_flipping, {
+            assert!(!$mat2::IDENTITY.is_flipping());
+            assert!(!$mat2::from_angle(deg(135.0)).is_flipping());
+            assert!($mat2::from_diagonal($newvec2(-1.0, 1.0)).is_flipping());
+            assert!($mat2::from_diagonal($newvec2(2.0, -0.5)).is_flipping());
+            assert!(!$mat2::from_diagonal($newvec2(-2.0, -0.5)).is_flipping());
+            assert!(!$mat2::ZERO.is_flipping());
+            assert!(!$mat2::from_cols($vec2::X, -$vec2::X).is_flipping());
+        });
+
+        glam_test!(test_mat2_try_inverse, {
+            let m = $mat2::from_scale_angle($vec2::new(4.0, 5.0), deg(30.0));
+            assert!(m.is_invertible());
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+            assert_approx_eq!($mat2::IDENTITY, m * m.try_inverse().unwrap());
+
+            for m in [
+                $mat2::ZERO,
+                $mat2::NAN,
+                $mat2::from_diagonal($newvec2(1.0, 0.0)),
+                $mat2::from_diagonal($newvec2($t::INFINITY, 1.0)),
+                $mat2::from_cols($newvec2(1.0, 1.0), $newvec2(1.0, 1.0 + $t::EPSILON)),
+            ] {
+                assert!(!m.is_invertible());
+                assert_eq!(None, m.try_inverse());
+            }
+
+            // the tolerance is relative
+            let small = $mat2::from_diagonal($newvec2(1.0e-10, 1.0e-10));
+            assert!(small.is_invertible());
+            assert_approx_eq!($mat2::IDENTITY, small * small.try_inverse().unwrap());
+            // but the inverse of a tiny matrix must not overflow
+            let tiny = $mat2::from_diagonal($vec2::splat($t::MIN_POSITIVE.sqrt() * 0.25));
+            assert!(!tiny.is_invertible());
+            assert_eq!(None, tiny.try_inverse());
+            assert_eq!(Err(glam::error::Error::Singular), tiny.inverse_checked());
+        });
+
         glam_test!(test_mat2_inverse, {
             let inv = $mat2::IDENTITY.inverse();
             assert_approx_eq!($mat2::IDENTITY, inv);
@@ -194,6 +346,26 @@ macro_rules! impl_mat2_tests {
             let a = $mat2::from_cols_array_2d(&MATRIX);
             assert_eq!(format!("{}", a), "[[1, 2], [3, 4]]");
             assert_eq!(format!("{:.2}", a), "[[1.00, 2.00], [3.00, 4.00]]");
+            assert_eq!(format!("{:4}", a), "[[   1,    2], [   3,    4]]");
+            assert_eq!(format!("{:#}", a), "[1, 3]\n[2, 4]");
+            assert_eq!(format!("{:#.1}", -a), "[-1.0, -3.0]\n[-2.0, -4.0]");
+            // the flags apply to each value, as they do for vectors
+            assert_eq!(
+                format!("{:+#}", a),
+                format!("{:+}\n{:+}", a.row(0), a.row(1))
+            );
+            assert_eq!(format!("{:*<#3}", a), "[1**, 3**]\n[2**, 4**]");
+            assert_eq!(format!("{:^#5}", -a), "[ -1  ,  -3  ]\n[ -2  ,  -4  ]");
+            assert_eq!(format!("{:+#05.1}", a), "[+01.0, +03.0]\n[+02.0, +04.0]");
+            assert_eq!(
+                format!("{:.1?}", a),
+                format!(
+                    "{} {{ x_axis: {}, y_axis: {} }}",
+                    stringify!($mat2),
+                    format!("{:.1?}", a.x_axis),
+                    format!("{:.1?}", a.y_axis)
+                )
+            );
         });
 
         glam_test!(test_mat2_to_from_slice, {
@@ -292,6 +464,12 @@ mod dmat2 {
         assert_eq!(mem::align_of::<DVec2>(), mem::align_of::<DMat2>());
     });
 
+    glam_test!(test_const_transpose, {
+        const M: DMat2 = DMat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]);
+        const T: DMat2 = M.transpose();
+        assert_eq!(T.x_axis, M.row(0));
+    });
+
     impl_mat2_tests!(f64, dmat2, DMat2, DMat3, dvec2, DVec2);
     impl_as_ref_tests!(DMat2);
 }
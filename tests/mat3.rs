@@ -27,6 +27,36 @@ macro_rules! impl_mat3_tests {
             assert_eq!(MATRIX1D, M2.to_cols_array());
         });
 
+        glam_test!(test_from_str, {
+            let a = $mat3::from_cols_array(&MATRIX1D);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 9,
+                    found: 8
+                }),
+                "[[1, 2, 3], [4, 5, 6], [7, 8]]".parse::<$mat3>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$mat3>()
+            );
+        });
+
+        glam_test!(test_bytes, {
+            let m = $mat3::from_cols_array(&MATRIX1D);
+            let size = core::mem::size_of::<$t>();
+            let le = m.to_le_bytes();
+            let be = m.to_be_bytes();
+            assert_eq!(le.len(), size * 9);
+            assert_eq!(&le[size..size * 2], &MATRIX1D[1].to_le_bytes());
+            assert_eq!(&be[size..size * 2], &MATRIX1D[1].to_be_bytes());
+            assert_eq!(m, $mat3::from_le_bytes(le));
+            assert_eq!(m, $mat3::from_be_bytes(be));
+        });
+
         glam_test!(test_mat3_identity, {
             assert_eq!(
                 $mat3::IDENTITY,
@@ -109,6 +139,68 @@ macro_rules! impl_mat3_tests {
             assert_eq!(b, f);
         });
 
+        glam_test!(test_orthonormal_basis_from_normal, {
+            for n in [
+                $vec3::X,
+                $vec3::NEG_Y,
+                $vec3::Z,
+                $vec3::NEG_Z,
+                $vec3::new(-0.0, 0.0, -1.0),
+                $vec3::new(1.0, 2.0, -3.0).normalize(),
+                $vec3::new(0.5, -0.5, 1e-6).normalize(),
+            ] {
+                let m = $mat3::orthonormal_basis_from_normal(n);
+                assert_eq!(m.mul_vec3($vec3::Z), n);
+                assert_approx_eq!(m.transpose() * m, $mat3::IDENTITY, 1e-6);
+                assert_approx_eq!(m.determinant(), 1.0, 1e-6);
+                let (x_axis, y_axis) = n.any_orthonormal_pair();
+                assert_eq!(m.mul_vec3($vec3::X), x_axis);
+                assert_eq!(m.mul_vec3($vec3::Y), y_axis);
+            }
+            should_glam_assert!({ $mat3::orthonormal_basis_from_normal($vec3::ONE) });
+        });
+
+        glam_test!(test_from_normal_tangent, {
+            let m = $mat3::from_normal_tangent($vec3::Z, $vec3::X, 1.0);
+            assert_eq!(m, $mat3::IDENTITY);
+            let m = $mat3::from_normal_tangent($vec3::Z, $vec3::X, -1.0);
+            assert_eq!(m.mul_vec3($vec3::Y), $vec3::NEG_Y);
+
+            // the tangent is re-orthogonalized against the normal
+            let n = $vec3::new(1.0, 1.0, 0.0).normalize();
+            let m = $mat3::from_normal_tangent(n, $vec3::new(2.0, 0.0, 0.5), 1.0);
+            assert_approx_eq!(m.transpose() * m, $mat3::IDENTITY, 1e-6);
+            assert_approx_eq!(m.determinant(), 1.0, 1e-6);
+            assert_eq!(m.mul_vec3($vec3::Z), n);
+            assert!(m.mul_vec3($vec3::X).dot($vec3::X) > 0.0);
+            let m = $mat3::from_normal_tangent(n, $vec3::new(2.0, 0.0, 0.5), -1.0);
+            assert_approx_eq!(m.determinant(), -1.0, 1e-6);
+
+            // a tangent parallel to the normal is replaced
+            let m = $mat3::from_normal_tangent(n, n * 2.0, 1.0);
+            assert!(m.is_finite());
+            assert_approx_eq!(m.transpose() * m, $mat3::IDENTITY, 1e-6);
+        });
+
+        glam_test!(test_from_cross_product, {
+            let v = $vec3::new(1.0, -2.0, 3.0);
+            let k = $mat3::from_cross_product(v);
+            for x in [$vec3::X, $vec3::Y, $vec3::Z, $vec3::new(-4.0, 0.5, 2.0)] {
+                assert_eq!(k * x, v.cross(x));
+            }
+            assert_eq!(k.transpose(), -k);
+            assert_eq!(k.to_axial_vector(), v);
+            // the symmetric part is ignored
+            let s = $mat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [2.0, 4.0, 5.0], [3.0, 5.0, 6.0]]);
+            assert_eq!((k + s).to_axial_vector(), v);
+            // Rodrigues' rotation formula
+            let axis = $vec3::new(2.0, 1.0, -2.0).normalize();
+            let angle: $t = 0.5;
+            let k = $mat3::from_cross_product(axis);
+            let r = $mat3::IDENTITY + k * angle.sin() + k * k * (1.0 - angle.cos());
+            assert_approx_eq!(r, $mat3::from_axis_angle(axis, angle), 1e-6);
+        });
+
         glam_test!(test_from_rotation, {
             let rot_x1 = $mat3::from_rotation_x(deg(180.0));
             let rot_x2 = $mat3::from_axis_angle($vec3::X, deg(180.0));
@@ -141,6 +233,11 @@ macro_rules! impl_mat3_tests {
             );
         });
 
+        glam_test!(test_mat3_const_from_translation, {
+            const M: $mat3 = $mat3::from_translation($vec2::new(2.0, 4.0));
+            assert_eq!($vec2::new(2.0, 4.0), M.transform_point2($vec2::ZERO));
+        });
+
         glam_test!(test_mat3_transform2d, {
             let m = $mat3::from_translation($vec2::new(2.0, 4.0));
             assert_eq!($vec2::ZERO, m.transform_vector2($vec2::ZERO));
@@ -200,6 +297,24 @@ macro_rules! impl_mat3_tests {
             assert_approx_eq!(yxz0, yxz1, 1e-6);
         });
 
+        glam_test!(test_from_outer_product, {
+            let m = $mat3::from_outer_product($vec3::new(1.0, 2.0, 3.0), $vec3::new(4.0, 5.0, 6.0));
+            assert_eq!(
+                m,
+                $mat3::from_cols_array_2d(&[
+                    [4.0, 8.0, 12.0],
+                    [5.0, 10.0, 15.0],
+                    [6.0, 12.0, 18.0]
+                ])
+            );
+            // projection onto the plane with the given normal
+            let n = $vec3::new(1.0, 2.0, -2.0).normalize();
+            let p = $mat3::IDENTITY - $mat3::from_outer_product(n, n);
+            let v = $vec3::new(3.0, -1.0, 2.0);
+            assert_approx_eq!(p.mul_vec3(v), v - n * n.dot(v), 1e-6);
+            assert_approx_eq!(n.dot(p.mul_vec3(v)), 0.0, 1e-6);
+        });
+
         glam_test!(test_from_diagonal, {
             let m = $mat3::from_diagonal($vec3::new(2.0, 4.0, 8.0));
             assert_approx_eq!(m * $vec3::new(1.0, 1.0, 1.0), $vec3::new(2.0, 4.0, 8.0));
@@ -231,6 +346,84 @@ macro_rules! impl_mat3_tests {
             );
         });
 
+        glam_test!(test_mat3_rows_array_and_tuples, {
+            let m = $mat3::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.transpose().to_cols_array_2d(), m.to_rows_array_2d());
+            assert_eq!($mat3::from_rows_array_2d(&MATRIX), m.transpose());
+            assert_eq!($mat3::from_rows_array_2d(&m.to_rows_array_2d()), m);
+
+            let t: (($t, $t, $t), ($t, $t, $t), ($t, $t, $t)) = m.into();
+            assert_eq!(t.0, m.x_axis.into());
+            assert_eq!(t.1, m.y_axis.into());
+            assert_eq!($mat3::from(t), m);
+        });
+
+        glam_test!(test_mat3_col_and_row_iters, {
+            let mut m = $mat3::from_cols_array_2d(&MATRIX);
+            assert!(m.cols().eq((0..3).map(|i| m.col(i))));
+            assert!(m.rows().eq((0..3).map(|i| m.row(i))));
+            assert!(m.rows().eq(m.transpose().cols()));
+
+            for col in m.cols_mut() {
+                *col *= 2.0;
+            }
+            assert_eq!(m, $mat3::from_cols_array_2d(&MATRIX) * 2.0);
+        });
+
+        glam_test!(test_mat3_element_and_row_mut, {
+            let mut m = $mat3::from_cols_array_2d(&MATRIX);
+            for col in 0..3 {
+                for row in 0..3 {
+                    assert_eq!(m.element(col, row), MATRIX[col][row]);
+                }
+            }
+            *m.element_mut(1, 0) = -1.0;
+            assert_eq!(m.y_axis.x, -1.0);
+
+            let r = m.row(1) * 2.0;
+            m.set_row(1, r);
+            assert_eq!(m.row(1), r);
+            m.row_mut(0, |row| *row = -*row);
+            assert_eq!(
+                m.row(0),
+                (-$mat3::from_cols_array_2d(&MATRIX).row(0)).with_y(1.0)
+            );
+
+            should_panic!({ $mat3::ZERO.element(3, 0) });
+            should_panic!({ $mat3::ZERO.element(0, 3) });
+            should_panic!({
+                let mut m = $mat3::ZERO;
+                m.set_row(3, m.x_axis);
+            });
+        });
+
+        glam_test!(test_mat3_col_axis, {
+            use glam::Axis;
+            let mut m = $mat3::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.col_axis(Axis::X), m.col(0));
+            assert_eq!(m.col_axis(Axis::Y), m.col(1));
+            assert_eq!(m.col_axis(Axis::Z), m.col(2));
+            *m.col_axis_mut(Axis::X) = m.col(1);
+            assert_eq!(m.x_axis, m.y_axis);
+            should_panic!({ m.col_axis(Axis::W) });
+        });
+
+        glam_test!(test_mat3_from_fn, {
+            let m = $mat3::from_cols_array_2d(&MATRIX);
+            assert_eq!($mat3::from_fn(|col, row| MATRIX[col][row]), m);
+            assert_eq!($mat3::from_fn(|col, row| m.row(row)[col]), m);
+            assert_eq!(
+                $mat3::from_fn(|col, row| if col == row { 1.0 } else { 0.0 }),
+                $mat3::IDENTITY
+            );
+        });
+
+        glam_test!(test_mat3_map, {
+            let m = $mat3::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.map(|e| e * 2.0), m * 2.0);
+            assert_eq!(m.map(|e| -e), -m);
+        });
+
         glam_test!(test_mat3_transpose, {
             let m = $newmat3(
                 $newvec3(1.0, 2.0, 3.0),
@@ -255,6 +448,71 @@ macro_rules! impl_mat3_tests {
             );
         });
 
+        glam_test!(test_mat3_handedness, {
+            let rotation = $mat3::from_rotation_y(deg(135.0));
+            assert!(rotation.is_right_handed());
+            assert!(!rotation.is_flipping());
+
+            let mirror = $mat3::from_diagonal($vec3::new(1.0, -1.0, 1.0));
+            assert!(!mirror.is_right_handed());
+            assert!(mirror.is_flipping());
+            assert!((rotation * mirror).is_flipping());
+            assert!((mirror * mirror).is_right_handed());
+
+            // tolerance scales with the column lengths
+            let small = $mat3::from_diagonal($vec3::new(-1.0e-10, 1.0e-10, 1.0e-10));
+            assert!(small.is_flipping());
+            assert!((small * rotation).is_flipping());
+
+            // close to singular
+            for m in [
+                $mat3::ZERO,
+                $newmat3(
+                    $newvec3(1.0, 0.0, 0.0),
+                    $newvec3(0.0, 1.0, 0.0),
+                    $newvec3(1.0, 0.0, 0.0),
+                ),
+                $newmat3(
+                    $newvec3(1.0, 0.0, 0.0),
+                    $newvec3(0.0, 1.0, 0.0),
+                    $newvec3(1.0, 1.0, 0.0),
+                ),
+            ] {
+                assert!(!m.is_right_handed());
+                assert!(!m.is_flipping());
+            }
+        });
+
+        glam_test!(test_mat3_try_inverse, {
+            let m = $mat3::from_scale_angle_translation(
+                $vec2::new(4.0, 5.0),
+                deg(30.0),
+                $vec2::new(1.0, 2.0),
+            );
+            assert!(m.is_invertible());
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+            assert_approx_eq!($mat3::IDENTITY, m * m.try_inverse().unwrap(), 1.0e-6);
+
+            // not singular but close enough to be rejected
+            let nearly_singular = $newmat3(
+                $newvec3(1.0, 0.0, 0.0),
+                $newvec3(0.0, 1.0, 0.0),
+                $newvec3(1.0, 1.0, $t::EPSILON * 0.5),
+            );
+            for m in [
+                $mat3::ZERO,
+                $mat3::NAN,
+                $mat3::from_diagonal($vec3::new(1.0, 1.0, 0.0)),
+                nearly_singular,
+                // the inverse would overflow
+                $mat3::from_diagonal($vec3::splat($t::MIN_POSITIVE.cbrt() * 0.25)),
+            ] {
+                assert!(!m.is_invertible());
+                assert_eq!(None, m.try_inverse());
+                assert_eq!(Err(glam::error::Error::Singular), m.inverse_checked());
+            }
+        });
+
         glam_test!(test_mat3_inverse, {
             // assert_eq!(None, $mat3::ZERO.inverse());
             let inv = $mat3::IDENTITY.inverse();
@@ -412,6 +670,14 @@ mod mat3 {
         );
     });
 
+    glam_test!(test_const_transpose, {
+        const M: Mat3 =
+            Mat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        const T: Mat3 = M.transpose();
+        assert_eq!(T, M.transpose());
+        assert_eq!(T.x_axis, M.row(0));
+    });
+
     impl_mat3_tests!(f32, mat3, Mat3, Mat2, Mat4, Quat, vec3, Vec3, Vec2);
     impl_as_ref_tests!(Mat3);
 }
@@ -420,6 +686,20 @@ mod mat3a {
     use super::support::deg;
     use glam::{mat3a, swizzles::*, vec3a, Mat2, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A};
 
+    glam_test!(test_from_quat_matches_mat3, {
+        // the SIMD implementation must give the same result as the scalar Mat3 one
+        let quats = [
+            Quat::IDENTITY,
+            Quat::from_rotation_x(deg(30.0)),
+            Quat::from_rotation_y(deg(-100.0)),
+            Quat::from_euler(glam::EulerRot::ZYX, 0.3, -1.2, 2.5),
+            Quat::from_axis_angle(Vec3::new(1.0, 2.0, -3.0).normalize(), 4.0),
+        ];
+        for q in quats {
+            assert_eq!(Mat3A::from(glam::Mat3::from_quat(q)), Mat3A::from_quat(q));
+        }
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(48, mem::size_of::<Mat3A>());
@@ -453,6 +733,14 @@ mod dmat3 {
         assert_eq!(mem::align_of::<DVec3>(), mem::align_of::<DMat3>());
     });
 
+    glam_test!(test_const_transpose, {
+        const M: DMat3 =
+            DMat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        const T: DMat3 = M.transpose();
+        assert_eq!(T, M.transpose());
+        assert_eq!(T.x_axis, M.row(0));
+    });
+
     impl_mat3_tests!(f64, dmat3, DMat3, DMat2, DMat4, DQuat, dvec3, DVec3, DVec2);
     impl_as_ref_tests!(DMat3);
 }
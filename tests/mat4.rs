@@ -39,6 +39,89 @@ macro_rules! impl_mat4_tests {
             assert_eq!(MATRIX1D, M2.to_cols_array());
         });
 
+        glam_test!(test_inverse_checked, {
+            let m = $mat4::from_scale_rotation_translation(
+                $vec3::new(1.0, 2.0, 3.0),
+                $quat::from_rotation_y(1.0),
+                $vec3::new(4.0, 5.0, 6.0),
+            );
+            assert_eq!(m.inverse_checked(), Ok(m.inverse()));
+            assert_eq!(
+                $mat4::ZERO.inverse_checked(),
+                Err(glam::error::Error::Singular)
+            );
+            assert_eq!(
+                $mat4::NAN.inverse_checked(),
+                Err(glam::error::Error::Singular)
+            );
+            assert_eq!(
+                $mat4::from_cols_slice_checked(&MATRIX1D[1..]),
+                Err(glam::error::Error::SliceTooShort {
+                    required: 16,
+                    found: 15
+                })
+            );
+            assert_eq!(
+                $mat4::from_cols_slice_checked(&MATRIX1D),
+                Ok($mat4::from_cols_array(&MATRIX1D))
+            );
+        });
+
+        glam_test!(test_finite_or, {
+            let a = $mat4::from_cols_array(&MATRIX1D);
+            let mut b = MATRIX1D;
+            b[3] = $t::NAN;
+            b[14] = $t::INFINITY;
+            assert_eq!($mat4::from_cols_array(&b).finite_or($mat4::ZERO), {
+                let mut c = MATRIX1D;
+                c[3] = 0.0;
+                c[14] = 0.0;
+                $mat4::from_cols_array(&c)
+            });
+            assert_eq!(a.finite_or($mat4::NAN), a);
+            assert_eq!($mat4::NAN.finite_or(a), a);
+        });
+
+        glam_test!(test_ulps_eq, {
+            let a = $mat4::from_cols_array(&MATRIX1D);
+            let mut b = MATRIX1D;
+            b[5] = $t::from_bits(b[5].to_bits() + 2);
+            let b = $mat4::from_cols_array(&b);
+            assert!(a.ulps_eq(b, 2));
+            assert!(!a.ulps_eq(b, 1));
+            assert!(!$mat4::NAN.ulps_eq($mat4::NAN, 4));
+        });
+
+        glam_test!(test_from_str, {
+            let a = $mat4::from_cols_array(&MATRIX1D);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 16,
+                    found: 15
+                }),
+                "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15]]".parse::<$mat4>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$mat4>()
+            );
+        });
+
+        glam_test!(test_bytes, {
+            let m = $mat4::from_cols_array(&MATRIX1D);
+            let size = core::mem::size_of::<$t>();
+            let le = m.to_le_bytes();
+            let be = m.to_be_bytes();
+            assert_eq!(le.len(), size * 16);
+            assert_eq!(&le[size..size * 2], &MATRIX1D[1].to_le_bytes());
+            assert_eq!(&be[size..size * 2], &MATRIX1D[1].to_be_bytes());
+            assert_eq!(m, $mat4::from_le_bytes(le));
+            assert_eq!(m, $mat4::from_be_bytes(be));
+        });
+
         glam_test!(test_mat4_identity, {
             assert_eq!(
                 $mat4::IDENTITY,
@@ -113,6 +196,18 @@ macro_rules! impl_mat4_tests {
             should_panic!({ $mat4::ZERO.row(4) });
         });
 
+        glam_test!(test_from_outer_product, {
+            let a = $vec4::new(1.0, 2.0, 3.0, 4.0);
+            let b = $vec4::new(-1.0, 0.5, 2.0, 0.0);
+            let m = $mat4::from_outer_product(a, b);
+            assert_eq!(m.x_axis, -a);
+            assert_eq!(m.y_axis, a * 0.5);
+            assert_eq!(m.z_axis, a * 2.0);
+            assert_eq!(m.w_axis, $vec4::ZERO);
+            let v = $vec4::new(3.0, -1.0, 2.0, 1.0);
+            assert_eq!(m * v, a * b.dot(v));
+        });
+
         glam_test!(test_mat4_from_axes, {
             let a = $mat4::from_cols_array_2d(&[
                 [1.0, 2.0, 3.0, 4.0],
@@ -140,6 +235,11 @@ macro_rules! impl_mat4_tests {
             assert_eq!(b, f);
         });
 
+        glam_test!(test_mat4_const_from_translation, {
+            const M: $mat4 = $mat4::from_translation($vec3::new(1.0, 2.0, 3.0));
+            assert_eq!($vec3::new(1.0, 2.0, 3.0), M.transform_point3($vec3::ZERO));
+        });
+
         glam_test!(test_mat4_translation, {
             let translate = $mat4::from_translation($newvec3(1.0, 2.0, 3.0));
             assert_eq!(
@@ -222,6 +322,28 @@ macro_rules! impl_mat4_tests {
             should_glam_assert!({ $mat4::ZERO.transform_point3($vec3::X) });
         });
 
+        glam_test!(test_mat4_mul_affine, {
+            let a = $mat4::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, 2.0),
+                $quat::from_rotation_x(deg(90.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let b = $mat4::from_scale_rotation_translation(
+                $vec3::new(2.0, 1.0, 3.0),
+                $quat::from_rotation_y(deg(30.0)),
+                $vec3::new(-4.0, 5.0, 6.0),
+            );
+            assert_approx_eq!(a.mul_mat4(&b), a.mul_mat4_affine(&b), 1.0e-5);
+            assert_approx_eq!(b.mul_mat4(&a), b.mul_mat4_affine(&a), 1.0e-5);
+            assert_eq!($mat4::IDENTITY, $mat4::IDENTITY.mul_mat4_affine(&$mat4::IDENTITY));
+
+            // the lhs does not need to be affine
+            let proj = $mat4::perspective_rh(deg(60.0), 1.5, 0.1, 100.0);
+            assert_approx_eq!(proj * b, proj.mul_mat4_affine(&b), 1.0e-5);
+
+            should_glam_assert!({ a.mul_mat4_affine(&proj) });
+        });
+
         glam_test!(test_from_ypr, {
             use glam::EulerRot;
             let zero = deg(0.0);
@@ -263,6 +385,81 @@ macro_rules! impl_mat4_tests {
             should_glam_assert!({ $mat4::from_scale($vec3::ZERO) });
         });
 
+        glam_test!(test_mat4_rows_array_and_tuples, {
+            let m = $mat4::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.transpose().to_cols_array_2d(), m.to_rows_array_2d());
+            assert_eq!($mat4::from_rows_array_2d(&MATRIX), m.transpose());
+            assert_eq!($mat4::from_rows_array_2d(&m.to_rows_array_2d()), m);
+
+            let t: (($t, $t, $t, $t), ($t, $t, $t, $t), ($t, $t, $t, $t), ($t, $t, $t, $t)) = m.into();
+            assert_eq!(t.0, m.x_axis.into());
+            assert_eq!(t.1, m.y_axis.into());
+            assert_eq!($mat4::from(t), m);
+        });
+
+        glam_test!(test_mat4_col_and_row_iters, {
+            let mut m = $mat4::from_cols_array_2d(&MATRIX);
+            assert!(m.cols().eq((0..4).map(|i| m.col(i))));
+            assert!(m.rows().eq((0..4).map(|i| m.row(i))));
+            assert!(m.rows().eq(m.transpose().cols()));
+
+            for col in m.cols_mut() {
+                *col *= 2.0;
+            }
+            assert_eq!(m, $mat4::from_cols_array_2d(&MATRIX) * 2.0);
+        });
+
+        glam_test!(test_mat4_element_and_row_mut, {
+            let mut m = $mat4::from_cols_array_2d(&MATRIX);
+            for col in 0..4 {
+                for row in 0..4 {
+                    assert_eq!(m.element(col, row), MATRIX[col][row]);
+                }
+            }
+            *m.element_mut(1, 0) = -1.0;
+            assert_eq!(m.y_axis.x, -1.0);
+
+            let r = m.row(1) * 2.0;
+            m.set_row(1, r);
+            assert_eq!(m.row(1), r);
+            m.row_mut(0, |row| *row = -*row);
+            assert_eq!(m.row(0), (-$mat4::from_cols_array_2d(&MATRIX).row(0)).with_y(1.0));
+
+            should_panic!({ $mat4::ZERO.element(4, 0) });
+            should_panic!({ $mat4::ZERO.element(0, 4) });
+            should_panic!({
+                let mut m = $mat4::ZERO;
+                m.set_row(4, m.x_axis);
+            });
+        });
+
+        glam_test!(test_mat4_col_axis, {
+            use glam::Axis;
+            let mut m = $mat4::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.col_axis(Axis::X), m.col(0));
+            assert_eq!(m.col_axis(Axis::Y), m.col(1));
+            assert_eq!(m.col_axis(Axis::Z), m.col(2));
+            assert_eq!(m.col_axis(Axis::W), m.col(3));
+            *m.col_axis_mut(Axis::X) = m.col(1);
+            assert_eq!(m.x_axis, m.y_axis);
+        });
+
+        glam_test!(test_mat4_from_fn, {
+            let m = $mat4::from_cols_array_2d(&MATRIX);
+            assert_eq!($mat4::from_fn(|col, row| MATRIX[col][row]), m);
+            assert_eq!($mat4::from_fn(|col, row| m.row(row)[col]), m);
+            assert_eq!(
+                $mat4::from_fn(|col, row| if col == row { 1.0 } else { 0.0 }),
+                $mat4::IDENTITY
+            );
+        });
+
+        glam_test!(test_mat4_map, {
+            let m = $mat4::from_cols_array_2d(&MATRIX);
+            assert_eq!(m.map(|e| e * 2.0), m * 2.0);
+            assert_eq!(m.map(|e| -e), -m);
+        });
+
         glam_test!(test_mat4_transpose, {
             let m = $newmat4(
                 $newvec4(1.0, 2.0, 3.0, 4.0),
@@ -299,6 +496,80 @@ macro_rules! impl_mat4_tests {
             );
         });
 
+        glam_test!(test_mat4_try_inverse, {
+            let m = $mat4::from_scale_rotation_translation(
+                $vec3::new(1.0, 2.0, 3.0),
+                $quat::from_rotation_y(1.0),
+                $vec3::new(4.0, 5.0, 6.0),
+            );
+            assert!(m.is_invertible());
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+            let proj = $mat4::perspective_rh(deg(60.0), 1.5, 0.001, 1000.0);
+            assert!(proj.is_invertible());
+            assert_eq!(Some(proj.inverse()), proj.try_inverse());
+            // transforms far from the origin must not be rejected
+            for m in [
+                $mat4::from_translation($vec3::new(1.0e7, 2.0e7, 0.0)),
+                $mat4::from_rotation_translation(
+                    $quat::from_rotation_z(1.0),
+                    $vec3::new(1.0e7, -2.0e7, 3.0e6),
+                ),
+            ] {
+                assert!(m.is_invertible());
+                assert_eq!(Some(m.inverse()), m.try_inverse());
+                assert_eq!(Ok(m.inverse()), m.inverse_checked());
+            }
+            // the last column is only ignored for affine transforms
+            let m = $mat4::from_diagonal($vec4::new(1.0, 1.0, 1.0, 1.0e-8));
+            assert!(m.is_invertible());
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+
+            for m in [
+                $mat4::ZERO,
+                $mat4::NAN,
+                $mat4::from_scale($newvec3(1.0, 0.0, 1.0)),
+                $mat4::from_scale($newvec3(1.0, $t::EPSILON * 0.5, 1.0))
+                    * $mat4::from_rotation_x(deg(45.0))
+                    * $mat4::from_scale($newvec3(1.0, 1.0, 2.0)),
+                // the inverse would overflow
+                $mat4::from_diagonal($vec4::splat($t::MIN_POSITIVE.powf(0.25) * 0.25)),
+            ] {
+                assert!(!m.is_invertible());
+                assert_eq!(None, m.try_inverse());
+            }
+        });
+
+        glam_test!(test_mat4_handedness, {
+            let srt = $mat4::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, 2.0),
+                $quat::from_rotation_x(deg(90.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            assert!(srt.is_right_handed());
+            assert!(!srt.is_flipping());
+
+            let mirror = $mat4::from_scale($newvec3(-1.0, 1.0, 1.0));
+            assert!(!mirror.is_right_handed());
+            assert!(mirror.is_flipping());
+            assert!((srt * mirror).is_flipping());
+            assert!((mirror * srt * mirror).is_right_handed());
+
+            // the tolerance must not grow with the translation
+            let far = $mat4::from_translation($vec3::new(1.0e7, -2.0e7, 3.0e6));
+            assert!((far * mirror).is_flipping());
+            assert!(!(far * mirror).is_right_handed());
+            assert!((far * srt).is_right_handed());
+            assert!(!(far * srt).is_flipping());
+            assert!($mat4::from_diagonal($vec4::new(1.0, 1.0, 1.0, 1.0e-8)).is_right_handed());
+            assert!($mat4::from_diagonal($vec4::new(1.0, 1.0, -1.0, 1.0e-8)).is_flipping());
+
+            assert!(!$mat4::ZERO.is_right_handed());
+            assert!(!$mat4::ZERO.is_flipping());
+            let singular = $mat4::from_scale($newvec3(1.0, 0.0, 1.0));
+            assert!(!singular.is_right_handed());
+            assert!(!singular.is_flipping());
+        });
+
         glam_test!(test_mat4_inverse, {
             // assert_eq!(None, $mat4::ZERO.inverse());
             let inv = $mat4::IDENTITY.inverse();
@@ -642,6 +913,18 @@ macro_rules! impl_mat4_tests {
                 format!("{:.1}", a),
                 "[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0], [9.0, 10.0, 11.0, 12.0], [13.0, 14.0, 15.0, 16.0]]"
             );
+            assert_eq!(
+                format!("{:#}", a),
+                "[1, 5,  9, 13]\n[2, 6, 10, 14]\n[3, 7, 11, 15]\n[4, 8, 12, 16]"
+            );
+            assert_eq!(
+                format!("{:#.2}", a),
+                "[1.00, 5.00,  9.00, 13.00]\n[2.00, 6.00, 10.00, 14.00]\n[3.00, 7.00, 11.00, 15.00]\n[4.00, 8.00, 12.00, 16.00]"
+            );
+            assert_eq!(
+                format!("{:+#}", a),
+                "[+1, +5,  +9, +13]\n[+2, +6, +10, +14]\n[+3, +7, +11, +15]\n[+4, +8, +12, +16]"
+            );
         });
 
         glam_test!(test_mat4_to_from_slice, {
@@ -716,6 +999,62 @@ mod mat4 {
     use super::support::deg;
     use glam::{mat4, swizzles::*, vec3, vec4, Mat3, Mat4, Quat, Vec3, Vec4};
 
+    glam_test!(test_flat_slice, {
+        let matrices = [Mat4::IDENTITY, Mat4::from_translation(vec3(1.0, 2.0, 3.0))];
+        let flat = Mat4::as_flat_slice(&matrices);
+        assert_eq!(32, flat.len());
+        assert_eq!(&Mat4::IDENTITY.to_cols_array(), &flat[..16]);
+        assert_eq!(&matrices[1].to_cols_array(), &flat[16..]);
+        assert_eq!(&matrices, Mat4::from_flat_slice(flat));
+    });
+
+    glam_test!(test_from_quat_matches_mat3, {
+        // the SIMD implementation must give the same result as the scalar Mat3 one
+        let quats = [
+            Quat::IDENTITY,
+            Quat::from_rotation_x(deg(30.0)),
+            Quat::from_rotation_y(deg(-100.0)),
+            Quat::from_euler(glam::EulerRot::ZYX, 0.3, -1.2, 2.5),
+            Quat::from_axis_angle(Vec3::new(1.0, 2.0, -3.0).normalize(), 4.0),
+        ];
+        for q in quats {
+            assert_eq!(Mat4::from_mat3(Mat3::from_quat(q)), Mat4::from_quat(q));
+        }
+    });
+
+    glam_test!(test_transpose_slice, {
+        let m = Mat4::from_cols_array(&core::array::from_fn(|i| i as f32));
+        let mut matrices = [m, Mat4::IDENTITY, m * 2.0];
+        Mat4::transpose_slice(&mut matrices);
+        assert_eq!(
+            [m.transpose(), Mat4::IDENTITY, (m * 2.0).transpose()],
+            matrices
+        );
+        Mat4::transpose_slice(&mut []);
+    });
+
+    glam_test!(test_mat4_macro, {
+        const M: Mat4 = mat4!(1.0);
+        assert_eq!(Mat4::IDENTITY, M);
+        assert_eq!(Mat4::from_diagonal(Vec4::splat(2.0)), mat4!(2.0));
+        let t = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(
+            Mat4::from_translation(t),
+            mat4!(Vec4::X, Vec4::Y, Vec4::Z, t, 1.0)
+        );
+        let m = Mat4::from_cols_array(&[
+            1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+        ]);
+        assert_eq!(
+            m,
+            mat4!(
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0
+            )
+        );
+        assert_eq!(m, mat4!(m.x_axis, m.y_axis, m.z_axis, m.w_axis));
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(mem::align_of::<Vec4>(), mem::align_of::<Mat4>());
@@ -785,6 +1124,45 @@ mod dmat4 {
         assert_eq!(128, mem::size_of::<DMat4>());
     });
 
+    glam_test!(test_scalar_parity, {
+        // DMat4 may use f64x2 SIMD, which must give the same results as scalar arithmetic
+        let a = DMat4::from_cols_array(&[
+            1.0, -2.0, 3.5, 0.25, 5.0, 6.0, -7.0, 8.0, 9.5, 10.0, 11.0, -12.0, 13.0, -14.0, 15.0,
+            16.0,
+        ]);
+        let b = DMat4::from_scale_rotation_translation(
+            DVec3::new(1.5, 2.0, -0.5),
+            DQuat::from_rotation_y(0.7),
+            DVec3::new(-3.0, 4.0, 5.0),
+        );
+        let a_cols = a.to_cols_array_2d();
+        let b_cols = b.to_cols_array_2d();
+
+        let mut transposed = [[0.0; 4]; 4];
+        for (i, col) in a_cols.iter().enumerate() {
+            for (j, e) in col.iter().enumerate() {
+                transposed[j][i] = *e;
+            }
+        }
+        assert_eq!(DMat4::from_cols_array_2d(&transposed), a.transpose());
+
+        let mul_vec = |cols: &[[f64; 4]; 4], v: [f64; 4]| {
+            let mut res = [0.0; 4];
+            for (r, e) in res.iter_mut().enumerate() {
+                *e = cols[0][r] * v[0] + cols[1][r] * v[1] + cols[2][r] * v[2] + cols[3][r] * v[3];
+            }
+            res
+        };
+        let v = [0.5, -1.5, 2.0, 1.0];
+        assert_eq!(DVec4::from(mul_vec(&a_cols, v)), a * DVec4::from(v));
+        let product = b_cols.map(|col| mul_vec(&a_cols, col));
+        assert_eq!(DMat4::from_cols_array_2d(&product), a * b);
+
+        for m in [a, b, a * b] {
+            assert_approx_eq!(DMat4::IDENTITY, m * m.inverse(), 1.0e-12);
+        }
+    });
+
     impl_mat4_tests!(f64, dmat4, dvec4, dvec3, DMat4, DMat3, DQuat, DVec4, DVec3);
     impl_as_ref_tests!(DMat4);
 }
@@ -0,0 +1,84 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_plane_tests {
+    ($t:ident, $plane:ident, $vec3:ident) => {
+        glam_test!(test_new, {
+            let p = $plane::new($vec3::Y, -2.0);
+            assert_eq!($vec3::Y, p.normal);
+            assert_eq!(-2.0, p.d);
+            assert_eq!(
+                p,
+                $plane::from_point_normal($vec3::new(5.0, 2.0, -3.0), $vec3::Y)
+            );
+        });
+
+        glam_test!(test_from_points, {
+            let a = $vec3::new(1.0, 2.0, 3.0);
+            let b = $vec3::new(4.0, 2.0, 3.0);
+            let c = $vec3::new(1.0, 2.0, -5.0);
+            let p = $plane::from_points(a, b, c);
+            assert_approx_eq!($vec3::Y, p.normal);
+            assert_approx_eq!(-2.0, p.d);
+            let p = $plane::from_points(a, c, b);
+            assert_approx_eq!(-$vec3::Y, p.normal);
+            for v in [a, b, c] {
+                assert_approx_eq!(0.0, p.normal.dot(v) + p.d);
+            }
+            should_glam_assert!({ $plane::from_points(a, b, a * 2.0 - b) });
+        });
+
+        glam_test!(test_signed_distance, {
+            let p = $plane::from_point_normal($vec3::new(0.0, 1.0, 0.0), $vec3::Y);
+            assert_eq!(2.0, p.signed_distance($vec3::new(5.0, 3.0, -1.0)));
+            assert_eq!(-3.0, p.signed_distance($vec3::new(5.0, -2.0, -1.0)));
+            assert_eq!(0.0, p.signed_distance($vec3::new(5.0, 1.0, -1.0)));
+            // scaled by the normal length
+            let p = $plane::from_point_normal($vec3::new(0.0, 1.0, 0.0), -$vec3::Y * 2.0);
+            assert_eq!(-4.0, p.signed_distance($vec3::new(5.0, 3.0, -1.0)));
+        });
+
+        glam_test!(test_intersect_segment, {
+            let p = $plane::from_point_normal($vec3::new(0.0, 1.0, 0.0), $vec3::Y * 2.0);
+            let a = $vec3::new(1.0, -1.0, 0.0);
+            let b = $vec3::new(1.0, 3.0, 4.0);
+            assert_eq!(Some(0.5), p.intersect_segment(a, b));
+            assert_eq!(Some(0.5), p.intersect_segment(b, a));
+            // touching at the end points
+            assert_eq!(Some(0.0), p.intersect_segment($vec3::Y, b));
+            assert_eq!(Some(1.0), p.intersect_segment(a, $vec3::Y));
+            // both on the same side
+            assert_eq!(None, p.intersect_segment(b, b * 2.0));
+            assert_eq!(None, p.intersect_segment(a, a * 2.0));
+            // in the plane
+            assert_eq!(
+                None,
+                p.intersect_segment($vec3::Y, $vec3::new(1.0, 1.0, 1.0))
+            );
+        });
+    };
+}
+
+mod plane {
+    use glam::{Plane, Vec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<Plane>());
+        assert_eq!(mem::align_of::<f32>(), mem::align_of::<Plane>());
+    });
+
+    impl_plane_tests!(f32, Plane, Vec3);
+}
+
+mod dplane {
+    use glam::{DPlane, DVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(32, mem::size_of::<DPlane>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DPlane>());
+    });
+
+    impl_plane_tests!(f64, DPlane, DVec3);
+}
@@ -341,6 +341,8 @@ macro_rules! impl_quat_tests {
             // );
             assert_eq!(format!("{}", a), "[0, 0, 0, 1]");
             assert_eq!(format!("{:.2}", a), "[0.00, 0.00, 0.00, 1.00]");
+            assert_eq!(format!("{:+.1}", a), "[+0.0, +0.0, +0.0, +1.0]");
+            assert_eq!(format!("{:4}", a), "[   0,    0,    0,    1]");
         });
 
         glam_test!(test_identity, {
@@ -418,6 +420,46 @@ macro_rules! impl_quat_tests {
             assert_eq!([two, two].into_iter().product::<$quat>(), two * two);
         });
 
+        glam_test!(test_from_mat_checked, {
+            let q = $quat::from_rotation_y(1.0);
+            assert_eq!(
+                $quat::from_mat3_checked(&$mat3::from_quat(q)),
+                Ok($quat::from_mat3(&$mat3::from_quat(q)))
+            );
+            assert_eq!(
+                $quat::from_mat4_checked(&$mat4::from_rotation_translation(q, $vec3::ONE)),
+                Ok($quat::from_mat4(&$mat4::from_quat(q)))
+            );
+            let not_orthonormal = [
+                $mat3::ZERO,
+                $mat3::from_diagonal($vec3::new(1.0, 2.0, 1.0)),
+                $mat3::from_diagonal($vec3::new(1.0, -1.0, 1.0)),
+                $mat3::from_cols($vec3::X, $vec3::X, $vec3::Z),
+                $mat3::NAN,
+            ];
+            for m in not_orthonormal {
+                assert_eq!(
+                    $quat::from_mat3_checked(&m),
+                    Err(glam::error::Error::NotOrthonormal)
+                );
+            }
+            assert_eq!(
+                $quat::from_slice_checked(&[0.0, 0.0, 0.0]),
+                Err(glam::error::Error::SliceTooShort {
+                    required: 4,
+                    found: 3
+                })
+            );
+        });
+
+        glam_test!(test_ulps_eq, {
+            let a = $quat::from_rotation_y(1.0);
+            let b = $quat::from_array(a.to_array().map(|x| $t::from_bits(x.to_bits() + 1)));
+            assert!(a.ulps_eq(b, 1));
+            assert!(!a.ulps_eq(b, 0));
+            assert!(!a.ulps_eq(-a, 4));
+        });
+
         glam_test!(test_is_finite, {
             assert!($quat::from_xyzw(0.0, 0.0, 0.0, 0.0).is_finite());
             assert!($quat::from_xyzw(-1e-10, 1.0, 1e10, 42.0).is_finite());
@@ -509,6 +551,35 @@ macro_rules! impl_quat_tests {
             assert!($new(1.0, 2.0, 3.0, 4.0).to_array() == [1.0, 2.0, 3.0, 4.0]);
         });
 
+        glam_test!(test_from_str, {
+            let a = $new(1.0, 2.0, 3.0, 4.0);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 4,
+                    found: 3
+                }),
+                "[1, 2, 3]".parse::<$quat>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$quat>()
+            );
+        });
+
+        glam_test!(test_bytes, {
+            let q = $new(1.0, 2.0, 3.0, 4.0);
+            let size = core::mem::size_of::<$t>();
+            let le = q.to_le_bytes();
+            let be = q.to_be_bytes();
+            assert_eq!(&le[..size], &(1.0 as $t).to_le_bytes());
+            assert_eq!(&be[size * 3..], &(4.0 as $t).to_be_bytes());
+            assert_eq!(q, $quat::from_le_bytes(le));
+            assert_eq!(q, $quat::from_be_bytes(be));
+        });
+
         glam_test!(test_to_axis_angle, {
             {
                 let q = $quat::from_xyzw(
@@ -664,5 +735,40 @@ mod dquat {
         assert_eq!(mem::align_of::<f64>(), mem::align_of::<DQuat>());
     });
 
+    glam_test!(test_scalar_parity, {
+        // DQuat may use f64x2 SIMD, multiplication must give the same results as scalar arithmetic
+        let quats = [
+            DQuat::from_euler(EulerRot::YXZ, 0.3, -1.2, 2.5),
+            DQuat::from_euler(EulerRot::ZYX, -2.9, 0.7, 0.1),
+            DQuat::from_axis_angle(DVec3::new(1.0, 2.0, -3.0).normalize(), 4.0),
+        ];
+        let v = DVec3::new(1.5, -2.25, 1.0e3);
+        for a in quats {
+            let b = DVec3::new(a.x, a.y, a.z);
+            let expected =
+                v * (a.w * a.w - b.dot(b)) + b * (v.dot(b) * 2.0) + b.cross(v) * (a.w * 2.0);
+            assert_eq!(expected, a.mul_vec3(v));
+            for q in quats {
+                let expected = DQuat::from_xyzw(
+                    a.w * q.x + a.x * q.w + a.y * q.z - a.z * q.y,
+                    a.w * q.y - a.x * q.z + a.y * q.w + a.z * q.x,
+                    a.w * q.z + a.x * q.y - a.y * q.x + a.z * q.w,
+                    a.w * q.w - a.x * q.x - a.y * q.y - a.z * q.z,
+                );
+                assert_eq!(expected, a.mul_quat(q));
+
+                if a == q {
+                    continue;
+                }
+                // the dot product may be summed in a different order
+                let theta = a.dot(q).abs().acos();
+                let end = if a.dot(q) < 0.0 { -q } else { q };
+                let expected =
+                    (a * (theta * 0.75).sin() + end * (theta * 0.25).sin()) / theta.sin();
+                assert_approx_eq!(expected, a.slerp(q, 0.25), 1.0e-6);
+            }
+        }
+    });
+
     impl_quat_tests!(f64, dquat, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
 }
@@ -0,0 +1,218 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_ray3_tests {
+    ($t:ident, $ray3:ident, $hit:ident, $plane:ident, $vec3:ident) => {
+        glam_test!(test_new, {
+            let r = $ray3::new($vec3::new(1.0, 2.0, 3.0), $vec3::X);
+            assert_eq!($vec3::new(1.0, 2.0, 3.0), r.origin);
+            assert_eq!($vec3::X, r.direction);
+            assert_eq!($vec3::new(3.0, 2.0, 3.0), r.at(2.0));
+            assert_eq!(r.origin, r.at(0.0));
+        });
+
+        fn check_triangle(r: $ray3, a: $vec3, b: $vec3, c: $vec3) -> (Option<$hit>, Option<$hit>) {
+            let hit = r.intersect_triangle(a, b, c);
+            let hit_wt = r.intersect_triangle_watertight(a, b, c);
+            match (hit, hit_wt) {
+                (Some(hit), Some(hit_wt)) => {
+                    assert_approx_eq!(hit.t, hit_wt.t, 1e-5);
+                    assert_approx_eq!(hit.u, hit_wt.u, 1e-5);
+                    assert_approx_eq!(hit.v, hit_wt.v, 1e-5);
+                    assert_eq!(hit.front_face, hit_wt.front_face);
+                    let bary = hit.barycentric();
+                    let p = a * bary.x + b * bary.y + c * bary.z;
+                    assert_approx_eq!(r.at(hit.t), p, 1e-5);
+                }
+                (None, None) => (),
+                _ => panic!("{:?} != {:?}", hit, hit_wt),
+            }
+            (hit, hit_wt)
+        }
+
+        glam_test!(test_intersect_triangle, {
+            let a = $vec3::new(0.0, 0.0, 0.0);
+            let b = $vec3::new(1.0, 0.0, 0.0);
+            let c = $vec3::new(0.0, 1.0, 0.0);
+
+            // front face, the triangle is counter-clockwise looking down -z
+            let r = $ray3::new($vec3::new(0.25, 0.5, 2.0), -$vec3::Z);
+            let (hit, _) = check_triangle(r, a, b, c);
+            let hit = hit.unwrap();
+            assert_approx_eq!(2.0, hit.t);
+            assert_approx_eq!(0.25, hit.u);
+            assert_approx_eq!(0.5, hit.v);
+            assert!(hit.front_face);
+            assert_approx_eq!($vec3::new(0.25, 0.25, 0.5), hit.barycentric());
+
+            // back face, `t` is scaled by the direction length
+            let r = $ray3::new($vec3::new(0.25, 0.5, -2.0), $vec3::Z * 4.0);
+            let (hit, _) = check_triangle(r, a, b, c);
+            let hit = hit.unwrap();
+            assert_approx_eq!(0.5, hit.t);
+            assert!(!hit.front_face);
+
+            // every axis permutation of the watertight test
+            for dir in [
+                $vec3::new(1.0, 0.2, -0.3),
+                $vec3::new(-1.0, 0.2, -0.3),
+                $vec3::new(0.1, 1.0, 0.3),
+                $vec3::new(0.1, -1.0, 0.3),
+                $vec3::new(0.1, 0.2, 1.0),
+                $vec3::new(0.1, 0.2, -1.0),
+            ] {
+                let a = $vec3::new(4.0, -1.0, 0.5);
+                let b = $vec3::new(-1.0, 3.0, 2.0);
+                let c = $vec3::new(0.5, -2.0, 6.0);
+                let target = (a + b + c) / 3.0;
+                let r = $ray3::new(target - dir * 10.0, dir);
+                let (hit, _) = check_triangle(r, a, b, c);
+                let hit = hit.unwrap();
+                assert_approx_eq!(10.0, hit.t, 1e-5);
+                let n = (b - a).cross(c - a);
+                assert_eq!(dir.dot(n) < 0.0, hit.front_face);
+                let (hit_flipped, _) = check_triangle(r, a, c, b);
+                assert_eq!(!hit.front_face, hit_flipped.unwrap().front_face);
+            }
+        });
+
+        glam_test!(test_intersect_triangle_miss, {
+            let a = $vec3::new(0.0, 0.0, 0.0);
+            let b = $vec3::new(1.0, 0.0, 0.0);
+            let c = $vec3::new(0.0, 1.0, 0.0);
+            // outside
+            let r = $ray3::new($vec3::new(0.75, 0.75, 1.0), -$vec3::Z);
+            assert_eq!((None, None), check_triangle(r, a, b, c));
+            let r = $ray3::new($vec3::new(-0.1, 0.5, 1.0), -$vec3::Z);
+            assert_eq!((None, None), check_triangle(r, a, b, c));
+            // behind the origin
+            let r = $ray3::new($vec3::new(0.25, 0.25, 1.0), $vec3::Z);
+            assert_eq!((None, None), check_triangle(r, a, b, c));
+            // parallel and in the plane of the triangle
+            let r = $ray3::new($vec3::new(-1.0, 0.25, 0.0), $vec3::X);
+            assert_eq!((None, None), check_triangle(r, a, b, c));
+            // degenerate triangle
+            let r = $ray3::new($vec3::new(0.5, 0.0, 1.0), -$vec3::Z);
+            assert_eq!((None, None), check_triangle(r, a, b, b * 2.0));
+            // zero direction
+            let r = $ray3::new($vec3::new(0.25, 0.25, 1.0), $vec3::ZERO);
+            assert_eq!((None, None), check_triangle(r, a, b, c));
+        });
+
+        glam_test!(test_intersect_triangle_watertight, {
+            // a quad split along its diagonal, rays through the shared edge hit at least one
+            // of the triangles
+            let a = $vec3::new(0.0, 0.0, 0.0);
+            let b = $vec3::new(1.0, 0.0, 0.0);
+            let c = $vec3::new(1.0, 1.0, 0.0);
+            let d = $vec3::new(0.0, 1.0, 0.0);
+            for i in 0..=100 {
+                let s = i as $t / 100.0;
+                let dir = $vec3::new(0.31, -0.17, -1.0);
+                let r = $ray3::new($vec3::new(s, s, 0.0) - dir * 3.0, dir);
+                let hit0 = r.intersect_triangle_watertight(a, b, c);
+                let hit1 = r.intersect_triangle_watertight(a, c, d);
+                assert!(hit0.is_some() || hit1.is_some());
+            }
+
+            // a fan of triangles around a shared vertex, rays through the vertex hit at least
+            // one of the triangles
+            let fan = [$vec3::X, $vec3::Y, -$vec3::X, -$vec3::Y, $vec3::X];
+            for i in 1..=100 {
+                let dir = $vec3::new(-0.3, 0.7, -1.0) * (i as $t / 10.0);
+                let r = $ray3::new($vec3::ZERO - dir * 3.0, dir);
+                assert!(fan
+                    .windows(2)
+                    .any(|e| r.intersect_triangle_watertight(a, e[0], e[1]).is_some()));
+            }
+        });
+
+        glam_test!(test_intersect_sphere, {
+            let center = $vec3::new(0.0, 0.0, -5.0);
+            let r = $ray3::new($vec3::ZERO, -$vec3::Z);
+            assert_eq!(Some((4.0, 6.0)), r.intersect_sphere(center, 1.0));
+            // `t` is scaled by the direction length
+            let r = $ray3::new($vec3::ZERO, -$vec3::Z * 2.0);
+            assert_eq!(Some((2.0, 3.0)), r.intersect_sphere(center, 1.0));
+            // origin inside the sphere
+            let r = $ray3::new(center, $vec3::X);
+            assert_eq!(Some((-1.0, 1.0)), r.intersect_sphere(center, 1.0));
+            // tangent
+            let r = $ray3::new($vec3::new(1.0, 0.0, 0.0), -$vec3::Z);
+            let (t0, t1) = r.intersect_sphere(center, 1.0).unwrap();
+            assert_approx_eq!(5.0, t0);
+            assert_approx_eq!(5.0, t1);
+            // hit points are on the sphere
+            let r = $ray3::new($vec3::new(0.3, -0.2, 1.0), $vec3::new(-0.05, 0.03, -1.0));
+            let (t0, t1) = r.intersect_sphere(center, 1.0).unwrap();
+            assert!(t0 < t1);
+            assert_approx_eq!(1.0, r.at(t0).distance(center), 1e-5);
+            assert_approx_eq!(1.0, r.at(t1).distance(center), 1e-5);
+            // miss
+            let r = $ray3::new($vec3::new(1.01, 0.0, 0.0), -$vec3::Z);
+            assert_eq!(None, r.intersect_sphere(center, 1.0));
+            // behind
+            let r = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_eq!(None, r.intersect_sphere(center, 1.0));
+            // zero direction
+            let r = $ray3::new($vec3::ZERO, $vec3::ZERO);
+            assert_eq!(None, r.intersect_sphere(center, 1.0));
+            // a small sphere far from the ray origin
+            let center = $vec3::new(0.0, 0.0, -10000.0);
+            let r = $ray3::new($vec3::ZERO, -$vec3::Z);
+            let (t0, t1) = r.intersect_sphere(center, 0.01).unwrap();
+            assert_approx_eq!(9999.99, t0, 1e-3);
+            assert_approx_eq!(10000.01, t1, 1e-3);
+            let r = $ray3::new($vec3::new(0.009, 0.0, 0.0), -$vec3::Z);
+            assert!(r.intersect_sphere(center, 0.01).is_some());
+        });
+
+        glam_test!(test_intersect_plane, {
+            let plane = $plane::from_point_normal($vec3::new(0.0, 0.0, -5.0), $vec3::Z);
+            let r = $ray3::new($vec3::new(1.0, 2.0, 0.0), -$vec3::Z * 2.0);
+            assert_eq!(Some(2.5), r.intersect_plane(plane));
+            // the back side of the plane
+            let r = $ray3::new($vec3::new(1.0, 2.0, -10.0), $vec3::Z);
+            assert_eq!(Some(5.0), r.intersect_plane(plane));
+            // oblique
+            let r = $ray3::new($vec3::ZERO, $vec3::new(1.0, 1.0, -1.0));
+            let t = r.intersect_plane(plane).unwrap();
+            assert_approx_eq!($vec3::new(5.0, 5.0, -5.0), r.at(t));
+            // behind the origin
+            let r = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_eq!(None, r.intersect_plane(plane));
+            // parallel
+            let r = $ray3::new($vec3::ZERO, $vec3::X);
+            assert_eq!(None, r.intersect_plane(plane));
+            let r = $ray3::new($vec3::new(0.0, 0.0, -5.0), $vec3::X);
+            assert_eq!(None, r.intersect_plane(plane));
+            // starting on the plane
+            let r = $ray3::new($vec3::new(0.0, 0.0, -5.0), $vec3::Z);
+            assert_eq!(Some(0.0), r.intersect_plane(plane));
+        });
+    };
+}
+
+mod ray3 {
+    use glam::{Plane, Ray3, RayHit, Vec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(24, mem::size_of::<Ray3>());
+        assert_eq!(mem::align_of::<f32>(), mem::align_of::<Ray3>());
+    });
+
+    impl_ray3_tests!(f32, Ray3, RayHit, Plane, Vec3);
+}
+
+mod dray3 {
+    use glam::{DPlane, DRay3, DRayHit, DVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(48, mem::size_of::<DRay3>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DRay3>());
+    });
+
+    impl_ray3_tests!(f64, DRay3, DRayHit, DPlane, DVec3);
+}
@@ -182,6 +182,68 @@ macro_rules! impl_vec_float_normalize_tests {
             assert_eq!(from_x_y(MAX, 0.0).normalize_or_zero(), $vec::ZERO);
             assert_eq!(from_x_y(MAX, MAX).normalize_or_zero(), $vec::ZERO);
         });
+
+        glam_test!(test_normalize_and_length, {
+            assert_eq!(
+                from_x_y(-42.0, 0.0).normalize_and_length(),
+                (from_x_y(-1.0, 0.0), 42.0)
+            );
+            assert_eq!(
+                from_x_y(3.0, 4.0).normalize_and_length(),
+                (from_x_y(3.0, 4.0).normalize(), 5.0)
+            );
+
+            should_glam_assert!({ from_x_y(0.0, 0.0).normalize_and_length() });
+            should_glam_assert!({ from_x_y($t::NAN, 0.0).normalize_and_length() });
+        });
+
+        glam_test!(test_try_normalize_and_length, {
+            assert_eq!(
+                from_x_y(-42.0, 0.0).try_normalize_and_length(),
+                Some((from_x_y(-1.0, 0.0), 42.0))
+            );
+            assert_eq!(
+                from_x_y($t::MAX.sqrt(), 0.0).try_normalize_and_length(),
+                Some((from_x_y(1.0, 0.0), $t::MAX.sqrt()))
+            );
+
+            assert_eq!(from_x_y(0.0, 0.0).try_normalize_and_length(), None);
+            assert_eq!(
+                from_x_y($t::MIN_POSITIVE, 0.0).try_normalize_and_length(),
+                None
+            );
+            assert_eq!(from_x_y($t::INFINITY, 0.0).try_normalize_and_length(), None);
+            assert_eq!(from_x_y($t::NAN, 0.0).try_normalize_and_length(), None);
+            assert_eq!(from_x_y($t::MAX, $t::MAX).try_normalize_and_length(), None);
+        });
+    };
+}
+
+#[macro_export]
+macro_rules! impl_vec_f32_fast_tests {
+    ($vec:ident) => {
+        glam_test!(test_length_fast, {
+            for s in [1.0, -1e10, 1e-10] {
+                let v = $vec::from_fn(|i| (i as f32 + 1.0) * s);
+                let length = v.length();
+                assert!((v.length_fast() - length).abs() <= length * 1e-6);
+                assert!((v.distance_fast(-v) - 2.0 * length).abs() <= length * 2e-6);
+                assert!((v.normalize_fast().length() - 1.0).abs() <= 1e-6);
+            }
+
+            // zero and subnormal squared lengths are computed exactly
+            assert_eq!(0.0, $vec::ZERO.length_fast());
+            let tiny = $vec::X * 1e-30;
+            assert_eq!(tiny.length(), tiny.length_fast());
+
+            // an overflowing squared length matches the exact functions instead of giving NaN
+            let huge = $vec::X * 1e20;
+            assert_eq!(f32::INFINITY, huge.length_fast());
+            assert_eq!(f32::INFINITY, huge.distance_fast($vec::ZERO));
+            assert_eq!(huge.normalize(), huge.normalize_fast());
+
+            should_glam_assert!({ $vec::ZERO.normalize_fast() });
+        });
     };
 }
 
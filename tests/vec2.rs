@@ -14,6 +14,91 @@ macro_rules! impl_vec2_tests {
             assert_eq!([1 as $t, 2 as $t], *V2.as_ref());
         });
 
+        glam_test!(test_bytes, {
+            let v = $new(1 as $t, 2 as $t);
+            let size = core::mem::size_of::<$t>();
+            let le = v.to_le_bytes();
+            let be = v.to_be_bytes();
+            assert_eq!(le.len(), size * 2);
+            assert_eq!(&le[..size], &(1 as $t).to_le_bytes());
+            assert_eq!(&be[size * 1..], &(2 as $t).to_be_bytes());
+            assert_eq!(v, $vec2::from_le_bytes(le));
+            assert_eq!(v, $vec2::from_be_bytes(be));
+        });
+
+        glam_test!(test_iter, {
+            let v = $new(1 as $t, 2 as $t);
+            let a = v.to_array();
+            assert!(v.into_iter().eq(a));
+            assert!((&v).into_iter().eq(a.iter()));
+
+            let mut m = v;
+            for e in &mut m {
+                *e += 1 as $t;
+            }
+            assert_eq!(m, v + $vec2::ONE);
+
+            assert_eq!(a.into_iter().collect::<$vec2>(), v);
+            // values after the first 2 are ignored
+            assert_eq!((1..).map(|i| i as $t).collect::<$vec2>(), v);
+            assert_eq!(
+                $vec2::try_from_iter(a.into_iter().skip(1)),
+                Err(glam::error::Error::IterTooShort {
+                    required: 2,
+                    found: 1,
+                })
+            );
+            should_panic!({ core::iter::empty::<$t>().collect::<$vec2>() });
+        });
+
+        glam_test!(test_with_swizzles, {
+            use glam::Vec2Swizzles;
+            let v = $new(1 as $t, 2 as $t);
+            assert_eq!(v.with_yx(v), $new(2 as $t, 1 as $t));
+            let mut m = v;
+            m.set_yx(v);
+            assert_eq!(m, $new(2 as $t, 1 as $t));
+        });
+
+        glam_test!(test_axis, {
+            use glam::Axis;
+            let mut v = $new(1 as $t, 2 as $t);
+            assert_eq!(v[Axis::X], v.x);
+            assert_eq!($vec2::axis(Axis::X), $vec2::X);
+            assert_eq!(v[Axis::Y], v.y);
+            assert_eq!($vec2::axis(Axis::Y), $vec2::Y);
+            for (i, axis) in Axis::ALL[..2].iter().enumerate() {
+                assert_eq!(axis.index(), i);
+                assert_eq!(Axis::from_index(i), Some(*axis));
+                v[*axis] = 0 as $t;
+            }
+            assert_eq!(v, $vec2::ZERO);
+            should_panic!({ v[Axis::Z] });
+            should_panic!({ $vec2::axis(Axis::Z) });
+        });
+
+        glam_test!(test_from_fn, {
+            assert_eq!($vec2::from_fn(|i| (i + 1) as $t), $new(1 as $t, 2 as $t));
+            let mut calls = 0;
+            let v = $vec2::from_fn(|i| {
+                assert_eq!(i, calls);
+                calls += 1;
+                calls as $t
+            });
+            assert_eq!(v, $new(1 as $t, 2 as $t));
+        });
+
+        glam_test!(test_map_and_zip_with, {
+            let v = $new(1 as $t, 2 as $t);
+            assert_eq!(v.map(|e| e * 2 as $t), $new(2 as $t, 4 as $t));
+            assert_eq!(v.map(|e| e), v);
+            assert_eq!(v.zip_with(v, |a, b| a + b), v + v);
+            assert_eq!(
+                v.zip_with($vec2::ONE, |a, b| if a > b { a } else { b }),
+                v.max($vec2::ONE)
+            );
+        });
+
         glam_test!(test_vec2_consts, {
             assert_eq!($vec2::ZERO, $new(0 as $t, 0 as $t));
             assert_eq!($vec2::ONE, $new(1 as $t, 1 as $t));
@@ -55,6 +140,24 @@ macro_rules! impl_vec2_tests {
             assert_eq!($vec2::new(0 as $t, 1 as $t), $vec2::Y);
         });
 
+        glam_test!(test_from_str, {
+            let a = $vec2::new(1 as $t, 2 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 2,
+                    found: 1
+                }),
+                "[1]".parse::<$vec2>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$vec2>()
+            );
+        });
+
         glam_test!(test_fmt, {
             let a = $vec2::new(1 as $t, 2 as $t);
             assert_eq!(
@@ -71,6 +174,8 @@ macro_rules! impl_vec2_tests {
                 )
             );
             assert_eq!(format!("{}", a), "[1, 2]");
+            assert_eq!(format!("{:2}", a), "[ 1,  2]");
+            assert_eq!(format!("{:<2}", a), "[1 , 2 ]");
         });
 
         glam_test!(test_zero, {
@@ -189,12 +294,46 @@ macro_rules! impl_vec2_tests {
             assert_eq!($new(1 as $t, 2 as $t), b.max(a));
         });
 
+        glam_test!(test_min_max_position, {
+            let a = $new(3 as $t, 5 as $t);
+            assert_eq!(0, a.min_position());
+            assert_eq!(1, a.max_position());
+            let b = $new(2 as $t, 2 as $t);
+            assert_eq!(0, b.min_position());
+            assert_eq!(0, b.max_position());
+        });
+
+        glam_test!(test_min_max_pair, {
+            let a = $new(3 as $t, 5 as $t);
+            let b = $new(4 as $t, 2 as $t);
+            assert_eq!((a.min(b), a.max(b)), a.min_max(b));
+            assert_eq!((a.min(b), a.max(b)), b.min_max(a));
+        });
+
+        glam_test!(test_sorted, {
+            let sorted = $new(1 as $t, 2 as $t);
+            for [x, y] in [[1, 2], [2, 1]] {
+                let v = $new(x as $t, y as $t);
+                assert_eq!(sorted, v.sorted());
+            }
+            assert_eq!($vec2::ONE, $vec2::ONE.sorted());
+        });
+
         glam_test!(test_sum_product, {
             let a = $new(2 as $t, 3 as $t);
             assert_eq!(a.element_sum(), 5 as $t);
             assert_eq!(a.element_product(), 6 as $t);
         });
 
+        glam_test!(test_snap, {
+            fn vec(x: i32, y: i32) -> $vec2 {
+                $vec2::new(x as $t, y as $t)
+            }
+            assert_eq!(vec(7, 12).snap(vec(5, 4)), vec(5, 12));
+            assert_eq!(vec(8, 13).snap(vec(5, 4)), vec(10, 12));
+            assert_eq!(vec(9, 13).snap_to(vec(5, 4), vec(2, 1)), vec(7, 13));
+        });
+
         glam_test!(test_clamp, {
             fn vec(x: i32, y: i32) -> $vec2 {
                 $vec2::new(x as $t, y as $t)
@@ -307,6 +446,30 @@ macro_rules! impl_vec2_tests {
             assert_eq!($mask::splat(true), $mask::new(true, true));
         });
 
+        glam_test!(test_mask_from_bitmask, {
+            for bitmask in 0..=0b11 {
+                assert_eq!($mask::from_bitmask(bitmask).bitmask(), bitmask);
+                assert_eq!($mask::from_bitmask(bitmask | !0b11).bitmask(), bitmask);
+            }
+            assert_eq!($mask::from_bitmask(0), $mask::FALSE);
+            assert_eq!($mask::from_bitmask(0b11), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_count_true, {
+            assert_eq!($mask::FALSE.count_true(), 0);
+            assert_eq!($mask::TRUE.count_true(), 2);
+            assert_eq!($mask::new(true, false).count_true(), 1);
+        });
+
+        glam_test!(test_mask_into_iter, {
+            let v: Vec<bool> = $mask::new(true, false).into_iter().collect();
+            assert_eq!(v, [true, false]);
+            assert_eq!(
+                $mask::new(false, false).into_iter().filter(|b| *b).count(),
+                0
+            );
+        });
+
         glam_test!(test_mask_bitmask, {
             assert_eq!($mask::new(false, false).bitmask(), 0b00);
             assert_eq!($mask::new(true, false).bitmask(), 0b01);
@@ -525,6 +688,23 @@ macro_rules! impl_vec2_signed_tests {
     ($t:ident, $new:ident, $vec2:ident, $vec3:ident, $mask:ident) => {
         impl_vec2_tests!($t, $new, $vec2, $vec3, $mask);
 
+        glam_test!(test_abs_min_max_position, {
+            let a = $new(-7 as $t, 2 as $t);
+            assert_eq!(0, a.abs_max_position());
+            assert_eq!(1, a.abs_min_position());
+            let b = $new(1 as $t, $t::MIN);
+            assert_eq!(1, b.abs_max_position());
+            assert_eq!(0, b.abs_min_position());
+        });
+
+        glam_test!(test_snap_signed, {
+            fn vec(x: i32, y: i32) -> $vec2 {
+                $vec2::new(x as $t, y as $t)
+            }
+            assert_eq!(vec(-7, -13).snap(vec(5, 4)), vec(-5, -12));
+            assert_eq!(vec(-8, 13).snap(vec(-5, -4)), vec(-10, 12));
+        });
+
         glam_test!(test_is_negative_bitmask, {
             assert_eq!($vec2::ZERO.is_negative_bitmask(), 0b00);
             assert_eq!($vec2::ONE.is_negative_bitmask(), 0b00);
@@ -602,6 +782,18 @@ macro_rules! impl_vec2_signed_tests {
             assert_eq!(four.rem_euclid(-three), one);
             assert_eq!((-four).rem_euclid(-three), two);
         });
+
+        glam_test!(test_div_floor, {
+            let one = $vec2::ONE;
+            let two = one + one;
+            let three = two + one;
+            assert_eq!(three.div_floor(two), one);
+            assert_eq!((-three).div_floor(two), -two);
+            assert_eq!(three.div_floor(-two), -two);
+            assert_eq!((-three).div_floor(-two), one);
+            assert_eq!((-two - two).div_floor(two), -two);
+            assert_eq!($vec2::ZERO.div_floor(-two), $vec2::ZERO);
+        });
     };
 }
 
@@ -609,6 +801,14 @@ macro_rules! impl_vec2_signed_integer_tests {
     ($t:ident, $new:ident, $vec2:ident, $vec3:ident, $mask:ident) => {
         impl_vec2_signed_tests!($t, $new, $vec2, $vec3, $mask);
 
+        glam_test!(test_unsigned_abs, {
+            for (a, expected) in [($t::MIN, -($t::MIN as i128)), (-3, 3), (0, 0), (3, 3)] {
+                for e in $vec2::splat(a).unsigned_abs().to_array() {
+                    assert_eq!(e as i128, expected);
+                }
+            }
+        });
+
         glam_test!(test_signum, {
             assert_eq!($vec3::ZERO.signum(), $vec3::ZERO);
             assert_eq!($vec3::ONE.signum(), $vec3::ONE);
@@ -619,6 +819,21 @@ macro_rules! impl_vec2_signed_integer_tests {
 
 macro_rules! impl_vec2_eq_hash_tests {
     ($t:ident, $new:ident) => {
+        glam_test!(test_vec2_ord, {
+            let a = $new(1 as $t, 2 as $t);
+            let b = $new(1 as $t, 3 as $t);
+            let c = $new(2 as $t, 0 as $t);
+            assert!(a < b);
+            assert!(b < c);
+            assert_eq!(core::cmp::Ordering::Equal, a.cmp(&a));
+
+            let mut sorted = [c, a, b];
+            sorted.sort();
+            assert_eq!([a, b, c], sorted);
+            // inherent methods are element-wise rather than lexicographic
+            assert_eq!($new(1 as $t, 0 as $t), a.min(c));
+        });
+
         glam_test!(test_ve2_hash, {
             use std::collections::hash_map::DefaultHasher;
             use std::hash::Hash;
@@ -705,6 +920,18 @@ macro_rules! impl_vec2_float_tests {
             should_glam_assert!({ $vec2::ONE.reject_from_normalized($vec2::ONE) });
         });
 
+        glam_test!(test_reflect_refract, {
+            assert_eq!($new(1.0, 1.0), $new(1.0, -1.0).reflect($vec2::Y));
+            let incident = $new(1.0, -1.0).normalize();
+            assert_eq!(Some(incident), incident.refract($vec2::Y, 1.0));
+            let refracted = incident.refract($vec2::Y, 1.0 / 1.5).unwrap();
+            assert_approx_eq!(refracted.x, incident.x / 1.5, 1e-6);
+            assert!(refracted.is_normalized());
+            assert_eq!(None, incident.refract($vec2::Y, 1.5));
+            should_glam_assert!({ $vec2::ONE.reflect($vec2::ONE) });
+            should_glam_assert!({ $vec2::X.refract($vec2::ONE, 1.0) });
+        });
+
         glam_test!(test_signum, {
             assert_eq!($vec2::ZERO.signum(), $vec2::ONE);
             assert_eq!((-$vec2::ZERO).signum(), -$vec2::ONE);
@@ -769,6 +996,29 @@ macro_rules! impl_vec2_float_tests {
             assert!($vec2::new(NAN, 0.0).round().x.is_nan());
         });
 
+        glam_test!(test_round_ties_even, {
+            assert_eq!($vec2::new(0.5, 1.5).round_ties_even(), $vec2::new(0.0, 2.0));
+            let r = $vec2::new(-0.5, 3.5).round_ties_even();
+            assert_eq!(r, $vec2::new(-0.0, 4.0));
+            assert!(r.is_negative_bitmask() & 1 != 0);
+            assert!($vec2::NAN.round_ties_even().is_nan_mask().all());
+            assert_eq!($vec2::INFINITY.round_ties_even(), $vec2::INFINITY);
+        });
+
+        glam_test!(test_as_int_rounding, {
+            let v = $vec2::new(-1.25, 0.75);
+            assert_eq!(v.as_ivec2_round(), glam::IVec2::new(-1, 1));
+            assert_eq!(v.as_ivec2_floor(), glam::IVec2::new(-2, 0));
+            assert_eq!(v.as_ivec2_ceil(), glam::IVec2::new(-1, 1));
+            assert_eq!(v.as_uvec2_round(), glam::UVec2::new(0, 1));
+            assert_eq!(v.as_uvec2_floor(), glam::UVec2::new(0, 0));
+            assert_eq!(v.as_uvec2_ceil(), glam::UVec2::new(0, 1));
+            assert_eq!(v.as_i64vec2_floor(), v.floor().as_i64vec2());
+            assert_eq!(v.as_u64vec2_ceil(), v.ceil().as_u64vec2());
+            assert_eq!($vec2::NAN.as_ivec2_round(), glam::IVec2::ZERO);
+            assert_eq!($vec2::INFINITY.as_ivec2_ceil(), glam::IVec2::MAX);
+        });
+
         glam_test!(test_floor, {
             assert_eq!($vec2::new(1.35, -1.5).floor(), $vec2::new(1.0, -2.0));
             assert_eq!(
@@ -791,6 +1041,21 @@ macro_rules! impl_vec2_float_tests {
             );
         });
 
+        glam_test!(test_modf, {
+            let (trunc, fract) = $vec2::new(1.25, -1.5).modf();
+            assert_eq!(trunc, $vec2::new(1.0, -1.0));
+            assert_eq!(fract, $vec2::new(0.25, -0.5));
+            assert_eq!(trunc + fract, $vec2::new(1.25, -1.5));
+            assert!(fract.is_negative_bitmask() & 0b10 != 0);
+
+            let (trunc, fract) = $vec2::new($t::INFINITY, $t::NEG_INFINITY).modf();
+            assert_eq!(trunc, $vec2::new($t::INFINITY, $t::NEG_INFINITY));
+            assert_eq!(fract, $vec2::ZERO);
+            assert!(fract.is_negative_bitmask() & 0b10 != 0);
+            let (trunc, fract) = $vec2::NAN.modf();
+            assert!(trunc.is_nan_mask().all() && fract.is_nan_mask().all());
+        });
+
         glam_test!(test_ceil, {
             assert_eq!($vec2::new(1.35, -1.5).ceil(), $vec2::new(2.0, -1.0));
             assert_eq!(
@@ -841,6 +1106,175 @@ macro_rules! impl_vec2_float_tests {
             assert_approx_eq!($vec2::new(-0.25, 0.5), v1.midpoint(v2));
         });
 
+        glam_test!(test_centroid, {
+            let points = [
+                $vec2::new(0.0, 0.0),
+                $vec2::new(2.0, 0.0),
+                $vec2::new(2.0, 4.0),
+                $vec2::new(0.0, 4.0),
+            ];
+            assert_eq!($vec2::new(1.0, 2.0), $vec2::centroid(&points));
+            assert!($vec2::centroid(&[]).is_nan_mask().all());
+
+            // naive summation drifts noticeably from 0.1 here
+            let points = vec![$vec2::splat(0.1); 1_000_000];
+            assert_approx_eq!($vec2::splat(0.1), $vec2::centroid(&points));
+        });
+
+        glam_test!(test_weighted_mean, {
+            let points = [$vec2::new(0.0, 0.0), $vec2::new(4.0, 8.0)];
+            assert_eq!(
+                $vec2::new(1.0, 2.0),
+                $vec2::weighted_mean(&points, &[3.0, 1.0])
+            );
+            assert_eq!(
+                $vec2::centroid(&points),
+                $vec2::weighted_mean(&points, &[0.5, 0.5])
+            );
+
+            let points = vec![$vec2::splat(0.1); 1_000_000];
+            let weights = vec![0.5; 1_000_000];
+            assert_approx_eq!($vec2::splat(0.1), $vec2::weighted_mean(&points, &weights));
+
+            should_panic!({ $vec2::weighted_mean(&points, &[1.0]) });
+        });
+
+        glam_test!(test_total_cmp, {
+            use core::cmp::Ordering;
+            let a = $vec2::new(1.0, 2.0);
+            let b = $vec2::new(1.0, 3.0);
+            assert_eq!(a.cmp_total(b), Ordering::Less);
+            assert_eq!(b.cmp_total(a), Ordering::Greater);
+            assert_eq!(a.cmp_total(a), Ordering::Equal);
+            assert_eq!(a.cmp_lex(b), Ordering::Less);
+            assert_eq!(
+                $vec2::NAN.cmp_lex($vec2::ZERO),
+                $vec2::NAN.cmp_total($vec2::ZERO)
+            );
+            let mut sorted = [b, $vec2::NAN, a];
+            sorted.sort_by(|l, r| l.cmp_lex(*r));
+            assert_eq!([a, b], sorted[..2]);
+            assert!(sorted[2].is_nan());
+            assert_eq!($vec2::ZERO.cmp_total($vec2::splat(-0.0)), Ordering::Greater);
+            assert_eq!($vec2::NAN.cmp_total($vec2::NAN), Ordering::Equal);
+            assert_eq!($vec2::NAN.cmp_total($vec2::INFINITY), Ordering::Greater);
+            assert_eq!((-$vec2::NAN).cmp_total($vec2::NEG_INFINITY), Ordering::Less);
+
+            assert_eq!(a.min_total(b), a.min(b));
+            assert_eq!(a.max_total(b), a.max(b));
+            assert!(a.max_total($vec2::NAN).is_nan_mask().all());
+            assert_eq!(a.min_total($vec2::NAN), a);
+            assert_eq!(
+                $vec2::ZERO
+                    .min_total($vec2::splat(-0.0))
+                    .is_negative_bitmask(),
+                (1 << 2) - 1
+            );
+
+            assert_eq!(a.total_ord(), a.total_ord());
+            assert_ne!($vec2::ZERO.total_ord(), $vec2::splat(-0.0).total_ord());
+            assert_eq!($vec2::NAN.total_ord(), $vec2::NAN.total_ord());
+            assert!(a.total_ord() < b.total_ord());
+
+            let mut set = std::collections::BTreeSet::new();
+            set.insert(b.total_ord());
+            set.insert(a.total_ord());
+            set.insert($vec2::NAN.total_ord());
+            set.insert(a.total_ord());
+            assert_eq!(set.len(), 3);
+            assert_eq!(set.first().unwrap().0, a);
+
+            let mut set = std::collections::HashSet::new();
+            set.insert($vec2::NAN.total_ord());
+            assert!(set.contains(&$vec2::NAN.total_ord()));
+            assert!(!set.contains(&a.total_ord()));
+        });
+
+        glam_test!(test_hashable, {
+            let a = $vec2::new(1.0, -2.0);
+            assert_eq!(a, $vec2::from_bits(a.to_bits()));
+            assert_eq!(a.x.to_bits(), a.to_bits().x);
+            assert_eq!($vec2::ZERO.hashable(), $vec2::splat(-0.0).hashable());
+            assert_eq!($vec2::NAN.hashable(), (-$vec2::NAN).hashable());
+            assert_ne!(a.hashable(), $vec2::ZERO.hashable());
+
+            let mut set = std::collections::HashSet::new();
+            set.insert(a.hashable());
+            set.insert($vec2::ZERO.hashable());
+            set.insert($vec2::splat(-0.0).hashable());
+            set.insert($vec2::NAN.hashable());
+            set.insert((-$vec2::NAN).hashable());
+            set.insert(a.hashable());
+            assert_eq!(set.len(), 3);
+            assert!(set.contains(&$vec2::splat(0.0).hashable()));
+        });
+
+        glam_test!(test_min_max_nan, {
+            let a = $vec2::new($t::NAN, 1.0);
+            let b = $vec2::new(0.0, $t::NAN);
+            assert!(a.min_nan(b).x.is_nan() && a.min_nan(b).y.is_nan());
+            assert!(a.max_nan(b).x.is_nan() && a.max_nan(b).y.is_nan());
+            assert!(a.clamp_nan($vec2::ZERO, $vec2::ONE).x.is_nan());
+            assert_eq!(a.clamp_nan($vec2::ZERO, $vec2::ONE).y, 1.0);
+            assert!($vec2::ONE
+                .clamp_nan($vec2::NAN, $vec2::ONE)
+                .is_nan_mask()
+                .all());
+
+            let a = $vec2::new(1.0, -2.0);
+            let b = $vec2::new(-1.0, 3.0);
+            assert_eq!(a.min_nan(b), a.min(b));
+            assert_eq!(a.max_nan(b), a.max(b));
+            assert_eq!(
+                $vec2::splat(4.0).clamp_nan($vec2::ZERO, $vec2::ONE),
+                $vec2::ONE
+            );
+
+            let neg_zero = $vec2::splat(-0.0).min_nan($vec2::ZERO);
+            assert_eq!(neg_zero.is_negative_bitmask(), (1 << 2) - 1);
+            let zero = $vec2::splat(-0.0).max_nan($vec2::ZERO);
+            assert_eq!(zero.is_negative_bitmask(), 0);
+        });
+
+        glam_test!(test_ulps_eq, {
+            let a = $vec2::new(1.0, 1e10);
+            let next = $vec2::from_array(a.to_array().map(|x| $t::from_bits(x.to_bits() + 1)));
+            let far = $vec2::from_array(a.to_array().map(|x| $t::from_bits(x.to_bits() + 5)));
+            assert!(a.ulps_eq(a, 0));
+            assert!(!a.ulps_eq(next, 0));
+            assert!(a.ulps_eq(next, 1));
+            assert!(next.ulps_eq(a, 1));
+            assert!(!a.ulps_eq(far, 4));
+            assert!($vec2::ZERO.ulps_eq(-$vec2::ZERO, 0));
+            assert!(!$vec2::splat($t::MIN_POSITIVE).ulps_eq($vec2::splat(-$t::MIN_POSITIVE), 4));
+            assert!(!$vec2::NAN.ulps_eq($vec2::NAN, 4));
+            assert!($vec2::INFINITY.ulps_eq($vec2::INFINITY, 0));
+        });
+
+        glam_test!(test_compensated, {
+            let e = $t::EPSILON;
+            let a = $vec2::new(1.0 + e, -1.0);
+            let b = $vec2::new(1.0 - e, 1.0);
+            assert_eq!(a.dot(b), 0.0);
+            assert_eq!(a.dot_compensated(b), -e * e);
+            assert_eq!($vec2::ONE.dot_compensated($vec2::splat(2.0)), 2.0 * 2.0);
+
+            let big = 4.0 / $t::EPSILON;
+            let values = [$vec2::splat(big), $vec2::ONE, $vec2::splat(-big)];
+            assert_eq!(values.iter().sum::<$vec2>(), $vec2::ZERO);
+            assert_eq!($vec2::sum_compensated(&values), $vec2::ONE);
+            assert_eq!($vec2::sum_compensated(&[]), $vec2::ZERO);
+        });
+
+        glam_test!(test_finite_or, {
+            let a = $vec2::new($t::NAN, 1.0);
+            assert_eq!(a.is_finite_mask(), $mask::new(false, true));
+            assert!($vec2::ONE.is_finite_mask().all());
+            assert!(!$vec2::NAN.is_finite_mask().any());
+            assert_eq!(a.finite_or($vec2::splat(2.0)), $vec2::new(2.0, 1.0));
+            assert_eq!($vec2::ONE.finite_or($vec2::NAN), $vec2::ONE);
+        });
+
         glam_test!(test_is_finite, {
             assert!($vec2::new(0.0, 0.0).is_finite());
             assert!($vec2::new(-1e-10, 1e10).is_finite());
@@ -863,6 +1297,102 @@ macro_rules! impl_vec2_float_tests {
             );
         });
 
+        glam_test!(test_exp2_ln_log2, {
+            assert_eq!($vec2::new(1.0, 2.0).exp2(), $vec2::new(2.0, 4.0));
+            assert_eq!($vec2::new(1.0, 2.0).log2(), $vec2::new(0.0, 1.0));
+            assert_approx_eq!(
+                $vec2::new(1.0, 2.0).ln(),
+                $vec2::new((1.0 as $t).ln(), (2.0 as $t).ln())
+            );
+            assert_approx_eq!($vec2::new(1.0, 2.0).exp().ln(), $vec2::new(1.0, 2.0), 1e-5);
+            assert!($vec2::splat(-1.0).ln().is_nan_mask().all());
+            assert_eq!($vec2::ZERO.log2(), $vec2::NEG_INFINITY);
+        });
+
+        glam_test!(test_pow, {
+            assert_approx_eq!(
+                $vec2::splat(2.0).pow($vec2::new(3.0, 0.5)),
+                $vec2::new((2.0 as $t).powf(3.0), (2.0 as $t).powf(0.5))
+            );
+            assert_approx_eq!(
+                $vec2::new(1.0, 2.0).pow($vec2::new(3.0, 0.5)),
+                $vec2::new((1.0 as $t).powf(3.0), (2.0 as $t).powf(0.5))
+            );
+            assert_eq!($vec2::new(1.0, 2.0).pow($vec2::ONE), $vec2::new(1.0, 2.0));
+        });
+
+        glam_test!(test_trig, {
+            let a = $vec2::new(0.5, -1.25);
+            let u = $vec2::new(0.5, -1.0);
+            assert_approx_eq!(
+                a.sin(),
+                $vec2::new((0.5 as $t).sin(), (-1.25 as $t).sin()),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.cos(),
+                $vec2::new((0.5 as $t).cos(), (-1.25 as $t).cos()),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.tan(),
+                $vec2::new((0.5 as $t).tan(), (-1.25 as $t).tan()),
+                1e-6
+            );
+            assert_approx_eq!(
+                u.asin(),
+                $vec2::new((0.5 as $t).asin(), (-1.0 as $t).asin()),
+                1e-6
+            );
+            assert_approx_eq!(
+                u.acos(),
+                $vec2::new((0.5 as $t).acos(), (-1.0 as $t).acos()),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.atan(),
+                $vec2::new((0.5 as $t).atan(), (-1.25 as $t).atan()),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.atan2($vec2::new(1.0, -2.0)),
+                $vec2::new((0.5 as $t).atan2(1.0), (-1.25 as $t).atan2(-2.0)),
+                1e-6
+            );
+            assert_approx_eq!(u.asin().sin(), u, 1e-6);
+            assert!($vec2::splat(2.0).acos().is_nan_mask().all());
+        });
+
+        glam_test!(test_rotate_towards, {
+            use core::$t::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+            let v = $vec2::new(2.0, 0.0);
+            // limited by max_angle
+            assert_approx_eq!(
+                $vec2::new(1.0, 1.0) * core::$t::consts::SQRT_2,
+                v.rotate_towards($vec2::new(0.0, 5.0), FRAC_PI_4),
+                1e-6
+            );
+            assert_approx_eq!(
+                $vec2::new(1.0, -1.0) * core::$t::consts::SQRT_2,
+                v.rotate_towards($vec2::new(0.0, -5.0), FRAC_PI_4),
+                1e-6
+            );
+            // does not overshoot the target
+            assert_approx_eq!(
+                $vec2::new(0.0, 2.0),
+                v.rotate_towards($vec2::new(0.0, 0.5), PI),
+                1e-6
+            );
+            assert_eq!(v, v.rotate_towards($vec2::X, FRAC_PI_2));
+            assert_eq!(v, v.rotate_towards($vec2::Y, 0.0));
+            // negative angles rotate away from the target
+            assert_approx_eq!(
+                $vec2::new(0.0, -2.0),
+                v.rotate_towards($vec2::Y, -FRAC_PI_2),
+                1e-6
+            );
+        });
+
         glam_test!(test_angle_between, {
             let angle = $vec2::new(1.0, 0.0).angle_between($vec2::new(0.0, 1.0));
             assert_approx_eq!(core::$t::consts::FRAC_PI_2, angle, 1e-6);
@@ -933,9 +1463,24 @@ macro_rules! impl_vec2_float_tests {
             );
         });
 
+        glam_test!(test_angle_length, {
+            use core::$t::consts::{FRAC_PI_2, PI};
+            assert_approx_eq!(
+                $vec2::from_angle_length(FRAC_PI_2, 2.0),
+                $vec2::new(0.0, 2.0)
+            );
+            assert_approx_eq!($vec2::from_angle_length(PI, 0.5), $vec2::new(-0.5, 0.0));
+            assert_eq!($vec2::new(0.0, -3.0).to_angle_length(), (-FRAC_PI_2, 3.0));
+            assert_eq!($vec2::ZERO.to_angle_length(), (0.0, 0.0));
+            let v = $vec2::new(-1.5, 2.5);
+            let (angle, length) = v.to_angle_length();
+            assert_approx_eq!($vec2::from_angle_length(angle, length), v);
+        });
+
         glam_test!(test_fmt_float, {
             let a = $vec2::new(1.0, 2.0);
             assert_eq!(format!("{:.2}", a), "[1.00, 2.00]");
+            assert_eq!(format!("{:4.1}", a), "[ 1.0,  2.0]");
         });
 
         glam_test!(test_angle_conversion, {
@@ -1093,12 +1638,195 @@ macro_rules! impl_vec2_bit_op_tests {
                 }
             }
         });
+
+        glam_test!(test_rotate_and_wrapping_shift, {
+            let n = UVec2::new(0, 1);
+            for x in $t_min..$t_max {
+                let v = $vec2::splat(x);
+                let a = v.to_array();
+                for i in 0..2 {
+                    assert_eq!(v.rotate_left(n)[i], a[i].rotate_left(n[i]));
+                    assert_eq!(v.rotate_right(n)[i], a[i].rotate_right(n[i]));
+                    assert_eq!(v.wrapping_shl(n)[i], a[i].wrapping_shl(n[i]));
+                    assert_eq!(v.wrapping_shr(n)[i], a[i].wrapping_shr(n[i]));
+                }
+            }
+        });
+
+        glam_test!(test_bit_counting, {
+            for x in $t_min..$t_max {
+                let v = $vec2::new(x, !x);
+                let a = v.to_array();
+                assert_eq!(v.count_ones().to_array(), a.map(|e| e.count_ones()));
+                assert_eq!(v.leading_zeros().to_array(), a.map(|e| e.leading_zeros()));
+                assert_eq!(v.trailing_zeros().to_array(), a.map(|e| e.trailing_zeros()));
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec2_const_arithmetic_tests {
+    ($t:ident, $vec2:ident) => {
+        glam_test!(test_const_arithmetic, {
+            const V: $vec2 = $vec2::new(1, 2);
+            const DOT: $t = V.dot(V);
+            const LEN_SQ: $t = V.length_squared();
+            const DOT_INTO: $vec2 = V.dot_into_vec(V);
+            const SUM: $t = V.element_sum();
+            const PRODUCT: $t = V.element_product();
+            assert_eq!(DOT, 5);
+            assert_eq!(LEN_SQ, 5);
+            assert_eq!(DOT_INTO, $vec2::splat(5));
+            assert_eq!(SUM, 3);
+            assert_eq!(PRODUCT, 2);
+        });
+    };
+}
+
+macro_rules! impl_vec2_try_from_float_tests {
+    ($t:ident, $vec2:ident) => {
+        glam_test!(test_try_from_float, {
+            use glam::error::TryFromVecError;
+            assert_eq!(
+                $vec2::try_from(glam::Vec2::new(1.0, 2.0)),
+                Ok($vec2::new(1, 2))
+            );
+            assert_eq!(
+                $vec2::try_from(glam::Vec2::splat($t::MIN as _)),
+                Ok($vec2::splat($t::MIN))
+            );
+            for bad in [-1.0, 0.5, $t::MAX as f64 * 2.0, f64::INFINITY, f64::NAN] {
+                let mut a = [1.0; 2];
+                a[1] = bad as _;
+                let r = $vec2::try_from(glam::Vec2::from_array(a));
+                if bad == -1.0 && $t::MIN != 0 {
+                    assert_eq!(r, Ok($vec2::from_array(a.map(|f| f as $t))));
+                } else {
+                    assert_eq!(r, Err(TryFromVecError { index: 1 }));
+                }
+            }
+            assert_eq!(
+                $vec2::try_from(glam::DVec2::new(1.0, 2.0)),
+                Ok($vec2::new(1, 2))
+            );
+            assert_eq!(
+                $vec2::try_from(glam::DVec2::splat($t::MIN as _)),
+                Ok($vec2::splat($t::MIN))
+            );
+            for bad in [-1.0, 0.5, $t::MAX as f64 * 2.0, f64::INFINITY, f64::NAN] {
+                let mut a = [1.0; 2];
+                a[1] = bad as _;
+                let r = $vec2::try_from(glam::DVec2::from_array(a));
+                if bad == -1.0 && $t::MIN != 0 {
+                    assert_eq!(r, Ok($vec2::from_array(a.map(|f| f as $t))));
+                } else {
+                    assert_eq!(r, Err(TryFromVecError { index: 1 }));
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec2_overflowing_tests {
+    ($t:ident, $vec2:ident, $mask:ident) => {
+        glam_test!(test_overflowing_ops, {
+            let a = $vec2::new($t::MAX, 2);
+            let b = $vec2::new(1, 3);
+            assert_eq!(
+                a.overflowing_add(b),
+                (a.wrapping_add(b), $mask::new(true, false))
+            );
+            assert_eq!(b.overflowing_add(b), (b + b, $mask::FALSE));
+
+            let a = $vec2::new($t::MIN, 5);
+            assert_eq!(
+                a.overflowing_sub(b),
+                (a.wrapping_sub(b), $mask::new(true, false))
+            );
+            assert_eq!(b.overflowing_sub(b), ($vec2::ZERO, $mask::FALSE));
+
+            let a = $vec2::new($t::MAX, 2);
+            let b = $vec2::new(2, 3);
+            assert_eq!(
+                a.overflowing_mul(b),
+                (a.wrapping_mul(b), $mask::new(true, false))
+            );
+            assert_eq!(b.overflowing_mul(b), (b * b, $mask::FALSE));
+        });
+
+        glam_test!(test_midpoint, {
+            for (a, b) in [
+                ($t::MAX, $t::MAX),
+                ($t::MIN, $t::MAX),
+                ($t::MAX, $t::MAX - 1),
+                ($t::MIN, $t::MIN),
+                (3, 4),
+            ] {
+                let m = $vec2::splat(a).midpoint($vec2::splat(b));
+                // i128 division rounds towards zero, matching the documented rounding
+                let expected = ((a as i128 + b as i128) / 2) as $t;
+                assert_eq!(m, $vec2::splat(expected));
+                assert_eq!(m, $vec2::splat(b).midpoint($vec2::splat(a)));
+            }
+        });
+
+        glam_test!(test_widening_mul, {
+            for (a, b) in [
+                ($t::MAX, $t::MAX),
+                ($t::MIN, $t::MAX),
+                ($t::MIN, $t::MIN),
+                (3, 5),
+            ] {
+                let (lo, hi) = $vec2::splat(a).widening_mul($vec2::splat(b));
+                // reassemble the full product, which fits in 128 bits for every element type
+                let product = (a as i128).wrapping_mul(b as i128);
+                for i in 0..2 {
+                    assert_eq!(((hi[i] as i128) << $t::BITS) | lo[i] as i128, product);
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec2_carrying_add_tests {
+    ($t:ident, $vec2:ident, $mask:ident) => {
+        glam_test!(test_carrying_add, {
+            let a = $vec2::new($t::MAX, 1);
+            let b = $vec2::new(0, 2);
+            assert_eq!(a.carrying_add(b, $mask::FALSE), (a + b, $mask::FALSE));
+            assert_eq!(
+                a.carrying_add(b, $mask::TRUE),
+                ($vec2::new(0, 4), $mask::new(true, false))
+            );
+            assert_eq!(
+                $vec2::MAX.carrying_add($vec2::MAX, $mask::TRUE),
+                ($vec2::MAX, $mask::TRUE)
+            );
+        });
     };
 }
 
 mod vec2 {
     use glam::{vec2, BVec2, Vec2, Vec3};
 
+    glam_test!(test_vec2_macro, {
+        const V: Vec2 = vec2!(1.0, 2.0);
+        assert_eq!(Vec2::new(1.0, 2.0), V);
+        assert_eq!(Vec2::splat(0.5), vec2!(0.5));
+        let x = 3.0;
+        assert_eq!(Vec2::new(3.0, 4.0), vec2!(x, x + 1.0,));
+        assert_eq!(Vec2::new(1.0, 2.0), vec2!(V));
+    });
+
+    glam_test!(test_as_saturating, {
+        let v = Vec2::new(-1.0e10, 1.0e10);
+        assert_eq!(
+            v.as_i16vec2_saturating(),
+            glam::I16Vec2::new(i16::MIN, i16::MAX)
+        );
+        assert_eq!(v.as_u16vec2_saturating(), glam::U16Vec2::new(0, u16::MAX));
+    });
+
     glam_test!(test_align, {
         use core::mem;
         assert_eq!(8, mem::size_of::<Vec2>());
@@ -1177,7 +1905,20 @@ mod vec2 {
         assert_eq!(I64Vec2::new(1, 2), U64Vec2::new(1, 2).as_i64vec2());
     });
 
+    glam_test!(test_pack_half, {
+        let v = vec2(1.0, -65504.0);
+        assert_eq!(v.pack_half_2x16(), 0xfbff_3c00);
+        assert_eq!(Vec2::unpack_half_2x16(0xfbff_3c00), v);
+        // rounded to the nearest half float
+        assert_eq!(
+            Vec2::unpack_half_2x16(vec2(0.1, 1e6).pack_half_2x16()),
+            vec2(0.099975586, f32::INFINITY)
+        );
+    });
+
     impl_vec2_float_tests!(f32, vec2, Vec2, Vec3, BVec2);
+
+    impl_vec_f32_fast_tests!(Vec2);
 }
 
 mod dvec2 {
@@ -1220,8 +1961,14 @@ mod i16vec2 {
             I16Vec2::new(1, 2),
             I16Vec2::try_from(U16Vec2::new(1, 2)).unwrap()
         );
-        assert!(I16Vec2::try_from(U16Vec2::new(u16::MAX, 2)).is_err());
-        assert!(I16Vec2::try_from(U16Vec2::new(1, u16::MAX)).is_err());
+        assert_eq!(
+            I16Vec2::try_from(U16Vec2::new(u16::MAX, 2)),
+            Err(glam::error::TryFromVecError { index: 0 })
+        );
+        assert_eq!(
+            I16Vec2::try_from(U16Vec2::new(1, u16::MAX)),
+            Err(glam::error::TryFromVecError { index: 1 })
+        );
 
         assert_eq!(
             I16Vec2::new(1, 2),
@@ -1344,6 +2091,10 @@ mod i16vec2 {
 
     impl_vec2_scalar_bit_op_tests!(I16Vec2, -2, 2);
     impl_vec2_bit_op_tests!(I16Vec2, -2, 2);
+
+    impl_vec2_overflowing_tests!(i16, I16Vec2, BVec2);
+    impl_vec2_try_from_float_tests!(i16, I16Vec2);
+    impl_vec2_const_arithmetic_tests!(i16, I16Vec2);
 }
 
 mod u16vec2 {
@@ -1479,6 +2230,11 @@ mod u16vec2 {
 
     impl_vec2_scalar_bit_op_tests!(U16Vec2, 0, 2);
     impl_vec2_bit_op_tests!(U16Vec2, 0, 2);
+
+    impl_vec2_overflowing_tests!(u16, U16Vec2, BVec2);
+    impl_vec2_try_from_float_tests!(u16, U16Vec2);
+    impl_vec2_const_arithmetic_tests!(u16, U16Vec2);
+    impl_vec2_carrying_add_tests!(u16, U16Vec2, BVec2);
 }
 
 mod ivec2 {
@@ -1610,11 +2366,49 @@ mod ivec2 {
 
     impl_vec2_scalar_bit_op_tests!(IVec2, -2, 2);
     impl_vec2_bit_op_tests!(IVec2, -2, 2);
+
+    impl_vec2_overflowing_tests!(i32, IVec2, BVec2);
+    impl_vec2_try_from_float_tests!(i32, IVec2);
+    impl_vec2_const_arithmetic_tests!(i32, IVec2);
 }
 
 mod uvec2 {
     use glam::{uvec2, BVec2, I16Vec2, I64Vec2, IVec2, U16Vec2, U64Vec2, UVec2, UVec3};
 
+    glam_test!(test_morton, {
+        assert_eq!(UVec2::ZERO.to_morton(), 0);
+        assert_eq!(UVec2::X.to_morton(), 0b01);
+        assert_eq!(UVec2::Y.to_morton(), 0b10);
+        assert_eq!(uvec2(3, 5).to_morton(), 0b100111);
+        assert_eq!(UVec2::MAX.to_morton(), u64::MAX);
+        for v in [uvec2(3, 5), uvec2(0x1234_5678, 0x9abc_def0), UVec2::MAX] {
+            assert_eq!(UVec2::from_morton(v.to_morton()), v);
+        }
+    });
+
+    glam_test!(test_hilbert, {
+        // order 1 visits the cells in a U shape
+        assert_eq!(UVec2::from_hilbert(0, 1), uvec2(0, 0));
+        assert_eq!(UVec2::from_hilbert(1, 1), uvec2(0, 1));
+        assert_eq!(UVec2::from_hilbert(2, 1), uvec2(1, 1));
+        assert_eq!(UVec2::from_hilbert(3, 1), uvec2(1, 0));
+        let mut prev = UVec2::ZERO;
+        for index in 0..64 {
+            let v = UVec2::from_hilbert(index, 3);
+            assert_eq!(v.to_hilbert(3), index);
+            assert_eq!(
+                v.as_ivec2().distance_squared(prev.as_ivec2()),
+                index.min(1) as i32
+            );
+            prev = v;
+        }
+        for v in [uvec2(0x1234_5678, 0x9abc_def0), UVec2::MAX] {
+            assert_eq!(UVec2::from_hilbert(v.to_hilbert(32), 32), v);
+        }
+        should_glam_assert!({ uvec2(4, 0).to_hilbert(2) });
+        should_glam_assert!({ UVec2::from_hilbert(16, 2) });
+    });
+
     glam_test!(test_align, {
         use core::mem;
         assert_eq!(8, mem::size_of::<UVec2>());
@@ -1736,11 +2530,34 @@ mod uvec2 {
 
     impl_vec2_scalar_bit_op_tests!(UVec2, 0, 2);
     impl_vec2_bit_op_tests!(UVec2, 0, 2);
+
+    impl_vec2_overflowing_tests!(u32, UVec2, BVec2);
+    impl_vec2_try_from_float_tests!(u32, UVec2);
+    impl_vec2_const_arithmetic_tests!(u32, UVec2);
+    impl_vec2_carrying_add_tests!(u32, UVec2, BVec2);
 }
 
 mod i64vec2 {
     use glam::{i64vec2, BVec2, I16Vec2, I64Vec2, I64Vec3, IVec2, U16Vec2, U64Vec2, UVec2};
 
+    glam_test!(test_as_saturating, {
+        let v = I64Vec2::new(i64::MIN, i64::MAX);
+        assert_eq!(
+            v.as_i16vec2_saturating(),
+            glam::I16Vec2::new(i16::MIN, i16::MAX)
+        );
+        assert_eq!(v.as_u16vec2_saturating(), glam::U16Vec2::new(0, u16::MAX));
+        assert_eq!(
+            v.as_ivec2_saturating(),
+            glam::IVec2::new(i32::MIN, i32::MAX)
+        );
+        assert_eq!(v.as_uvec2_saturating(), glam::UVec2::new(0, u32::MAX));
+        assert_eq!(
+            v.as_u64vec2_saturating(),
+            glam::U64Vec2::new(0, 9223372036854775807)
+        );
+    });
+
     glam_test!(test_align, {
         use core::mem;
         assert_eq!(16, mem::size_of::<I64Vec2>());
@@ -1802,11 +2619,24 @@ mod i64vec2 {
 
     impl_vec2_scalar_bit_op_tests!(I64Vec2, -2, 2);
     impl_vec2_bit_op_tests!(I64Vec2, -2, 2);
+
+    impl_vec2_overflowing_tests!(i64, I64Vec2, BVec2);
+    impl_vec2_try_from_float_tests!(i64, I64Vec2);
+    impl_vec2_const_arithmetic_tests!(i64, I64Vec2);
 }
 
 mod u64vec2 {
     use glam::{u64vec2, BVec2, I16Vec2, I64Vec2, IVec2, U16Vec2, U64Vec2, U64Vec3, UVec2};
 
+    glam_test!(test_as_saturating, {
+        let v = U64Vec2::new(u64::MAX, 1);
+        assert_eq!(v.as_i16vec2_saturating(), glam::I16Vec2::new(i16::MAX, 1));
+        assert_eq!(v.as_u16vec2_saturating(), glam::U16Vec2::new(u16::MAX, 1));
+        assert_eq!(v.as_ivec2_saturating(), glam::IVec2::new(i32::MAX, 1));
+        assert_eq!(v.as_uvec2_saturating(), glam::UVec2::new(u32::MAX, 1));
+        assert_eq!(v.as_i64vec2_saturating(), glam::I64Vec2::new(i64::MAX, 1));
+    });
+
     glam_test!(test_align, {
         use core::mem;
         assert_eq!(16, mem::size_of::<U64Vec2>());
@@ -1867,4 +2697,9 @@ mod u64vec2 {
 
     impl_vec2_scalar_bit_op_tests!(U64Vec2, 0, 2);
     impl_vec2_bit_op_tests!(U64Vec2, 0, 2);
+
+    impl_vec2_overflowing_tests!(u64, U64Vec2, BVec2);
+    impl_vec2_try_from_float_tests!(u64, U64Vec2);
+    impl_vec2_const_arithmetic_tests!(u64, U64Vec2);
+    impl_vec2_carrying_add_tests!(u64, U64Vec2, BVec2);
 }
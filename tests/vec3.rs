@@ -14,6 +14,100 @@ macro_rules! impl_vec3_tests {
             assert_eq!([1 as $t, 2 as $t, 3 as $t], *V2.as_ref());
         });
 
+        glam_test!(test_bytes, {
+            let v = $new(1 as $t, 2 as $t, 3 as $t);
+            let size = core::mem::size_of::<$t>();
+            let le = v.to_le_bytes();
+            let be = v.to_be_bytes();
+            assert_eq!(le.len(), size * 3);
+            assert_eq!(&le[..size], &(1 as $t).to_le_bytes());
+            assert_eq!(&be[size * 2..], &(3 as $t).to_be_bytes());
+            assert_eq!(v, $vec3::from_le_bytes(le));
+            assert_eq!(v, $vec3::from_be_bytes(be));
+        });
+
+        glam_test!(test_iter, {
+            let v = $new(1 as $t, 2 as $t, 3 as $t);
+            let a = v.to_array();
+            assert!(v.into_iter().eq(a));
+            assert!((&v).into_iter().eq(a.iter()));
+
+            let mut m = v;
+            for e in &mut m {
+                *e += 1 as $t;
+            }
+            assert_eq!(m, v + $vec3::ONE);
+
+            assert_eq!(a.into_iter().collect::<$vec3>(), v);
+            // values after the first 3 are ignored
+            assert_eq!((1..).map(|i| i as $t).collect::<$vec3>(), v);
+            assert_eq!(
+                $vec3::try_from_iter(a.into_iter().skip(1)),
+                Err(glam::error::Error::IterTooShort {
+                    required: 3,
+                    found: 2,
+                })
+            );
+            should_panic!({ core::iter::empty::<$t>().collect::<$vec3>() });
+        });
+
+        glam_test!(test_with_swizzles, {
+            use glam::Vec3Swizzles;
+            let v = $new(1 as $t, 2 as $t, 3 as $t);
+            assert_eq!(v.with_xy(v.zz()), $new(3 as $t, 3 as $t, 3 as $t));
+            assert_eq!(v.with_zx(v.xy()), $new(2 as $t, 2 as $t, 1 as $t));
+            let mut m = v;
+            m.set_yz(v.zy());
+            assert_eq!(m, $new(1 as $t, 3 as $t, 2 as $t));
+            assert_eq!(v.with_zyx(v), $new(3 as $t, 2 as $t, 1 as $t));
+            m.set_yzx(v);
+            assert_eq!(m, $new(3 as $t, 1 as $t, 2 as $t));
+        });
+
+        glam_test!(test_axis, {
+            use glam::Axis;
+            let mut v = $new(1 as $t, 2 as $t, 3 as $t);
+            assert_eq!(v[Axis::X], v.x);
+            assert_eq!($vec3::axis(Axis::X), $vec3::X);
+            assert_eq!(v[Axis::Y], v.y);
+            assert_eq!($vec3::axis(Axis::Y), $vec3::Y);
+            assert_eq!(v[Axis::Z], v.z);
+            assert_eq!($vec3::axis(Axis::Z), $vec3::Z);
+            for (i, axis) in Axis::ALL[..3].iter().enumerate() {
+                assert_eq!(axis.index(), i);
+                assert_eq!(Axis::from_index(i), Some(*axis));
+                v[*axis] = 0 as $t;
+            }
+            assert_eq!(v, $vec3::ZERO);
+            should_panic!({ v[Axis::W] });
+            should_panic!({ $vec3::axis(Axis::W) });
+        });
+
+        glam_test!(test_from_fn, {
+            assert_eq!(
+                $vec3::from_fn(|i| (i + 1) as $t),
+                $new(1 as $t, 2 as $t, 3 as $t)
+            );
+            let mut calls = 0;
+            let v = $vec3::from_fn(|i| {
+                assert_eq!(i, calls);
+                calls += 1;
+                calls as $t
+            });
+            assert_eq!(v, $new(1 as $t, 2 as $t, 3 as $t));
+        });
+
+        glam_test!(test_map_and_zip_with, {
+            let v = $new(1 as $t, 2 as $t, 3 as $t);
+            assert_eq!(v.map(|e| e * 2 as $t), $new(2 as $t, 4 as $t, 6 as $t));
+            assert_eq!(v.map(|e| e), v);
+            assert_eq!(v.zip_with(v, |a, b| a + b), v + v);
+            assert_eq!(
+                v.zip_with($vec3::ONE, |a, b| if a > b { a } else { b }),
+                v.max($vec3::ONE)
+            );
+        });
+
         glam_test!(test_vec3_consts, {
             assert_eq!($vec3::ZERO, $new(0 as $t, 0 as $t, 0 as $t));
             assert_eq!($vec3::ONE, $new(1 as $t, 1 as $t, 1 as $t));
@@ -80,6 +174,39 @@ macro_rules! impl_vec3_tests {
             assert_eq!($vec3::new(0 as $t, 0 as $t, 1 as $t), $vec3::Z);
         });
 
+        glam_test!(test_from_slice_checked, {
+            let a = [1 as $t, 2 as $t, 3 as $t, 4 as $t];
+            assert_eq!(
+                $vec3::from_slice_checked(&a),
+                Ok($vec3::new(1 as $t, 2 as $t, 3 as $t))
+            );
+            assert_eq!(
+                $vec3::from_slice_checked(&a[..2]),
+                Err(glam::error::Error::SliceTooShort {
+                    required: 3,
+                    found: 2
+                })
+            );
+        });
+
+        glam_test!(test_from_str, {
+            let a = $vec3::new(1 as $t, 2 as $t, 3 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 3,
+                    found: 2
+                }),
+                "[1, 2]".parse::<$vec3>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$vec3>()
+            );
+        });
+
         glam_test!(test_fmt, {
             let a = $vec3::new(1 as $t, 2 as $t, 3 as $t);
             assert_eq!(
@@ -97,6 +224,8 @@ macro_rules! impl_vec3_tests {
                 )
             );
             assert_eq!(format!("{}", a), "[1, 2, 3]");
+            assert_eq!(format!("{:2}", a), "[ 1,  2,  3]");
+            assert_eq!(format!("{:<2}", a), "[1 , 2 , 3 ]");
         });
 
         glam_test!(test_zero, {
@@ -226,6 +355,50 @@ macro_rules! impl_vec3_tests {
             assert_eq!((4 as $t, 5 as $t, 6 as $t), b.max(a).into());
         });
 
+        glam_test!(test_min_max_position, {
+            let a = $new(3 as $t, 5 as $t, 1 as $t);
+            assert_eq!(2, a.min_position());
+            assert_eq!(1, a.max_position());
+            let b = $new(4 as $t, 1 as $t, 4 as $t);
+            assert_eq!(1, b.min_position());
+            assert_eq!(0, b.max_position());
+        });
+
+        glam_test!(test_min_max_pair, {
+            let a = $new(3 as $t, 5 as $t, 1 as $t);
+            let b = $new(4 as $t, 2 as $t, 1 as $t);
+            assert_eq!((a.min(b), a.max(b)), a.min_max(b));
+            assert_eq!((a.min(b), a.max(b)), b.min_max(a));
+        });
+
+        glam_test!(test_sorted, {
+            let sorted = $new(1 as $t, 2 as $t, 3 as $t);
+            for [x, y, z] in [
+                [1, 2, 3],
+                [1, 3, 2],
+                [2, 1, 3],
+                [2, 3, 1],
+                [3, 1, 2],
+                [3, 2, 1],
+            ] {
+                let v = $new(x as $t, y as $t, z as $t);
+                assert_eq!(sorted, v.sorted());
+            }
+            assert_eq!($vec3::ONE, $vec3::ONE.sorted());
+        });
+
+        glam_test!(test_snap, {
+            fn vec(x: i32, y: i32, z: i32) -> $vec3 {
+                $vec3::new(x as $t, y as $t, z as $t)
+            }
+            assert_eq!(vec(7, 12, 3).snap(vec(5, 4, 2)), vec(5, 12, 4));
+            assert_eq!(vec(8, 13, 5).snap(vec(5, 4, 10)), vec(10, 12, 10));
+            assert_eq!(
+                vec(9, 13, 6).snap_to(vec(5, 4, 4), vec(2, 1, 3)),
+                vec(7, 13, 7)
+            );
+        });
+
         glam_test!(test_clamp, {
             fn vec(x: i32, y: i32, z: i32) -> $vec3 {
                 $vec3::new(x as $t, y as $t, z as $t)
@@ -396,6 +569,33 @@ macro_rules! impl_vec3_tests {
             assert_eq!($mask::splat(true), $mask::new(true, true, true));
         });
 
+        glam_test!(test_mask_from_bitmask, {
+            for bitmask in 0..=0b111 {
+                assert_eq!($mask::from_bitmask(bitmask).bitmask(), bitmask);
+                assert_eq!($mask::from_bitmask(bitmask | !0b111).bitmask(), bitmask);
+            }
+            assert_eq!($mask::from_bitmask(0), $mask::FALSE);
+            assert_eq!($mask::from_bitmask(0b111), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_count_true, {
+            assert_eq!($mask::FALSE.count_true(), 0);
+            assert_eq!($mask::TRUE.count_true(), 3);
+            assert_eq!($mask::new(true, false, true).count_true(), 2);
+        });
+
+        glam_test!(test_mask_into_iter, {
+            let v: Vec<bool> = $mask::new(true, false, true).into_iter().collect();
+            assert_eq!(v, [true, false, true]);
+            assert_eq!(
+                $mask::new(false, false, false)
+                    .into_iter()
+                    .filter(|b| *b)
+                    .count(),
+                0
+            );
+        });
+
         glam_test!(test_mask_bitmask, {
             assert_eq!($mask::new(false, false, false).bitmask(), 0b000);
             assert_eq!($mask::new(true, false, false).bitmask(), 0b001);
@@ -625,6 +825,23 @@ macro_rules! impl_vec3_signed_tests {
     ($t:ident, $new:ident, $vec3:ident, $mask:ident) => {
         impl_vec3_tests!($t, $new, $vec3, $mask);
 
+        glam_test!(test_abs_min_max_position, {
+            let a = $new(-7 as $t, 2 as $t, 5 as $t);
+            assert_eq!(0, a.abs_max_position());
+            assert_eq!(1, a.abs_min_position());
+            let b = $new(1 as $t, 1 as $t, $t::MIN);
+            assert_eq!(2, b.abs_max_position());
+            assert_eq!(0, b.abs_min_position());
+        });
+
+        glam_test!(test_snap_signed, {
+            fn vec(x: i32, y: i32, z: i32) -> $vec3 {
+                $vec3::new(x as $t, y as $t, z as $t)
+            }
+            assert_eq!(vec(-7, -13, -2).snap(vec(5, 4, 3)), vec(-5, -12, -3));
+            assert_eq!(vec(-8, 13, 7).snap(vec(-5, -4, -3)), vec(-10, 12, 6));
+        });
+
         glam_test!(test_neg, {
             let a = $new(1 as $t, 2 as $t, 3 as $t);
             assert_eq!((-1 as $t, -2 as $t, -3 as $t), (-a).into());
@@ -712,6 +929,18 @@ macro_rules! impl_vec3_signed_tests {
             assert_eq!(four.rem_euclid(-three), one);
             assert_eq!((-four).rem_euclid(-three), two);
         });
+
+        glam_test!(test_div_floor, {
+            let one = $vec3::ONE;
+            let two = one + one;
+            let three = two + one;
+            assert_eq!(three.div_floor(two), one);
+            assert_eq!((-three).div_floor(two), -two);
+            assert_eq!(three.div_floor(-two), -two);
+            assert_eq!((-three).div_floor(-two), one);
+            assert_eq!((-two - two).div_floor(two), -two);
+            assert_eq!($vec3::ZERO.div_floor(-two), $vec3::ZERO);
+        });
     };
 }
 
@@ -719,6 +948,14 @@ macro_rules! impl_vec3_signed_integer_tests {
     ($t:ident, $new:ident, $vec3:ident, $mask:ident) => {
         impl_vec3_signed_tests!($t, $new, $vec3, $mask);
 
+        glam_test!(test_unsigned_abs, {
+            for (a, expected) in [($t::MIN, -($t::MIN as i128)), (-3, 3), (0, 0), (3, 3)] {
+                for e in $vec3::splat(a).unsigned_abs().to_array() {
+                    assert_eq!(e as i128, expected);
+                }
+            }
+        });
+
         glam_test!(test_signum, {
             assert_eq!($vec3::ZERO.signum(), $vec3::ZERO);
             assert_eq!($vec3::ONE.signum(), $vec3::ONE);
@@ -729,6 +966,21 @@ macro_rules! impl_vec3_signed_integer_tests {
 
 macro_rules! impl_vec3_eq_hash_tests {
     ($t:ident, $new:ident) => {
+        glam_test!(test_vec3_ord, {
+            let a = $new(1 as $t, 2 as $t, 3 as $t);
+            let b = $new(1 as $t, 2 as $t, 4 as $t);
+            let c = $new(2 as $t, 0 as $t, 0 as $t);
+            assert!(a < b);
+            assert!(b < c);
+            assert_eq!(core::cmp::Ordering::Equal, a.cmp(&a));
+
+            let mut sorted = [c, a, b];
+            sorted.sort();
+            assert_eq!([a, b, c], sorted);
+            // inherent methods are element-wise rather than lexicographic
+            assert_eq!($new(1 as $t, 0 as $t, 0 as $t), a.min(c));
+        });
+
         glam_test!(test_vec3_hash, {
             use std::collections::hash_map::DefaultHasher;
             use std::hash::Hash;
@@ -759,7 +1011,7 @@ macro_rules! impl_vec3_eq_hash_tests {
 }
 
 macro_rules! impl_vec3_float_tests {
-    ($t:ident, $new:ident, $vec3:ident, $mask:ident) => {
+    ($t:ident, $new:ident, $vec3:ident, $vec2:ident, $mask:ident) => {
         impl_vec3_signed_tests!($t, $new, $vec3, $mask);
         impl_vec_float_normalize_tests!($t, $vec3);
 
@@ -828,6 +1080,68 @@ macro_rules! impl_vec3_float_tests {
             should_glam_assert!({ $vec3::ONE.reject_from_normalized($vec3::ONE) });
         });
 
+        glam_test!(test_project_reject_plane, {
+            let v = $new(1.0, 2.0, 3.0);
+            assert_eq!(
+                $new(1.0, 2.0, 0.0),
+                v.project_onto_plane($new(0.0, 0.0, 2.0))
+            );
+            assert_eq!(
+                $new(0.0, 0.0, 3.0),
+                v.reject_from_plane($new(0.0, 0.0, 2.0))
+            );
+            assert_eq!(
+                $new(1.0, 2.0, 0.0),
+                v.project_onto_plane_normalized($new(0.0, 0.0, -1.0))
+            );
+            assert_eq!(
+                $new(0.0, 0.0, 3.0),
+                v.reject_from_plane_normalized($new(0.0, 0.0, -1.0))
+            );
+            let n = $new(1.0, 1.0, 0.0);
+            let p = v.project_onto_plane(n);
+            assert_approx_eq!(0.0, p.dot(n), 1e-6);
+            assert_approx_eq!(v, p + v.reject_from_plane(n), 1e-6);
+            assert_approx_eq!(p, v.project_onto_plane_normalized(n.normalize()), 1e-6);
+            should_glam_assert!({ $vec3::ONE.project_onto_plane($vec3::ZERO) });
+            should_glam_assert!({ $vec3::ONE.reject_from_plane($vec3::ZERO) });
+            should_glam_assert!({ $vec3::ONE.project_onto_plane_normalized($vec3::ONE) });
+            should_glam_assert!({ $vec3::ONE.reject_from_plane_normalized($vec3::ONE) });
+        });
+
+        glam_test!(test_reflect_refract, {
+            assert_eq!(
+                $new(1.0, 1.0, -2.0),
+                $new(1.0, -1.0, -2.0).reflect($vec3::Y)
+            );
+            assert_eq!(
+                $new(1.0, -1.0, 2.0),
+                $new(1.0, -1.0, -2.0).reflect(-$vec3::Z)
+            );
+
+            let incident = $new(1.0, -1.0, 0.0).normalize();
+            assert_eq!(Some(incident), incident.refract($vec3::Y, 1.0));
+            // Snell's law, sin(t) = eta * sin(i)
+            let eta = 1.0 / 1.5;
+            let refracted = incident.refract($vec3::Y, eta).unwrap();
+            let sin_t = eta * core::$t::consts::FRAC_1_SQRT_2;
+            assert_approx_eq!(
+                $new(sin_t, -(1.0 - sin_t * sin_t).sqrt(), 0.0),
+                refracted,
+                1e-6
+            );
+            assert!(refracted.is_normalized());
+            // head on
+            assert_approx_eq!(-$vec3::Y, (-$vec3::Y).refract($vec3::Y, eta).unwrap(), 1e-6);
+            // total internal reflection
+            assert_eq!(None, incident.refract($vec3::Y, 1.5));
+            assert!((-$vec3::Y).refract($vec3::Y, 1.5).is_some());
+
+            should_glam_assert!({ $vec3::ONE.reflect($vec3::ONE) });
+            should_glam_assert!({ $vec3::ONE.refract($vec3::Y, 1.0) });
+            should_glam_assert!({ $vec3::X.refract($vec3::ONE, 1.0) });
+        });
+
         glam_test!(test_signum, {
             assert_eq!($vec3::ZERO.signum(), $vec3::ONE);
             assert_eq!((-$vec3::ZERO).signum(), -$vec3::ONE);
@@ -893,6 +1207,32 @@ macro_rules! impl_vec3_float_tests {
             assert!($vec3::new(NAN, 0.0, 0.0).round().x.is_nan());
         });
 
+        glam_test!(test_round_ties_even, {
+            assert_eq!(
+                $vec3::new(0.5, 1.5, 2.5).round_ties_even(),
+                $vec3::new(0.0, 2.0, 2.0)
+            );
+            let r = $vec3::new(-0.5, 3.5, -1.25).round_ties_even();
+            assert_eq!(r, $vec3::new(-0.0, 4.0, -1.0));
+            assert!(r.is_negative_bitmask() & 1 != 0);
+            assert!($vec3::NAN.round_ties_even().is_nan_mask().all());
+            assert_eq!($vec3::INFINITY.round_ties_even(), $vec3::INFINITY);
+        });
+
+        glam_test!(test_as_int_rounding, {
+            let v = $vec3::new(-1.25, 0.75, 2.25);
+            assert_eq!(v.as_ivec3_round(), glam::IVec3::new(-1, 1, 2));
+            assert_eq!(v.as_ivec3_floor(), glam::IVec3::new(-2, 0, 2));
+            assert_eq!(v.as_ivec3_ceil(), glam::IVec3::new(-1, 1, 3));
+            assert_eq!(v.as_uvec3_round(), glam::UVec3::new(0, 1, 2));
+            assert_eq!(v.as_uvec3_floor(), glam::UVec3::new(0, 0, 2));
+            assert_eq!(v.as_uvec3_ceil(), glam::UVec3::new(0, 1, 3));
+            assert_eq!(v.as_i64vec3_floor(), v.floor().as_i64vec3());
+            assert_eq!(v.as_u64vec3_ceil(), v.ceil().as_u64vec3());
+            assert_eq!($vec3::NAN.as_ivec3_round(), glam::IVec3::ZERO);
+            assert_eq!($vec3::INFINITY.as_ivec3_ceil(), glam::IVec3::MAX);
+        });
+
         glam_test!(test_floor, {
             assert_eq!(
                 $vec3::new(1.35, 1.5, -1.5).floor(),
@@ -921,6 +1261,21 @@ macro_rules! impl_vec3_float_tests {
             );
         });
 
+        glam_test!(test_modf, {
+            let (trunc, fract) = $vec3::new(1.25, -1.5, -3.0).modf();
+            assert_eq!(trunc, $vec3::new(1.0, -1.0, -3.0));
+            assert_eq!(fract, $vec3::new(0.25, -0.5, -0.0));
+            assert_eq!(trunc + fract, $vec3::new(1.25, -1.5, -3.0));
+            assert!(fract.is_negative_bitmask() & 0b10 != 0);
+
+            let (trunc, fract) = $vec3::new($t::INFINITY, $t::NEG_INFINITY, 0.0).modf();
+            assert_eq!(trunc, $vec3::new($t::INFINITY, $t::NEG_INFINITY, 0.0));
+            assert_eq!(fract, $vec3::ZERO);
+            assert!(fract.is_negative_bitmask() & 0b10 != 0);
+            let (trunc, fract) = $vec3::NAN.modf();
+            assert!(trunc.is_nan_mask().all() && fract.is_nan_mask().all());
+        });
+
         glam_test!(test_ceil, {
             assert_eq!(
                 $vec3::new(1.35, 1.5, -1.5).ceil(),
@@ -977,6 +1332,186 @@ macro_rules! impl_vec3_float_tests {
             assert_approx_eq!($vec3::new(-0.25, 0.5, 1.0), v1.midpoint(v2));
         });
 
+        glam_test!(test_centroid, {
+            let points = [
+                $vec3::new(0.0, 0.0, 0.0),
+                $vec3::new(2.0, 0.0, 0.0),
+                $vec3::new(2.0, 4.0, 4.0),
+                $vec3::new(0.0, 4.0, 4.0),
+            ];
+            assert_eq!($vec3::new(1.0, 2.0, 2.0), $vec3::centroid(&points));
+            assert!($vec3::centroid(&[]).is_nan_mask().all());
+
+            // naive summation drifts noticeably from 0.1 here
+            let points = vec![$vec3::splat(0.1); 1_000_000];
+            assert_approx_eq!($vec3::splat(0.1), $vec3::centroid(&points));
+        });
+
+        glam_test!(test_weighted_mean, {
+            let points = [$vec3::new(0.0, 0.0, 0.0), $vec3::new(4.0, 8.0, 12.0)];
+            assert_eq!(
+                $vec3::new(1.0, 2.0, 3.0),
+                $vec3::weighted_mean(&points, &[3.0, 1.0])
+            );
+            assert_eq!(
+                $vec3::centroid(&points),
+                $vec3::weighted_mean(&points, &[0.5, 0.5])
+            );
+
+            let points = vec![$vec3::splat(0.1); 1_000_000];
+            let weights = vec![0.5; 1_000_000];
+            assert_approx_eq!($vec3::splat(0.1), $vec3::weighted_mean(&points, &weights));
+
+            should_panic!({ $vec3::weighted_mean(&points, &[1.0]) });
+        });
+
+        glam_test!(test_total_cmp, {
+            use core::cmp::Ordering;
+            let a = $vec3::new(1.0, 2.0, 0.0);
+            let b = $vec3::new(1.0, 3.0, 0.0);
+            assert_eq!(a.cmp_total(b), Ordering::Less);
+            assert_eq!(b.cmp_total(a), Ordering::Greater);
+            assert_eq!(a.cmp_total(a), Ordering::Equal);
+            assert_eq!(a.cmp_lex(b), Ordering::Less);
+            assert_eq!(
+                $vec3::NAN.cmp_lex($vec3::ZERO),
+                $vec3::NAN.cmp_total($vec3::ZERO)
+            );
+            let mut sorted = [b, $vec3::NAN, a];
+            sorted.sort_by(|l, r| l.cmp_lex(*r));
+            assert_eq!([a, b], sorted[..2]);
+            assert!(sorted[2].is_nan());
+            assert_eq!($vec3::ZERO.cmp_total($vec3::splat(-0.0)), Ordering::Greater);
+            assert_eq!($vec3::NAN.cmp_total($vec3::NAN), Ordering::Equal);
+            assert_eq!($vec3::NAN.cmp_total($vec3::INFINITY), Ordering::Greater);
+            assert_eq!((-$vec3::NAN).cmp_total($vec3::NEG_INFINITY), Ordering::Less);
+
+            assert_eq!(a.min_total(b), a.min(b));
+            assert_eq!(a.max_total(b), a.max(b));
+            assert!(a.max_total($vec3::NAN).is_nan_mask().all());
+            assert_eq!(a.min_total($vec3::NAN), a);
+            assert_eq!(
+                $vec3::ZERO
+                    .min_total($vec3::splat(-0.0))
+                    .is_negative_bitmask(),
+                (1 << 3) - 1
+            );
+
+            assert_eq!(a.total_ord(), a.total_ord());
+            assert_ne!($vec3::ZERO.total_ord(), $vec3::splat(-0.0).total_ord());
+            assert_eq!($vec3::NAN.total_ord(), $vec3::NAN.total_ord());
+            assert!(a.total_ord() < b.total_ord());
+
+            let mut set = std::collections::BTreeSet::new();
+            set.insert(b.total_ord());
+            set.insert(a.total_ord());
+            set.insert($vec3::NAN.total_ord());
+            set.insert(a.total_ord());
+            assert_eq!(set.len(), 3);
+            assert_eq!(set.first().unwrap().0, a);
+
+            let mut set = std::collections::HashSet::new();
+            set.insert($vec3::NAN.total_ord());
+            assert!(set.contains(&$vec3::NAN.total_ord()));
+            assert!(!set.contains(&a.total_ord()));
+        });
+
+        glam_test!(test_hashable, {
+            let a = $vec3::new(1.0, -2.0, 3.0);
+            assert_eq!(a, $vec3::from_bits(a.to_bits()));
+            assert_eq!(a.x.to_bits(), a.to_bits().x);
+            assert_eq!($vec3::ZERO.hashable(), $vec3::splat(-0.0).hashable());
+            assert_eq!($vec3::NAN.hashable(), (-$vec3::NAN).hashable());
+            assert_ne!(a.hashable(), $vec3::ZERO.hashable());
+
+            let mut set = std::collections::HashSet::new();
+            set.insert(a.hashable());
+            set.insert($vec3::ZERO.hashable());
+            set.insert($vec3::splat(-0.0).hashable());
+            set.insert($vec3::NAN.hashable());
+            set.insert((-$vec3::NAN).hashable());
+            set.insert(a.hashable());
+            assert_eq!(set.len(), 3);
+            assert!(set.contains(&$vec3::splat(0.0).hashable()));
+        });
+
+        glam_test!(test_min_max_nan, {
+            let a = $vec3::new($t::NAN, 1.0, 0.0);
+            let b = $vec3::new(0.0, $t::NAN, 0.0);
+            assert!(a.min_nan(b).x.is_nan() && a.min_nan(b).y.is_nan());
+            assert!(a.max_nan(b).x.is_nan() && a.max_nan(b).y.is_nan());
+            assert!(a.clamp_nan($vec3::ZERO, $vec3::ONE).x.is_nan());
+            assert_eq!(a.clamp_nan($vec3::ZERO, $vec3::ONE).y, 1.0);
+            assert!($vec3::ONE
+                .clamp_nan($vec3::NAN, $vec3::ONE)
+                .is_nan_mask()
+                .all());
+
+            let a = $vec3::new(1.0, -2.0, 0.0);
+            let b = $vec3::new(-1.0, 3.0, 0.0);
+            assert_eq!(a.min_nan(b), a.min(b));
+            assert_eq!(a.max_nan(b), a.max(b));
+            assert_eq!(
+                $vec3::splat(4.0).clamp_nan($vec3::ZERO, $vec3::ONE),
+                $vec3::ONE
+            );
+
+            let neg_zero = $vec3::splat(-0.0).min_nan($vec3::ZERO);
+            assert_eq!(neg_zero.is_negative_bitmask(), (1 << 3) - 1);
+            let zero = $vec3::splat(-0.0).max_nan($vec3::ZERO);
+            assert_eq!(zero.is_negative_bitmask(), 0);
+        });
+
+        glam_test!(test_ulps_eq, {
+            let a = $vec3::new(1.0, 1e10, -1e-10);
+            let next = $vec3::from_array(a.to_array().map(|x| $t::from_bits(x.to_bits() + 1)));
+            let far = $vec3::from_array(a.to_array().map(|x| $t::from_bits(x.to_bits() + 5)));
+            assert!(a.ulps_eq(a, 0));
+            assert!(!a.ulps_eq(next, 0));
+            assert!(a.ulps_eq(next, 1));
+            assert!(next.ulps_eq(a, 1));
+            assert!(!a.ulps_eq(far, 4));
+            assert!($vec3::ZERO.ulps_eq(-$vec3::ZERO, 0));
+            assert!(!$vec3::splat($t::MIN_POSITIVE).ulps_eq($vec3::splat(-$t::MIN_POSITIVE), 4));
+            assert!(!$vec3::NAN.ulps_eq($vec3::NAN, 4));
+            assert!($vec3::INFINITY.ulps_eq($vec3::INFINITY, 0));
+        });
+
+        glam_test!(test_normalize_checked, {
+            assert_eq!($vec3::X.normalize_checked(), Ok($vec3::X));
+            assert_eq!($vec3::new(0.0, 2.0, 0.0).normalize_checked(), Ok($vec3::Y));
+            for v in [$vec3::ZERO, $vec3::INFINITY, $vec3::NAN] {
+                assert_eq!(
+                    v.normalize_checked(),
+                    Err(glam::error::Error::NonNormalizable)
+                );
+            }
+        });
+
+        glam_test!(test_compensated, {
+            let e = $t::EPSILON;
+            let a = $vec3::new(1.0 + e, -1.0, 0.0);
+            let b = $vec3::new(1.0 - e, 1.0, 0.0);
+            assert_eq!(a.dot(b), 0.0);
+            assert_eq!(a.dot_compensated(b), -e * e);
+            assert_eq!($vec3::ONE.dot_compensated($vec3::splat(2.0)), 3.0 * 2.0);
+
+            let big = 4.0 / $t::EPSILON;
+            let values = [$vec3::splat(big), $vec3::ONE, $vec3::splat(-big)];
+            assert_eq!(values.iter().sum::<$vec3>(), $vec3::ZERO);
+            assert_eq!($vec3::sum_compensated(&values), $vec3::ONE);
+            assert_eq!($vec3::sum_compensated(&[]), $vec3::ZERO);
+        });
+
+        glam_test!(test_finite_or, {
+            let a = $vec3::new($t::NAN, 1.0, $t::NEG_INFINITY);
+            assert_eq!(a.is_finite_mask(), $mask::new(false, true, false));
+            assert!($vec3::ONE.is_finite_mask().all());
+            assert!(!$vec3::NAN.is_finite_mask().any());
+            assert_eq!(a.finite_or($vec3::splat(2.0)), $vec3::new(2.0, 1.0, 2.0));
+            assert_eq!($vec3::ONE.finite_or($vec3::NAN), $vec3::ONE);
+        });
+
         glam_test!(test_is_finite, {
             assert!($vec3::new(0.0, 0.0, 0.0).is_finite());
             assert!($vec3::new(-1e-10, 1.0, 1e10).is_finite());
@@ -1002,6 +1537,91 @@ macro_rules! impl_vec3_float_tests {
             );
         });
 
+        glam_test!(test_exp2_ln_log2, {
+            assert_eq!($vec3::new(1.0, 2.0, 3.0).exp2(), $vec3::new(2.0, 4.0, 8.0));
+            assert_eq!($vec3::new(1.0, 2.0, 4.0).log2(), $vec3::new(0.0, 1.0, 2.0));
+            assert_approx_eq!(
+                $vec3::new(1.0, 2.0, 3.0).ln(),
+                $vec3::new((1.0 as $t).ln(), (2.0 as $t).ln(), (3.0 as $t).ln())
+            );
+            assert_approx_eq!(
+                $vec3::new(1.0, 2.0, 3.0).exp().ln(),
+                $vec3::new(1.0, 2.0, 3.0),
+                1e-5
+            );
+            assert!($vec3::splat(-1.0).ln().is_nan_mask().all());
+            assert_eq!($vec3::ZERO.log2(), $vec3::NEG_INFINITY);
+        });
+
+        glam_test!(test_pow, {
+            assert_approx_eq!(
+                $vec3::splat(2.0).pow($vec3::new(3.0, 0.5, -1.0)),
+                $vec3::new(
+                    (2.0 as $t).powf(3.0),
+                    (2.0 as $t).powf(0.5),
+                    (2.0 as $t).powf(-1.0)
+                )
+            );
+            assert_approx_eq!(
+                $vec3::new(1.0, 2.0, 3.0).pow($vec3::new(3.0, 0.5, -1.0)),
+                $vec3::new(
+                    (1.0 as $t).powf(3.0),
+                    (2.0 as $t).powf(0.5),
+                    (3.0 as $t).powf(-1.0)
+                )
+            );
+            assert_eq!(
+                $vec3::new(1.0, 2.0, 3.0).pow($vec3::ONE),
+                $vec3::new(1.0, 2.0, 3.0)
+            );
+        });
+
+        glam_test!(test_trig, {
+            let a = $vec3::new(0.5, -1.25, 2.0);
+            let u = $vec3::new(0.5, -1.0, 0.25);
+            assert_approx_eq!(
+                a.sin(),
+                $vec3::new((0.5 as $t).sin(), (-1.25 as $t).sin(), (2.0 as $t).sin()),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.cos(),
+                $vec3::new((0.5 as $t).cos(), (-1.25 as $t).cos(), (2.0 as $t).cos()),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.tan(),
+                $vec3::new((0.5 as $t).tan(), (-1.25 as $t).tan(), (2.0 as $t).tan()),
+                1e-6
+            );
+            assert_approx_eq!(
+                u.asin(),
+                $vec3::new((0.5 as $t).asin(), (-1.0 as $t).asin(), (0.25 as $t).asin()),
+                1e-6
+            );
+            assert_approx_eq!(
+                u.acos(),
+                $vec3::new((0.5 as $t).acos(), (-1.0 as $t).acos(), (0.25 as $t).acos()),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.atan(),
+                $vec3::new((0.5 as $t).atan(), (-1.25 as $t).atan(), (2.0 as $t).atan()),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.atan2($vec3::new(1.0, -2.0, -0.5)),
+                $vec3::new(
+                    (0.5 as $t).atan2(1.0),
+                    (-1.25 as $t).atan2(-2.0),
+                    (2.0 as $t).atan2(-0.5)
+                ),
+                1e-6
+            );
+            assert_approx_eq!(u.asin().sin(), u, 1e-6);
+            assert!($vec3::splat(2.0).acos().is_nan_mask().all());
+        });
+
         glam_test!(test_angle_between, {
             let angle = $vec3::new(1.0, 0.0, 1.0).angle_between($vec3::new(1.0, 1.0, 0.0));
             assert_approx_eq!(core::$t::consts::FRAC_PI_3, angle, 1e-6);
@@ -1013,6 +1633,66 @@ macro_rules! impl_vec3_float_tests {
             assert_approx_eq!(2.0 * core::$t::consts::FRAC_PI_3, angle, 1e-6);
         });
 
+        glam_test!(test_signed_angle_between, {
+            use core::$t::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+            let angle = $vec3::X.signed_angle_between($vec3::Y, $vec3::Z);
+            assert_approx_eq!(FRAC_PI_2, angle, 1e-6);
+            let angle = $vec3::X.signed_angle_between($vec3::Y, -$vec3::Z);
+            assert_approx_eq!(-FRAC_PI_2, angle, 1e-6);
+            let angle = $vec3::Y.signed_angle_between($vec3::X, $vec3::Z);
+            assert_approx_eq!(-FRAC_PI_2, angle, 1e-6);
+
+            // matches the 2D convention in the xy plane
+            let a = $vec3::new(2.0, 1.0, 0.0);
+            let b = $vec3::new(-1.0, 3.0, 0.0);
+            let angle = a.signed_angle_between(b, $vec3::Z);
+            assert_approx_eq!(a.truncate().angle_between(b.truncate()), angle, 1e-6);
+
+            // non-unit vectors and axis not perpendicular to the inputs
+            let angle = $vec3::new(10.0, 0.0, 10.0)
+                .signed_angle_between($vec3::new(5.0, 5.0, 0.0), $vec3::new(-1.0, 2.0, 3.0));
+            assert_approx_eq!(FRAC_PI_3, angle, 1e-6);
+
+            // accurate for nearly parallel vectors
+            let a = $vec3::new(1.0, 0.0, 0.0);
+            let b = $vec3::new(1.0, 1e-4, 0.0);
+            assert_approx_eq!(1e-4, a.signed_angle_between(b, $vec3::Z), 1e-9);
+            assert_approx_eq!(-1e-4, b.signed_angle_between(a, $vec3::Z), 1e-9);
+
+            assert_eq!(0.0, a.signed_angle_between(a * 2.0, $vec3::Z));
+            assert_approx_eq!(PI, a.signed_angle_between(-a, $vec3::Z), 1e-6);
+        });
+
+        glam_test!(test_rotate_towards, {
+            use core::$t::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+            let v = $vec3::new(2.0, 0.0, 3.0);
+            let r = v.rotate_towards($vec3::new(0.0, 5.0, -1.0), $vec3::Z, FRAC_PI_4);
+            assert_approx_eq!(
+                $vec3::new(core::$t::consts::SQRT_2, core::$t::consts::SQRT_2, 3.0),
+                r,
+                1e-6
+            );
+            let r = v.rotate_towards($vec3::new(0.0, -5.0, 1.0), $vec3::Z, FRAC_PI_4);
+            assert_approx_eq!(
+                $vec3::new(core::$t::consts::SQRT_2, -core::$t::consts::SQRT_2, 3.0),
+                r,
+                1e-6
+            );
+            // does not overshoot the target and keeps the axial component
+            let r = v.rotate_towards($vec3::new(0.0, 0.5, 7.0), $vec3::Z, PI);
+            assert_approx_eq!($vec3::new(0.0, 2.0, 3.0), r, 1e-6);
+            assert_approx_eq!(v.length(), r.length(), 1e-6);
+            // negative angles rotate away from the target
+            let r = v.rotate_towards($vec3::Y, $vec3::Z, -FRAC_PI_2);
+            assert_approx_eq!($vec3::new(0.0, -2.0, 3.0), r, 1e-6);
+            // about an arbitrary axis
+            let axis = $vec3::new(1.0, 1.0, 1.0).normalize();
+            let r = $vec3::X.rotate_towards($vec3::Y, axis, PI);
+            assert_approx_eq!($vec3::Y, r, 1e-6);
+
+            should_glam_assert!({ $vec3::X.rotate_towards($vec3::Y, $vec3::ONE, 1.0) });
+        });
+
         glam_test!(test_clamp_length, {
             // Too long gets shortened
             assert_eq!(
@@ -1086,9 +1766,153 @@ macro_rules! impl_vec3_float_tests {
             );
         });
 
+        glam_test!(test_srgb, {
+            let srgb = $vec3::new(0.0, 0.04045, 0.5);
+            let linear = srgb.to_linear();
+            assert_approx_eq!(linear, $vec3::new(0.0, 0.04045 / 12.92, 0.21404114));
+            assert_approx_eq!(linear.to_srgb(), srgb);
+            assert_eq!($vec3::ONE.to_linear(), $vec3::ONE);
+            assert_approx_eq!($vec3::ONE.to_srgb(), $vec3::ONE);
+            for i in 0..=256 {
+                let c = $vec3::splat(i as $t / 256.0);
+                assert!(c.to_linear_fast().abs_diff_eq(c.to_linear(), 0.003));
+                assert!(c.to_srgb_fast().abs_diff_eq(c.to_srgb(), 0.003));
+            }
+        });
+
+        glam_test!(test_hsv_hsl, {
+            let colors = [
+                ($vec3::new(1.0, 0.0, 0.0), [0.0, 1.0, 1.0], [0.0, 1.0, 0.5]),
+                (
+                    $vec3::new(0.0, 1.0, 0.0),
+                    [1.0 / 3.0, 1.0, 1.0],
+                    [1.0 / 3.0, 1.0, 0.5],
+                ),
+                (
+                    $vec3::new(0.0, 0.0, 0.5),
+                    [2.0 / 3.0, 1.0, 0.5],
+                    [2.0 / 3.0, 1.0, 0.25],
+                ),
+                (
+                    $vec3::new(1.0, 0.0, 1.0),
+                    [5.0 / 6.0, 1.0, 1.0],
+                    [5.0 / 6.0, 1.0, 0.5],
+                ),
+                ($vec3::new(0.5, 0.5, 0.5), [0.0, 0.0, 0.5], [0.0, 0.0, 0.5]),
+                (
+                    $vec3::new(0.75, 0.5, 0.25),
+                    [1.0 / 12.0, 2.0 / 3.0, 0.75],
+                    [1.0 / 12.0, 0.5, 0.5],
+                ),
+                ($vec3::ZERO, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]),
+                ($vec3::ONE, [0.0, 0.0, 1.0], [0.0, 0.0, 1.0]),
+            ];
+            for (rgb, hsv, hsl) in colors {
+                assert_approx_eq!(rgb.rgb_to_hsv(), $vec3::from_array(hsv));
+                assert_approx_eq!(rgb.rgb_to_hsl(), $vec3::from_array(hsl));
+                assert_approx_eq!($vec3::from_array(hsv).hsv_to_rgb(), rgb);
+                assert_approx_eq!($vec3::from_array(hsl).hsl_to_rgb(), rgb);
+            }
+            // hue wraps around
+            assert_approx_eq!(
+                $vec3::new(1.0, 1.0, 1.0).hsv_to_rgb(),
+                $vec3::new(1.0, 0.0, 0.0)
+            );
+            assert_approx_eq!(
+                $vec3::new(-1.0 / 3.0, 1.0, 0.5).hsl_to_rgb(),
+                $vec3::new(0.0, 0.0, 1.0)
+            );
+        });
+
+        glam_test!(test_spherical, {
+            use core::$t::consts::{FRAC_PI_2, PI};
+            assert_eq!($vec3::ZERO.to_spherical(), (0.0, 0.0, 0.0));
+            let (r, theta, phi) = $vec3::new(0.0, 2.0, 0.0).to_spherical();
+            assert_eq!(r, 2.0);
+            assert_approx_eq!(theta, FRAC_PI_2);
+            assert_approx_eq!(phi, FRAC_PI_2);
+            assert_approx_eq!(
+                $vec3::from_spherical(2.0, FRAC_PI_2, FRAC_PI_2),
+                $vec3::new(0.0, 2.0, 0.0)
+            );
+            assert_approx_eq!($vec3::from_spherical(1.0, PI, 0.0), $vec3::NEG_Z);
+
+            let (r, theta, phi) = $vec3::new(0.0, 2.0, 0.0).to_spherical_y_up();
+            assert_eq!((r, theta), (2.0, 0.0));
+            assert_eq!(phi, 0.0);
+            assert_approx_eq!($vec3::from_spherical_y_up(1.0, FRAC_PI_2, 0.0), $vec3::Z);
+            assert_approx_eq!(
+                $vec3::from_spherical_y_up(1.0, FRAC_PI_2, FRAC_PI_2),
+                $vec3::X
+            );
+
+            for v in [$vec3::new(1.0, 2.0, 3.0), $vec3::new(-0.5, 0.25, -4.0)] {
+                let (r, theta, phi) = v.to_spherical();
+                assert_approx_eq!($vec3::from_spherical(r, theta, phi), v, 1e-5);
+                let (r, theta, phi) = v.to_spherical_y_up();
+                assert_approx_eq!($vec3::from_spherical_y_up(r, theta, phi), v, 1e-5);
+            }
+        });
+
+        glam_test!(test_lat_lon, {
+            use core::$t::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+            assert_approx_eq!($vec3::from_lat_lon(0.0, 0.0), $vec3::Z);
+            assert_approx_eq!($vec3::from_lat_lon(0.0, FRAC_PI_2), $vec3::X);
+            assert_approx_eq!($vec3::from_lat_lon(0.0, PI), $vec3::NEG_Z);
+            assert_approx_eq!($vec3::from_lat_lon(FRAC_PI_2, 1.0), $vec3::Y);
+            assert_approx_eq!($vec3::from_lat_lon(-FRAC_PI_2, 1.0), $vec3::NEG_Y);
+            assert_eq!($vec3::ZERO.to_lat_lon(), (0.0, 0.0));
+            assert_eq!($vec3::new(0.0, 0.0, 2.0).to_lat_lon(), (0.0, 0.0));
+            let (lat, lon) = $vec3::new(-1.0, 1.0, 0.0).to_lat_lon();
+            assert_approx_eq!(lat, FRAC_PI_4);
+            assert_approx_eq!(lon, -FRAC_PI_2);
+            let v = $vec3::new(1.0, -2.0, 3.0).normalize();
+            let (lat, lon) = v.to_lat_lon();
+            assert_approx_eq!($vec3::from_lat_lon(lat, lon), v);
+            let (_, theta, phi) = v.to_spherical_y_up();
+            assert_approx_eq!(lat, FRAC_PI_2 - theta);
+            assert_approx_eq!(lon, phi);
+        });
+
+        glam_test!(test_equirectangular_uv, {
+            use glam::$vec2;
+            assert_approx_eq!($vec3::Z.to_equirectangular_uv(), $vec2::new(0.5, 0.5));
+            assert_approx_eq!($vec3::X.to_equirectangular_uv(), $vec2::new(0.75, 0.5));
+            assert_approx_eq!($vec3::NEG_X.to_equirectangular_uv(), $vec2::new(0.25, 0.5));
+            assert_approx_eq!($vec3::Y.to_equirectangular_uv(), $vec2::new(0.5, 0.0));
+            assert_approx_eq!($vec3::NEG_Y.to_equirectangular_uv(), $vec2::new(0.5, 1.0));
+            // both sides of the seam map to u = 0
+            assert_eq!($vec3::NEG_Z.to_equirectangular_uv().x, 0.0);
+            assert_eq!($vec3::new(-0.0, 0.0, -1.0).to_equirectangular_uv().x, 0.0);
+
+            assert_approx_eq!(
+                $vec3::from_array($vec2::new(0.5, 0.5).equirectangular_to_dir().to_array()),
+                $vec3::Z
+            );
+            assert_approx_eq!(
+                $vec3::from_array($vec2::new(0.0, 0.5).equirectangular_to_dir().to_array()),
+                $vec3::NEG_Z
+            );
+            assert_approx_eq!(
+                $vec3::from_array($vec2::new(1.75, 0.5).equirectangular_to_dir().to_array()),
+                $vec3::X,
+                1e-6
+            );
+            let v = $vec3::new(1.0, -2.0, 3.0).normalize();
+            assert_approx_eq!(
+                $vec3::from_array(
+                    v.to_equirectangular_uv()
+                        .equirectangular_to_dir()
+                        .to_array()
+                ),
+                v
+            );
+        });
+
         glam_test!(test_fmt_float, {
             let a = $vec3::new(1.0, 2.0, 3.0);
             assert_eq!(format!("{:.2}", a), "[1.00, 2.00, 3.00]");
+            assert_eq!(format!("{:4.1}", a), "[ 1.0,  2.0,  3.0]");
         });
     };
 }
@@ -1250,11 +2074,240 @@ macro_rules! impl_vec3_bit_op_tests {
                 }
             }
         });
+
+        glam_test!(test_rotate_and_wrapping_shift, {
+            let n = UVec3::new(0, 1, 7);
+            for x in $t_min..$t_max {
+                let v = $vec3::splat(x);
+                let a = v.to_array();
+                for i in 0..3 {
+                    assert_eq!(v.rotate_left(n)[i], a[i].rotate_left(n[i]));
+                    assert_eq!(v.rotate_right(n)[i], a[i].rotate_right(n[i]));
+                    assert_eq!(v.wrapping_shl(n)[i], a[i].wrapping_shl(n[i]));
+                    assert_eq!(v.wrapping_shr(n)[i], a[i].wrapping_shr(n[i]));
+                }
+            }
+        });
+
+        glam_test!(test_bit_counting, {
+            for x in $t_min..$t_max {
+                let v = $vec3::new(x, !x, x.rotate_left(3));
+                let a = v.to_array();
+                assert_eq!(v.count_ones().to_array(), a.map(|e| e.count_ones()));
+                assert_eq!(v.leading_zeros().to_array(), a.map(|e| e.leading_zeros()));
+                assert_eq!(v.trailing_zeros().to_array(), a.map(|e| e.trailing_zeros()));
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec3_const_arithmetic_tests {
+    ($t:ident, $vec3:ident) => {
+        glam_test!(test_const_arithmetic, {
+            const V: $vec3 = $vec3::new(1, 2, 3);
+            const DOT: $t = V.dot(V);
+            const LEN_SQ: $t = V.length_squared();
+            const DOT_INTO: $vec3 = V.dot_into_vec(V);
+            const SUM: $t = V.element_sum();
+            const PRODUCT: $t = V.element_product();
+            assert_eq!(DOT, 14);
+            assert_eq!(LEN_SQ, 14);
+            assert_eq!(DOT_INTO, $vec3::splat(14));
+            assert_eq!(SUM, 6);
+            assert_eq!(PRODUCT, 6);
+            const CROSS: $vec3 = $vec3::X.cross($vec3::Y);
+            assert_eq!(CROSS, $vec3::Z);
+        });
+    };
+}
+
+macro_rules! impl_vec3_try_from_float_tests {
+    ($t:ident, $vec3:ident) => {
+        glam_test!(test_try_from_float, {
+            use glam::error::TryFromVecError;
+            assert_eq!(
+                $vec3::try_from(glam::Vec3::new(1.0, 2.0, 3.0)),
+                Ok($vec3::new(1, 2, 3))
+            );
+            assert_eq!(
+                $vec3::try_from(glam::Vec3::splat($t::MIN as _)),
+                Ok($vec3::splat($t::MIN))
+            );
+            for bad in [-1.0, 0.5, $t::MAX as f64 * 2.0, f64::INFINITY, f64::NAN] {
+                let mut a = [1.0; 3];
+                a[2] = bad as _;
+                let r = $vec3::try_from(glam::Vec3::from_array(a));
+                if bad == -1.0 && $t::MIN != 0 {
+                    assert_eq!(r, Ok($vec3::from_array(a.map(|f| f as $t))));
+                } else {
+                    assert_eq!(r, Err(TryFromVecError { index: 2 }));
+                }
+            }
+            assert_eq!(
+                $vec3::try_from(glam::Vec3A::new(1.0, 2.0, 3.0)),
+                Ok($vec3::new(1, 2, 3))
+            );
+            assert_eq!(
+                $vec3::try_from(glam::Vec3A::splat($t::MIN as _)),
+                Ok($vec3::splat($t::MIN))
+            );
+            for bad in [-1.0, 0.5, $t::MAX as f64 * 2.0, f64::INFINITY, f64::NAN] {
+                let mut a = [1.0; 3];
+                a[2] = bad as _;
+                let r = $vec3::try_from(glam::Vec3A::from_array(a));
+                if bad == -1.0 && $t::MIN != 0 {
+                    assert_eq!(r, Ok($vec3::from_array(a.map(|f| f as $t))));
+                } else {
+                    assert_eq!(r, Err(TryFromVecError { index: 2 }));
+                }
+            }
+            assert_eq!(
+                $vec3::try_from(glam::DVec3::new(1.0, 2.0, 3.0)),
+                Ok($vec3::new(1, 2, 3))
+            );
+            assert_eq!(
+                $vec3::try_from(glam::DVec3::splat($t::MIN as _)),
+                Ok($vec3::splat($t::MIN))
+            );
+            for bad in [-1.0, 0.5, $t::MAX as f64 * 2.0, f64::INFINITY, f64::NAN] {
+                let mut a = [1.0; 3];
+                a[2] = bad as _;
+                let r = $vec3::try_from(glam::DVec3::from_array(a));
+                if bad == -1.0 && $t::MIN != 0 {
+                    assert_eq!(r, Ok($vec3::from_array(a.map(|f| f as $t))));
+                } else {
+                    assert_eq!(r, Err(TryFromVecError { index: 2 }));
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec3_overflowing_tests {
+    ($t:ident, $vec3:ident, $mask:ident) => {
+        glam_test!(test_overflowing_ops, {
+            let a = $vec3::new($t::MAX, 2, 2);
+            let b = $vec3::new(1, 3, 3);
+            assert_eq!(
+                a.overflowing_add(b),
+                (a.wrapping_add(b), $mask::new(true, false, false))
+            );
+            assert_eq!(b.overflowing_add(b), (b + b, $mask::FALSE));
+
+            let a = $vec3::new($t::MIN, 5, 5);
+            assert_eq!(
+                a.overflowing_sub(b),
+                (a.wrapping_sub(b), $mask::new(true, false, false))
+            );
+            assert_eq!(b.overflowing_sub(b), ($vec3::ZERO, $mask::FALSE));
+
+            let a = $vec3::new($t::MAX, 2, 2);
+            let b = $vec3::new(2, 3, 3);
+            assert_eq!(
+                a.overflowing_mul(b),
+                (a.wrapping_mul(b), $mask::new(true, false, false))
+            );
+            assert_eq!(b.overflowing_mul(b), (b * b, $mask::FALSE));
+        });
+
+        glam_test!(test_midpoint, {
+            for (a, b) in [
+                ($t::MAX, $t::MAX),
+                ($t::MIN, $t::MAX),
+                ($t::MAX, $t::MAX - 1),
+                ($t::MIN, $t::MIN),
+                (3, 4),
+            ] {
+                let m = $vec3::splat(a).midpoint($vec3::splat(b));
+                // i128 division rounds towards zero, matching the documented rounding
+                let expected = ((a as i128 + b as i128) / 2) as $t;
+                assert_eq!(m, $vec3::splat(expected));
+                assert_eq!(m, $vec3::splat(b).midpoint($vec3::splat(a)));
+            }
+        });
+
+        glam_test!(test_widening_mul, {
+            for (a, b) in [
+                ($t::MAX, $t::MAX),
+                ($t::MIN, $t::MAX),
+                ($t::MIN, $t::MIN),
+                (3, 5),
+            ] {
+                let (lo, hi) = $vec3::splat(a).widening_mul($vec3::splat(b));
+                // reassemble the full product, which fits in 128 bits for every element type
+                let product = (a as i128).wrapping_mul(b as i128);
+                for i in 0..3 {
+                    assert_eq!(((hi[i] as i128) << $t::BITS) | lo[i] as i128, product);
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec3_carrying_add_tests {
+    ($t:ident, $vec3:ident, $mask:ident) => {
+        glam_test!(test_carrying_add, {
+            let a = $vec3::new($t::MAX, 1, 1);
+            let b = $vec3::new(0, 2, 2);
+            assert_eq!(a.carrying_add(b, $mask::FALSE), (a + b, $mask::FALSE));
+            assert_eq!(
+                a.carrying_add(b, $mask::TRUE),
+                ($vec3::new(0, 4, 4), $mask::new(true, false, false))
+            );
+            assert_eq!(
+                $vec3::MAX.carrying_add($vec3::MAX, $mask::TRUE),
+                ($vec3::MAX, $mask::TRUE)
+            );
+        });
     };
 }
 
 mod vec3 {
-    use glam::{vec3, BVec3, Vec3};
+    use glam::{vec2, vec3, BVec3, Vec3, Vec3A};
+
+    glam_test!(test_flat_slice, {
+        use glam::error::Error;
+        let mut vectors = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)];
+        let flat = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        assert_eq!(&flat[..6], Vec3::as_flat_slice(&vectors));
+        assert_eq!(&vectors, Vec3::from_flat_slice(&flat[..6]));
+        assert!(Vec3::from_flat_slice(&[]).is_empty());
+        assert_eq!(
+            Err(Error::SliceRemainder {
+                multiple: 3,
+                remainder: 1
+            }),
+            Vec3::from_flat_slice_checked(&flat)
+        );
+        should_panic!({ Vec3::from_flat_slice(&flat) });
+
+        Vec3::as_flat_slice_mut(&mut vectors)[4] = 0.0;
+        assert_eq!(Vec3::new(4.0, 0.0, 6.0), vectors[1]);
+        let mut flat = flat;
+        Vec3::from_flat_slice_mut(&mut flat[3..6])[0].x = -1.0;
+        assert_eq!(-1.0, flat[3]);
+    });
+
+    glam_test!(test_vec3_macro, {
+        const V: Vec3 = vec3!(vec2(1.0, 2.0), 3.0);
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), V);
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), vec3!(1.0, vec2(2.0, 3.0)));
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), vec3!(1.0, 2.0, 3.0));
+        assert_eq!(Vec3::splat(0.5), vec3!(0.5));
+        assert_eq!(V, vec3!(Vec3A::from(V)));
+    });
+
+    glam_test!(test_as_saturating, {
+        let v = Vec3::new(-1.0e10, 1.0e10, f32::NAN);
+        assert_eq!(
+            v.as_i16vec3_saturating(),
+            glam::I16Vec3::new(i16::MIN, i16::MAX, 0)
+        );
+        assert_eq!(
+            v.as_u16vec3_saturating(),
+            glam::U16Vec3::new(0, u16::MAX, 0)
+        );
+    });
 
     glam_test!(test_align, {
         use std::mem;
@@ -1436,13 +2489,95 @@ mod vec3 {
         assert_eq!(Vec3A::new(1.0, 2.0, 3.0), U64Vec3::new(1, 2, 3).as_vec3a());
     });
 
-    impl_vec3_float_tests!(f32, vec3, Vec3, BVec3);
+    glam_test!(test_r11g11b10, {
+        let v = vec3(1.0, 0.5, 64512.0);
+        assert_eq!(v.to_r11g11b10(), 0xf7dc_03c0);
+        assert_eq!(Vec3::from_r11g11b10(0xf7dc_03c0), v);
+        assert_eq!(
+            Vec3::from_r11g11b10(vec3(0.1, -1.0, 1e6).to_r11g11b10()),
+            vec3(0.099609375, 0.0, f32::INFINITY)
+        );
+    });
+
+    impl_vec3_float_tests!(f32, vec3, Vec3, Vec2, BVec3);
+
+    impl_vec_f32_fast_tests!(Vec3);
 }
 
 mod vec3a {
     use glam::BVec3A;
     use glam::{vec3a, Vec3A, Vec4};
 
+    glam_test!(test_dot4, {
+        let a: [Vec3A; 4] = core::array::from_fn(|i| Vec3A::from_fn(|j| (i * 3 + j) as f32));
+        let b: [Vec3A; 4] = core::array::from_fn(|i| Vec3A::from_fn(|j| 2.0 - (i + j) as f32));
+        let expected = Vec4::from_fn(|i| a[i].dot(b[i]));
+        assert_eq!(
+            expected,
+            Vec3A::dot4(a[0], b[0], a[1], b[1], a[2], b[2], a[3], b[3])
+        );
+        // the sums are rounded in the same order as `dot`
+        let c = Vec3A::from_array([1.0, 1e8, -1e8]);
+        let d = Vec3A::ONE;
+        assert_eq!(Vec4::splat(c.dot(d)), Vec3A::dot4(c, d, c, d, c, d, c, d));
+        assert_eq!(
+            Vec4::new(1.0, 0.0, 0.0, -1.0),
+            Vec3A::dot4(
+                Vec3A::X,
+                Vec3A::X,
+                Vec3A::X,
+                Vec3A::Y,
+                Vec3A::Z,
+                Vec3A::ZERO,
+                -Vec3A::Z,
+                Vec3A::Z
+            )
+        );
+    });
+
+    glam_test!(test_distance_squared_batch, {
+        let points: Vec<Vec3A> = (0..11)
+            .map(|i| vec3a(i as f32, -2.0 * i as f32, 0.5 * i as f32))
+            .collect();
+        let point = vec3a(3.0, 1.0, -2.0);
+        let mut out = [0.0; 11];
+        Vec3A::distance_squared_batch(&points, point, &mut out);
+        for (p, d) in points.iter().zip(out) {
+            assert_eq!(p.distance_squared(point), d);
+        }
+        Vec3A::distance_squared_batch(&[], point, &mut []);
+        should_panic!({ Vec3A::distance_squared_batch(&points, point, &mut [0.0; 3]) });
+    });
+
+    glam_test!(test_nearest_point_index, {
+        let points: Vec<Vec3A> = (0..11).map(|i| Vec3A::splat(i as f32)).collect();
+        for (i, p) in points.iter().enumerate() {
+            assert_eq!(Some(i), Vec3A::nearest_point_index(&points, *p + 0.1));
+        }
+        assert_eq!(Some(0), Vec3A::nearest_point_index(&points, Vec3A::NEG_ONE));
+        assert_eq!(
+            Some(0),
+            Vec3A::nearest_point_index(&[Vec3A::ONE; 6], Vec3A::ZERO)
+        );
+        assert_eq!(None, Vec3A::nearest_point_index(&[], Vec3A::ZERO));
+        assert_eq!(None, Vec3A::nearest_point_index(&[Vec3A::NAN], Vec3A::ZERO));
+        assert_eq!(
+            None,
+            Vec3A::nearest_point_index(&[Vec3A::NAN; 4], Vec3A::ZERO)
+        );
+
+        // a `NaN` point in any lane must not be selected
+        for lane in 0..4 {
+            let mut points = [4.0, 3.0, 2.0, 1.0, 5.0].map(Vec3A::splat);
+            points[lane] = Vec3A::NAN;
+            let expected = if lane == 3 { 2 } else { 3 };
+            assert_eq!(
+                Some(expected),
+                Vec3A::nearest_point_index(&points, Vec3A::ZERO)
+            );
+        }
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(16, mem::size_of::<Vec3A>());
@@ -1506,7 +2641,9 @@ mod vec3a {
         assert_eq!(v2.min_element(), 2.0);
     });
 
-    impl_vec3_float_tests!(f32, vec3a, Vec3A, BVec3A);
+    impl_vec3_float_tests!(f32, vec3a, Vec3A, Vec2, BVec3A);
+
+    impl_vec_f32_fast_tests!(Vec3A);
 }
 
 mod dvec3 {
@@ -1529,7 +2666,16 @@ mod dvec3 {
         assert_eq!(DVec3::new(1.0, 2.0, 3.0), DVec3::from(UVec3::new(1, 2, 3)));
     });
 
-    impl_vec3_float_tests!(f64, dvec3, DVec3, BVec3);
+    glam_test!(test_to_render_relative, {
+        let camera = DVec3::new(6_378_136.0, -2.0e7, 0.0);
+        let p = camera + DVec3::new(0.123, 4.5, -0.001);
+        let rel = p.to_render_relative(camera);
+        assert!(rel.abs_diff_eq(Vec3::new(0.123, 4.5, -0.001), 1e-6));
+        // truncating before subtracting loses precision
+        assert!(!(p.as_vec3() - camera.as_vec3()).abs_diff_eq(rel, 1e-2));
+    });
+
+    impl_vec3_float_tests!(f64, dvec3, DVec3, DVec2, BVec3);
 }
 
 mod i16vec3 {
@@ -1546,9 +2692,18 @@ mod i16vec3 {
             I16Vec3::new(1, 2, 3),
             I16Vec3::try_from(U16Vec3::new(1, 2, 3)).unwrap()
         );
-        assert!(I16Vec3::try_from(U16Vec3::new(u16::MAX, 2, 3)).is_err());
-        assert!(I16Vec3::try_from(U16Vec3::new(1, u16::MAX, 3)).is_err());
-        assert!(I16Vec3::try_from(U16Vec3::new(1, 2, u16::MAX)).is_err());
+        assert_eq!(
+            I16Vec3::try_from(U16Vec3::new(u16::MAX, 2, 3)),
+            Err(glam::error::TryFromVecError { index: 0 })
+        );
+        assert_eq!(
+            I16Vec3::try_from(U16Vec3::new(1, u16::MAX, 3)),
+            Err(glam::error::TryFromVecError { index: 1 })
+        );
+        assert_eq!(
+            I16Vec3::try_from(U16Vec3::new(1, 2, u16::MAX)),
+            Err(glam::error::TryFromVecError { index: 2 })
+        );
 
         assert_eq!(
             I16Vec3::new(1, 2, 3),
@@ -1677,6 +2832,10 @@ mod i16vec3 {
 
     impl_vec3_scalar_bit_op_tests!(I16Vec3, -2, 2);
     impl_vec3_bit_op_tests!(I16Vec3, -2, 2);
+
+    impl_vec3_overflowing_tests!(i16, I16Vec3, BVec3);
+    impl_vec3_try_from_float_tests!(i16, I16Vec3);
+    impl_vec3_const_arithmetic_tests!(i16, I16Vec3);
 }
 
 mod u16vec3 {
@@ -1816,6 +2975,11 @@ mod u16vec3 {
 
     impl_vec3_scalar_bit_op_tests!(U16Vec3, 0, 2);
     impl_vec3_bit_op_tests!(U16Vec3, 0, 2);
+
+    impl_vec3_overflowing_tests!(u16, U16Vec3, BVec3);
+    impl_vec3_try_from_float_tests!(u16, U16Vec3);
+    impl_vec3_const_arithmetic_tests!(u16, U16Vec3);
+    impl_vec3_carrying_add_tests!(u16, U16Vec3, BVec3);
 }
 
 mod ivec3 {
@@ -1950,11 +3114,49 @@ mod ivec3 {
 
     impl_vec3_scalar_bit_op_tests!(IVec3, -2, 2);
     impl_vec3_bit_op_tests!(IVec3, -2, 2);
+
+    impl_vec3_overflowing_tests!(i32, IVec3, BVec3);
+    impl_vec3_try_from_float_tests!(i32, IVec3);
+    impl_vec3_const_arithmetic_tests!(i32, IVec3);
 }
 
 mod uvec3 {
     use glam::{uvec3, BVec3, I16Vec3, I64Vec3, IVec3, U16Vec3, U64Vec3, UVec3};
 
+    glam_test!(test_morton, {
+        assert_eq!(UVec3::ZERO.to_morton(), 0);
+        assert_eq!(UVec3::X.to_morton(), 0b001);
+        assert_eq!(UVec3::Y.to_morton(), 0b010);
+        assert_eq!(UVec3::Z.to_morton(), 0b100);
+        assert_eq!(uvec3(3, 1, 2).to_morton(), 0b101011);
+        assert_eq!(UVec3::splat(0x1f_ffff).to_morton(), u64::MAX >> 1);
+        for v in [uvec3(3, 1, 2), uvec3(0x12345, 0x1f_ffff, 0xabcde)] {
+            assert_eq!(UVec3::from_morton(v.to_morton()), v);
+        }
+    });
+
+    glam_test!(test_hilbert, {
+        let mut prev = UVec3::ZERO;
+        for index in 0..512 {
+            let v = UVec3::from_hilbert(index, 3);
+            assert_eq!(v.to_hilbert(3), index);
+            assert_eq!(
+                v.as_ivec3().distance_squared(prev.as_ivec3()),
+                index.min(1) as i32
+            );
+            prev = v;
+        }
+        for v in [uvec3(0x12345, 0x1f_ffff, 0xabcde), UVec3::splat(0x1f_ffff)] {
+            assert_eq!(UVec3::from_hilbert(v.to_hilbert(21), 21), v);
+        }
+        should_glam_assert!({ uvec3(0, 4, 0).to_hilbert(2) });
+        should_glam_assert!({ UVec3::from_hilbert(64, 2) });
+    });
+
+    glam_test!(test_morton_out_of_range, {
+        should_glam_assert!({ UVec3::new(1 << 21, 0, 0).to_morton() });
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(12, mem::size_of::<UVec3>());
@@ -2081,11 +3283,37 @@ mod uvec3 {
 
     impl_vec3_scalar_bit_op_tests!(UVec3, 0, 2);
     impl_vec3_bit_op_tests!(UVec3, 0, 2);
+
+    impl_vec3_overflowing_tests!(u32, UVec3, BVec3);
+    impl_vec3_try_from_float_tests!(u32, UVec3);
+    impl_vec3_const_arithmetic_tests!(u32, UVec3);
+    impl_vec3_carrying_add_tests!(u32, UVec3, BVec3);
 }
 
 mod i64vec3 {
     use glam::{i64vec3, BVec3, I16Vec3, I64Vec3, IVec3, U16Vec3, U64Vec3, UVec3};
 
+    glam_test!(test_as_saturating, {
+        let v = I64Vec3::new(i64::MIN, i64::MAX, -1);
+        assert_eq!(
+            v.as_i16vec3_saturating(),
+            glam::I16Vec3::new(i16::MIN, i16::MAX, -1)
+        );
+        assert_eq!(
+            v.as_u16vec3_saturating(),
+            glam::U16Vec3::new(0, u16::MAX, 0)
+        );
+        assert_eq!(
+            v.as_ivec3_saturating(),
+            glam::IVec3::new(i32::MIN, i32::MAX, -1)
+        );
+        assert_eq!(v.as_uvec3_saturating(), glam::UVec3::new(0, u32::MAX, 0));
+        assert_eq!(
+            v.as_u64vec3_saturating(),
+            glam::U64Vec3::new(0, 9223372036854775807, 0)
+        );
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(24, mem::size_of::<I64Vec3>());
@@ -2147,11 +3375,39 @@ mod i64vec3 {
 
     impl_vec3_scalar_bit_op_tests!(I64Vec3, -2, 2);
     impl_vec3_bit_op_tests!(I64Vec3, -2, 2);
+
+    impl_vec3_overflowing_tests!(i64, I64Vec3, BVec3);
+    impl_vec3_try_from_float_tests!(i64, I64Vec3);
+    impl_vec3_const_arithmetic_tests!(i64, I64Vec3);
 }
 
 mod u64vec3 {
     use glam::{u64vec3, BVec3, I16Vec3, I64Vec3, IVec3, U16Vec3, U64Vec3, UVec3};
 
+    glam_test!(test_as_saturating, {
+        let v = U64Vec3::new(u64::MAX, 1, 40000);
+        assert_eq!(
+            v.as_i16vec3_saturating(),
+            glam::I16Vec3::new(i16::MAX, 1, i16::MAX)
+        );
+        assert_eq!(
+            v.as_u16vec3_saturating(),
+            glam::U16Vec3::new(u16::MAX, 1, 40000)
+        );
+        assert_eq!(
+            v.as_ivec3_saturating(),
+            glam::IVec3::new(i32::MAX, 1, 40000)
+        );
+        assert_eq!(
+            v.as_uvec3_saturating(),
+            glam::UVec3::new(u32::MAX, 1, 40000)
+        );
+        assert_eq!(
+            v.as_i64vec3_saturating(),
+            glam::I64Vec3::new(i64::MAX, 1, 40000)
+        );
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(24, mem::size_of::<U64Vec3>());
@@ -2212,4 +3468,9 @@ mod u64vec3 {
 
     impl_vec3_scalar_bit_op_tests!(U64Vec3, 0, 2);
     impl_vec3_bit_op_tests!(U64Vec3, 0, 2);
+
+    impl_vec3_overflowing_tests!(u64, U64Vec3, BVec3);
+    impl_vec3_try_from_float_tests!(u64, U64Vec3);
+    impl_vec3_const_arithmetic_tests!(u64, U64Vec3);
+    impl_vec3_carrying_add_tests!(u64, U64Vec3, BVec3);
 }
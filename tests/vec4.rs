@@ -14,6 +14,109 @@ macro_rules! impl_vec4_tests {
             assert_eq!([1 as $t, 2 as $t, 3 as $t, 4 as $t], *V2.as_ref());
         });
 
+        glam_test!(test_bytes, {
+            let v = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            let size = core::mem::size_of::<$t>();
+            let le = v.to_le_bytes();
+            let be = v.to_be_bytes();
+            assert_eq!(le.len(), size * 4);
+            assert_eq!(&le[..size], &(1 as $t).to_le_bytes());
+            assert_eq!(&be[size * 3..], &(4 as $t).to_be_bytes());
+            assert_eq!(v, $vec4::from_le_bytes(le));
+            assert_eq!(v, $vec4::from_be_bytes(be));
+        });
+
+        glam_test!(test_iter, {
+            let v = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            let a = v.to_array();
+            assert!(v.into_iter().eq(a));
+            assert!((&v).into_iter().eq(a.iter()));
+
+            let mut m = v;
+            for e in &mut m {
+                *e += 1 as $t;
+            }
+            assert_eq!(m, v + $vec4::ONE);
+
+            assert_eq!(a.into_iter().collect::<$vec4>(), v);
+            // values after the first 4 are ignored
+            assert_eq!((1..).map(|i| i as $t).collect::<$vec4>(), v);
+            assert_eq!(
+                $vec4::try_from_iter(a.into_iter().skip(1)),
+                Err(glam::error::Error::IterTooShort {
+                    required: 4,
+                    found: 3,
+                })
+            );
+            should_panic!({ core::iter::empty::<$t>().collect::<$vec4>() });
+        });
+
+        glam_test!(test_with_swizzles, {
+            use glam::Vec4Swizzles;
+            let v = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(
+                v.with_yzw(v.xyz()),
+                $new(1 as $t, 1 as $t, 2 as $t, 3 as $t)
+            );
+            assert_eq!(v.with_wx(v.xy()), $new(2 as $t, 2 as $t, 3 as $t, 1 as $t));
+            let mut m = v;
+            m.set_xz(v.ww());
+            assert_eq!(m, $new(4 as $t, 2 as $t, 4 as $t, 4 as $t));
+            m.set_wzy(v.xyz());
+            assert_eq!(m, $new(4 as $t, 3 as $t, 2 as $t, 1 as $t));
+            assert_eq!(v.with_wzyx(v), $new(4 as $t, 3 as $t, 2 as $t, 1 as $t));
+            m.set_yzwx(v);
+            assert_eq!(m, $new(4 as $t, 1 as $t, 2 as $t, 3 as $t));
+        });
+
+        glam_test!(test_axis, {
+            use glam::Axis;
+            let mut v = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(v[Axis::X], v.x);
+            assert_eq!($vec4::axis(Axis::X), $vec4::X);
+            assert_eq!(v[Axis::Y], v.y);
+            assert_eq!($vec4::axis(Axis::Y), $vec4::Y);
+            assert_eq!(v[Axis::Z], v.z);
+            assert_eq!($vec4::axis(Axis::Z), $vec4::Z);
+            assert_eq!(v[Axis::W], v.w);
+            assert_eq!($vec4::axis(Axis::W), $vec4::W);
+            for (i, axis) in Axis::ALL[..4].iter().enumerate() {
+                assert_eq!(axis.index(), i);
+                assert_eq!(Axis::from_index(i), Some(*axis));
+                v[*axis] = 0 as $t;
+            }
+            assert_eq!(v, $vec4::ZERO);
+            assert_eq!(Axis::from_index(4), None);
+        });
+
+        glam_test!(test_from_fn, {
+            assert_eq!(
+                $vec4::from_fn(|i| (i + 1) as $t),
+                $new(1 as $t, 2 as $t, 3 as $t, 4 as $t)
+            );
+            let mut calls = 0;
+            let v = $vec4::from_fn(|i| {
+                assert_eq!(i, calls);
+                calls += 1;
+                calls as $t
+            });
+            assert_eq!(v, $new(1 as $t, 2 as $t, 3 as $t, 4 as $t));
+        });
+
+        glam_test!(test_map_and_zip_with, {
+            let v = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(
+                v.map(|e| e * 2 as $t),
+                $new(2 as $t, 4 as $t, 6 as $t, 8 as $t)
+            );
+            assert_eq!(v.map(|e| e), v);
+            assert_eq!(v.zip_with(v, |a, b| a + b), v + v);
+            assert_eq!(
+                v.zip_with($vec4::ONE, |a, b| if a > b { a } else { b }),
+                v.max($vec4::ONE)
+            );
+        });
+
         glam_test!(test_vec4_consts, {
             assert_eq!($vec4::ZERO, $new(0 as $t, 0 as $t, 0 as $t, 0 as $t));
             assert_eq!($vec4::ONE, $new(1 as $t, 1 as $t, 1 as $t, 1 as $t));
@@ -114,6 +217,24 @@ macro_rules! impl_vec4_tests {
             );
         });
 
+        glam_test!(test_from_str, {
+            let a = $vec4::new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), format!("{:?}", a).parse());
+            assert_eq!(Ok(a), format!("{:#?}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongComponentCount {
+                    expected: 4,
+                    found: 3
+                }),
+                "[1, 2, 3]".parse::<$vec4>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidSyntax),
+                "[1, 2".parse::<$vec4>()
+            );
+        });
+
         glam_test!(test_fmt, {
             let a = $vec4::new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
             assert_eq!(
@@ -139,6 +260,8 @@ macro_rules! impl_vec4_tests {
                 )
             );
             assert_eq!(format!("{}", a), "[1, 2, 3, 4]");
+            assert_eq!(format!("{:2}", a), "[ 1,  2,  3,  4]");
+            assert_eq!(format!("{:<2}", a), "[1 , 2 , 3 , 4 ]");
         });
 
         glam_test!(test_zero, {
@@ -276,6 +399,71 @@ macro_rules! impl_vec4_tests {
             assert_eq!((5 as $t, 6 as $t, 7 as $t, 8 as $t), b.max(a).into());
         });
 
+        glam_test!(test_min_max_position, {
+            let a = $new(3 as $t, 5 as $t, 1 as $t, 6 as $t);
+            assert_eq!(2, a.min_position());
+            assert_eq!(3, a.max_position());
+            let b = $new(1 as $t, 5 as $t, 5 as $t, 1 as $t);
+            assert_eq!(0, b.min_position());
+            assert_eq!(1, b.max_position());
+        });
+
+        glam_test!(test_min_max_pair, {
+            let a = $new(3 as $t, 5 as $t, 1 as $t, 6 as $t);
+            let b = $new(4 as $t, 2 as $t, 1 as $t, 0 as $t);
+            assert_eq!((a.min(b), a.max(b)), a.min_max(b));
+            assert_eq!((a.min(b), a.max(b)), b.min_max(a));
+        });
+
+        glam_test!(test_sorted, {
+            let sorted = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            for [x, y, z, w] in [
+                [1, 2, 3, 4],
+                [1, 2, 4, 3],
+                [1, 3, 2, 4],
+                [1, 3, 4, 2],
+                [1, 4, 2, 3],
+                [1, 4, 3, 2],
+                [2, 1, 3, 4],
+                [2, 1, 4, 3],
+                [2, 3, 1, 4],
+                [2, 3, 4, 1],
+                [2, 4, 1, 3],
+                [2, 4, 3, 1],
+                [3, 1, 2, 4],
+                [3, 1, 4, 2],
+                [3, 2, 1, 4],
+                [3, 2, 4, 1],
+                [3, 4, 1, 2],
+                [3, 4, 2, 1],
+                [4, 1, 2, 3],
+                [4, 1, 3, 2],
+                [4, 2, 1, 3],
+                [4, 2, 3, 1],
+                [4, 3, 1, 2],
+                [4, 3, 2, 1],
+            ] {
+                let v = $new(x as $t, y as $t, z as $t, w as $t);
+                assert_eq!(sorted, v.sorted());
+            }
+            assert_eq!($vec4::ONE, $vec4::ONE.sorted());
+        });
+
+        glam_test!(test_snap, {
+            fn vec(x: i32, y: i32, z: i32, w: i32) -> $vec4 {
+                $vec4::new(x as $t, y as $t, z as $t, w as $t)
+            }
+            assert_eq!(vec(7, 12, 3, 0).snap(vec(5, 4, 2, 3)), vec(5, 12, 4, 0));
+            assert_eq!(
+                vec(8, 13, 5, 14).snap(vec(5, 4, 10, 4)),
+                vec(10, 12, 10, 16)
+            );
+            assert_eq!(
+                vec(9, 13, 6, 2).snap_to(vec(5, 4, 4, 3), vec(2, 1, 3, 2)),
+                vec(7, 13, 7, 2)
+            );
+        });
+
         glam_test!(test_clamp, {
             fn vec(x: i32, y: i32, z: i32, w: i32) -> $vec4 {
                 $vec4::new(x as $t, y as $t, z as $t, w as $t)
@@ -485,6 +673,33 @@ macro_rules! impl_vec4_tests {
             assert_eq!($mask::splat(true), $mask::new(true, true, true, true));
         });
 
+        glam_test!(test_mask_from_bitmask, {
+            for bitmask in 0..=0b1111 {
+                assert_eq!($mask::from_bitmask(bitmask).bitmask(), bitmask);
+                assert_eq!($mask::from_bitmask(bitmask | !0b1111).bitmask(), bitmask);
+            }
+            assert_eq!($mask::from_bitmask(0), $mask::FALSE);
+            assert_eq!($mask::from_bitmask(0b1111), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_count_true, {
+            assert_eq!($mask::FALSE.count_true(), 0);
+            assert_eq!($mask::TRUE.count_true(), 4);
+            assert_eq!($mask::new(true, false, true, false).count_true(), 2);
+        });
+
+        glam_test!(test_mask_into_iter, {
+            let v: Vec<bool> = $mask::new(true, false, true, false).into_iter().collect();
+            assert_eq!(v, [true, false, true, false]);
+            assert_eq!(
+                $mask::new(false, false, false, false)
+                    .into_iter()
+                    .filter(|b| *b)
+                    .count(),
+                0
+            );
+        });
+
         glam_test!(test_mask_bitmask, {
             assert_eq!($mask::new(false, false, false, false).bitmask(), 0b0000);
             assert_eq!($mask::new(false, false, true, true).bitmask(), 0b1100);
@@ -725,6 +940,29 @@ macro_rules! impl_vec4_signed_tests {
     ($t:ident, $new:ident, $vec4:ident, $vec3:ident, $vec2:ident, $mask:ident) => {
         impl_vec4_tests!($t, $new, $vec4, $vec3, $vec2, $mask);
 
+        glam_test!(test_abs_min_max_position, {
+            let a = $new(-7 as $t, 2 as $t, 5 as $t, -1 as $t);
+            assert_eq!(0, a.abs_max_position());
+            assert_eq!(3, a.abs_min_position());
+            let b = $new(1 as $t, 1 as $t, 1 as $t, $t::MIN);
+            assert_eq!(3, b.abs_max_position());
+            assert_eq!(0, b.abs_min_position());
+        });
+
+        glam_test!(test_snap_signed, {
+            fn vec(x: i32, y: i32, z: i32, w: i32) -> $vec4 {
+                $vec4::new(x as $t, y as $t, z as $t, w as $t)
+            }
+            assert_eq!(
+                vec(-7, -13, -2, -1).snap(vec(5, 4, 3, 4)),
+                vec(-5, -12, -3, 0)
+            );
+            assert_eq!(
+                vec(-8, 13, 7, -9).snap(vec(-5, -4, -3, -4)),
+                vec(-10, 12, 6, -8)
+            );
+        });
+
         glam_test!(test_neg, {
             let a = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
             assert_eq!((-1 as $t, -2 as $t, -3 as $t, -4 as $t), (-a).into());
@@ -814,6 +1052,18 @@ macro_rules! impl_vec4_signed_tests {
             assert_eq!(four.rem_euclid(-three), one);
             assert_eq!((-four).rem_euclid(-three), two);
         });
+
+        glam_test!(test_div_floor, {
+            let one = $vec4::ONE;
+            let two = one + one;
+            let three = two + one;
+            assert_eq!(three.div_floor(two), one);
+            assert_eq!((-three).div_floor(two), -two);
+            assert_eq!(three.div_floor(-two), -two);
+            assert_eq!((-three).div_floor(-two), one);
+            assert_eq!((-two - two).div_floor(two), -two);
+            assert_eq!($vec4::ZERO.div_floor(-two), $vec4::ZERO);
+        });
     };
 }
 
@@ -821,6 +1071,14 @@ macro_rules! impl_vec4_signed_integer_tests {
     ($t:ident, $new:ident, $vec4:ident, $vec3:ident, $vec2:ident, $mask:ident) => {
         impl_vec4_signed_tests!($t, $new, $vec4, $vec3, $vec2, $mask);
 
+        glam_test!(test_unsigned_abs, {
+            for (a, expected) in [($t::MIN, -($t::MIN as i128)), (-3, 3), (0, 0), (3, 3)] {
+                for e in $vec4::splat(a).unsigned_abs().to_array() {
+                    assert_eq!(e as i128, expected);
+                }
+            }
+        });
+
         glam_test!(test_signum, {
             assert_eq!($vec4::ZERO.signum(), $vec4::ZERO);
             assert_eq!($vec4::ONE.signum(), $vec4::ONE);
@@ -831,6 +1089,21 @@ macro_rules! impl_vec4_signed_integer_tests {
 
 macro_rules! impl_vec4_eq_hash_tests {
     ($t:ident, $new:ident) => {
+        glam_test!(test_vec4_ord, {
+            let a = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            let b = $new(1 as $t, 2 as $t, 3 as $t, 5 as $t);
+            let c = $new(2 as $t, 0 as $t, 0 as $t, 0 as $t);
+            assert!(a < b);
+            assert!(b < c);
+            assert_eq!(core::cmp::Ordering::Equal, a.cmp(&a));
+
+            let mut sorted = [c, a, b];
+            sorted.sort();
+            assert_eq!([a, b, c], sorted);
+            // inherent methods are element-wise rather than lexicographic
+            assert_eq!($new(1 as $t, 0 as $t, 0 as $t, 0 as $t), a.min(c));
+        });
+
         glam_test!(test_ve2_hash, {
             use std::collections::hash_map::DefaultHasher;
             use std::hash::Hash;
@@ -1014,6 +1287,32 @@ macro_rules! impl_vec4_float_tests {
             assert!($vec4::new(NAN, 0.0, 0.0, 1.0).round().x.is_nan());
         });
 
+        glam_test!(test_round_ties_even, {
+            assert_eq!(
+                $vec4::new(0.5, 1.5, 2.5, -2.5).round_ties_even(),
+                $vec4::new(0.0, 2.0, 2.0, -2.0)
+            );
+            let r = $vec4::new(-0.5, 3.5, -1.25, 1e30).round_ties_even();
+            assert_eq!(r, $vec4::new(-0.0, 4.0, -1.0, 1e30));
+            assert!(r.is_negative_bitmask() & 1 != 0);
+            assert!($vec4::NAN.round_ties_even().is_nan_mask().all());
+            assert_eq!($vec4::INFINITY.round_ties_even(), $vec4::INFINITY);
+        });
+
+        glam_test!(test_as_int_rounding, {
+            let v = $vec4::new(-1.25, 0.75, 2.25, -3.75);
+            assert_eq!(v.as_ivec4_round(), glam::IVec4::new(-1, 1, 2, -4));
+            assert_eq!(v.as_ivec4_floor(), glam::IVec4::new(-2, 0, 2, -4));
+            assert_eq!(v.as_ivec4_ceil(), glam::IVec4::new(-1, 1, 3, -3));
+            assert_eq!(v.as_uvec4_round(), glam::UVec4::new(0, 1, 2, 0));
+            assert_eq!(v.as_uvec4_floor(), glam::UVec4::new(0, 0, 2, 0));
+            assert_eq!(v.as_uvec4_ceil(), glam::UVec4::new(0, 1, 3, 0));
+            assert_eq!(v.as_i64vec4_floor(), v.floor().as_i64vec4());
+            assert_eq!(v.as_u64vec4_ceil(), v.ceil().as_u64vec4());
+            assert_eq!($vec4::NAN.as_ivec4_round(), glam::IVec4::ZERO);
+            assert_eq!($vec4::INFINITY.as_ivec4_ceil(), glam::IVec4::MAX);
+        });
+
         glam_test!(test_floor, {
             assert_eq!(
                 $vec4::new(1.35, 1.5, -1.5, 1.999).floor(),
@@ -1042,6 +1341,21 @@ macro_rules! impl_vec4_float_tests {
             );
         });
 
+        glam_test!(test_modf, {
+            let (trunc, fract) = $vec4::new(1.25, -1.5, -3.0, 7.75).modf();
+            assert_eq!(trunc, $vec4::new(1.0, -1.0, -3.0, 7.0));
+            assert_eq!(fract, $vec4::new(0.25, -0.5, -0.0, 0.75));
+            assert_eq!(trunc + fract, $vec4::new(1.25, -1.5, -3.0, 7.75));
+            assert!(fract.is_negative_bitmask() & 0b10 != 0);
+
+            let (trunc, fract) = $vec4::new($t::INFINITY, $t::NEG_INFINITY, 0.0, 0.0).modf();
+            assert_eq!(trunc, $vec4::new($t::INFINITY, $t::NEG_INFINITY, 0.0, 0.0));
+            assert_eq!(fract, $vec4::ZERO);
+            assert!(fract.is_negative_bitmask() & 0b10 != 0);
+            let (trunc, fract) = $vec4::NAN.modf();
+            assert!(trunc.is_nan_mask().all() && fract.is_nan_mask().all());
+        });
+
         glam_test!(test_ceil, {
             assert_eq!(
                 $vec4::new(1.35, 1.5, -1.5, 1234.1234).ceil(),
@@ -1098,6 +1412,181 @@ macro_rules! impl_vec4_float_tests {
             assert_approx_eq!($vec4::new(-0.25, 0.5, 1.0, 0.75), v1.midpoint(v2));
         });
 
+        glam_test!(test_centroid, {
+            let points = [
+                $vec4::new(0.0, 0.0, 0.0, 0.0),
+                $vec4::new(2.0, 0.0, 0.0, 0.0),
+                $vec4::new(2.0, 4.0, 4.0, 4.0),
+                $vec4::new(0.0, 4.0, 4.0, 4.0),
+            ];
+            assert_eq!($vec4::new(1.0, 2.0, 2.0, 2.0), $vec4::centroid(&points));
+            assert!($vec4::centroid(&[]).is_nan_mask().all());
+
+            // naive summation drifts noticeably from 0.1 here
+            let points = vec![$vec4::splat(0.1); 1_000_000];
+            assert_approx_eq!($vec4::splat(0.1), $vec4::centroid(&points));
+        });
+
+        glam_test!(test_weighted_mean, {
+            let points = [
+                $vec4::new(0.0, 0.0, 0.0, 0.0),
+                $vec4::new(4.0, 8.0, 12.0, 16.0),
+            ];
+            assert_eq!(
+                $vec4::new(1.0, 2.0, 3.0, 4.0),
+                $vec4::weighted_mean(&points, &[3.0, 1.0])
+            );
+            assert_eq!(
+                $vec4::centroid(&points),
+                $vec4::weighted_mean(&points, &[0.5, 0.5])
+            );
+
+            let points = vec![$vec4::splat(0.1); 1_000_000];
+            let weights = vec![0.5; 1_000_000];
+            assert_approx_eq!($vec4::splat(0.1), $vec4::weighted_mean(&points, &weights));
+
+            should_panic!({ $vec4::weighted_mean(&points, &[1.0]) });
+        });
+
+        glam_test!(test_total_cmp, {
+            use core::cmp::Ordering;
+            let a = $vec4::new(1.0, 2.0, 0.0, 0.0);
+            let b = $vec4::new(1.0, 3.0, 0.0, 0.0);
+            assert_eq!(a.cmp_total(b), Ordering::Less);
+            assert_eq!(b.cmp_total(a), Ordering::Greater);
+            assert_eq!(a.cmp_total(a), Ordering::Equal);
+            assert_eq!(a.cmp_lex(b), Ordering::Less);
+            assert_eq!(
+                $vec4::NAN.cmp_lex($vec4::ZERO),
+                $vec4::NAN.cmp_total($vec4::ZERO)
+            );
+            let mut sorted = [b, $vec4::NAN, a];
+            sorted.sort_by(|l, r| l.cmp_lex(*r));
+            assert_eq!([a, b], sorted[..2]);
+            assert!(sorted[2].is_nan());
+            assert_eq!($vec4::ZERO.cmp_total($vec4::splat(-0.0)), Ordering::Greater);
+            assert_eq!($vec4::NAN.cmp_total($vec4::NAN), Ordering::Equal);
+            assert_eq!($vec4::NAN.cmp_total($vec4::INFINITY), Ordering::Greater);
+            assert_eq!((-$vec4::NAN).cmp_total($vec4::NEG_INFINITY), Ordering::Less);
+
+            assert_eq!(a.min_total(b), a.min(b));
+            assert_eq!(a.max_total(b), a.max(b));
+            assert!(a.max_total($vec4::NAN).is_nan_mask().all());
+            assert_eq!(a.min_total($vec4::NAN), a);
+            assert_eq!(
+                $vec4::ZERO
+                    .min_total($vec4::splat(-0.0))
+                    .is_negative_bitmask(),
+                (1 << 4) - 1
+            );
+
+            assert_eq!(a.total_ord(), a.total_ord());
+            assert_ne!($vec4::ZERO.total_ord(), $vec4::splat(-0.0).total_ord());
+            assert_eq!($vec4::NAN.total_ord(), $vec4::NAN.total_ord());
+            assert!(a.total_ord() < b.total_ord());
+
+            let mut set = std::collections::BTreeSet::new();
+            set.insert(b.total_ord());
+            set.insert(a.total_ord());
+            set.insert($vec4::NAN.total_ord());
+            set.insert(a.total_ord());
+            assert_eq!(set.len(), 3);
+            assert_eq!(set.first().unwrap().0, a);
+
+            let mut set = std::collections::HashSet::new();
+            set.insert($vec4::NAN.total_ord());
+            assert!(set.contains(&$vec4::NAN.total_ord()));
+            assert!(!set.contains(&a.total_ord()));
+        });
+
+        glam_test!(test_hashable, {
+            let a = $vec4::new(1.0, -2.0, 3.0, -4.0);
+            assert_eq!(a, $vec4::from_bits(a.to_bits()));
+            assert_eq!(a.x.to_bits(), a.to_bits().x);
+            assert_eq!($vec4::ZERO.hashable(), $vec4::splat(-0.0).hashable());
+            assert_eq!($vec4::NAN.hashable(), (-$vec4::NAN).hashable());
+            assert_ne!(a.hashable(), $vec4::ZERO.hashable());
+
+            let mut set = std::collections::HashSet::new();
+            set.insert(a.hashable());
+            set.insert($vec4::ZERO.hashable());
+            set.insert($vec4::splat(-0.0).hashable());
+            set.insert($vec4::NAN.hashable());
+            set.insert((-$vec4::NAN).hashable());
+            set.insert(a.hashable());
+            assert_eq!(set.len(), 3);
+            assert!(set.contains(&$vec4::splat(0.0).hashable()));
+        });
+
+        glam_test!(test_min_max_nan, {
+            let a = $vec4::new($t::NAN, 1.0, 0.0, 0.0);
+            let b = $vec4::new(0.0, $t::NAN, 0.0, 0.0);
+            assert!(a.min_nan(b).x.is_nan() && a.min_nan(b).y.is_nan());
+            assert!(a.max_nan(b).x.is_nan() && a.max_nan(b).y.is_nan());
+            assert!(a.clamp_nan($vec4::ZERO, $vec4::ONE).x.is_nan());
+            assert_eq!(a.clamp_nan($vec4::ZERO, $vec4::ONE).y, 1.0);
+            assert!($vec4::ONE
+                .clamp_nan($vec4::NAN, $vec4::ONE)
+                .is_nan_mask()
+                .all());
+
+            let a = $vec4::new(1.0, -2.0, 0.0, 0.0);
+            let b = $vec4::new(-1.0, 3.0, 0.0, 0.0);
+            assert_eq!(a.min_nan(b), a.min(b));
+            assert_eq!(a.max_nan(b), a.max(b));
+            assert_eq!(
+                $vec4::splat(4.0).clamp_nan($vec4::ZERO, $vec4::ONE),
+                $vec4::ONE
+            );
+
+            let neg_zero = $vec4::splat(-0.0).min_nan($vec4::ZERO);
+            assert_eq!(neg_zero.is_negative_bitmask(), (1 << 4) - 1);
+            let zero = $vec4::splat(-0.0).max_nan($vec4::ZERO);
+            assert_eq!(zero.is_negative_bitmask(), 0);
+        });
+
+        glam_test!(test_ulps_eq, {
+            let a = $vec4::new(1.0, 1e10, -1e-10, 0.0);
+            let next = $vec4::from_array(a.to_array().map(|x| $t::from_bits(x.to_bits() + 1)));
+            let far = $vec4::from_array(a.to_array().map(|x| $t::from_bits(x.to_bits() + 5)));
+            assert!(a.ulps_eq(a, 0));
+            assert!(!a.ulps_eq(next, 0));
+            assert!(a.ulps_eq(next, 1));
+            assert!(next.ulps_eq(a, 1));
+            assert!(!a.ulps_eq(far, 4));
+            assert!($vec4::ZERO.ulps_eq(-$vec4::ZERO, 0));
+            assert!(!$vec4::splat($t::MIN_POSITIVE).ulps_eq($vec4::splat(-$t::MIN_POSITIVE), 4));
+            assert!(!$vec4::NAN.ulps_eq($vec4::NAN, 4));
+            assert!($vec4::INFINITY.ulps_eq($vec4::INFINITY, 0));
+        });
+
+        glam_test!(test_compensated, {
+            let e = $t::EPSILON;
+            let a = $vec4::new(1.0 + e, -1.0, 0.0, 0.0);
+            let b = $vec4::new(1.0 - e, 1.0, 0.0, 0.0);
+            assert_eq!(a.dot(b), 0.0);
+            assert_eq!(a.dot_compensated(b), -e * e);
+            assert_eq!($vec4::ONE.dot_compensated($vec4::splat(2.0)), 4.0 * 2.0);
+
+            let big = 4.0 / $t::EPSILON;
+            let values = [$vec4::splat(big), $vec4::ONE, $vec4::splat(-big)];
+            assert_eq!(values.iter().sum::<$vec4>(), $vec4::ZERO);
+            assert_eq!($vec4::sum_compensated(&values), $vec4::ONE);
+            assert_eq!($vec4::sum_compensated(&[]), $vec4::ZERO);
+        });
+
+        glam_test!(test_finite_or, {
+            let a = $vec4::new($t::NAN, 1.0, $t::NEG_INFINITY, $t::INFINITY);
+            assert_eq!(a.is_finite_mask(), $mask::new(false, true, false, false));
+            assert!($vec4::ONE.is_finite_mask().all());
+            assert!(!$vec4::NAN.is_finite_mask().any());
+            assert_eq!(
+                a.finite_or($vec4::splat(2.0)),
+                $vec4::new(2.0, 1.0, 2.0, 2.0)
+            );
+            assert_eq!($vec4::ONE.finite_or($vec4::NAN), $vec4::ONE);
+        });
+
         glam_test!(test_is_finite, {
             assert!($vec4::new(0.0, 0.0, 0.0, 0.0).is_finite());
             assert!($vec4::new(-1e-10, 1.0, 1e10, 42.0).is_finite());
@@ -1129,6 +1618,135 @@ macro_rules! impl_vec4_float_tests {
             );
         });
 
+        glam_test!(test_exp2_ln_log2, {
+            assert_eq!(
+                $vec4::new(1.0, 2.0, 3.0, 4.0).exp2(),
+                $vec4::new(2.0, 4.0, 8.0, 16.0)
+            );
+            assert_eq!(
+                $vec4::new(1.0, 2.0, 4.0, 8.0).log2(),
+                $vec4::new(0.0, 1.0, 2.0, 3.0)
+            );
+            assert_approx_eq!(
+                $vec4::new(1.0, 2.0, 3.0, 4.0).ln(),
+                $vec4::new(
+                    (1.0 as $t).ln(),
+                    (2.0 as $t).ln(),
+                    (3.0 as $t).ln(),
+                    (4.0 as $t).ln()
+                )
+            );
+            assert_approx_eq!(
+                $vec4::new(1.0, 2.0, 3.0, 4.0).exp().ln(),
+                $vec4::new(1.0, 2.0, 3.0, 4.0),
+                1e-5
+            );
+            assert!($vec4::splat(-1.0).ln().is_nan_mask().all());
+            assert_eq!($vec4::ZERO.log2(), $vec4::NEG_INFINITY);
+        });
+
+        glam_test!(test_pow, {
+            assert_approx_eq!(
+                $vec4::splat(2.0).pow($vec4::new(3.0, 0.5, -1.0, 0.0)),
+                $vec4::new(
+                    (2.0 as $t).powf(3.0),
+                    (2.0 as $t).powf(0.5),
+                    (2.0 as $t).powf(-1.0),
+                    (2.0 as $t).powf(0.0)
+                )
+            );
+            assert_approx_eq!(
+                $vec4::new(1.0, 2.0, 3.0, 4.0).pow($vec4::new(3.0, 0.5, -1.0, 0.0)),
+                $vec4::new(
+                    (1.0 as $t).powf(3.0),
+                    (2.0 as $t).powf(0.5),
+                    (3.0 as $t).powf(-1.0),
+                    (4.0 as $t).powf(0.0)
+                )
+            );
+            assert_eq!(
+                $vec4::new(1.0, 2.0, 3.0, 4.0).pow($vec4::ONE),
+                $vec4::new(1.0, 2.0, 3.0, 4.0)
+            );
+        });
+
+        glam_test!(test_trig, {
+            let a = $vec4::new(0.5, -1.25, 2.0, -0.75);
+            let u = $vec4::new(0.5, -1.0, 0.25, 0.0);
+            assert_approx_eq!(
+                a.sin(),
+                $vec4::new(
+                    (0.5 as $t).sin(),
+                    (-1.25 as $t).sin(),
+                    (2.0 as $t).sin(),
+                    (-0.75 as $t).sin()
+                ),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.cos(),
+                $vec4::new(
+                    (0.5 as $t).cos(),
+                    (-1.25 as $t).cos(),
+                    (2.0 as $t).cos(),
+                    (-0.75 as $t).cos()
+                ),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.tan(),
+                $vec4::new(
+                    (0.5 as $t).tan(),
+                    (-1.25 as $t).tan(),
+                    (2.0 as $t).tan(),
+                    (-0.75 as $t).tan()
+                ),
+                1e-6
+            );
+            assert_approx_eq!(
+                u.asin(),
+                $vec4::new(
+                    (0.5 as $t).asin(),
+                    (-1.0 as $t).asin(),
+                    (0.25 as $t).asin(),
+                    (0.0 as $t).asin()
+                ),
+                1e-6
+            );
+            assert_approx_eq!(
+                u.acos(),
+                $vec4::new(
+                    (0.5 as $t).acos(),
+                    (-1.0 as $t).acos(),
+                    (0.25 as $t).acos(),
+                    (0.0 as $t).acos()
+                ),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.atan(),
+                $vec4::new(
+                    (0.5 as $t).atan(),
+                    (-1.25 as $t).atan(),
+                    (2.0 as $t).atan(),
+                    (-0.75 as $t).atan()
+                ),
+                1e-6
+            );
+            assert_approx_eq!(
+                a.atan2($vec4::new(1.0, -2.0, -0.5, 0.0)),
+                $vec4::new(
+                    (0.5 as $t).atan2(1.0),
+                    (-1.25 as $t).atan2(-2.0),
+                    (2.0 as $t).atan2(-0.5),
+                    (-0.75 as $t).atan2(0.0)
+                ),
+                1e-6
+            );
+            assert_approx_eq!(u.asin().sin(), u, 1e-6);
+            assert!($vec4::splat(2.0).acos().is_nan_mask().all());
+        });
+
         glam_test!(test_clamp_length, {
             // Too long gets shortened
             assert_eq!(
@@ -1184,9 +1802,26 @@ macro_rules! impl_vec4_float_tests {
             );
         });
 
+        glam_test!(test_srgb, {
+            let srgb = $vec4::new(0.0, 0.04045, 0.5, 0.5);
+            let linear = srgb.to_linear();
+            assert_approx_eq!(linear, $vec4::new(0.0, 0.04045 / 12.92, 0.21404114, 0.5));
+            assert_approx_eq!(linear.to_srgb(), srgb);
+            assert_eq!($vec4::ONE.to_linear(), $vec4::ONE);
+            assert_approx_eq!($vec4::ONE.to_srgb(), $vec4::ONE);
+            for i in 0..=256 {
+                let c = $vec4::splat(i as $t / 256.0);
+                assert!(c.to_linear_fast().abs_diff_eq(c.to_linear(), 0.003));
+                assert!(c.to_srgb_fast().abs_diff_eq(c.to_srgb(), 0.003));
+            }
+            assert_eq!($vec4::new(0.5, 0.5, 0.5, 0.25).to_linear().w, 0.25);
+            assert_eq!($vec4::new(0.5, 0.5, 0.5, 0.25).to_srgb_fast().w, 0.25);
+        });
+
         glam_test!(test_fmt_float, {
             let a = $vec4::new(1.0, 2.0, 3.0, 4.0);
             assert_eq!(format!("{:.2}", a), "[1.00, 2.00, 3.00, 4.00]");
+            assert_eq!(format!("{:4.1}", a), "[ 1.0,  2.0,  3.0,  4.0]");
         });
     };
 }
@@ -1365,6 +2000,174 @@ macro_rules! impl_vec4_bit_op_tests {
                 }
             }
         });
+
+        glam_test!(test_rotate_and_wrapping_shift, {
+            let n = UVec4::new(0, 1, 7, u32::MAX);
+            for x in $t_min..$t_max {
+                let v = $vec4::splat(x);
+                let a = v.to_array();
+                for i in 0..4 {
+                    assert_eq!(v.rotate_left(n)[i], a[i].rotate_left(n[i]));
+                    assert_eq!(v.rotate_right(n)[i], a[i].rotate_right(n[i]));
+                    assert_eq!(v.wrapping_shl(n)[i], a[i].wrapping_shl(n[i]));
+                    assert_eq!(v.wrapping_shr(n)[i], a[i].wrapping_shr(n[i]));
+                }
+            }
+        });
+
+        glam_test!(test_bit_counting, {
+            for x in $t_min..$t_max {
+                let v = $vec4::new(x, !x, x.rotate_left(3), !x.rotate_left(3));
+                let a = v.to_array();
+                assert_eq!(v.count_ones().to_array(), a.map(|e| e.count_ones()));
+                assert_eq!(v.leading_zeros().to_array(), a.map(|e| e.leading_zeros()));
+                assert_eq!(v.trailing_zeros().to_array(), a.map(|e| e.trailing_zeros()));
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec4_const_arithmetic_tests {
+    ($t:ident, $vec4:ident) => {
+        glam_test!(test_const_arithmetic, {
+            const V: $vec4 = $vec4::new(1, 2, 3, 4);
+            const DOT: $t = V.dot(V);
+            const LEN_SQ: $t = V.length_squared();
+            const DOT_INTO: $vec4 = V.dot_into_vec(V);
+            const SUM: $t = V.element_sum();
+            const PRODUCT: $t = V.element_product();
+            assert_eq!(DOT, 30);
+            assert_eq!(LEN_SQ, 30);
+            assert_eq!(DOT_INTO, $vec4::splat(30));
+            assert_eq!(SUM, 10);
+            assert_eq!(PRODUCT, 24);
+        });
+    };
+}
+
+macro_rules! impl_vec4_try_from_float_tests {
+    ($t:ident, $vec4:ident) => {
+        glam_test!(test_try_from_float, {
+            use glam::error::TryFromVecError;
+            assert_eq!(
+                $vec4::try_from(glam::Vec4::new(1.0, 2.0, 3.0, 4.0)),
+                Ok($vec4::new(1, 2, 3, 4))
+            );
+            assert_eq!(
+                $vec4::try_from(glam::Vec4::splat($t::MIN as _)),
+                Ok($vec4::splat($t::MIN))
+            );
+            for bad in [-1.0, 0.5, $t::MAX as f64 * 2.0, f64::INFINITY, f64::NAN] {
+                let mut a = [1.0; 4];
+                a[3] = bad as _;
+                let r = $vec4::try_from(glam::Vec4::from_array(a));
+                if bad == -1.0 && $t::MIN != 0 {
+                    assert_eq!(r, Ok($vec4::from_array(a.map(|f| f as $t))));
+                } else {
+                    assert_eq!(r, Err(TryFromVecError { index: 3 }));
+                }
+            }
+            assert_eq!(
+                $vec4::try_from(glam::DVec4::new(1.0, 2.0, 3.0, 4.0)),
+                Ok($vec4::new(1, 2, 3, 4))
+            );
+            assert_eq!(
+                $vec4::try_from(glam::DVec4::splat($t::MIN as _)),
+                Ok($vec4::splat($t::MIN))
+            );
+            for bad in [-1.0, 0.5, $t::MAX as f64 * 2.0, f64::INFINITY, f64::NAN] {
+                let mut a = [1.0; 4];
+                a[3] = bad as _;
+                let r = $vec4::try_from(glam::DVec4::from_array(a));
+                if bad == -1.0 && $t::MIN != 0 {
+                    assert_eq!(r, Ok($vec4::from_array(a.map(|f| f as $t))));
+                } else {
+                    assert_eq!(r, Err(TryFromVecError { index: 3 }));
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec4_overflowing_tests {
+    ($t:ident, $vec4:ident, $mask:ident) => {
+        glam_test!(test_overflowing_ops, {
+            let a = $vec4::new($t::MAX, 2, 2, 2);
+            let b = $vec4::new(1, 3, 3, 3);
+            assert_eq!(
+                a.overflowing_add(b),
+                (a.wrapping_add(b), $mask::new(true, false, false, false))
+            );
+            assert_eq!(b.overflowing_add(b), (b + b, $mask::FALSE));
+
+            let a = $vec4::new($t::MIN, 5, 5, 5);
+            assert_eq!(
+                a.overflowing_sub(b),
+                (a.wrapping_sub(b), $mask::new(true, false, false, false))
+            );
+            assert_eq!(b.overflowing_sub(b), ($vec4::ZERO, $mask::FALSE));
+
+            let a = $vec4::new($t::MAX, 2, 2, 2);
+            let b = $vec4::new(2, 3, 3, 3);
+            assert_eq!(
+                a.overflowing_mul(b),
+                (a.wrapping_mul(b), $mask::new(true, false, false, false))
+            );
+            assert_eq!(b.overflowing_mul(b), (b * b, $mask::FALSE));
+        });
+
+        glam_test!(test_midpoint, {
+            for (a, b) in [
+                ($t::MAX, $t::MAX),
+                ($t::MIN, $t::MAX),
+                ($t::MAX, $t::MAX - 1),
+                ($t::MIN, $t::MIN),
+                (3, 4),
+            ] {
+                let m = $vec4::splat(a).midpoint($vec4::splat(b));
+                // i128 division rounds towards zero, matching the documented rounding
+                let expected = ((a as i128 + b as i128) / 2) as $t;
+                assert_eq!(m, $vec4::splat(expected));
+                assert_eq!(m, $vec4::splat(b).midpoint($vec4::splat(a)));
+            }
+        });
+
+        glam_test!(test_widening_mul, {
+            for (a, b) in [
+                ($t::MAX, $t::MAX),
+                ($t::MIN, $t::MAX),
+                ($t::MIN, $t::MIN),
+                (3, 5),
+            ] {
+                let (lo, hi) = $vec4::splat(a).widening_mul($vec4::splat(b));
+                // reassemble the full product, which fits in 128 bits for every element type
+                let product = (a as i128).wrapping_mul(b as i128);
+                for i in 0..4 {
+                    assert_eq!(((hi[i] as i128) << $t::BITS) | lo[i] as i128, product);
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_vec4_carrying_add_tests {
+    ($t:ident, $vec4:ident, $mask:ident) => {
+        glam_test!(test_carrying_add, {
+            let a = $vec4::new($t::MAX, 1, 1, 1);
+            let b = $vec4::new(0, 2, 2, 2);
+            assert_eq!(a.carrying_add(b, $mask::FALSE), (a + b, $mask::FALSE));
+            assert_eq!(
+                a.carrying_add(b, $mask::TRUE),
+                (
+                    $vec4::new(0, 4, 4, 4),
+                    $mask::new(true, false, false, false)
+                )
+            );
+            assert_eq!(
+                $vec4::MAX.carrying_add($vec4::MAX, $mask::TRUE),
+                ($vec4::MAX, $mask::TRUE)
+            );
+        });
     };
 }
 
@@ -1373,7 +2176,93 @@ mod vec4 {
     use glam::BVec4;
     #[cfg(not(feature = "scalar-math"))]
     use glam::BVec4A;
-    use glam::{vec4, Vec2, Vec3, Vec4};
+    use glam::{vec4, Vec2, Vec3, Vec3A, Vec4};
+
+    glam_test!(test_flat_slice_align, {
+        use glam::error::Error;
+        #[repr(C, align(16))]
+        struct F32x9_A16([f32; 9]);
+
+        let a = F32x9_A16([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(
+            &[Vec4::new(1.0, 2.0, 3.0, 4.0), Vec4::new(5.0, 6.0, 7.0, 8.0)],
+            Vec4::from_flat_slice(&a.0[..8])
+        );
+        let misaligned = Vec4::from_flat_slice_checked(&a.0[1..5]);
+        if core::mem::align_of::<Vec4>() > core::mem::align_of::<f32>() {
+            assert_eq!(Err(Error::SliceMisaligned { align: 16 }), misaligned);
+            should_panic!({ Vec4::from_flat_slice(&a.0[1..5]) });
+        } else {
+            assert_eq!(Ok(&[Vec4::new(2.0, 3.0, 4.0, 5.0)][..]), misaligned);
+        }
+
+        // an empty slice is accepted whatever the alignment of its dangling pointer
+        assert_eq!(Ok(&[][..]), Vec4::from_flat_slice_checked(&[]));
+        assert!(Vec4::from_flat_slice(&[]).is_empty());
+        assert!(Vec4::from_flat_slice_mut(&mut []).is_empty());
+        assert!(glam::Mat2::from_flat_slice(&[]).is_empty());
+        assert!(glam::Mat4::from_flat_slice_mut(&mut []).is_empty());
+    });
+
+    glam_test!(test_transpose4, {
+        let rows: [Vec4; 4] = core::array::from_fn(|i| Vec4::from_fn(|j| (i * 4 + j) as f32));
+        let cols = Vec4::transpose4(rows[0], rows[1], rows[2], rows[3]);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(rows[j][i], cols[i][j]);
+            }
+        }
+        assert_eq!(rows, Vec4::transpose4(cols[0], cols[1], cols[2], cols[3]));
+    });
+
+    glam_test!(test_dot4, {
+        let a: [Vec4; 4] = core::array::from_fn(|i| Vec4::from_fn(|j| (i * 4 + j) as f32));
+        let b: [Vec4; 4] = core::array::from_fn(|i| Vec4::from_fn(|j| 2.0 - (i + j) as f32));
+        let expected = Vec4::from_fn(|i| a[i].dot(b[i]));
+        assert_eq!(
+            expected,
+            Vec4::dot4(a[0], b[0], a[1], b[1], a[2], b[2], a[3], b[3])
+        );
+        // the sums are rounded in the same order as `dot`
+        let c = Vec4::from_array([1e8, 1.0, -1e8, 1.0]);
+        let d = Vec4::ONE;
+        assert_eq!(Vec4::splat(c.dot(d)), Vec4::dot4(c, d, c, d, c, d, c, d));
+        assert_eq!(
+            Vec4::new(1.0, 0.0, 0.0, -1.0),
+            Vec4::dot4(
+                Vec4::X,
+                Vec4::X,
+                Vec4::X,
+                Vec4::Y,
+                Vec4::Z,
+                Vec4::ZERO,
+                -Vec4::Z,
+                Vec4::Z
+            )
+        );
+    });
+
+    glam_test!(test_vec4_macro, {
+        const V: Vec4 = vec4!(Vec3::new(1.0, 2.0, 3.0), 4.0);
+        assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), V);
+        assert_eq!(V, vec4!(1.0, Vec3A::new(2.0, 3.0, 4.0)));
+        assert_eq!(V, vec4!(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)));
+        assert_eq!(V, vec4!(1.0, Vec2::new(2.0, 3.0), 4.0));
+        assert_eq!(V, vec4!(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vec4::splat(0.5), vec4!(0.5));
+    });
+
+    glam_test!(test_as_saturating, {
+        let v = Vec4::new(-1.0e10, 1.0e10, f32::NAN, -2.5);
+        assert_eq!(
+            v.as_i16vec4_saturating(),
+            glam::I16Vec4::new(i16::MIN, i16::MAX, 0, -2)
+        );
+        assert_eq!(
+            v.as_u16vec4_saturating(),
+            glam::U16Vec4::new(0, u16::MAX, 0, 0)
+        );
+    });
 
     glam_test!(test_align, {
         use std::mem;
@@ -1654,11 +2543,34 @@ mod vec4 {
         );
     });
 
+    glam_test!(test_pack_half, {
+        let v = Vec4::new(1.0, -2.0, 0.5, 65504.0);
+        assert_eq!(v.pack_half_4x16(), 0x7bff_3800_c000_3c00);
+        assert_eq!(Vec4::unpack_half_4x16(0x7bff_3800_c000_3c00), v);
+        assert!(Vec4::unpack_half_4x16(Vec4::NAN.pack_half_4x16())
+            .is_nan_mask()
+            .all());
+    });
+
+    glam_test!(test_10_10_10_2, {
+        let v = Vec4::new(1.0, 0.0, 0.5, 1.0);
+        assert_eq!(v.to_unorm_10_10_10_2(), 0xe000_03ff);
+        assert_approx_eq!(Vec4::from_unorm_10_10_10_2(0xe000_03ff), v, 1e-3);
+        let n = Vec4::new(0.0, 0.6, -0.8, 1.0);
+        assert_eq!(
+            Vec4::from_snorm_10_10_10_2(n.to_snorm_10_10_10_2()),
+            Vec4::new(0.0, 307.0 / 511.0, -409.0 / 511.0, 1.0)
+        );
+        assert_eq!(Vec4::NEG_ONE.to_snorm_10_10_10_2(), 0xe018_0601);
+    });
+
     #[cfg(not(feature = "scalar-math"))]
     impl_vec4_float_tests!(f32, vec4, Vec4, Vec3, Vec2, BVec4A);
 
     #[cfg(feature = "scalar-math")]
     impl_vec4_float_tests!(f32, vec4, Vec4, Vec3, Vec2, BVec4);
+
+    impl_vec_f32_fast_tests!(Vec4);
 }
 
 mod dvec4 {
@@ -1690,6 +2602,48 @@ mod dvec4 {
         );
     });
 
+    glam_test!(test_scalar_parity, {
+        // DVec4 may use f64x2 SIMD, which must give the same results as scalar arithmetic
+        let values = [
+            DVec4::new(1.5, -2.25, 3.0, -0.0),
+            DVec4::new(-4.0, 0.125, f64::INFINITY, 1.0e300),
+            DVec4::new(f64::NAN, -1.0e-300, 7.0, f64::NEG_INFINITY),
+        ];
+        let bits = |v: DVec4| v.to_array().map(f64::to_bits);
+        let map2 = |a: DVec4, b: DVec4, f: fn(f64, f64) -> f64| {
+            DVec4::new(f(a.x, b.x), f(a.y, b.y), f(a.z, b.z), f(a.w, b.w))
+        };
+        for a in values {
+            assert_eq!(bits(-a), bits(a.to_array().map(|x| -x).into()));
+            assert_eq!(bits(a.abs()), bits(a.to_array().map(f64::abs).into()));
+            for b in values {
+                assert_eq!(bits(a + b), bits(map2(a, b, |a, b| a + b)));
+                assert_eq!(bits(a - b), bits(map2(a, b, |a, b| a - b)));
+                assert_eq!(bits(a * b), bits(map2(a, b, |a, b| a * b)));
+                assert_eq!(bits(a / b), bits(map2(a, b, |a, b| a / b)));
+                for s in [b.x, b.y, b.z, b.w] {
+                    let splat = DVec4::splat(s);
+                    assert_eq!(bits(a * s), bits(a * splat));
+                    assert_eq!(bits(s - a), bits(splat - a));
+                    let mut c = a;
+                    c /= s;
+                    assert_eq!(bits(c), bits(a / splat));
+                }
+                let mut c = a;
+                c += b;
+                assert_eq!(bits(c), bits(a + b));
+            }
+        }
+        let a = DVec4::new(1.0, 2.0, 3.0, 4.0);
+        let b = DVec4::new(5.0, -6.0, 7.0, -8.0);
+        assert_eq!(1.0 * 5.0 - 2.0 * 6.0 + 3.0 * 7.0 - 4.0 * 8.0, a.dot(b));
+        assert!(a.dot(values[2]).is_nan());
+        // the products are summed in scalar order, where cancellation depends on the order
+        let c = DVec4::new(1.0e16, 1.0, -1.0e16, 1.0);
+        assert_eq!(((1.0e16 + 1.0) + -1.0e16) + 1.0, c.dot(DVec4::ONE));
+        assert_eq!(1.0, c.dot(DVec4::ONE));
+    });
+
     impl_vec4_float_tests!(f64, dvec4, DVec4, DVec3, DVec2, BVec4);
 }
 
@@ -1712,10 +2666,22 @@ mod i16vec4 {
             I16Vec4::new(1, 2, 3, 4),
             I16Vec4::try_from(U16Vec4::new(1, 2, 3, 4)).unwrap()
         );
-        assert!(I16Vec4::try_from(U16Vec4::new(u16::MAX, 2, 3, 4)).is_err());
-        assert!(I16Vec4::try_from(U16Vec4::new(1, u16::MAX, 3, 4)).is_err());
-        assert!(I16Vec4::try_from(U16Vec4::new(1, 2, u16::MAX, 4)).is_err());
-        assert!(I16Vec4::try_from(U16Vec4::new(1, 2, 3, u16::MAX)).is_err());
+        assert_eq!(
+            I16Vec4::try_from(U16Vec4::new(u16::MAX, 2, 3, 4)),
+            Err(glam::error::TryFromVecError { index: 0 })
+        );
+        assert_eq!(
+            I16Vec4::try_from(U16Vec4::new(1, u16::MAX, 3, 4)),
+            Err(glam::error::TryFromVecError { index: 1 })
+        );
+        assert_eq!(
+            I16Vec4::try_from(U16Vec4::new(1, 2, u16::MAX, 4)),
+            Err(glam::error::TryFromVecError { index: 2 })
+        );
+        assert_eq!(
+            I16Vec4::try_from(U16Vec4::new(1, 2, 3, u16::MAX)),
+            Err(glam::error::TryFromVecError { index: 3 })
+        );
 
         assert_eq!(
             I16Vec4::new(1, 2, 3, 4),
@@ -1850,6 +2816,10 @@ mod i16vec4 {
 
     impl_vec4_scalar_bit_op_tests!(I16Vec4, -2, 2);
     impl_vec4_bit_op_tests!(I16Vec4, -2, 2);
+
+    impl_vec4_overflowing_tests!(i16, I16Vec4, BVec4);
+    impl_vec4_try_from_float_tests!(i16, I16Vec4);
+    impl_vec4_const_arithmetic_tests!(i16, I16Vec4);
 }
 
 mod u16vec4 {
@@ -2008,6 +2978,11 @@ mod u16vec4 {
 
     impl_vec4_scalar_bit_op_tests!(U16Vec4, 0, 2);
     impl_vec4_bit_op_tests!(U16Vec4, 0, 2);
+
+    impl_vec4_overflowing_tests!(u16, U16Vec4, BVec4);
+    impl_vec4_try_from_float_tests!(u16, U16Vec4);
+    impl_vec4_const_arithmetic_tests!(u16, U16Vec4);
+    impl_vec4_carrying_add_tests!(u16, U16Vec4, BVec4);
 }
 
 mod ivec4 {
@@ -2158,6 +3133,10 @@ mod ivec4 {
 
     impl_vec4_scalar_bit_op_tests!(IVec4, -2, 2);
     impl_vec4_bit_op_tests!(IVec4, -2, 2);
+
+    impl_vec4_overflowing_tests!(i32, IVec4, BVec4);
+    impl_vec4_try_from_float_tests!(i32, IVec4);
+    impl_vec4_const_arithmetic_tests!(i32, IVec4);
 }
 
 mod uvec4 {
@@ -2302,6 +3281,11 @@ mod uvec4 {
 
     impl_vec4_scalar_bit_op_tests!(UVec4, 0, 2);
     impl_vec4_bit_op_tests!(UVec4, 0, 2);
+
+    impl_vec4_overflowing_tests!(u32, UVec4, BVec4);
+    impl_vec4_try_from_float_tests!(u32, UVec4);
+    impl_vec4_const_arithmetic_tests!(u32, UVec4);
+    impl_vec4_carrying_add_tests!(u32, UVec4, BVec4);
 }
 
 mod i64vec4 {
@@ -2309,6 +3293,30 @@ mod i64vec4 {
         i64vec4, BVec4, I16Vec4, I64Vec2, I64Vec3, I64Vec4, IVec4, U16Vec4, U64Vec4, UVec4,
     };
 
+    glam_test!(test_as_saturating, {
+        let v = I64Vec4::new(i64::MIN, i64::MAX, -1, 70000);
+        assert_eq!(
+            v.as_i16vec4_saturating(),
+            glam::I16Vec4::new(i16::MIN, i16::MAX, -1, i16::MAX)
+        );
+        assert_eq!(
+            v.as_u16vec4_saturating(),
+            glam::U16Vec4::new(0, u16::MAX, 0, u16::MAX)
+        );
+        assert_eq!(
+            v.as_ivec4_saturating(),
+            glam::IVec4::new(i32::MIN, i32::MAX, -1, 70000)
+        );
+        assert_eq!(
+            v.as_uvec4_saturating(),
+            glam::UVec4::new(0, u32::MAX, 0, 70000)
+        );
+        assert_eq!(
+            v.as_u64vec4_saturating(),
+            glam::U64Vec4::new(0, 9223372036854775807, 0, 70000)
+        );
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(32, mem::size_of::<I64Vec4>());
@@ -2388,6 +3396,10 @@ mod i64vec4 {
 
     impl_vec4_scalar_bit_op_tests!(I64Vec4, -2, 2);
     impl_vec4_bit_op_tests!(I64Vec4, -2, 2);
+
+    impl_vec4_overflowing_tests!(i64, I64Vec4, BVec4);
+    impl_vec4_try_from_float_tests!(i64, I64Vec4);
+    impl_vec4_const_arithmetic_tests!(i64, I64Vec4);
 }
 
 mod u64vec4 {
@@ -2395,6 +3407,30 @@ mod u64vec4 {
         u64vec4, BVec4, I16Vec4, I64Vec4, IVec4, U16Vec4, U64Vec2, U64Vec3, U64Vec4, UVec4,
     };
 
+    glam_test!(test_as_saturating, {
+        let v = U64Vec4::new(u64::MAX, 1, 40000, 0);
+        assert_eq!(
+            v.as_i16vec4_saturating(),
+            glam::I16Vec4::new(i16::MAX, 1, i16::MAX, 0)
+        );
+        assert_eq!(
+            v.as_u16vec4_saturating(),
+            glam::U16Vec4::new(u16::MAX, 1, 40000, 0)
+        );
+        assert_eq!(
+            v.as_ivec4_saturating(),
+            glam::IVec4::new(i32::MAX, 1, 40000, 0)
+        );
+        assert_eq!(
+            v.as_uvec4_saturating(),
+            glam::UVec4::new(u32::MAX, 1, 40000, 0)
+        );
+        assert_eq!(
+            v.as_i64vec4_saturating(),
+            glam::I64Vec4::new(i64::MAX, 1, 40000, 0)
+        );
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(32, mem::size_of::<U64Vec4>());
@@ -2469,4 +3505,9 @@ mod u64vec4 {
 
     impl_vec4_scalar_bit_op_tests!(U64Vec4, 0, 2);
     impl_vec4_bit_op_tests!(U64Vec4, 0, 2);
+
+    impl_vec4_overflowing_tests!(u64, U64Vec4, BVec4);
+    impl_vec4_try_from_float_tests!(u64, U64Vec4);
+    impl_vec4_const_arithmetic_tests!(u64, U64Vec4);
+    impl_vec4_carrying_add_tests!(u64, U64Vec4, BVec4);
 }